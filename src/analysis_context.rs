@@ -1,12 +1,22 @@
 use crate::config::ResolvedConfig;
 use crate::errors::{Result, UntangleError};
-use crate::parse::go::GoFrontend;
+use crate::parse::cpp::CppIncludePaths;
+use crate::parse::csharp::CSharpWorkspace;
+use crate::parse::custom::{self, CustomGrammar};
+use crate::parse::elixir::MixProject;
+use crate::parse::go::{GoFrontend, GoWorkspace};
+use crate::parse::jvm::JvmSourceIndex;
+use crate::parse::php::ComposerAutoload;
+use crate::parse::python::{PythonFileIndex, PythonReexports, PythonSourceRoots};
+use crate::parse::ruby::{RubyConstantIndex, RubyLoadPaths};
 use crate::parse::rust::RustFrontend;
+use crate::parse::typescript::TypeScriptProject;
 use crate::walk::{self, Language};
 use serde::Deserialize;
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::process::Command;
+use std::sync::Arc;
 
 pub struct AnalysisContext {
     pub scan_root: PathBuf,
@@ -16,7 +26,20 @@ pub struct AnalysisContext {
     pub all_files: Vec<(Language, PathBuf)>,
     pub go_modules: HashMap<PathBuf, String>,
     pub go_module_path: Option<String>,
+    pub go_workspace: Option<GoWorkspace>,
     pub rust_workspace: Option<RustWorkspaceContext>,
+    pub typescript_project: Option<Arc<TypeScriptProject>>,
+    pub jvm_index: Option<Arc<JvmSourceIndex>>,
+    pub csharp_workspace: Option<Arc<CSharpWorkspace>>,
+    pub cpp_include_paths: Option<CppIncludePaths>,
+    pub php_autoload: Option<Arc<ComposerAutoload>>,
+    pub mix_project: Option<Arc<MixProject>>,
+    pub python_source_roots: Option<PythonSourceRoots>,
+    pub python_reexports: Option<Arc<PythonReexports>>,
+    pub python_file_index: Option<Arc<PythonFileIndex>>,
+    pub ruby_load_paths: Option<RubyLoadPaths>,
+    pub ruby_constants: Option<Arc<RubyConstantIndex>>,
+    pub custom_grammars: HashMap<Language, Arc<CustomGrammar>>,
 }
 
 #[derive(Clone, Debug)]
@@ -67,6 +90,17 @@ impl RustWorkspaceContext {
         }
     }
 
+    /// A single crate whose sources are under `src/` of the project root.
+    pub fn single_crate(name: String) -> Self {
+        Self::from_packages(vec![RustPackage {
+            normalized_name: normalize_rust_crate_name(&name),
+            name,
+            manifest_dir: PathBuf::new(),
+            source_roots: vec![PathBuf::from("src")],
+            entry_source_root: PathBuf::from("src"),
+        }])
+    }
+
     pub fn find_package_for_file<'a>(
         &'a self,
         file_path: &Path,
@@ -145,6 +179,7 @@ pub fn build_analysis_context(
                 &config.include,
                 &exclude,
                 config.include_tests,
+                &config.frontends,
             )?;
             let mut files_by_lang = HashMap::new();
             if !files.is_empty() {
@@ -158,6 +193,7 @@ pub fn build_analysis_context(
                 &config.include,
                 &exclude,
                 config.include_tests,
+                &config.frontends,
             )?;
             let mut langs: Vec<Language> = files_by_lang.keys().copied().collect();
             langs.sort_by(|a, b| {
@@ -207,6 +243,10 @@ pub fn build_analysis_context(
         }
     });
 
+    let go_workspace = langs
+        .contains(&Language::Go)
+        .then(|| GoWorkspace::discover(&project_root));
+
     let rust_workspace = if langs.contains(&Language::Rust) {
         let rust_root = find_manifest_root(scan_root, "Cargo.toml").unwrap_or_else(|| {
             find_manifest_root(&project_root, "Cargo.toml").unwrap_or_else(|| project_root.clone())
@@ -216,6 +256,99 @@ pub fn build_analysis_context(
         None
     };
 
+    let typescript_project = if langs.contains(&Language::TypeScript) {
+        let files = files_by_lang
+            .get(&Language::TypeScript)
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        Some(Arc::new(
+            TypeScriptProject::discover(&project_root).with_files(&project_root, files),
+        ))
+    } else {
+        None
+    };
+
+    let jvm_index = if langs.contains(&Language::Java) || langs.contains(&Language::Kotlin) {
+        let jvm_files = [Language::Java, Language::Kotlin]
+            .iter()
+            .filter_map(|lang| files_by_lang.get(lang))
+            .flatten();
+        Some(Arc::new(JvmSourceIndex::from_files(
+            jvm_files,
+            &project_root,
+        )))
+    } else {
+        None
+    };
+
+    let csharp_workspace = files_by_lang
+        .get(&Language::CSharp)
+        .filter(|_| langs.contains(&Language::CSharp))
+        .map(|files| Arc::new(CSharpWorkspace::discover(&project_root, files)));
+
+    let cpp_include_paths = if langs.contains(&Language::Cpp) {
+        Some(CppIncludePaths::discover(
+            &project_root,
+            &config.cpp.include_paths,
+        ))
+    } else {
+        None
+    };
+
+    let php_autoload = if langs.contains(&Language::Php) {
+        Some(ComposerAutoload::discover(&project_root))
+            .filter(|autoload| !autoload.is_empty())
+            .map(Arc::new)
+    } else {
+        None
+    };
+
+    let mix_project = files_by_lang
+        .get(&Language::Elixir)
+        .filter(|_| langs.contains(&Language::Elixir))
+        .map(|files| Arc::new(MixProject::discover(&project_root, files)));
+
+    let python_source_roots = files_by_lang
+        .get(&Language::Python)
+        .filter(|_| langs.contains(&Language::Python))
+        .map(|files| {
+            PythonSourceRoots::discover(&project_root, &config.python.source_roots, files)
+        });
+    let python_reexports = python_source_roots
+        .as_ref()
+        .filter(|_| config.python.follow_reexports)
+        .zip(files_by_lang.get(&Language::Python))
+        .map(|(roots, files)| Arc::new(PythonReexports::discover(&project_root, files, roots)));
+    let python_file_index = python_source_roots
+        .as_ref()
+        .zip(files_by_lang.get(&Language::Python))
+        .map(|(roots, files)| Arc::new(PythonFileIndex::discover(&project_root, files, roots)));
+
+    let ruby_load_paths = files_by_lang
+        .get(&Language::Ruby)
+        .filter(|_| langs.contains(&Language::Ruby))
+        .map(|files| {
+            RubyLoadPaths::discover(
+                &project_root,
+                &config.ruby.load_path,
+                config.ruby.detect_rails,
+                files,
+            )
+        });
+    let ruby_constants = ruby_load_paths
+        .as_ref()
+        .filter(|_| config.ruby.zeitwerk)
+        .zip(files_by_lang.get(&Language::Ruby))
+        .map(|(load_paths, files)| {
+            Arc::new(RubyConstantIndex::discover(
+                &project_root,
+                &load_paths.paths(),
+                files,
+            ))
+        });
+
+    let custom_grammars = custom::load_grammars(&langs, config)?;
+
     Ok(AnalysisContext {
         scan_root: scan_root.to_path_buf(),
         project_root,
@@ -224,7 +357,20 @@ pub fn build_analysis_context(
         all_files,
         go_modules,
         go_module_path,
+        go_workspace,
         rust_workspace,
+        typescript_project,
+        jvm_index,
+        csharp_workspace,
+        cpp_include_paths,
+        php_autoload,
+        mix_project,
+        python_source_roots,
+        python_reexports,
+        python_file_index,
+        ruby_load_paths,
+        ruby_constants,
+        custom_grammars,
     })
 }
 
@@ -2,18 +2,24 @@ use crate::analysis_context::{build_analysis_context, canonicalize_root, resolve
 use crate::config::resolve::{resolve_config, CliOverrides};
 use crate::config::ResolvedConfig;
 use crate::errors::Result;
-use crate::formats::AnalyzeReportFormat;
-use crate::graph::builder::{GraphBuilder, ResolvedImport};
+use crate::formats::{AnalyzeReportFormat, HotspotSort};
+use crate::graph::builder::{ExternalImport, GraphBuilder, ResolvedImport};
+use crate::graph::classes::{module_type_counts, TypeCounts};
+use crate::graph::external::{split_external, ExternalDependencies};
+use crate::graph::granularity::Granularity;
 use crate::graph::ir::DepGraph;
+use crate::graph::load::edge_admitted;
+use crate::graph::symbols::SourceFile;
 use crate::insights::Insight;
-use crate::metrics::scc::find_non_trivial_sccs;
+use crate::metrics::scc::find_non_trivial_sccs_with;
 use crate::metrics::scc::SccInfo;
 use crate::metrics::summary::Summary;
 use crate::output::json::{LanguageStats, Metadata};
 use crate::parse::common::{ImportConfidence, RawImport, SourceLocation};
 use crate::parse::factory;
-use crate::parse::go::GoFrontend;
 use crate::parse::ParseFrontend;
+use crate::quality::complexity::FileTypes;
+use crate::quality::functions::frontend_for;
 use crate::walk::{self, Language};
 use rayon::prelude::*;
 use std::collections::HashMap;
@@ -26,13 +32,21 @@ pub struct AnalysisReportRequest {
     pub lang: Option<Language>,
     pub quiet: bool,
     pub include_tests: bool,
+    pub include_external: bool,
     pub include: Vec<String>,
     pub exclude: Vec<String>,
+    pub allow_custom_grammars: bool,
     pub format: Option<AnalyzeReportFormat>,
     pub top: Option<usize>,
+    pub sort: Option<HotspotSort>,
     pub threshold_fanout: Option<usize>,
     pub threshold_scc: Option<usize>,
+    pub cfg: Vec<String>,
+    pub exclude_cfg: Vec<String>,
+    pub go_tags: Vec<String>,
+    pub ignore_type_only: bool,
     pub insights_disabled: bool,
+    pub granularity: Granularity,
 }
 
 struct FileParseResult {
@@ -40,6 +54,8 @@ struct FileParseResult {
     imports: Vec<RawImport>,
     language: Language,
     original_file: PathBuf,
+    /// Types the file declares, when main-sequence insights need them
+    types: Option<FileTypes>,
 }
 
 pub struct AnalysisSnapshot {
@@ -48,6 +64,10 @@ pub struct AnalysisSnapshot {
     pub sccs: Vec<SccInfo>,
     pub metadata: Metadata,
     pub insights: Option<Vec<Insight>>,
+    /// Third-party coupling, when external packages are included
+    pub external: Option<ExternalDependencies>,
+    /// Types each module declares, when main-sequence insights are enabled
+    pub module_types: HashMap<PathBuf, TypeCounts>,
 }
 
 pub fn run_report(request: AnalysisReportRequest) -> Result<()> {
@@ -59,11 +79,17 @@ pub fn run_report(request: AnalysisReportRequest) -> Result<()> {
             lang: request.lang,
             quiet: request.quiet,
             include_tests: request.include_tests,
+            include_external: request.include_external,
             include: request.include,
             exclude: request.exclude,
+            allow_custom_grammars: request.allow_custom_grammars,
             fail_on: Vec::new(),
             threshold_fanout: request.threshold_fanout,
             threshold_scc: request.threshold_scc,
+            cfg: request.cfg,
+            exclude_cfg: request.exclude_cfg,
+            go_tags: request.go_tags,
+            ignore_type_only: request.ignore_type_only,
         },
     )?;
     let context = build_analysis_context(&scan_root, &project_root, &config)?;
@@ -73,28 +99,36 @@ pub fn run_report(request: AnalysisReportRequest) -> Result<()> {
         &context.project_root,
         &config,
         request.insights_disabled,
+        request.granularity,
     )?;
 
     let mut stdout = std::io::stdout();
     let top = request.top.or(config.analyze_report.top);
+    let sort = request.sort.unwrap_or(config.analyze_report.sort);
     match format {
         AnalyzeReportFormat::Json => crate::output::json::write_analyze_json(
             &mut stdout,
             &snapshot.graph,
             &snapshot.summary,
             &snapshot.sccs,
+            &snapshot.module_types,
             snapshot.metadata.clone(),
             top,
+            sort,
             snapshot.insights.clone(),
+            snapshot.external.clone(),
         )?,
         AnalyzeReportFormat::Text => crate::output::text::write_analyze_text(
             &mut stdout,
             &snapshot.graph,
             &snapshot.summary,
             &snapshot.sccs,
+            &snapshot.module_types,
             &snapshot.metadata,
             top,
+            sort,
             snapshot.insights.as_deref(),
+            snapshot.external.as_ref(),
         )?,
         AnalyzeReportFormat::Sarif => crate::output::sarif::write_sarif(
             &mut stdout,
@@ -115,6 +149,7 @@ pub fn build_analysis_snapshot(
     project_root: &std::path::Path,
     config: &ResolvedConfig,
     insights_disabled: bool,
+    granularity: Granularity,
 ) -> Result<AnalysisSnapshot> {
     let start = Instant::now();
     let context = build_analysis_context(scan_root, project_root, config)?;
@@ -138,7 +173,16 @@ pub fn build_analysis_snapshot(
         None
     };
 
-    let parse_results: Vec<FileParseResult> = context
+    let insights_enabled = !insights_disabled
+        && !matches!(
+            config.analyze_report.insights,
+            crate::config::InsightsConfig::Off
+        );
+    // Main-sequence insights need the types each module declares. They are
+    // extracted from the source read for the imports, not in another pass.
+    let collect_types = insights_enabled && config.rules.main_sequence.enabled;
+
+    let mut parse_results: Vec<FileParseResult> = context
         .all_files
         .par_iter()
         .filter_map(|(lang, file_path)| {
@@ -159,16 +203,33 @@ pub fn build_analysis_snapshot(
                 context.go_module_path.clone()
             };
 
-            let frontend =
-                factory::create_frontend(*lang, config, &file_go_module, &context.rust_workspace);
+            let frontend = match factory::create_frontend(*lang, config, &file_go_module, &context)
+            {
+                Ok(frontend) => frontend,
+                Err(error) => {
+                    tracing::warn!("Skipping {}: {}", file_path.display(), error);
+                    files_skipped.fetch_add(1, Ordering::Relaxed);
+                    return None;
+                }
+            };
             let imports = frontend.extract_imports(&source, file_path);
-            let source_module = factory::source_module_path(
+            let source_module = frontend.source_module(
+                &source,
                 file_path,
-                &context.project_root,
-                *lang,
-                context.rust_workspace.as_ref(),
+                factory::source_module_path(
+                    file_path,
+                    &context.project_root,
+                    *lang,
+                    context.rust_workspace.as_ref(),
+                    context.csharp_workspace.as_deref(),
+                ),
             );
 
+            let types = collect_types
+                .then(|| frontend_for(*lang))
+                .flatten()
+                .map(|complexity| complexity.extract_types(&source));
+
             if let Some(ref progress) = progress {
                 progress.inc(1);
             }
@@ -178,6 +239,7 @@ pub fn build_analysis_snapshot(
                 imports,
                 language: *lang,
                 original_file: file_path.clone(),
+                types,
             })
         })
         .collect();
@@ -187,6 +249,21 @@ pub fn build_analysis_snapshot(
     }
 
     let files_parsed = parse_results.len();
+    let type_files: Vec<SourceFile<FileTypes>> = parse_results
+        .iter_mut()
+        .filter_map(|result| {
+            Some(SourceFile {
+                symbols: result.types.take()?,
+                file: result
+                    .original_file
+                    .strip_prefix(&context.project_root)
+                    .unwrap_or(&result.original_file)
+                    .to_path_buf(),
+                module: result.source_module.clone(),
+                language: result.language,
+            })
+        })
+        .collect();
     let files_skipped = files_skipped.load(Ordering::Relaxed);
 
     let resolvers: HashMap<Language, Box<dyn ParseFrontend>> = context
@@ -194,33 +271,22 @@ pub fn build_analysis_snapshot(
         .iter()
         .filter(|&&lang| lang != Language::Go)
         .map(|&lang| {
-            let frontend = factory::create_frontend(
-                lang,
-                config,
-                &context.go_module_path,
-                &context.rust_workspace,
-            );
-            (lang, frontend)
+            let frontend =
+                factory::create_frontend(lang, config, &context.go_module_path, &context)?;
+            Ok((lang, frontend))
         })
-        .collect();
+        .collect::<Result<_>>()?;
     let go_resolvers: HashMap<PathBuf, Box<dyn ParseFrontend>> = context
         .go_modules
         .iter()
         .map(|(mod_root, mod_path)| {
-            let frontend = GoFrontend::with_module_path(mod_path.clone())
-                .with_exclude_stdlib(config.go.exclude_stdlib);
-            (
-                mod_root.clone(),
-                Box::new(frontend) as Box<dyn ParseFrontend>,
-            )
+            let frontend =
+                factory::create_frontend(Language::Go, config, &Some(mod_path.clone()), &context)?;
+            Ok((mod_root.clone(), frontend))
         })
-        .collect();
-    let fallback_go_resolver = factory::create_frontend(
-        Language::Go,
-        config,
-        &context.go_module_path,
-        &context.rust_workspace,
-    );
+        .collect::<Result<_>>()?;
+    let fallback_go_resolver =
+        factory::create_frontend(Language::Go, config, &context.go_module_path, &context)?;
     let go_files_by_module = group_go_files_by_module(&context);
     let files_by_lang_for_resolve: HashMap<Language, Vec<PathBuf>> = context
         .files_by_lang
@@ -228,6 +294,7 @@ pub fn build_analysis_snapshot(
         .map(|(&lang, files)| (lang, files.clone()))
         .collect();
 
+    let cfg_selection = config.rust.cfg_selection();
     let mut builder = GraphBuilder::new();
     let mut resolution_counts: HashMap<Language, (usize, usize)> = HashMap::new();
     for result in &parse_results {
@@ -243,43 +310,92 @@ pub fn build_analysis_snapshot(
 
         let counts = resolution_counts.entry(result.language).or_insert((0, 0));
         for raw in &result.imports {
-            if matches!(
-                raw.confidence,
-                ImportConfidence::External
-                    | ImportConfidence::Dynamic
-                    | ImportConfidence::Unresolvable
-            ) {
-                counts.1 += 1;
+            let Some(kind) = edge_admitted(raw, config, &cfg_selection) else {
+                // Imports the parser could not pin down count as unresolved.
+                if matches!(
+                    raw.confidence,
+                    ImportConfidence::Dynamic
+                        | ImportConfidence::Ambiguous
+                        | ImportConfidence::Unresolvable
+                ) {
+                    counts.1 += 1;
+                }
                 continue;
-            }
+            };
 
-            match resolver.resolve(raw, &context.project_root, &lang_files) {
-                Some(target_module) => {
-                    counts.0 += 1;
-                    builder.add_import(&ResolvedImport {
+            let targets = match raw.confidence {
+                ImportConfidence::External => Vec::new(),
+                _ => resolver.resolve_targets(raw, &context.project_root, &lang_files),
+            };
+            if targets.is_empty() {
+                counts.1 += 1;
+                let package = config
+                    .include_external
+                    .then(|| resolver.external_package(raw))
+                    .flatten();
+                if let Some(package) = package {
+                    builder.add_external(&ExternalImport {
                         source_module: result.source_module.clone(),
-                        target_module,
+                        package,
                         location: SourceLocation {
                             file: result.source_module.clone(),
                             line: raw.line,
                             column: raw.column,
+                            cfg: raw.cfg.as_ref().map(ToString::to_string),
                         },
                         language: Some(result.language),
+                        context: raw.context,
                     });
                 }
-                None => counts.1 += 1,
+                continue;
+            }
+            counts.0 += 1;
+            for target_module in targets {
+                builder.add_import(&ResolvedImport {
+                    source_module: result.source_module.clone(),
+                    target_module,
+                    location: SourceLocation {
+                        file: result.source_module.clone(),
+                        line: raw.line,
+                        column: raw.column,
+                        cfg: raw.cfg.as_ref().map(ToString::to_string),
+                    },
+                    language: Some(result.language),
+                    kind,
+                    context: raw.context,
+                });
             }
         }
     }
 
-    let graph = builder.build();
-    let summary = Summary::from_graph(&graph);
-    let sccs = find_non_trivial_sccs(&graph);
+    let (graph, external) = split_external(builder.build());
+    let external = config.include_external.then_some(external);
+    // Main-sequence insights are about architecture components, projected
+    // from the module graph whatever the report granularity.
+    let module_types = if collect_types {
+        module_type_counts(&type_files)
+    } else {
+        HashMap::new()
+    };
+    let components = if collect_types {
+        crate::architecture::policy::component_metrics(
+            &graph,
+            &context.project_root,
+            &config.analyze_architecture,
+            &module_types,
+        )
+    } else {
+        Vec::new()
+    };
+    let graph = granularity.project(&context, config, graph);
+    let scc_options = config.python.scc_options();
+    let summary = Summary::from_graph_with(&graph, scc_options);
+    let sccs = find_non_trivial_sccs_with(&graph, scc_options);
     let unresolved_imports: usize = resolution_counts
         .values()
         .map(|(_, unresolved)| unresolved)
         .sum();
-    let metadata = metadata_for(
+    let mut metadata = metadata_for(
         &context,
         &graph,
         &resolution_counts,
@@ -289,21 +405,17 @@ pub fn build_analysis_snapshot(
         unresolved_imports,
         start.elapsed().as_millis() as u64,
     );
-    let insights = if insights_disabled
-        || matches!(
-            config.analyze_report.insights,
-            crate::config::InsightsConfig::Off
-        ) {
-        None
-    } else {
-        Some(crate::insights::generate_insights_with_config(
+    metadata.granularity = granularity.to_string();
+    let insights = insights_enabled.then(|| {
+        crate::insights::generate_insights_with_config(
             &graph,
             &summary,
             &sccs,
             &config.rules,
             &config.overrides,
-        ))
-    };
+            &components,
+        )
+    });
 
     Ok(AnalysisSnapshot {
         graph,
@@ -311,6 +423,8 @@ pub fn build_analysis_snapshot(
         sccs,
         metadata,
         insights,
+        external,
+        module_types,
     })
 }
 
@@ -368,6 +482,7 @@ fn resolver_context<'a>(
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn metadata_for(
     context: &crate::analysis_context::AnalysisContext,
     graph: &crate::graph::ir::DepGraph,
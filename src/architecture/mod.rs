@@ -1,7 +1,8 @@
 pub mod policy;
 
 use crate::errors::Result;
-use crate::graph::ir::{DepGraph, GraphNode};
+use crate::graph::ir::{DepGraph, GraphNode, NodeKind};
+use crate::walk::Language;
 use petgraph::visit::EdgeRef;
 use serde::Serialize;
 use std::collections::{BTreeMap, BTreeSet, HashSet, VecDeque};
@@ -78,6 +79,10 @@ pub fn project_architecture(graph: &DepGraph, root: &Path, level: usize) -> Arch
 
     for idx in graph.node_indices() {
         let node = &graph[idx];
+        // Third-party packages are not components; policy checks them separately.
+        if node.kind == NodeKind::External {
+            continue;
+        }
         let component_id = project_node(node, level);
         module_to_component.insert(idx, component_id.clone());
         *component_module_counts.entry(component_id).or_default() += 1;
@@ -238,6 +243,11 @@ fn project_node(node: &GraphNode, level: usize) -> String {
 }
 
 fn module_segments(node: &GraphNode) -> Vec<String> {
+    if node.language == Some(Language::CSharp) {
+        // C# module paths start with the assembly name, which may itself
+        // contain dots; keep it as the first segment so level 1 = assembly.
+        return assembly_segments(&node.path);
+    }
     let mut segments = name_segments(&node.name);
     trim_leading_boilerplate(&mut segments);
     trim_terminal_module_marker(&mut segments);
@@ -253,6 +263,21 @@ fn module_segments(node: &GraphNode) -> Vec<String> {
     }
 }
 
+fn assembly_segments(path: &Path) -> Vec<String> {
+    let segments: Vec<String> = path
+        .components()
+        .filter_map(|component| match component {
+            Component::Normal(value) => Some(value.to_string_lossy().into_owned()),
+            _ => None,
+        })
+        .collect();
+    if segments.is_empty() {
+        vec![ROOT_FALLBACK.to_string()]
+    } else {
+        segments
+    }
+}
+
 fn path_segments(path: &Path) -> Vec<String> {
     let mut segments = Vec::new();
     for component in path.components() {
@@ -441,10 +466,7 @@ fn greedy_order(nodes: &BTreeSet<String>, edges: &BTreeSet<EdgeKey>) -> Vec<Stri
             Side::Right => right.push(choice.0.clone()),
         }
         remaining.remove(&choice.0);
-        active_edges = active_edges
-            .into_iter()
-            .filter(|edge| edge.from != choice.0 && edge.to != choice.0)
-            .collect();
+        active_edges.retain(|edge| edge.from != choice.0 && edge.to != choice.0);
     }
 
     right.reverse();
@@ -692,6 +714,7 @@ fn incoming_map(
 mod tests {
     use super::*;
     use crate::graph::ir::{EdgeKind, GraphEdge, NodeKind};
+    use crate::parse::common::ImportContext;
     use std::path::PathBuf;
 
     fn add_node(graph: &mut DepGraph, name: &str, path: &str) -> petgraph::graph::NodeIndex {
@@ -719,6 +742,8 @@ mod tests {
                 kind: EdgeKind::Import,
                 source_locations: vec![],
                 weight: 1,
+                cfg: None,
+                context: ImportContext::Runtime,
             },
         );
         graph.add_edge(
@@ -728,6 +753,8 @@ mod tests {
                 kind: EdgeKind::Import,
                 source_locations: vec![],
                 weight: 1,
+                cfg: None,
+                context: ImportContext::Runtime,
             },
         );
         graph.add_edge(
@@ -737,6 +764,8 @@ mod tests {
                 kind: EdgeKind::Import,
                 source_locations: vec![],
                 weight: 1,
+                cfg: None,
+                context: ImportContext::Runtime,
             },
         );
 
@@ -767,6 +796,8 @@ mod tests {
                     kind: EdgeKind::Import,
                     source_locations: vec![],
                     weight: 1,
+                    cfg: None,
+                    context: ImportContext::Runtime,
                 },
             );
         }
@@ -823,4 +854,18 @@ mod tests {
 
         assert_eq!(module_segments(&node), vec!["api", "client"]);
     }
+
+    #[test]
+    fn groups_csharp_namespaces_by_assembly() {
+        let node = GraphNode {
+            kind: NodeKind::Module,
+            path: PathBuf::from("Acme.Core/Models"),
+            name: "Acme.Core.Models".to_string(),
+            span: None,
+            language: Some(Language::CSharp),
+        };
+
+        assert_eq!(module_segments(&node), vec!["Acme.Core", "Models"]);
+        assert_eq!(project_node(&node, 1), "Acme.Core");
+    }
 }
@@ -3,13 +3,14 @@ use crate::architecture::{
 };
 use crate::config::{ArchitectureException, ResolvedArchitectureConfig};
 use crate::errors::{Result, UntangleError};
-use crate::graph::ir::DepGraph;
+use crate::graph::classes::TypeCounts;
+use crate::graph::ir::{DepGraph, NodeKind};
 use crate::parse::common::SourceLocation;
 use petgraph::algo::tarjan_scc;
 use petgraph::graph::DiGraph;
 use petgraph::visit::EdgeRef;
 use serde::Serialize;
-use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::io::Write;
 use std::path::{Path, PathBuf};
 use toml_edit::{value, Array, ArrayOfTables, DocumentMut, Item, Table};
@@ -26,6 +27,7 @@ pub enum ArchitectureVerdict {
 pub enum ArchitectureViolationKind {
     Allowlist,
     ForbiddenRule,
+    ForbiddenPackage,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -55,6 +57,13 @@ pub struct ArchitectureComponentMetric {
     pub fan_in: usize,
     pub fan_out: usize,
     pub instability: f64,
+    /// Share of the component's types that are abstract; absent when it
+    /// declares no types or types were not counted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub abstractness: Option<f64>,
+    /// Distance from the main sequence, |A + I - 1|
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub distance: Option<f64>,
     pub feedback: bool,
 }
 
@@ -123,6 +132,8 @@ struct ProjectionData {
     active_components: BTreeSet<String>,
     dependency_stats: BTreeMap<ComponentEdge, DependencyStats>,
     evidence: BTreeMap<ComponentEdge, BTreeMap<ModuleEdge, Vec<SourceLocation>>>,
+    /// Imports of third-party packages, keyed by (component, package)
+    package_evidence: BTreeMap<ComponentEdge, BTreeMap<ModuleEdge, Vec<SourceLocation>>>,
     feedback_edges: BTreeSet<ComponentEdge>,
 }
 
@@ -131,12 +142,25 @@ pub fn check_graph(
     root: &Path,
     config: &ResolvedArchitectureConfig,
     level_override: Option<usize>,
+) -> ArchitectureCheckResult {
+    check_graph_with_types(graph, root, config, level_override, &HashMap::new())
+}
+
+/// Check the graph, also reporting abstractness and distance from the main
+/// sequence from the types each module declares.
+pub fn check_graph_with_types(
+    graph: &DepGraph,
+    root: &Path,
+    config: &ResolvedArchitectureConfig,
+    level_override: Option<usize>,
+    types: &HashMap<PathBuf, TypeCounts>,
 ) -> ArchitectureCheckResult {
     let level = level_override.unwrap_or(config.level).max(1);
     let data = collect_projection_data(graph, root, level, &config.ignored_components);
     let cycles = collect_cycles(&data.active_components, &data.dependency_stats);
     let feedback_members = feedback_members(&data.feedback_edges);
-    let components = collect_component_metrics(&data, &feedback_members);
+    let component_types = component_type_counts(graph, level, &data.active_components, types);
+    let components = collect_component_metrics(&data, &feedback_members, &component_types);
     let (dependencies, violations, waived_dependency_count) = evaluate_dependencies(&data, config);
     let verdict = if (!violations.is_empty() && config.fail_on_violations)
         || (!cycles.is_empty() && config.fail_on_cycles)
@@ -169,6 +193,21 @@ pub fn check_graph(
     }
 }
 
+/// Per-component metrics at the configured level, including abstractness and
+/// distance from the main sequence.
+pub fn component_metrics(
+    graph: &DepGraph,
+    root: &Path,
+    config: &ResolvedArchitectureConfig,
+    types: &HashMap<PathBuf, TypeCounts>,
+) -> Vec<ArchitectureComponentMetric> {
+    let level = config.level.max(1);
+    let data = collect_projection_data(graph, root, level, &config.ignored_components);
+    let feedback_members = feedback_members(&data.feedback_edges);
+    let component_types = component_type_counts(graph, level, &data.active_components, types);
+    collect_component_metrics(&data, &feedback_members, &component_types)
+}
+
 pub fn infer_starter_policy(
     graph: &DepGraph,
     root: &Path,
@@ -183,7 +222,7 @@ pub fn infer_starter_policy(
         allowed_dependencies.insert(component.clone(), Vec::new());
     }
 
-    for (edge, _) in &data.dependency_stats {
+    for edge in data.dependency_stats.keys() {
         allowed_dependencies
             .entry(edge.0.clone())
             .or_default()
@@ -235,18 +274,23 @@ pub fn write_check_text<W: Write>(writer: &mut W, result: &ArchitectureCheckResu
     writeln!(writer, "-----------------")?;
     writeln!(
         writer,
-        "{:<24} {:>6} {:>7} {:>7} {:>12} {:>9}",
-        "Component", "Layer", "Modules", "FanIn", "FanOut", "Feedback"
+        "{:<24} {:>6} {:>7} {:>7} {:>12} {:>6} {:>6} {:>6} {:>9}",
+        "Component", "Layer", "Modules", "FanIn", "FanOut", "I", "A", "D", "Feedback"
     )?;
+    let optional =
+        |value: Option<f64>| value.map_or("-".to_string(), |value| format!("{value:.2}"));
     for component in &result.components {
         writeln!(
             writer,
-            "{:<24} {:>6} {:>7} {:>7} {:>12} {:>9}",
+            "{:<24} {:>6} {:>7} {:>7} {:>12} {:>6.2} {:>6} {:>6} {:>9}",
             component.id,
             component.layer,
             component.module_count,
             component.fan_in,
             component.fan_out,
+            component.instability,
+            optional(component.abstractness),
+            optional(component.distance),
             if component.feedback { "yes" } else { "no" }
         )?;
     }
@@ -353,6 +397,7 @@ pub fn write_starter_policy_file(
 
     let has_existing_policy = architecture.contains_key("allowed_dependencies")
         || architecture.contains_key("forbidden_dependencies")
+        || architecture.contains_key("forbidden_packages")
         || architecture.contains_key("exceptions")
         || architecture.contains_key("ignored_components")
         || architecture.contains_key("fail_on_violations")
@@ -369,6 +414,7 @@ pub fn write_starter_policy_file(
     architecture["fail_on_cycles"] = value(policy.fail_on_cycles);
     architecture.remove("ignored_components");
     architecture.remove("forbidden_dependencies");
+    architecture.remove("forbidden_packages");
     architecture.remove("exceptions");
     architecture.remove("allowed_dependencies");
 
@@ -415,11 +461,23 @@ fn collect_projection_data(
 
     let mut dependency_stats = BTreeMap::new();
     let mut evidence = BTreeMap::new();
+    let mut package_evidence = BTreeMap::new();
 
     for edge in graph.edge_references() {
         let from_module = graph[edge.source()].name.clone();
         let to_module = graph[edge.target()].name.clone();
         let from = project_component_id(&graph[edge.source()], level);
+        if graph[edge.target()].kind == NodeKind::External {
+            if active_components.contains(&from) {
+                package_evidence
+                    .entry((from, to_module.clone()))
+                    .or_insert_with(BTreeMap::new)
+                    .entry((from_module, to_module))
+                    .or_insert_with(Vec::new)
+                    .extend(edge.weight().source_locations.clone());
+            }
+            continue;
+        }
         let to = project_component_id(&graph[edge.target()], level);
         if from == to || !active_components.contains(&from) || !active_components.contains(&to) {
             continue;
@@ -445,13 +503,40 @@ fn collect_projection_data(
         active_components,
         dependency_stats,
         evidence,
+        package_evidence,
         feedback_edges,
     }
 }
 
+fn component_type_counts(
+    graph: &DepGraph,
+    level: usize,
+    active_components: &BTreeSet<String>,
+    types: &HashMap<PathBuf, TypeCounts>,
+) -> BTreeMap<String, TypeCounts> {
+    let mut counts: BTreeMap<String, TypeCounts> = BTreeMap::new();
+    for node in graph.node_weights() {
+        let Some(module) = types.get(&node.path) else {
+            continue;
+        };
+        if node.kind == NodeKind::External {
+            continue;
+        }
+        let component = project_component_id(node, level);
+        if !active_components.contains(&component) {
+            continue;
+        }
+        let entry = counts.entry(component).or_default();
+        entry.types += module.types;
+        entry.abstract_types += module.abstract_types;
+    }
+    counts
+}
+
 fn collect_component_metrics(
     data: &ProjectionData,
     feedback_members: &HashSet<String>,
+    component_types: &BTreeMap<String, TypeCounts>,
 ) -> Vec<ArchitectureComponentMetric> {
     let layers = layer_map(&data.projected);
     let fan_in = neighbor_counts(&data.active_components, &data.dependency_stats, true);
@@ -469,6 +554,11 @@ fn collect_component_metrics(
         } else {
             out_count as f64 / (in_count + out_count) as f64
         };
+        let abstractness = component_types
+            .get(&node.id)
+            .filter(|counts| counts.types > 0)
+            .map(|counts| counts.abstract_types as f64 / counts.types as f64);
+        let distance = abstractness.map(|abstractness| (abstractness + instability - 1.0).abs());
         metrics.push(ArchitectureComponentMetric {
             id: node.id.clone(),
             layer: *layers.get(&node.id).unwrap_or(&0),
@@ -476,6 +566,8 @@ fn collect_component_metrics(
             fan_in: in_count,
             fan_out: out_count,
             instability: (instability * 1000.0).round() / 1000.0,
+            abstractness: abstractness.map(|value| (value * 1000.0).round() / 1000.0),
+            distance: distance.map(|value| (value * 1000.0).round() / 1000.0),
             feedback: feedback_members.contains(&node.id),
         });
     }
@@ -584,7 +676,8 @@ fn evaluate_dependencies(
         let mut violated = false;
         let mut waived = false;
         if disallowed_by_allowlist || forbidden {
-            let evidence = build_violation_evidence(data, edge, &config.exceptions);
+            let evidence =
+                build_violation_evidence(data.evidence.get(edge), edge, &config.exceptions);
             if evidence.is_empty() {
                 waived = true;
                 waived_dependency_count += 1;
@@ -614,6 +707,27 @@ fn evaluate_dependencies(
         });
     }
 
+    for (edge, module_edges) in &data.package_evidence {
+        let forbidden = config
+            .forbidden_packages
+            .iter()
+            .any(|rule| rule.from == edge.0 && rule.package == edge.1);
+        if !forbidden {
+            continue;
+        }
+        let evidence = build_violation_evidence(Some(module_edges), edge, &config.exceptions);
+        if evidence.is_empty() {
+            waived_dependency_count += 1;
+        } else {
+            violations.push(ArchitectureViolation {
+                from: edge.0.clone(),
+                to: edge.1.clone(),
+                kind: ArchitectureViolationKind::ForbiddenPackage,
+                evidence,
+            });
+        }
+    }
+
     dependencies.sort_by(|a, b| a.from.cmp(&b.from).then(a.to.cmp(&b.to)));
     violations.sort_by(|a, b| a.from.cmp(&b.from).then(a.to.cmp(&b.to)));
     (dependencies, violations, waived_dependency_count)
@@ -631,11 +745,11 @@ fn allowlist_permits(config: &ResolvedArchitectureConfig, from: &str, to: &str)
 }
 
 fn build_violation_evidence(
-    data: &ProjectionData,
+    module_edges: Option<&BTreeMap<ModuleEdge, Vec<SourceLocation>>>,
     edge: &ComponentEdge,
     exceptions: &[ArchitectureException],
 ) -> Vec<ArchitectureViolationEvidence> {
-    let Some(module_edges) = data.evidence.get(edge) else {
+    let Some(module_edges) = module_edges else {
         return Vec::new();
     };
 
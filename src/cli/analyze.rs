@@ -2,10 +2,11 @@ use crate::analysis_report::{self, AnalysisReportRequest};
 use crate::cli::architecture;
 use crate::cli::architecture_check;
 use crate::cli::architecture_init;
-use crate::cli::common::{RuntimeArgs, TargetArgs};
+use crate::cli::common::{parse_granularity, CfgArgs, RuntimeArgs, TargetArgs};
 use crate::cli::graph;
 use crate::errors::Result;
-use crate::formats::AnalyzeReportFormat;
+use crate::formats::{AnalyzeReportFormat, HotspotSort};
+use crate::graph::granularity::Granularity;
 use clap::{Args, Subcommand, ValueEnum};
 
 #[derive(Debug, Args)]
@@ -45,6 +46,9 @@ pub struct ReportArgs {
     #[command(flatten)]
     pub runtime: RuntimeArgs,
 
+    #[command(flatten)]
+    pub cfg: CfgArgs,
+
     /// Output format
     #[arg(long)]
     pub format: Option<AnalyzeReportFormat>,
@@ -53,6 +57,10 @@ pub struct ReportArgs {
     #[arg(long)]
     pub top: Option<usize>,
 
+    /// Metric to rank hotspots by
+    #[arg(long)]
+    pub sort: Option<HotspotSort>,
+
     /// Fan-out threshold for reporting
     #[arg(long)]
     pub threshold_fanout: Option<usize>,
@@ -61,6 +69,14 @@ pub struct ReportArgs {
     #[arg(long)]
     pub threshold_scc: Option<usize>,
 
+    /// Leave type-checking-only imports out of cycle detection
+    #[arg(long)]
+    pub ignore_type_only: bool,
+
+    /// Report imports of third-party packages, per package and per module
+    #[arg(long)]
+    pub include_external: bool,
+
     /// Insight rendering mode
     #[arg(long, default_value = "auto")]
     pub insights: InsightsMode,
@@ -68,6 +84,10 @@ pub struct ReportArgs {
     /// Deprecated alias for `--insights off`
     #[arg(long, hide = true)]
     pub no_insights: bool,
+
+    /// Graph nodes: `module`, `directory[:depth]`, `class` or `function`
+    #[arg(long, default_value = "module", value_parser = parse_granularity)]
+    pub granularity: Granularity,
 }
 
 pub fn run(args: &AnalyzeArgs) -> Result<()> {
@@ -77,13 +97,21 @@ pub fn run(args: &AnalyzeArgs) -> Result<()> {
             lang: args.target.lang,
             quiet: args.runtime.quiet,
             include_tests: args.target.include_tests,
+            include_external: args.include_external,
             include: args.target.include.clone(),
             exclude: args.target.exclude.clone(),
+            allow_custom_grammars: args.target.allow_custom_grammars,
             format: args.format,
             top: args.top,
+            sort: args.sort,
             threshold_fanout: args.threshold_fanout,
             threshold_scc: args.threshold_scc,
+            cfg: args.cfg.cfg.clone(),
+            exclude_cfg: args.cfg.exclude_cfg.clone(),
+            go_tags: args.cfg.tags.clone(),
+            ignore_type_only: args.ignore_type_only,
             insights_disabled: args.no_insights || matches!(args.insights, InsightsMode::Off),
+            granularity: args.granularity,
         }),
         AnalyzeCommand::Graph(args) => graph::run(args),
         AnalyzeCommand::Architecture(args) => architecture::run(args),
@@ -32,6 +32,7 @@ impl ArchitectureArgs {
             include_tests: self.target.include_tests,
             include: self.target.include.clone(),
             exclude: self.target.exclude.clone(),
+            allow_custom_grammars: self.target.allow_custom_grammars,
             ..Default::default()
         }
     }
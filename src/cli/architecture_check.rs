@@ -1,10 +1,11 @@
-use crate::analysis_context::{canonicalize_root, resolve_project_root};
+use crate::analysis_context::{build_analysis_context, canonicalize_root, resolve_project_root};
 use crate::architecture::policy;
 use crate::cli::common::{RuntimeArgs, TargetArgs};
 use crate::config::resolve::{resolve_config, CliOverrides};
 use crate::errors::Result;
 use crate::formats::ArchitectureCheckFormat;
-use crate::graph::load::load_dependency_graph;
+use crate::graph::classes::count_module_types;
+use crate::graph::load::build_dependency_graph;
 use clap::Args;
 
 #[derive(Debug, Args)]
@@ -32,6 +33,7 @@ impl ArchitectureCheckArgs {
             include_tests: self.target.include_tests,
             include: self.target.include.clone(),
             exclude: self.target.exclude.clone(),
+            allow_custom_grammars: self.target.allow_custom_grammars,
             ..Default::default()
         }
     }
@@ -41,13 +43,19 @@ pub fn run(args: &ArchitectureCheckArgs) -> Result<()> {
     let path = args.target.path.clone().unwrap_or_else(|| ".".into());
     let scan_root = canonicalize_root(&path)?;
     let project_root = resolve_project_root(&scan_root, args.target.lang);
-    let config = resolve_config(&project_root, &args.to_cli_overrides())?;
-    let graph = load_dependency_graph(&scan_root, &project_root, &config)?;
-    let result = policy::check_graph(
+    let mut config = resolve_config(&project_root, &args.to_cli_overrides())?;
+    // Package rules need the third-party imports in the graph.
+    if !config.analyze_architecture.forbidden_packages.is_empty() {
+        config.include_external = true;
+    }
+    let context = build_analysis_context(&scan_root, &project_root, &config)?;
+    let graph = build_dependency_graph(&context, &config)?;
+    let result = policy::check_graph_with_types(
         &graph,
         &project_root,
         &config.analyze_architecture,
         args.level,
+        &count_module_types(&context, &config),
     );
 
     let mut stdout = std::io::stdout();
@@ -1,3 +1,4 @@
+use crate::graph::granularity::Granularity;
 use crate::walk::Language;
 use clap::Args;
 use std::path::PathBuf;
@@ -22,6 +23,27 @@ pub struct TargetArgs {
     /// Exclude glob patterns
     #[arg(long)]
     pub exclude: Vec<String>,
+
+    /// Load the tree-sitter grammar libraries named by `[frontends]` tables.
+    /// They are native code, so a project config alone cannot load them
+    #[arg(long)]
+    pub allow_custom_grammars: bool,
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct CfgArgs {
+    /// Treat a Rust cfg option as enabled (`test`, `feature=serde`)
+    #[arg(long = "cfg", value_name = "CFG")]
+    pub cfg: Vec<String>,
+
+    /// Treat a Rust cfg option as disabled, dropping edges that need it
+    #[arg(long = "exclude-cfg", value_name = "CFG")]
+    pub exclude_cfg: Vec<String>,
+
+    /// Go build tags to build for (`linux,amd64,integration`); files whose
+    /// `//go:build` line or `_GOOS`/`_GOARCH` suffix is not satisfied are skipped
+    #[arg(long = "tags", value_name = "TAGS", value_delimiter = ',')]
+    pub tags: Vec<String>,
 }
 
 #[derive(Debug, Clone, Args)]
@@ -31,10 +53,16 @@ pub struct RuntimeArgs {
     pub quiet: bool,
 }
 
+/// Parse `--lang`. Names that are not built in may be custom languages the
+/// config has yet to declare; config resolution rejects them if it doesn't.
 pub fn parse_language(s: &str) -> std::result::Result<Language, String> {
     s.parse()
 }
 
+pub fn parse_granularity(s: &str) -> std::result::Result<Granularity, String> {
+    s.parse()
+}
+
 pub fn resolve_path(path: &Option<PathBuf>) -> PathBuf {
     path.clone().unwrap_or_else(|| PathBuf::from("."))
 }
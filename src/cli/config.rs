@@ -1,7 +1,10 @@
 use crate::config::resolve::{resolve_config, CliOverrides};
+use crate::config::ResolvedConfig;
 use crate::errors::Result;
+use crate::parse::ruby::RubyLoadPaths;
+use crate::walk::{self, Language};
 use clap::{Args, Subcommand};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 #[derive(Debug, Args)]
 pub struct ConfigArgs {
@@ -33,6 +36,11 @@ pub fn run(args: &ConfigArgs) -> Result<()> {
             let mut stdout = std::io::stdout();
             crate::config::show::render_show(&mut stdout, &config)
                 .map_err(crate::errors::UntangleError::Io)?;
+            crate::config::show::render_inferred(
+                &mut stdout,
+                &inferred_ruby_load_paths(&working_dir, &config),
+            )
+            .map_err(crate::errors::UntangleError::Io)?;
         }
         ConfigAction::Explain { category, path } => {
             let working_dir = resolve_working_dir(path)?;
@@ -45,6 +53,24 @@ pub fn run(args: &ConfigArgs) -> Result<()> {
     Ok(())
 }
 
+fn inferred_ruby_load_paths(working_dir: &Path, config: &ResolvedConfig) -> RubyLoadPaths {
+    if !config.ruby.detect_rails {
+        return RubyLoadPaths::default();
+    }
+    let mut exclude = config.exclude.clone();
+    exclude.extend(config.ignore_patterns.iter().cloned());
+    let files = walk::discover_files(
+        working_dir,
+        Language::Ruby,
+        &config.include,
+        &exclude,
+        config.include_tests,
+        &config.frontends,
+    )
+    .unwrap_or_default();
+    RubyLoadPaths::discover(working_dir, &config.ruby.load_path, true, &files)
+}
+
 fn resolve_working_dir(path: &Option<PathBuf>) -> Result<PathBuf> {
     let p = path.clone().unwrap_or_else(|| PathBuf::from("."));
     p.canonicalize()
@@ -31,6 +31,10 @@ pub struct DiffArgs {
     /// Fail-on conditions (comma-separated)
     #[arg(long, value_delimiter = ',')]
     pub fail_on: Vec<String>,
+
+    /// Leave type-checking-only imports out of cycle detection
+    #[arg(long)]
+    pub ignore_type_only: bool,
 }
 
 impl DiffArgs {
@@ -41,7 +45,9 @@ impl DiffArgs {
             include_tests: self.target.include_tests,
             include: self.target.include.clone(),
             exclude: self.target.exclude.clone(),
+            allow_custom_grammars: self.target.allow_custom_grammars,
             fail_on: self.fail_on.clone(),
+            ignore_type_only: self.ignore_type_only,
             ..Default::default()
         }
     }
@@ -58,7 +64,7 @@ pub fn run(args: &DiffArgs) -> Result<()> {
         .map_err(|_| UntangleError::NoFiles { path: path.clone() })?;
     let config = resolve_config(&root, &args.to_cli_overrides())?;
     let repo = crate::git::open_repo(&root)?;
-    let langs = determine_languages(&root, config.lang)?;
+    let langs = determine_languages(&root, config.lang, &config.frontends)?;
 
     let mut exclude = config.exclude.clone();
     exclude.extend(config.ignore_patterns.iter().cloned());
@@ -83,9 +89,8 @@ pub fn run(args: &DiffArgs) -> Result<()> {
         include: &config.include,
         exclude: &exclude,
         include_tests: config.include_tests,
-        go_exclude_stdlib: config.go.exclude_stdlib,
-        ruby_load_paths: &config.ruby_load_paths(),
-        ruby_zeitwerk: config.ruby.zeitwerk,
+        config: &config,
+        scc_options: config.python.scc_options(),
         conditions: &conditions,
         architecture_config: has_architecture_policy.then_some(&config.analyze_architecture),
     })?;
@@ -136,11 +141,12 @@ fn has_architecture_policy(config: &ResolvedArchitectureConfig) -> bool {
 fn determine_languages(
     root: &std::path::Path,
     configured: Option<Language>,
+    frontends: &[crate::config::ResolvedFrontend],
 ) -> Result<Vec<Language>> {
     match configured {
         Some(lang) => Ok(vec![lang]),
         None => {
-            let detected = crate::walk::detect_languages(root);
+            let detected = crate::walk::detect_languages(root, frontends);
             if detected.is_empty() {
                 Err(UntangleError::NoFiles {
                     path: root.to_path_buf(),
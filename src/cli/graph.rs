@@ -1,9 +1,11 @@
-use crate::analysis_context::{canonicalize_root, resolve_project_root};
-use crate::cli::common::{RuntimeArgs, TargetArgs};
+use crate::analysis_context::{build_analysis_context, canonicalize_root, resolve_project_root};
+use crate::cli::common::{parse_granularity, CfgArgs, RuntimeArgs, TargetArgs};
 use crate::config::resolve::{resolve_config, CliOverrides};
 use crate::errors::Result;
 use crate::formats::GraphFormat;
-use crate::graph::load::load_dependency_graph;
+use crate::graph::granularity::Granularity;
+use crate::graph::ir::EdgeKind;
+use crate::graph::load::build_dependency_graph;
 use clap::Args;
 
 #[derive(Debug, Args)]
@@ -14,9 +16,20 @@ pub struct GraphArgs {
     #[command(flatten)]
     pub runtime: RuntimeArgs,
 
+    #[command(flatten)]
+    pub cfg: CfgArgs,
+
     /// Output format
     #[arg(long)]
     pub format: Option<GraphFormat>,
+
+    /// Graph nodes: `module`, `directory[:depth]`, `class` (types) or `function` (call graph)
+    #[arg(long, default_value = "module", value_parser = parse_granularity)]
+    pub granularity: Granularity,
+
+    /// Add a node per third-party package imported (module and directory graphs)
+    #[arg(long)]
+    pub include_external: bool,
 }
 
 impl GraphArgs {
@@ -25,8 +38,13 @@ impl GraphArgs {
             lang: self.target.lang,
             quiet: self.runtime.quiet,
             include_tests: self.target.include_tests,
+            include_external: self.include_external,
             include: self.target.include.clone(),
             exclude: self.target.exclude.clone(),
+            allow_custom_grammars: self.target.allow_custom_grammars,
+            cfg: self.cfg.cfg.clone(),
+            exclude_cfg: self.cfg.exclude_cfg.clone(),
+            go_tags: self.cfg.tags.clone(),
             ..Default::default()
         }
     }
@@ -38,7 +56,12 @@ pub fn run(args: &GraphArgs) -> Result<()> {
     let project_root = resolve_project_root(&scan_root, args.target.lang);
     let config = resolve_config(&project_root, &args.to_cli_overrides())?;
     let format = args.format.unwrap_or(config.analyze_graph.format);
-    let graph = load_dependency_graph(&scan_root, &project_root, &config)?;
+    let context = build_analysis_context(&scan_root, &project_root, &config)?;
+    let graph = args.granularity.project(
+        &context,
+        &config,
+        build_dependency_graph(&context, &config)?,
+    );
     let mut stdout = std::io::stdout();
 
     match format {
@@ -49,11 +72,21 @@ pub fn run(args: &GraphArgs) -> Result<()> {
                 .edge_indices()
                 .map(|e| {
                     let (s, t) = graph.edge_endpoints(e).unwrap();
-                    serde_json::json!({
+                    let mut edge = serde_json::json!({
                         "from": graph[s].name,
                         "to": graph[t].name,
                         "source_locations": graph[e].source_locations,
-                    })
+                    });
+                    if graph[e].kind != EdgeKind::Import {
+                        edge["kind"] = serde_json::json!(graph[e].kind);
+                    }
+                    if let Some(cfg) = &graph[e].cfg {
+                        edge["cfg"] = serde_json::json!(cfg);
+                    }
+                    if !graph[e].context.is_runtime() {
+                        edge["context"] = serde_json::json!(graph[e].context);
+                    }
+                    edge
                 })
                 .collect();
             serde_json::to_writer_pretty(
@@ -156,6 +156,7 @@ impl FunctionQualityArgs {
             include_tests: self.target.include_tests,
             include: self.target.include.clone(),
             exclude: self.target.exclude.clone(),
+            allow_custom_grammars: self.target.allow_custom_grammars,
             ..Default::default()
         }
     }
@@ -169,6 +170,7 @@ impl ProjectQualityArgs {
             include_tests: self.target.include_tests,
             include: self.target.include.clone(),
             exclude: self.target.exclude.clone(),
+            allow_custom_grammars: self.target.allow_custom_grammars,
             ..Default::default()
         }
     }
@@ -182,6 +184,7 @@ impl ReportQualityArgs {
             include_tests: self.target.include_tests,
             include: self.target.include.clone(),
             exclude: self.target.exclude.clone(),
+            allow_custom_grammars: self.target.allow_custom_grammars,
             ..Default::default()
         }
     }
@@ -195,6 +198,7 @@ impl SpecQualityArgs {
             include_tests: true,
             include: self.target.include.clone(),
             exclude: self.target.exclude.clone(),
+            allow_custom_grammars: self.target.allow_custom_grammars,
             ..Default::default()
         }
     }
@@ -7,8 +7,10 @@ pub mod show;
 
 use crate::formats::{
     AnalyzeReportFormat, ArchitectureCheckFormat, ArchitectureFormat, DiffFormat, GraphFormat,
-    QualityFormat, ServiceGraphFormat,
+    HotspotSort, QualityFormat, ServiceGraphFormat,
 };
+use crate::metrics::scc::SccOptions;
+use crate::parse::cfg::CfgSelection;
 use crate::walk::Language;
 use provenance::ProvenanceMap;
 use serde::Serialize;
@@ -18,8 +20,11 @@ pub(crate) mod keys {
     pub const DEFAULTS_LANG: &str = "defaults.lang";
     pub const DEFAULTS_QUIET: &str = "defaults.quiet";
     pub const DEFAULTS_INCLUDE_TESTS: &str = "defaults.include_tests";
+    pub const DEFAULTS_INCLUDE_EXTERNAL: &str = "defaults.include_external";
+    pub const DEFAULTS_ALLOW_CUSTOM_GRAMMARS: &str = "defaults.allow_custom_grammars";
     pub const ANALYZE_REPORT_FORMAT: &str = "analyze.report.format";
     pub const ANALYZE_REPORT_TOP: &str = "analyze.report.top";
+    pub const ANALYZE_REPORT_SORT: &str = "analyze.report.sort";
     pub const ANALYZE_REPORT_INSIGHTS: &str = "analyze.report.insights";
     pub const ANALYZE_REPORT_THRESHOLD_FANOUT: &str = "analyze.report.threshold_fanout";
     pub const ANALYZE_REPORT_THRESHOLD_SCC: &str = "analyze.report.threshold_scc";
@@ -36,6 +41,8 @@ pub(crate) mod keys {
         "analyze.architecture.allowed_dependencies";
     pub const ANALYZE_ARCHITECTURE_FORBIDDEN_DEPENDENCIES: &str =
         "analyze.architecture.forbidden_dependencies";
+    pub const ANALYZE_ARCHITECTURE_FORBIDDEN_PACKAGES: &str =
+        "analyze.architecture.forbidden_packages";
     pub const ANALYZE_ARCHITECTURE_EXCEPTIONS: &str = "analyze.architecture.exceptions";
     pub const DIFF_FORMAT: &str = "diff.format";
     pub const QUALITY_FUNCTIONS_FORMAT: &str = "quality.functions.format";
@@ -65,17 +72,32 @@ pub(crate) mod keys {
     pub const RULES_HIGH_ENTROPY_ENABLED: &str = "rules.high_entropy.enabled";
     pub const RULES_HIGH_ENTROPY_MIN_ENTROPY: &str = "rules.high_entropy.min_entropy";
     pub const RULES_HIGH_ENTROPY_MIN_FANOUT: &str = "rules.high_entropy.min_fanout";
+    pub const RULES_MAIN_SEQUENCE_ENABLED: &str = "rules.main_sequence.enabled";
+    pub const RULES_MAIN_SEQUENCE_MAX_DISTANCE: &str = "rules.main_sequence.max_distance";
     pub const GO_EXCLUDE_STDLIB: &str = "go.exclude_stdlib";
+    pub const GO_BUILD_TAGS: &str = "go.build_tags";
     pub const PYTHON_RESOLVE_RELATIVE: &str = "python.resolve_relative";
+    pub const PYTHON_IGNORE_TYPE_ONLY: &str = "python.ignore_type_only";
+    pub const PYTHON_SOURCE_ROOTS: &str = "python.source_roots";
+    pub const PYTHON_FOLLOW_REEXPORTS: &str = "python.follow_reexports";
     pub const RUBY_ZEITWERK: &str = "ruby.zeitwerk";
     pub const RUBY_LOAD_PATH: &str = "ruby.load_path";
+    pub const RUBY_DETECT_RAILS: &str = "ruby.detect_rails";
+    pub const CPP_INCLUDE_PATHS: &str = "cpp.include_paths";
+    pub const CPP_ANGLE_INCLUDES: &str = "cpp.angle_includes";
+    pub const RUST_MOD_EDGES: &str = "rust.mod_edges";
+    pub const RUST_CFG: &str = "rust.cfg";
+    pub const RUST_EXCLUDE_CFG: &str = "rust.exclude_cfg";
 
     pub const ALL: &[&str] = &[
         DEFAULTS_LANG,
         DEFAULTS_QUIET,
         DEFAULTS_INCLUDE_TESTS,
+        DEFAULTS_INCLUDE_EXTERNAL,
+        DEFAULTS_ALLOW_CUSTOM_GRAMMARS,
         ANALYZE_REPORT_FORMAT,
         ANALYZE_REPORT_TOP,
+        ANALYZE_REPORT_SORT,
         ANALYZE_REPORT_INSIGHTS,
         ANALYZE_REPORT_THRESHOLD_FANOUT,
         ANALYZE_REPORT_THRESHOLD_SCC,
@@ -88,6 +110,7 @@ pub(crate) mod keys {
         ANALYZE_ARCHITECTURE_IGNORED_COMPONENTS,
         ANALYZE_ARCHITECTURE_ALLOWED_DEPENDENCIES,
         ANALYZE_ARCHITECTURE_FORBIDDEN_DEPENDENCIES,
+        ANALYZE_ARCHITECTURE_FORBIDDEN_PACKAGES,
         ANALYZE_ARCHITECTURE_EXCEPTIONS,
         DIFF_FORMAT,
         QUALITY_FUNCTIONS_FORMAT,
@@ -116,10 +139,22 @@ pub(crate) mod keys {
         RULES_HIGH_ENTROPY_ENABLED,
         RULES_HIGH_ENTROPY_MIN_ENTROPY,
         RULES_HIGH_ENTROPY_MIN_FANOUT,
+        RULES_MAIN_SEQUENCE_ENABLED,
+        RULES_MAIN_SEQUENCE_MAX_DISTANCE,
         GO_EXCLUDE_STDLIB,
+        GO_BUILD_TAGS,
         PYTHON_RESOLVE_RELATIVE,
+        PYTHON_IGNORE_TYPE_ONLY,
+        PYTHON_SOURCE_ROOTS,
+        PYTHON_FOLLOW_REEXPORTS,
         RUBY_ZEITWERK,
         RUBY_LOAD_PATH,
+        RUBY_DETECT_RAILS,
+        CPP_INCLUDE_PATHS,
+        CPP_ANGLE_INCLUDES,
+        RUST_MOD_EDGES,
+        RUST_CFG,
+        RUST_EXCLUDE_CFG,
     ];
 }
 
@@ -130,6 +165,11 @@ pub struct ResolvedConfig {
     pub lang: Option<Language>,
     pub quiet: bool,
     pub include_tests: bool,
+    /// Add a node per third-party package the project imports
+    pub include_external: bool,
+    /// Open the grammar libraries of `[frontends]` tables. Only the user
+    /// config and the command line can set it.
+    pub allow_custom_grammars: bool,
 
     // Targeting
     pub include: Vec<String>,
@@ -156,6 +196,8 @@ pub struct ResolvedConfig {
     pub go: ResolvedGoConfig,
     pub python: ResolvedPythonConfig,
     pub ruby: ResolvedRubyConfig,
+    pub cpp: ResolvedCppConfig,
+    pub rust: ResolvedRustConfig,
 
     // Per-path overrides (compiled globs)
     pub overrides: Vec<(globset::GlobMatcher, OverrideEntry)>,
@@ -163,6 +205,9 @@ pub struct ResolvedConfig {
     // Services for cross-service dependency tracking
     pub services: Vec<ResolvedService>,
 
+    // Custom tree-sitter frontends
+    pub frontends: Vec<ResolvedFrontend>,
+
     // Provenance
     pub provenance: ProvenanceMap,
     pub loaded_files: Vec<PathBuf>,
@@ -172,6 +217,8 @@ pub struct ResolvedConfig {
 pub struct ResolvedAnalyzeReportConfig {
     pub format: AnalyzeReportFormat,
     pub top: Option<usize>,
+    /// Metric hotspots are ranked by
+    pub sort: HotspotSort,
     pub insights: InsightsConfig,
     pub threshold_fanout: Option<usize>,
     pub threshold_scc: Option<usize>,
@@ -182,6 +229,7 @@ impl Default for ResolvedAnalyzeReportConfig {
         Self {
             format: AnalyzeReportFormat::Json,
             top: None,
+            sort: HotspotSort::Fanout,
             insights: InsightsConfig::Auto,
             threshold_fanout: None,
             threshold_scc: None,
@@ -212,6 +260,7 @@ pub struct ResolvedArchitectureConfig {
     pub ignored_components: Vec<String>,
     pub allowed_dependencies: std::collections::BTreeMap<String, Vec<String>>,
     pub forbidden_dependencies: Vec<ArchitectureForbiddenDependency>,
+    pub forbidden_packages: Vec<ArchitectureForbiddenPackage>,
     pub exceptions: Vec<ArchitectureException>,
 }
 
@@ -226,6 +275,7 @@ impl Default for ResolvedArchitectureConfig {
             ignored_components: Vec::new(),
             allowed_dependencies: std::collections::BTreeMap::new(),
             forbidden_dependencies: Vec::new(),
+            forbidden_packages: Vec::new(),
             exceptions: Vec::new(),
         }
     }
@@ -237,6 +287,13 @@ pub struct ArchitectureForbiddenDependency {
     pub to: String,
 }
 
+/// A component that must not import a third-party package.
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct ArchitectureForbiddenPackage {
+    pub from: String,
+    pub package: String,
+}
+
 #[derive(Debug, Clone, Serialize, PartialEq, Eq)]
 pub struct ArchitectureException {
     pub from_component: Option<String>,
@@ -324,6 +381,22 @@ pub struct ResolvedService {
     pub base_urls: Vec<String>,
 }
 
+/// A custom language from `[frontends.<name>]`. Paths are resolved against
+/// the directory of the config file that declared it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResolvedFrontend {
+    pub name: String,
+    pub extensions: Vec<String>,
+    /// Shared library exporting the tree-sitter grammar
+    pub grammar: PathBuf,
+    /// Grammar entry point, `tree_sitter_<name>` by default
+    pub symbol: String,
+    /// Query file whose `@import` captures are the file's imports
+    pub query: PathBuf,
+    /// Path templates tried in order to resolve an import to a project file
+    pub resolve: Vec<String>,
+}
+
 /// Entry for a per-path override.
 #[derive(Debug, Clone)]
 pub struct OverrideEntry {
@@ -338,6 +411,7 @@ pub struct ResolvedRules {
     pub circular_dependency: CircularDependencyRule,
     pub deep_chain: DeepChainRule,
     pub high_entropy: HighEntropyRule,
+    pub main_sequence: MainSequenceRule,
 }
 
 #[derive(Debug, Clone)]
@@ -429,15 +503,36 @@ impl Default for HighEntropyRule {
     }
 }
 
+/// Components far from the main sequence (A + I = 1), at the architecture level.
+/// Off by default: it needs the types of every file, not only its imports.
+#[derive(Debug, Clone)]
+pub struct MainSequenceRule {
+    pub enabled: bool,
+    pub max_distance: f64,
+}
+
+impl Default for MainSequenceRule {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_distance: 0.7,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct ResolvedGoConfig {
     pub exclude_stdlib: bool,
+    /// Build tags (GOOS, GOARCH, custom) treated as satisfied; empty keeps
+    /// every file regardless of build constraints
+    pub build_tags: Vec<String>,
 }
 
 impl Default for ResolvedGoConfig {
     fn default() -> Self {
         Self {
             exclude_stdlib: true,
+            build_tags: Vec::new(),
         }
     }
 }
@@ -445,12 +540,30 @@ impl Default for ResolvedGoConfig {
 #[derive(Debug, Clone)]
 pub struct ResolvedPythonConfig {
     pub resolve_relative: bool,
+    /// Leave `if TYPE_CHECKING:` imports out of cycle detection
+    pub ignore_type_only: bool,
+    /// Extra directories (or globs) absolute imports resolve against
+    pub source_roots: Vec<String>,
+    /// Resolve names imported from a package to the module its
+    /// `__init__.py` re-exports them from
+    pub follow_reexports: bool,
 }
 
 impl Default for ResolvedPythonConfig {
     fn default() -> Self {
         Self {
             resolve_relative: true,
+            ignore_type_only: false,
+            source_roots: Vec::new(),
+            follow_reexports: false,
+        }
+    }
+}
+
+impl ResolvedPythonConfig {
+    pub fn scc_options(&self) -> SccOptions {
+        SccOptions {
+            ignore_type_only: self.ignore_type_only,
         }
     }
 }
@@ -459,6 +572,8 @@ impl Default for ResolvedPythonConfig {
 pub struct ResolvedRubyConfig {
     pub zeitwerk: bool,
     pub load_path: Vec<String>,
+    /// Infer load paths from Rails applications and engines
+    pub detect_rails: bool,
 }
 
 impl Default for ResolvedRubyConfig {
@@ -466,10 +581,36 @@ impl Default for ResolvedRubyConfig {
         Self {
             zeitwerk: false,
             load_path: vec!["lib".to_string(), "app".to_string()],
+            detect_rails: true,
         }
     }
 }
 
+#[derive(Debug, Clone, Default)]
+pub struct ResolvedCppConfig {
+    /// Include directories searched after those from compile_commands.json
+    pub include_paths: Vec<String>,
+    /// Also resolve `#include <...>` against include directories
+    pub angle_includes: bool,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ResolvedRustConfig {
+    /// Emit containment edges for `mod` declarations
+    pub mod_edges: bool,
+    /// cfg options treated as enabled (`test`, `feature=serde`)
+    pub cfg: Vec<String>,
+    /// cfg options treated as disabled
+    pub exclude_cfg: Vec<String>,
+}
+
+impl ResolvedRustConfig {
+    /// Build configuration that decides which cfg-gated imports become edges.
+    pub fn cfg_selection(&self) -> CfgSelection {
+        CfgSelection::new(&self.cfg, &self.exclude_cfg)
+    }
+}
+
 impl ResolvedConfig {
     /// Get Ruby load paths as PathBufs, for backward compat with existing code.
     pub fn ruby_load_paths(&self) -> Vec<PathBuf> {
@@ -1,18 +1,20 @@
 use crate::config::provenance::{ProvenanceMap, Source};
 use crate::config::schema::FileConfig;
 use crate::config::{
-    keys, ArchitectureException, ArchitectureForbiddenDependency, CircularDependencyRule,
-    DeepChainRule, GodModuleRule, HighEntropyRule, HighFanoutRule, InsightsConfig, OverrideEntry,
-    ResolvedAnalyzeReportConfig, ResolvedArchitectureConfig, ResolvedConfig, ResolvedDiffConfig,
-    ResolvedGoConfig, ResolvedGraphConfig, ResolvedPythonConfig, ResolvedQualityConfig,
-    ResolvedRubyConfig, ResolvedRules, ResolvedService, ResolvedServiceGraphConfig,
-    ResolvedSpecsQualityConfig,
+    keys, ArchitectureException, ArchitectureForbiddenDependency, ArchitectureForbiddenPackage,
+    CircularDependencyRule, DeepChainRule, GodModuleRule, HighEntropyRule, HighFanoutRule,
+    InsightsConfig, MainSequenceRule, OverrideEntry, ResolvedAnalyzeReportConfig,
+    ResolvedArchitectureConfig, ResolvedConfig, ResolvedCppConfig, ResolvedDiffConfig,
+    ResolvedFrontend, ResolvedGoConfig, ResolvedGraphConfig, ResolvedPythonConfig,
+    ResolvedQualityConfig, ResolvedRubyConfig, ResolvedRules, ResolvedRustConfig, ResolvedService,
+    ResolvedServiceGraphConfig, ResolvedSpecsQualityConfig,
 };
 use crate::errors::{Result, UntangleError};
 use crate::formats::{
     AnalyzeReportFormat, ArchitectureCheckFormat, ArchitectureFormat, DiffFormat, GraphFormat,
-    QualityFormat, ServiceGraphFormat,
+    HotspotSort, QualityFormat, ServiceGraphFormat,
 };
+use crate::parse::custom::{self, CustomLanguageId, MAX_NAME_LEN};
 use crate::walk::Language;
 use globset::Glob;
 use std::path::{Path, PathBuf};
@@ -23,11 +25,17 @@ pub struct CliOverrides {
     pub lang: Option<Language>,
     pub quiet: bool,
     pub include_tests: bool,
+    pub include_external: bool,
+    pub allow_custom_grammars: bool,
     pub include: Vec<String>,
     pub exclude: Vec<String>,
     pub fail_on: Vec<String>,
     pub threshold_fanout: Option<usize>,
     pub threshold_scc: Option<usize>,
+    pub cfg: Vec<String>,
+    pub exclude_cfg: Vec<String>,
+    pub ignore_type_only: bool,
+    pub go_tags: Vec<String>,
 }
 
 /// Resolve configuration by applying layers bottom-up:
@@ -45,6 +53,8 @@ pub fn resolve_config(working_dir: &Path, cli: &CliOverrides) -> Result<Resolved
         lang: None,
         quiet: false,
         include_tests: false,
+        include_external: false,
+        allow_custom_grammars: false,
         include: Vec::new(),
         exclude: Vec::new(),
         ignore_patterns: Vec::new(),
@@ -61,8 +71,11 @@ pub fn resolve_config(working_dir: &Path, cli: &CliOverrides) -> Result<Resolved
         go: ResolvedGoConfig::default(),
         python: ResolvedPythonConfig::default(),
         ruby: ResolvedRubyConfig::default(),
+        cpp: ResolvedCppConfig::default(),
+        rust: ResolvedRustConfig::default(),
         overrides: Vec::new(),
         services: Vec::new(),
+        frontends: Vec::new(),
         provenance: ProvenanceMap::new(),
         loaded_files: Vec::new(),
     };
@@ -118,6 +131,27 @@ pub fn resolve_config(working_dir: &Path, cli: &CliOverrides) -> Result<Resolved
     // 5. CLI overrides
     apply_cli_overrides(&mut config, cli, &mut prov);
 
+    // Custom languages are named by `Language` values, which hold the name.
+    if let Some(frontend) = config
+        .frontends
+        .iter()
+        .find(|frontend| CustomLanguageId::new(&frontend.name).is_none())
+    {
+        return Err(UntangleError::Config(format!(
+            "invalid frontend name `{}`: use up to {MAX_NAME_LEN} ASCII letters, digits, `-` and `_`",
+            frontend.name
+        )));
+    }
+
+    // `--lang` accepts any name; custom ones must be declared in a config.
+    if let Some(lang @ Language::Custom(id)) = config.lang {
+        if custom::definition(id, &config.frontends).is_none() {
+            return Err(UntangleError::Config(format!(
+                "unsupported language: {lang} (declare [frontends.{lang}] to add it)"
+            )));
+        }
+    }
+
     // Load .untangleignore
     let ignore_patterns = crate::config::ignore::load_untangleignore(working_dir);
     config.ignore_patterns = ignore_patterns;
@@ -161,6 +195,18 @@ fn parse_analyze_report_format(value: &str) -> Option<AnalyzeReportFormat> {
     }
 }
 
+fn parse_hotspot_sort(value: &str) -> Option<HotspotSort> {
+    match value {
+        "fanout" => Some(HotspotSort::Fanout),
+        "fanin" => Some(HotspotSort::Fanin),
+        "entropy" => Some(HotspotSort::Entropy),
+        "betweenness" => Some(HotspotSort::Betweenness),
+        "pagerank" => Some(HotspotSort::Pagerank),
+        "articulation" => Some(HotspotSort::Articulation),
+        _ => None,
+    }
+}
+
 fn parse_graph_format(value: &str) -> Option<GraphFormat> {
     match value {
         "json" => Some(GraphFormat::Json),
@@ -225,6 +271,8 @@ fn apply_file_config(
     source: Source,
     prov: &mut ProvenanceMap,
 ) {
+    // Custom languages first, so `lang` settings can name them.
+    apply_frontends_section(config, file, &source);
     apply_defaults_section(config, file, &source, prov);
     apply_command_defaults(config, file, &source, prov);
     apply_targeting_section(config, file);
@@ -255,6 +303,16 @@ fn apply_defaults_section(
         config.include_tests = include_tests;
         prov.set(keys::DEFAULTS_INCLUDE_TESTS, source.clone());
     }
+    if let Some(include_external) = file.defaults.include_external {
+        config.include_external = include_external;
+        prov.set(keys::DEFAULTS_INCLUDE_EXTERNAL, source.clone());
+    }
+    // A checked-out repository must not be able to make untangle run native
+    // code, so only the user's own config can allow grammar libraries.
+    if let (Some(allow), Source::UserConfig(_)) = (file.defaults.allow_custom_grammars, source) {
+        config.allow_custom_grammars = allow;
+        prov.set(keys::DEFAULTS_ALLOW_CUSTOM_GRAMMARS, source.clone());
+    }
 }
 
 fn apply_command_defaults(
@@ -273,6 +331,12 @@ fn apply_command_defaults(
         config.analyze_report.top = Some(top);
         prov.set(keys::ANALYZE_REPORT_TOP, source.clone());
     }
+    if let Some(ref sort) = file.analyze.report.sort {
+        if let Some(parsed) = parse_hotspot_sort(sort) {
+            config.analyze_report.sort = parsed;
+            prov.set(keys::ANALYZE_REPORT_SORT, source.clone());
+        }
+    }
     if let Some(ref insights) = file.analyze.report.insights {
         if let Some(parsed) = parse_insights_mode(insights) {
             config.analyze_report.insights = parsed;
@@ -361,6 +425,22 @@ fn apply_command_defaults(
             source.clone(),
         );
     }
+    if !file.analyze.architecture.forbidden_packages.is_empty() {
+        config.analyze_architecture.forbidden_packages = file
+            .analyze
+            .architecture
+            .forbidden_packages
+            .iter()
+            .map(|entry| ArchitectureForbiddenPackage {
+                from: entry.from.clone(),
+                package: entry.package.clone(),
+            })
+            .collect();
+        prov.set(
+            keys::ANALYZE_ARCHITECTURE_FORBIDDEN_PACKAGES,
+            source.clone(),
+        );
+    }
     if !file.analyze.architecture.exceptions.is_empty() {
         config.analyze_architecture.exceptions = file
             .analyze
@@ -461,6 +541,9 @@ fn apply_rules_section(
     if let Some(ref he) = file.rules.high_entropy {
         apply_high_entropy_config(&mut config.rules.high_entropy, he, source, prov);
     }
+    if let Some(ref ms) = file.rules.main_sequence {
+        apply_main_sequence_config(&mut config.rules.main_sequence, ms, source, prov);
+    }
 }
 
 fn apply_fail_on_section(config: &mut ResolvedConfig, file: &FileConfig) {
@@ -481,10 +564,26 @@ fn apply_language_section(
         config.go.exclude_stdlib = exclude_stdlib;
         prov.set(keys::GO_EXCLUDE_STDLIB, source.clone());
     }
+    if !file.go.build_tags.is_empty() {
+        config.go.build_tags = file.go.build_tags.clone();
+        prov.set(keys::GO_BUILD_TAGS, source.clone());
+    }
     if let Some(resolve_relative) = file.python.resolve_relative {
         config.python.resolve_relative = resolve_relative;
         prov.set(keys::PYTHON_RESOLVE_RELATIVE, source.clone());
     }
+    if let Some(ignore_type_only) = file.python.ignore_type_only {
+        config.python.ignore_type_only = ignore_type_only;
+        prov.set(keys::PYTHON_IGNORE_TYPE_ONLY, source.clone());
+    }
+    if !file.python.source_roots.is_empty() {
+        config.python.source_roots = file.python.source_roots.clone();
+        prov.set(keys::PYTHON_SOURCE_ROOTS, source.clone());
+    }
+    if let Some(follow_reexports) = file.python.follow_reexports {
+        config.python.follow_reexports = follow_reexports;
+        prov.set(keys::PYTHON_FOLLOW_REEXPORTS, source.clone());
+    }
     if let Some(zeitwerk) = file.ruby.zeitwerk {
         config.ruby.zeitwerk = zeitwerk;
         prov.set(keys::RUBY_ZEITWERK, source.clone());
@@ -493,6 +592,30 @@ fn apply_language_section(
         config.ruby.load_path = file.ruby.load_path.clone();
         prov.set(keys::RUBY_LOAD_PATH, source.clone());
     }
+    if let Some(detect_rails) = file.ruby.detect_rails {
+        config.ruby.detect_rails = detect_rails;
+        prov.set(keys::RUBY_DETECT_RAILS, source.clone());
+    }
+    if !file.cpp.include_paths.is_empty() {
+        config.cpp.include_paths = file.cpp.include_paths.clone();
+        prov.set(keys::CPP_INCLUDE_PATHS, source.clone());
+    }
+    if let Some(angle_includes) = file.cpp.angle_includes {
+        config.cpp.angle_includes = angle_includes;
+        prov.set(keys::CPP_ANGLE_INCLUDES, source.clone());
+    }
+    if let Some(mod_edges) = file.rust.mod_edges {
+        config.rust.mod_edges = mod_edges;
+        prov.set(keys::RUST_MOD_EDGES, source.clone());
+    }
+    if !file.rust.cfg.is_empty() {
+        config.rust.cfg = file.rust.cfg.clone();
+        prov.set(keys::RUST_CFG, source.clone());
+    }
+    if !file.rust.exclude_cfg.is_empty() {
+        config.rust.exclude_cfg = file.rust.exclude_cfg.clone();
+        prov.set(keys::RUST_EXCLUDE_CFG, source.clone());
+    }
 }
 
 fn apply_overrides_section(config: &mut ResolvedConfig, file: &FileConfig) {
@@ -543,6 +666,36 @@ fn apply_services_section(config: &mut ResolvedConfig, file: &FileConfig) {
     }
 }
 
+fn apply_frontends_section(config: &mut ResolvedConfig, file: &FileConfig, source: &Source) {
+    let base_dir = match source {
+        Source::UserConfig(path) | Source::ProjectConfig(path) => path.parent(),
+        _ => None,
+    }
+    .unwrap_or(Path::new(""));
+    let mut names: Vec<&String> = file.frontends.keys().collect();
+    names.sort();
+    for name in names {
+        let frontend = &file.frontends[name];
+        let resolved = ResolvedFrontend {
+            name: name.clone(),
+            extensions: frontend
+                .extensions
+                .iter()
+                .map(|ext| ext.trim_start_matches('.').to_string())
+                .collect(),
+            grammar: base_dir.join(&frontend.grammar),
+            symbol: frontend
+                .symbol
+                .clone()
+                .unwrap_or_else(|| format!("tree_sitter_{}", name.replace('-', "_"))),
+            query: base_dir.join(&frontend.query),
+            resolve: frontend.resolve.clone(),
+        };
+        config.frontends.retain(|existing| existing.name != *name);
+        config.frontends.push(resolved);
+    }
+}
+
 fn apply_high_fanout_config(
     rule: &mut HighFanoutRule,
     file: &crate::config::schema::HighFanoutRuleConfig,
@@ -654,6 +807,22 @@ fn apply_high_entropy_config(
     }
 }
 
+fn apply_main_sequence_config(
+    rule: &mut MainSequenceRule,
+    file: &crate::config::schema::MainSequenceRuleConfig,
+    source: &Source,
+    prov: &mut ProvenanceMap,
+) {
+    if let Some(enabled) = file.enabled {
+        rule.enabled = enabled;
+        prov.set(keys::RULES_MAIN_SEQUENCE_ENABLED, source.clone());
+    }
+    if let Some(max_distance) = file.max_distance {
+        rule.max_distance = max_distance;
+        prov.set(keys::RULES_MAIN_SEQUENCE_MAX_DISTANCE, source.clone());
+    }
+}
+
 // Override-specific apply functions: override block replaces entire rule object,
 // so we start from defaults and only set explicitly specified fields.
 fn apply_high_fanout_override(
@@ -785,6 +954,20 @@ fn apply_cli_overrides(config: &mut ResolvedConfig, cli: &CliOverrides, prov: &m
             Source::CliFlag("--include-tests".into()),
         );
     }
+    if cli.include_external {
+        config.include_external = true;
+        prov.set(
+            keys::DEFAULTS_INCLUDE_EXTERNAL,
+            Source::CliFlag("--include-external".into()),
+        );
+    }
+    if cli.allow_custom_grammars {
+        config.allow_custom_grammars = true;
+        prov.set(
+            keys::DEFAULTS_ALLOW_CUSTOM_GRAMMARS,
+            Source::CliFlag("--allow-custom-grammars".into()),
+        );
+    }
     if !cli.include.is_empty() {
         config.include = cli.include.clone();
     }
@@ -818,6 +1001,28 @@ fn apply_cli_overrides(config: &mut ResolvedConfig, cli: &CliOverrides, prov: &m
             Source::CliFlag("--threshold-scc".into()),
         );
     }
+    if !cli.cfg.is_empty() {
+        config.rust.cfg = cli.cfg.clone();
+        prov.set(keys::RUST_CFG, Source::CliFlag("--cfg".into()));
+    }
+    if !cli.exclude_cfg.is_empty() {
+        config.rust.exclude_cfg = cli.exclude_cfg.clone();
+        prov.set(
+            keys::RUST_EXCLUDE_CFG,
+            Source::CliFlag("--exclude-cfg".into()),
+        );
+    }
+    if !cli.go_tags.is_empty() {
+        config.go.build_tags = cli.go_tags.clone();
+        prov.set(keys::GO_BUILD_TAGS, Source::CliFlag("--tags".into()));
+    }
+    if cli.ignore_type_only {
+        config.python.ignore_type_only = true;
+        prov.set(
+            keys::PYTHON_IGNORE_TYPE_ONLY,
+            Source::CliFlag("--ignore-type-only".into()),
+        );
+    }
 }
 
 #[cfg(test)]
@@ -849,9 +1054,15 @@ mod tests {
             quiet: true,
             include_tests: true,
             threshold_fanout: Some(20),
+            exclude_cfg: vec!["test".to_string()],
+            ignore_type_only: true,
+            go_tags: vec!["linux".to_string()],
             ..Default::default()
         };
         let config = resolve_config(&dir, &cli).unwrap();
+        assert_eq!(config.rust.exclude_cfg, vec!["test".to_string()]);
+        assert!(config.python.ignore_type_only);
+        assert_eq!(config.go.build_tags, vec!["linux".to_string()]);
 
         assert!(config.quiet);
         assert!(config.include_tests);
@@ -980,6 +1191,99 @@ lang = "python"
         assert_eq!(web.lang, Some(Language::Python));
     }
 
+    #[test]
+    fn frontends_resolved_from_config() {
+        let tmp = tempfile::tempdir().unwrap();
+        std::fs::write(
+            tmp.path().join(".untangle.toml"),
+            r#"
+[defaults]
+lang = "resolve-test-dsl"
+
+[frontends.resolve-test-dsl]
+extensions = [".dsl"]
+grammar = "grammars/libdsl.so"
+query = "grammars/imports.scm"
+"#,
+        )
+        .unwrap();
+
+        let config = resolve_config(tmp.path(), &CliOverrides::default()).unwrap();
+
+        assert_eq!(
+            config.frontends,
+            vec![ResolvedFrontend {
+                name: "resolve-test-dsl".to_string(),
+                extensions: vec!["dsl".to_string()],
+                grammar: tmp.path().join("grammars/libdsl.so"),
+                symbol: "tree_sitter_resolve_test_dsl".to_string(),
+                query: tmp.path().join("grammars/imports.scm"),
+                resolve: Vec::new(),
+            }]
+        );
+        let lang = config.lang.unwrap();
+        assert_eq!(lang.to_string(), "resolve-test-dsl");
+        assert_eq!(
+            crate::walk::language_extensions(lang, &config.frontends),
+            vec!["dsl"]
+        );
+    }
+
+    #[test]
+    fn separate_configs_keep_their_own_frontends() {
+        let resolve = |extension: &str| {
+            let tmp = tempfile::tempdir().unwrap();
+            std::fs::write(
+                tmp.path().join(".untangle.toml"),
+                format!(
+                    "[frontends.dsl]\nextensions = [\"{extension}\"]\ngrammar = \"libdsl.so\"\nquery = \"imports.scm\"\n"
+                ),
+            )
+            .unwrap();
+            resolve_config(tmp.path(), &CliOverrides::default()).unwrap()
+        };
+        let base = resolve("dsl");
+        let head = resolve("dsl2");
+
+        let lang = "dsl".parse::<Language>().unwrap();
+        assert_eq!(
+            crate::walk::language_extensions(lang, &base.frontends),
+            vec!["dsl"]
+        );
+        assert_eq!(
+            crate::walk::language_extensions(lang, &head.frontends),
+            vec!["dsl2"]
+        );
+    }
+
+    #[test]
+    fn invalid_frontend_names_are_rejected() {
+        let tmp = tempfile::tempdir().unwrap();
+        std::fs::write(
+            tmp.path().join(".untangle.toml"),
+            "[frontends.\"my dsl\"]\nextensions = [\"dsl\"]\ngrammar = \"libdsl.so\"\nquery = \"imports.scm\"\n",
+        )
+        .unwrap();
+        let error = resolve_config(tmp.path(), &CliOverrides::default())
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("invalid frontend name `my dsl`"), "{error}");
+    }
+
+    #[test]
+    fn undeclared_custom_language_is_rejected() {
+        let tmp = tempfile::tempdir().unwrap();
+        let cli = CliOverrides {
+            lang: Some("undeclared-test-dsl".parse().unwrap()),
+            ..Default::default()
+        };
+        let error = resolve_config(tmp.path(), &cli).unwrap_err().to_string();
+        assert!(
+            error.contains("unsupported language: undeclared-test-dsl"),
+            "{error}"
+        );
+    }
+
     #[test]
     fn quality_and_language_sections_are_applied() {
         let tmp = tempfile::tempdir().unwrap();
@@ -997,13 +1301,25 @@ top = 3
 
 [go]
 exclude_stdlib = false
+build_tags = ["windows", "integration"]
 
 [python]
 resolve_relative = false
+ignore_type_only = true
+source_roots = ["src"]
+follow_reexports = true
 
 [ruby]
 zeitwerk = false
 load_path = ["lib", "app/models"]
+detect_rails = false
+
+[cpp]
+include_paths = ["include", "third_party/fmt/include"]
+angle_includes = true
+
+[rust]
+mod_edges = true
 "#,
         )
         .unwrap();
@@ -1015,12 +1331,26 @@ load_path = ["lib", "app/models"]
         assert_eq!(config.quality_project.format, QualityFormat::Text);
         assert_eq!(config.quality_project.top, Some(3));
         assert!(!config.go.exclude_stdlib);
+        assert_eq!(
+            config.go.build_tags,
+            vec!["windows".to_string(), "integration".to_string()]
+        );
         assert!(!config.python.resolve_relative);
+        assert!(config.python.ignore_type_only);
+        assert_eq!(config.python.source_roots, vec!["src".to_string()]);
+        assert!(config.python.follow_reexports);
         assert!(!config.ruby.zeitwerk);
         assert_eq!(
             config.ruby.load_path,
             vec!["lib".to_string(), "app/models".to_string()]
         );
+        assert!(!config.ruby.detect_rails);
+        assert_eq!(
+            config.cpp.include_paths,
+            vec!["include".to_string(), "third_party/fmt/include".to_string()]
+        );
+        assert!(config.cpp.angle_includes);
+        assert!(config.rust.mod_edges);
     }
 
     #[test]
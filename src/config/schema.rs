@@ -27,9 +27,15 @@ pub struct FileConfig {
     #[serde(default)]
     pub ruby: RubyFileConfig,
     #[serde(default)]
+    pub cpp: CppFileConfig,
+    #[serde(default)]
+    pub rust: RustFileConfig,
+    #[serde(default)]
     pub overrides: HashMap<String, OverrideFileConfig>,
     #[serde(default)]
     pub services: HashMap<String, ServiceFileConfig>,
+    #[serde(default)]
+    pub frontends: HashMap<String, FrontendFileConfig>,
 
     // Backward compatibility: old format had [thresholds] section
     #[serde(default)]
@@ -49,6 +55,17 @@ pub struct ServiceFileConfig {
     pub base_urls: Vec<String>,
 }
 
+/// Custom language frontend backed by a tree-sitter grammar library.
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct FrontendFileConfig {
+    pub extensions: Vec<String>,
+    pub grammar: String,
+    pub symbol: Option<String>,
+    pub query: String,
+    #[serde(default)]
+    pub resolve: Vec<String>,
+}
+
 /// Backward compat: old [defaults] had `exclude` which maps to targeting.exclude
 /// and `lang` which maps to defaults.lang
 #[derive(Debug, Clone, Deserialize, Default)]
@@ -58,6 +75,8 @@ pub struct DefaultsFileConfig {
     pub quiet: Option<bool>,
     pub top: Option<usize>,
     pub include_tests: Option<bool>,
+    pub include_external: Option<bool>,
+    pub allow_custom_grammars: Option<bool>,
     pub no_insights: Option<bool>,
     // Backward compat: old format had exclude here
     #[serde(default)]
@@ -78,6 +97,7 @@ pub struct AnalyzeFileConfig {
 pub struct AnalyzeReportFileConfig {
     pub format: Option<String>,
     pub top: Option<usize>,
+    pub sort: Option<String>,
     pub insights: Option<String>,
     pub threshold_fanout: Option<usize>,
     pub threshold_scc: Option<usize>,
@@ -102,6 +122,8 @@ pub struct AnalyzeArchitectureFileConfig {
     #[serde(default)]
     pub forbidden_dependencies: Vec<ArchitectureForbiddenDependencyFileConfig>,
     #[serde(default)]
+    pub forbidden_packages: Vec<ArchitectureForbiddenPackageFileConfig>,
+    #[serde(default)]
     pub exceptions: Vec<ArchitectureExceptionFileConfig>,
 }
 
@@ -111,6 +133,12 @@ pub struct ArchitectureForbiddenDependencyFileConfig {
     pub to: String,
 }
 
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct ArchitectureForbiddenPackageFileConfig {
+    pub from: String,
+    pub package: String,
+}
+
 #[derive(Debug, Clone, Deserialize, Default)]
 pub struct ArchitectureExceptionFileConfig {
     pub from_component: Option<String>,
@@ -173,6 +201,7 @@ pub struct RulesFileConfig {
     pub circular_dependency: Option<CircularDependencyRuleConfig>,
     pub deep_chain: Option<DeepChainRuleConfig>,
     pub high_entropy: Option<HighEntropyRuleConfig>,
+    pub main_sequence: Option<MainSequenceRuleConfig>,
 }
 
 #[derive(Debug, Clone, Deserialize, Default)]
@@ -212,6 +241,12 @@ pub struct HighEntropyRuleConfig {
     pub min_fanout: Option<usize>,
 }
 
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct MainSequenceRuleConfig {
+    pub enabled: Option<bool>,
+    pub max_distance: Option<f64>,
+}
+
 #[derive(Debug, Clone, Deserialize, Default)]
 pub struct FailOnFileConfig {
     #[serde(default)]
@@ -221,11 +256,17 @@ pub struct FailOnFileConfig {
 #[derive(Debug, Clone, Deserialize, Default)]
 pub struct GoFileConfig {
     pub exclude_stdlib: Option<bool>,
+    #[serde(default)]
+    pub build_tags: Vec<String>,
 }
 
 #[derive(Debug, Clone, Deserialize, Default)]
 pub struct PythonFileConfig {
     pub resolve_relative: Option<bool>,
+    pub ignore_type_only: Option<bool>,
+    #[serde(default)]
+    pub source_roots: Vec<String>,
+    pub follow_reexports: Option<bool>,
 }
 
 #[derive(Debug, Clone, Deserialize, Default)]
@@ -233,6 +274,23 @@ pub struct RubyFileConfig {
     pub zeitwerk: Option<bool>,
     #[serde(default)]
     pub load_path: Vec<String>,
+    pub detect_rails: Option<bool>,
+}
+
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct CppFileConfig {
+    #[serde(default)]
+    pub include_paths: Vec<String>,
+    pub angle_includes: Option<bool>,
+}
+
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct RustFileConfig {
+    pub mod_edges: Option<bool>,
+    #[serde(default)]
+    pub cfg: Vec<String>,
+    #[serde(default)]
+    pub exclude_cfg: Vec<String>,
 }
 
 #[derive(Debug, Clone, Deserialize, Default)]
@@ -378,13 +436,27 @@ conditions = ["fanout-increase", "new-scc"]
 
 [go]
 exclude_stdlib = true
+build_tags = ["linux", "amd64"]
 
 [python]
 resolve_relative = true
+ignore_type_only = true
+source_roots = ["src", "services/*/src"]
+follow_reexports = true
 
 [ruby]
 zeitwerk = false
 load_path = ["lib", "app"]
+detect_rails = false
+
+[cpp]
+include_paths = ["include"]
+angle_includes = true
+
+[rust]
+mod_edges = false
+cfg = ["feature=serde"]
+exclude_cfg = ["test"]
 
 [overrides."**/vendor/**"]
 enabled = false
@@ -409,8 +481,18 @@ rules.high_fanout.relative_to_p90 = false
 
         assert_eq!(config.fail_on.conditions.len(), 2);
         assert_eq!(config.go.exclude_stdlib, Some(true));
+        assert_eq!(config.go.build_tags, vec!["linux", "amd64"]);
         assert_eq!(config.python.resolve_relative, Some(true));
+        assert_eq!(config.python.ignore_type_only, Some(true));
+        assert_eq!(config.python.source_roots, vec!["src", "services/*/src"]);
+        assert_eq!(config.python.follow_reexports, Some(true));
         assert_eq!(config.ruby.load_path, vec!["lib", "app"]);
+        assert_eq!(config.ruby.detect_rails, Some(false));
+        assert_eq!(config.cpp.include_paths, vec!["include"]);
+        assert_eq!(config.cpp.angle_includes, Some(true));
+        assert_eq!(config.rust.mod_edges, Some(false));
+        assert_eq!(config.rust.cfg, vec!["feature=serde"]);
+        assert_eq!(config.rust.exclude_cfg, vec!["test"]);
 
         assert_eq!(config.overrides.len(), 2);
         let vendor = &config.overrides["**/vendor/**"];
@@ -524,4 +606,22 @@ lang = "python"
         assert_eq!(web.root, "services/web-frontend");
         assert_eq!(web.lang.as_deref(), Some("python"));
     }
+
+    #[test]
+    fn deserialize_frontends_config() {
+        let toml = r#"
+[frontends.starlark]
+extensions = ["star", "bzl"]
+grammar = "grammars/libtree-sitter-starlark.so"
+query = "grammars/starlark-imports.scm"
+resolve = ["{dir}/{import}"]
+"#;
+        let config = FileConfig::from_toml(toml).unwrap();
+        let starlark = &config.frontends["starlark"];
+        assert_eq!(starlark.extensions, vec!["star", "bzl"]);
+        assert_eq!(starlark.grammar, "grammars/libtree-sitter-starlark.so");
+        assert_eq!(starlark.symbol, None);
+        assert_eq!(starlark.query, "grammars/starlark-imports.scm");
+        assert_eq!(starlark.resolve, vec!["{dir}/{import}"]);
+    }
 }
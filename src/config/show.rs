@@ -1,4 +1,5 @@
 use crate::config::{keys, ResolvedConfig};
+use crate::parse::ruby::RubyLoadPaths;
 use std::io::Write;
 
 /// Render `config show` output.
@@ -24,6 +25,31 @@ pub fn render_show<W: Write>(w: &mut W, config: &ResolvedConfig) -> std::io::Res
     Ok(())
 }
 
+/// Render the settings `config show` inferred from the project layout
+/// rather than read from configuration. Writes nothing when there are none.
+pub fn render_inferred<W: Write>(
+    w: &mut W,
+    ruby_load_paths: &RubyLoadPaths,
+) -> std::io::Result<()> {
+    if ruby_load_paths.inferred.is_empty() {
+        return Ok(());
+    }
+
+    writeln!(w)?;
+    writeln!(w, "Inferred settings:")?;
+    for inferred in &ruby_load_paths.inferred {
+        writeln!(
+            w,
+            "  {}: {} <- inferred from {}",
+            keys::RUBY_LOAD_PATH,
+            inferred.path.display(),
+            inferred.origin.display()
+        )?;
+    }
+
+    Ok(())
+}
+
 /// Render `config explain <category>` output.
 pub fn render_explain<W: Write>(
     w: &mut W,
@@ -43,7 +69,7 @@ pub fn render_explain<W: Write>(
         writeln!(w, "Unknown rule category: {}", category)?;
         writeln!(
             w,
-            "Available categories: high_fanout, god_module, circular_dependency, deep_chain, high_entropy, architecture_policy"
+            "Available categories: high_fanout, god_module, circular_dependency, deep_chain, high_entropy, main_sequence, architecture_policy"
         )?;
         return Ok(());
     }
@@ -77,6 +103,8 @@ fn defaults_value(config: &ResolvedConfig, key: &str) -> Option<String> {
         ),
         keys::DEFAULTS_QUIET => Some(config.quiet.to_string()),
         keys::DEFAULTS_INCLUDE_TESTS => Some(config.include_tests.to_string()),
+        keys::DEFAULTS_INCLUDE_EXTERNAL => Some(config.include_external.to_string()),
+        keys::DEFAULTS_ALLOW_CUSTOM_GRAMMARS => Some(config.allow_custom_grammars.to_string()),
         _ => None,
     }
 }
@@ -90,6 +118,7 @@ fn analyze_value(config: &ResolvedConfig, key: &str) -> Option<String> {
                 .top
                 .map_or("(all)".to_string(), |n| n.to_string()),
         ),
+        keys::ANALYZE_REPORT_SORT => Some(config.analyze_report.sort.to_string()),
         keys::ANALYZE_REPORT_INSIGHTS => Some(match config.analyze_report.insights {
             crate::config::InsightsConfig::Auto => "auto".to_string(),
             crate::config::InsightsConfig::On => "on".to_string(),
@@ -131,6 +160,10 @@ fn analyze_value(config: &ResolvedConfig, key: &str) -> Option<String> {
             "{:?}",
             config.analyze_architecture.forbidden_dependencies
         )),
+        keys::ANALYZE_ARCHITECTURE_FORBIDDEN_PACKAGES => Some(format!(
+            "{:?}",
+            config.analyze_architecture.forbidden_packages
+        )),
         keys::ANALYZE_ARCHITECTURE_EXCEPTIONS => {
             Some(format!("{:?}", config.analyze_architecture.exceptions))
         }
@@ -216,6 +249,10 @@ fn rules_value(config: &ResolvedConfig, key: &str) -> Option<String> {
         keys::RULES_HIGH_ENTROPY_MIN_FANOUT => {
             Some(config.rules.high_entropy.min_fanout.to_string())
         }
+        keys::RULES_MAIN_SEQUENCE_ENABLED => Some(config.rules.main_sequence.enabled.to_string()),
+        keys::RULES_MAIN_SEQUENCE_MAX_DISTANCE => {
+            Some(config.rules.main_sequence.max_distance.to_string())
+        }
         _ => None,
     }
 }
@@ -223,9 +260,19 @@ fn rules_value(config: &ResolvedConfig, key: &str) -> Option<String> {
 fn language_value(config: &ResolvedConfig, key: &str) -> Option<String> {
     match key {
         keys::GO_EXCLUDE_STDLIB => Some(config.go.exclude_stdlib.to_string()),
+        keys::GO_BUILD_TAGS => Some(format!("{:?}", config.go.build_tags)),
         keys::PYTHON_RESOLVE_RELATIVE => Some(config.python.resolve_relative.to_string()),
+        keys::PYTHON_IGNORE_TYPE_ONLY => Some(config.python.ignore_type_only.to_string()),
+        keys::PYTHON_SOURCE_ROOTS => Some(format!("{:?}", config.python.source_roots)),
+        keys::PYTHON_FOLLOW_REEXPORTS => Some(config.python.follow_reexports.to_string()),
         keys::RUBY_ZEITWERK => Some(config.ruby.zeitwerk.to_string()),
         keys::RUBY_LOAD_PATH => Some(format!("{:?}", config.ruby.load_path)),
+        keys::RUBY_DETECT_RAILS => Some(config.ruby.detect_rails.to_string()),
+        keys::CPP_INCLUDE_PATHS => Some(format!("{:?}", config.cpp.include_paths)),
+        keys::CPP_ANGLE_INCLUDES => Some(config.cpp.angle_includes.to_string()),
+        keys::RUST_MOD_EDGES => Some(config.rust.mod_edges.to_string()),
+        keys::RUST_CFG => Some(format!("{:?}", config.rust.cfg)),
+        keys::RUST_EXCLUDE_CFG => Some(format!("{:?}", config.rust.exclude_cfg)),
         _ => None,
     }
 }
@@ -255,6 +302,8 @@ mod tests {
             lang: None,
             quiet: false,
             include_tests: false,
+            include_external: false,
+            allow_custom_grammars: false,
             include: Vec::new(),
             exclude: Vec::new(),
             ignore_patterns: Vec::new(),
@@ -271,8 +320,11 @@ mod tests {
             go: ResolvedGoConfig::default(),
             python: ResolvedPythonConfig::default(),
             ruby: ResolvedRubyConfig::default(),
+            cpp: Default::default(),
+            rust: Default::default(),
             overrides: Vec::new(),
             services: Vec::new(),
+            frontends: Vec::new(),
             provenance: prov,
             loaded_files: vec![PathBuf::from("/project/.untangle.toml")],
         }
@@ -302,6 +354,29 @@ mod tests {
         assert!(output.contains("Loaded config files: (none)"));
     }
 
+    #[test]
+    fn render_inferred_lists_ruby_load_paths() {
+        let load_paths = RubyLoadPaths {
+            configured: vec![PathBuf::from("lib")],
+            inferred: vec![crate::parse::ruby::InferredLoadPath {
+                path: PathBuf::from("app/models"),
+                origin: PathBuf::from("config/application.rb"),
+            }],
+        };
+        let mut buf = Vec::new();
+        render_inferred(&mut buf, &load_paths).unwrap();
+        let output = String::from_utf8(buf).unwrap();
+
+        assert!(output.contains("Inferred settings:"));
+        assert!(
+            output.contains("ruby.load_path: app/models <- inferred from config/application.rb")
+        );
+
+        let mut buf = Vec::new();
+        render_inferred(&mut buf, &RubyLoadPaths::default()).unwrap();
+        assert!(buf.is_empty());
+    }
+
     #[test]
     fn render_explain_known_category() {
         let config = make_test_config();
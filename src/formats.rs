@@ -26,3 +26,11 @@ format_enum!(ArchitectureCheckFormat { Json, Text });
 format_enum!(DiffFormat { Json, Text });
 format_enum!(QualityFormat { Json, Text });
 format_enum!(ServiceGraphFormat { Json, Text, Dot });
+format_enum!(HotspotSort {
+    Fanout,
+    Fanin,
+    Entropy,
+    Betweenness,
+    Pagerank,
+    Articulation
+});
@@ -1,8 +1,9 @@
 use crate::graph::ir::{DepGraph, EdgeKind, GraphEdge, GraphNode, NodeKind};
-use crate::parse::common::SourceLocation;
+use crate::parse::cfg::CfgPredicate;
+use crate::parse::common::{ImportContext, SourceLocation};
 use crate::walk::Language;
 use std::collections::HashMap;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 /// Resolved import ready for graph insertion
 #[derive(Debug, Clone)]
@@ -11,12 +12,75 @@ pub struct ResolvedImport {
     pub target_module: PathBuf,
     pub location: SourceLocation,
     pub language: Option<Language>,
+    pub kind: EdgeKind,
+    pub context: ImportContext,
+}
+
+/// Import of a third-party package, for external dependency nodes
+#[derive(Debug, Clone)]
+pub struct ExternalImport {
+    pub source_module: PathBuf,
+    /// Top-level package, Go module path or crate name
+    pub package: String,
+    pub location: SourceLocation,
+    pub language: Option<Language>,
+    pub context: ImportContext,
+}
+
+/// A function, method or type, identified by its file and line span.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SymbolRef {
+    pub kind: NodeKind,
+    /// File path relative to project root
+    pub path: PathBuf,
+    /// Name as extracted, e.g. `Invoice.total`
+    pub name: String,
+    pub span: (usize, usize),
+    pub language: Option<Language>,
+}
+
+/// Resolved call or type reference ready for graph insertion
+#[derive(Debug, Clone)]
+pub struct ResolvedReference {
+    pub source: SymbolRef,
+    pub target: SymbolRef,
+    pub kind: EdgeKind,
+    pub location: SourceLocation,
+}
+
+/// Human-readable node name: path separators become dots and the source
+/// file extension is dropped (e.g. `src/api/handler.py` → `src.api.handler`).
+/// C/C++ names keep the extension so a header and its source stay distinct.
+fn module_name(path: &Path, language: Option<Language>) -> String {
+    let name = path
+        .to_string_lossy()
+        .replace(['/', '\\'], ".")
+        .trim_end_matches(".py")
+        .trim_end_matches(".rb")
+        .trim_end_matches(".go")
+        .trim_end_matches(".rs")
+        .to_string();
+    match (language, path.extension().and_then(|ext| ext.to_str())) {
+        // Headers and sources share stems (`socket.h`/`socket.cc`); keep them apart.
+        (Some(Language::Cpp), _) => name,
+        // Custom languages only reach here for files with their extensions.
+        (Some(lang), Some(ext))
+            if matches!(lang, Language::Custom(_)) || lang.extensions().contains(&ext) =>
+        {
+            name.strip_suffix(&format!(".{ext}"))
+                .map(str::to_string)
+                .unwrap_or(name)
+        }
+        _ => name,
+    }
 }
 
 /// Builds a DepGraph from resolved imports with node and edge deduplication.
 pub struct GraphBuilder {
     graph: DepGraph,
     node_map: HashMap<PathBuf, petgraph::graph::NodeIndex>,
+    symbol_map: HashMap<SymbolRef, petgraph::graph::NodeIndex>,
+    external_map: HashMap<String, petgraph::graph::NodeIndex>,
 }
 
 impl GraphBuilder {
@@ -24,6 +88,8 @@ impl GraphBuilder {
         Self {
             graph: DepGraph::new(),
             node_map: HashMap::new(),
+            symbol_map: HashMap::new(),
+            external_map: HashMap::new(),
         }
     }
 
@@ -36,14 +102,7 @@ impl GraphBuilder {
         if let Some(&idx) = self.node_map.get(path) {
             return idx;
         }
-        let name = path
-            .to_string_lossy()
-            .replace(['/', '\\'], ".")
-            .trim_end_matches(".py")
-            .trim_end_matches(".rb")
-            .trim_end_matches(".go")
-            .trim_end_matches(".rs")
-            .to_string();
+        let name = module_name(path, language);
         let idx = self.graph.add_node(GraphNode {
             kind: NodeKind::Module,
             path: path.clone(),
@@ -55,25 +114,159 @@ impl GraphBuilder {
         idx
     }
 
+    /// Get or create a node for the given symbol. Names that repeat in a
+    /// file (trait impls of the same method) get their start line appended.
+    fn ensure_symbol(&mut self, symbol: &SymbolRef) -> petgraph::graph::NodeIndex {
+        if let Some(&idx) = self.symbol_map.get(symbol) {
+            return idx;
+        }
+        let mut name = format!(
+            "{}::{}",
+            module_name(&symbol.path, symbol.language),
+            symbol.name
+        );
+        if self
+            .symbol_map
+            .keys()
+            .any(|other| other.path == symbol.path && other.name == symbol.name)
+        {
+            name = format!("{name}:{}", symbol.span.0);
+        }
+        let idx = self.graph.add_node(GraphNode {
+            kind: symbol.kind,
+            path: symbol.path.clone(),
+            name,
+            span: Some(symbol.span),
+            language: symbol.language,
+        });
+        self.symbol_map.insert(symbol.clone(), idx);
+        idx
+    }
+
+    /// Add a resolved call or type reference to the graph. References from a
+    /// symbol to itself are left out.
+    pub fn add_reference(&mut self, reference: &ResolvedReference) {
+        if reference.source == reference.target {
+            return;
+        }
+        let source_idx = self.ensure_symbol(&reference.source);
+        let target_idx = self.ensure_symbol(&reference.target);
+
+        if let Some(edge_idx) = self.graph.find_edge(source_idx, target_idx) {
+            let edge = &mut self.graph[edge_idx];
+            // Inheritance, mixins and implementations outrank plain mentions.
+            if edge.kind == EdgeKind::References {
+                edge.kind = reference.kind;
+            }
+            if !edge.source_locations.contains(&reference.location) {
+                edge.source_locations.push(reference.location.clone());
+            }
+        } else {
+            self.graph.add_edge(
+                source_idx,
+                target_idx,
+                GraphEdge {
+                    kind: reference.kind,
+                    source_locations: vec![reference.location.clone()],
+                    weight: 1,
+                    cfg: None,
+                    context: ImportContext::Runtime,
+                },
+            );
+        }
+    }
+
+    /// Add a module node, whether or not any import reaches it.
+    pub fn add_module(&mut self, path: &PathBuf, language: Option<Language>) {
+        self.ensure_node(path, language);
+    }
+
+    /// Get or create the node for a third-party package.
+    fn ensure_external(
+        &mut self,
+        package: &str,
+        language: Option<Language>,
+    ) -> petgraph::graph::NodeIndex {
+        if let Some(&idx) = self.external_map.get(package) {
+            return idx;
+        }
+        let idx = self.graph.add_node(GraphNode {
+            kind: NodeKind::External,
+            path: PathBuf::from(package),
+            name: package.to_string(),
+            span: None,
+            language,
+        });
+        self.external_map.insert(package.to_string(), idx);
+        idx
+    }
+
     /// Add a resolved import to the graph.
     pub fn add_import(&mut self, import: &ResolvedImport) {
         let source_idx = self.ensure_node(&import.source_module, import.language);
         let target_idx = self.ensure_node(&import.target_module, import.language);
+        self.connect(
+            source_idx,
+            target_idx,
+            import.kind,
+            &import.location,
+            import.context,
+        );
+    }
+
+    /// Add an import of a third-party package to the graph.
+    pub fn add_external(&mut self, import: &ExternalImport) {
+        let source_idx = self.ensure_node(&import.source_module, import.language);
+        let target_idx = self.ensure_external(&import.package, import.language);
+        self.connect(
+            source_idx,
+            target_idx,
+            EdgeKind::Import,
+            &import.location,
+            import.context,
+        );
+    }
 
+    fn connect(
+        &mut self,
+        source_idx: petgraph::graph::NodeIndex,
+        target_idx: petgraph::graph::NodeIndex,
+        kind: EdgeKind,
+        location: &SourceLocation,
+        context: ImportContext,
+    ) {
         // Check if edge already exists
         if let Some(edge_idx) = self.graph.find_edge(source_idx, target_idx) {
             let edge = &mut self.graph[edge_idx];
-            if !edge.source_locations.contains(&import.location) {
-                edge.source_locations.push(import.location.clone());
+            // A `use` of a declared module is the stronger relationship.
+            if edge.kind == EdgeKind::Contains && kind == EdgeKind::Import {
+                edge.kind = EdgeKind::Import;
+            }
+            // The edge exists under any of its imports' predicates.
+            edge.cfg = match (edge.cfg.take(), &location.cfg) {
+                (Some(existing), Some(cfg)) => {
+                    match (CfgPredicate::parse(&existing), CfgPredicate::parse(cfg)) {
+                        (Some(a), Some(b)) => Some(a.or(b).to_string()),
+                        _ => Some(existing),
+                    }
+                }
+                _ => None,
+            };
+            // The edge is as strong as its strongest import.
+            edge.context = edge.context.max(context);
+            if !edge.source_locations.contains(location) {
+                edge.source_locations.push(location.clone());
             }
         } else {
             self.graph.add_edge(
                 source_idx,
                 target_idx,
                 GraphEdge {
-                    kind: EdgeKind::Import,
-                    source_locations: vec![import.location.clone()],
+                    kind,
+                    source_locations: vec![location.clone()],
                     weight: 1,
+                    cfg: location.cfg.clone(),
+                    context,
                 },
             );
         }
@@ -117,8 +310,11 @@ mod tests {
                 file: PathBuf::from("a.go"),
                 line: 1,
                 column: None,
+                cfg: None,
             },
             language: None,
+            kind: EdgeKind::Import,
+            context: ImportContext::Runtime,
         };
         let import2 = ResolvedImport {
             source_module: PathBuf::from("a.go"),
@@ -127,8 +323,11 @@ mod tests {
                 file: PathBuf::from("a.go"),
                 line: 2,
                 column: None,
+                cfg: None,
             },
             language: None,
+            kind: EdgeKind::Import,
+            context: ImportContext::Runtime,
         };
         builder.add_import(&import1);
         builder.add_import(&import2);
@@ -147,8 +346,11 @@ mod tests {
                 file: PathBuf::from("a.go"),
                 line: 1,
                 column: None,
+                cfg: None,
             },
             language: None,
+            kind: EdgeKind::Import,
+            context: ImportContext::Runtime,
         };
         let import2 = ResolvedImport {
             source_module: PathBuf::from("a.go"),
@@ -157,8 +359,11 @@ mod tests {
                 file: PathBuf::from("a.go"),
                 line: 5,
                 column: None,
+                cfg: None,
             },
             language: None,
+            kind: EdgeKind::Import,
+            context: ImportContext::Runtime,
         };
         builder.add_import(&import1);
         builder.add_import(&import2);
@@ -168,4 +373,100 @@ mod tests {
         let edge = graph.edge_weights().next().unwrap();
         assert_eq!(edge.source_locations.len(), 2);
     }
+
+    #[test]
+    fn import_edges_take_precedence_over_containment() {
+        let mut builder = GraphBuilder::new();
+        let declaration = ResolvedImport {
+            source_module: PathBuf::from("src/lib.rs"),
+            target_module: PathBuf::from("src/config.rs"),
+            location: SourceLocation {
+                file: PathBuf::from("src/lib.rs"),
+                line: 1,
+                column: None,
+                cfg: None,
+            },
+            language: Some(Language::Rust),
+            kind: EdgeKind::Contains,
+            context: ImportContext::Runtime,
+        };
+        builder.add_import(&declaration);
+        assert_eq!(
+            builder.graph.edge_weights().next().unwrap().kind,
+            EdgeKind::Contains
+        );
+
+        builder.add_import(&ResolvedImport {
+            location: SourceLocation {
+                file: PathBuf::from("src/lib.rs"),
+                line: 3,
+                column: None,
+                cfg: None,
+            },
+            kind: EdgeKind::Import,
+            context: ImportContext::Runtime,
+            ..declaration
+        });
+        let graph = builder.build();
+        let edge = graph.edge_weights().next().unwrap();
+        assert_eq!(edge.kind, EdgeKind::Import);
+        assert_eq!(edge.source_locations.len(), 2);
+    }
+
+    #[test]
+    fn edge_cfg_is_the_disjunction_of_location_cfgs() {
+        let import = |line: usize, cfg: Option<&str>| ResolvedImport {
+            source_module: PathBuf::from("src/lib.rs"),
+            target_module: PathBuf::from("src/yaml.rs"),
+            location: SourceLocation {
+                file: PathBuf::from("src/lib.rs"),
+                line,
+                column: None,
+                cfg: cfg.map(str::to_string),
+            },
+            language: Some(Language::Rust),
+            kind: EdgeKind::Import,
+            context: ImportContext::Runtime,
+        };
+
+        let mut builder = GraphBuilder::new();
+        builder.add_import(&import(1, Some("test")));
+        builder.add_import(&import(2, Some("feature = \"yaml\"")));
+        let graph = builder.build();
+        assert_eq!(
+            graph.edge_weights().next().unwrap().cfg.as_deref(),
+            Some("any(test, feature = \"yaml\")")
+        );
+
+        let mut builder = GraphBuilder::new();
+        builder.add_import(&import(1, Some("test")));
+        builder.add_import(&import(2, None));
+        builder.add_import(&import(3, Some("test")));
+        let graph = builder.build();
+        assert_eq!(graph.edge_weights().next().unwrap().cfg, None);
+    }
+
+    #[test]
+    fn module_names_drop_language_extensions() {
+        assert_eq!(
+            module_name(Path::new("src/api/handler.py"), None),
+            "src.api.handler"
+        );
+        assert_eq!(
+            module_name(
+                Path::new("src/components/Button.tsx"),
+                Some(Language::TypeScript)
+            ),
+            "src.components.Button"
+        );
+        // Go package directories keep dotted segments that look like extensions.
+        assert_eq!(
+            module_name(Path::new("pkg/js"), Some(Language::Go)),
+            "pkg.js"
+        );
+        assert_eq!(
+            module_name(Path::new("src/net/socket.h"), Some(Language::Cpp)),
+            "src.net.socket.h"
+        );
+    }
 }
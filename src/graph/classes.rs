@@ -0,0 +1,416 @@
+use crate::analysis_context::AnalysisContext;
+use crate::config::ResolvedConfig;
+use crate::graph::builder::{GraphBuilder, ResolvedReference, SymbolRef};
+use crate::graph::ir::{DepGraph, NodeKind};
+use crate::graph::symbols::{
+    extract_source_files, module_imports, module_labels, module_scopes, narrow, normalize, owner,
+    SourceFile,
+};
+use crate::parse::common::SourceLocation;
+use crate::quality::complexity::{
+    last_segment, FileTypes, TypeDecl, TypeKind, TypeReference, TypeRelation,
+};
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+
+/// Class-level graph: one node per class, module, struct, trait or interface
+/// that takes part in a relationship, with `inherits`, `mixin`, `implements`
+/// and `references` edges between them.
+///
+/// Type names are matched against the types of the referring module and of
+/// the modules it imports. A reference is only linked when exactly one
+/// candidate remains.
+pub fn build_class_graph(
+    context: &AnalysisContext,
+    config: &ResolvedConfig,
+    module_graph: &DepGraph,
+) -> DepGraph {
+    let files = extract_source_files(context, config, |frontend, source, _| {
+        frontend.extract_types(source)
+    });
+
+    let mut builder = GraphBuilder::new();
+    for reference in link_types(&files, &module_imports(module_graph)) {
+        builder.add_reference(&reference);
+    }
+    builder.build()
+}
+
+/// Resolve the type references in `files` to the declarations they name.
+/// `imports` maps each module to the modules it depends on.
+pub fn link_types(
+    files: &[SourceFile<FileTypes>],
+    imports: &HashMap<PathBuf, HashSet<PathBuf>>,
+) -> Vec<ResolvedReference> {
+    let mut references = Vec::new();
+    for (file, scope) in files.iter().zip(module_scopes(files, imports)) {
+        for reference in &file.symbols.references {
+            let Some((source_file, source)) = owning_type(file, &scope, reference) else {
+                continue;
+            };
+            let mut candidates: Vec<(&SourceFile<FileTypes>, &TypeDecl)> = scope
+                .iter()
+                .flat_map(|candidate_file| {
+                    candidate_file
+                        .symbols
+                        .declarations
+                        .iter()
+                        .filter(|decl| last_segment(&decl.name) == reference.name)
+                        .map(move |decl| (*candidate_file, decl))
+                })
+                .collect();
+            narrow(&mut candidates, |(candidate_file, _)| {
+                candidate_file.file == file.file
+            });
+            if let Some(qualifier) = &reference.qualifier {
+                let label = normalize(last_segment(qualifier));
+                narrow(&mut candidates, |(candidate_file, decl)| {
+                    owner(&decl.name).is_some_and(|owner| normalize(owner) == label)
+                        || module_labels(candidate_file).contains(&label)
+                });
+            }
+            let [(target_file, target)] = candidates.as_slice() else {
+                continue;
+            };
+            references.push(ResolvedReference {
+                source: class_ref(source_file, source),
+                target: class_ref(target_file, target),
+                kind: reference.relation.into(),
+                location: SourceLocation {
+                    file: file.file.clone(),
+                    line: reference.line,
+                    column: None,
+                    cfg: None,
+                },
+            });
+        }
+    }
+    references
+}
+
+/// Types a module declares, for component abstractness.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TypeCounts {
+    pub types: usize,
+    pub abstract_types: usize,
+}
+
+/// Count the types each module declares and how many of them are abstract.
+pub fn count_module_types(
+    context: &AnalysisContext,
+    config: &ResolvedConfig,
+) -> HashMap<PathBuf, TypeCounts> {
+    let files = extract_source_files(context, config, |frontend, source, _| {
+        frontend.extract_types(source)
+    });
+    module_type_counts(&files)
+}
+
+/// Interfaces, traits, protocols and abstract base classes are abstract.
+/// A Ruby module counts as abstract when some type mixes it in; otherwise
+/// it is only a namespace and not counted at all.
+pub fn module_type_counts(files: &[SourceFile<FileTypes>]) -> HashMap<PathBuf, TypeCounts> {
+    let mixins: HashSet<&str> = files
+        .iter()
+        .flat_map(|file| &file.symbols.references)
+        .filter(|reference| reference.relation == TypeRelation::Mixin)
+        .map(|reference| reference.name.as_str())
+        .collect();
+
+    let mut counts: HashMap<PathBuf, TypeCounts> = HashMap::new();
+    for file in files {
+        let module = counts.entry(file.module.clone()).or_default();
+        for decl in &file.symbols.declarations {
+            let is_abstract = match decl.kind {
+                TypeKind::Concrete => false,
+                TypeKind::Abstract => true,
+                TypeKind::Module if mixins.contains(last_segment(&decl.name)) => true,
+                TypeKind::Module => continue,
+            };
+            module.types += 1;
+            if is_abstract {
+                module.abstract_types += 1;
+            }
+        }
+    }
+    counts
+}
+
+/// Declaration a reference belongs to: in the same file, or for Rust `impl`
+/// blocks and Go methods, anywhere in the same module.
+fn owning_type<'a>(
+    file: &'a SourceFile<FileTypes>,
+    scope: &[&'a SourceFile<FileTypes>],
+    reference: &TypeReference,
+) -> Option<(&'a SourceFile<FileTypes>, &'a TypeDecl)> {
+    if let Some(decl) = file
+        .symbols
+        .declarations
+        .iter()
+        .find(|decl| decl.name == reference.owner)
+    {
+        return Some((file, decl));
+    }
+    let mut candidates: Vec<_> = scope
+        .iter()
+        .filter(|candidate_file| candidate_file.module == file.module)
+        .flat_map(|candidate_file| {
+            candidate_file
+                .symbols
+                .declarations
+                .iter()
+                .filter(|decl| last_segment(&decl.name) == last_segment(&reference.owner))
+                .map(move |decl| (*candidate_file, decl))
+        })
+        .collect();
+    match candidates.len() {
+        1 => candidates.pop(),
+        _ => None,
+    }
+}
+
+fn class_ref(file: &SourceFile<FileTypes>, decl: &TypeDecl) -> SymbolRef {
+    SymbolRef {
+        kind: NodeKind::Class,
+        path: file.file.clone(),
+        name: decl.name.clone(),
+        span: (decl.start_line, decl.end_line),
+        language: Some(file.language),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::ir::EdgeKind;
+    use crate::metrics::scc::find_non_trivial_sccs;
+    use crate::walk::Language;
+
+    fn decl(name: &str, start_line: usize, end_line: usize) -> TypeDecl {
+        TypeDecl {
+            name: name.to_string(),
+            start_line,
+            end_line,
+            kind: TypeKind::Concrete,
+        }
+    }
+
+    fn reference(
+        owner: &str,
+        name: &str,
+        qualifier: Option<&str>,
+        relation: TypeRelation,
+        line: usize,
+    ) -> TypeReference {
+        TypeReference {
+            owner: owner.to_string(),
+            name: name.to_string(),
+            qualifier: qualifier.map(str::to_string),
+            relation,
+            line,
+        }
+    }
+
+    fn file(
+        path: &str,
+        module: &str,
+        declarations: Vec<TypeDecl>,
+        references: Vec<TypeReference>,
+    ) -> SourceFile<FileTypes> {
+        SourceFile {
+            file: PathBuf::from(path),
+            module: PathBuf::from(module),
+            language: Language::Ruby,
+            symbols: FileTypes {
+                declarations,
+                references,
+            },
+        }
+    }
+
+    fn edges(references: &[ResolvedReference]) -> Vec<(&str, &str, EdgeKind)> {
+        references
+            .iter()
+            .map(|r| (r.source.name.as_str(), r.target.name.as_str(), r.kind))
+            .collect()
+    }
+
+    #[test]
+    fn links_inheritance_mixins_and_references_across_imports() {
+        let files = vec![
+            file(
+                "app/invoice.rb",
+                "app/invoice.rb",
+                vec![decl("Invoice", 1, 9)],
+                vec![
+                    reference("Invoice", "Record", None, TypeRelation::Inherits, 1),
+                    reference("Invoice", "Payable", None, TypeRelation::Mixin, 2),
+                    reference(
+                        "Invoice",
+                        "Card",
+                        Some("Billing"),
+                        TypeRelation::References,
+                        5,
+                    ),
+                ],
+            ),
+            file(
+                "app/record.rb",
+                "app/record.rb",
+                vec![decl("Record", 1, 3)],
+                vec![],
+            ),
+            file(
+                "app/payable.rb",
+                "app/payable.rb",
+                vec![decl("Payable", 1, 3)],
+                vec![],
+            ),
+            file(
+                "app/billing/card.rb",
+                "app/billing/card.rb",
+                vec![decl("Billing", 1, 5), decl("Billing::Card", 2, 4)],
+                vec![],
+            ),
+            // Not imported
+            file(
+                "app/wallet/card.rb",
+                "app/wallet/card.rb",
+                vec![decl("Card", 1, 3)],
+                vec![],
+            ),
+        ];
+        let mut imports: HashMap<PathBuf, HashSet<PathBuf>> = HashMap::new();
+        imports.insert(
+            PathBuf::from("app/invoice.rb"),
+            ["app/record.rb", "app/payable.rb", "app/billing/card.rb"]
+                .into_iter()
+                .map(PathBuf::from)
+                .collect(),
+        );
+
+        let references = link_types(&files, &imports);
+        assert_eq!(
+            edges(&references),
+            vec![
+                ("Invoice", "Record", EdgeKind::Inherits),
+                ("Invoice", "Payable", EdgeKind::Mixin),
+                ("Invoice", "Billing::Card", EdgeKind::References),
+            ]
+        );
+    }
+
+    #[test]
+    fn impl_blocks_attach_to_types_declared_elsewhere_in_the_module() {
+        let files = vec![
+            file(
+                "src/ledger.rs",
+                "src/ledger.rs",
+                vec![decl("Ledger", 1, 3), decl("Store", 5, 7)],
+                vec![],
+            ),
+            file(
+                "src/ledger/store.rs",
+                "src/ledger.rs",
+                vec![],
+                vec![reference(
+                    "Ledger",
+                    "Store",
+                    None,
+                    TypeRelation::Implements,
+                    1,
+                )],
+            ),
+        ];
+        let references = link_types(&files, &HashMap::new());
+        assert_eq!(
+            edges(&references),
+            vec![("Ledger", "Store", EdgeKind::Implements)]
+        );
+        assert_eq!(references[0].source.path, PathBuf::from("src/ledger.rs"));
+        assert_eq!(
+            references[0].location.file,
+            PathBuf::from("src/ledger/store.rs")
+        );
+    }
+
+    #[test]
+    fn class_cycles_inside_one_file_are_strongly_connected() {
+        let files = vec![file(
+            "models.py",
+            "models.py",
+            vec![
+                decl("Order", 1, 5),
+                decl("Customer", 7, 11),
+                decl("Report", 13, 15),
+            ],
+            vec![
+                reference("Order", "Customer", None, TypeRelation::References, 3),
+                reference("Customer", "Order", None, TypeRelation::References, 9),
+                reference("Report", "Order", None, TypeRelation::References, 14),
+            ],
+        )];
+        let mut builder = GraphBuilder::new();
+        for reference in link_types(&files, &HashMap::new()) {
+            builder.add_reference(&reference);
+        }
+        let graph = builder.build();
+        assert_eq!(graph.node_count(), 3);
+        let sccs = find_non_trivial_sccs(&graph);
+        assert_eq!(sccs.len(), 1);
+        assert_eq!(sccs[0].size, 2);
+    }
+
+    #[test]
+    fn counts_abstract_types_per_module() {
+        use crate::quality::complexity::python::PythonComplexity;
+        use crate::quality::complexity::ruby::RubyComplexity;
+        use crate::quality::complexity::ComplexityFrontend;
+
+        let python = br#"
+class Repository(ABC):
+    pass
+
+class Clock(typing.Protocol[T]):
+    pass
+
+class Codec(metaclass=abc.ABCMeta):
+    pass
+
+class Order(Base, frozen=True):
+    pass
+"#;
+        let ruby = br#"
+module Billing
+  module Auditable
+  end
+
+  class Invoice
+    include Auditable
+  end
+end
+"#;
+        let mut python_file = file("shop/models.py", "shop/models.py", vec![], vec![]);
+        python_file.language = Language::Python;
+        python_file.symbols = PythonComplexity.extract_types(python);
+        let mut ruby_file = file("app/billing.rb", "app/billing.rb", vec![], vec![]);
+        ruby_file.symbols = RubyComplexity.extract_types(ruby);
+
+        let counts = module_type_counts(&[python_file, ruby_file]);
+        assert_eq!(
+            counts[&PathBuf::from("shop/models.py")],
+            TypeCounts {
+                types: 4,
+                abstract_types: 3
+            }
+        );
+        // `Billing` is only a namespace; `Auditable` is mixed into `Invoice`.
+        assert_eq!(
+            counts[&PathBuf::from("app/billing.rb")],
+            TypeCounts {
+                types: 2,
+                abstract_types: 1
+            }
+        );
+    }
+}
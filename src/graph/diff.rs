@@ -1,15 +1,28 @@
+use crate::analysis_context::{AnalysisContext, RustWorkspaceContext};
 use crate::architecture::policy::{
     self, ArchitectureCheckResult, ArchitectureCycle, ArchitectureViolation,
 };
-use crate::config::ResolvedArchitectureConfig;
+use crate::config::{ResolvedArchitectureConfig, ResolvedConfig};
 use crate::errors::Result;
 use crate::graph::builder::{GraphBuilder, ResolvedImport};
 use crate::graph::ir::DepGraph;
-use crate::metrics::scc::find_non_trivial_sccs;
+use crate::graph::load::edge_admitted;
+use crate::metrics::propagation::Reachability;
+use crate::metrics::scc::{find_non_trivial_sccs_with, SccOptions};
 use crate::metrics::summary::Summary;
 use crate::parse::common::{ImportConfidence, SourceLocation};
-use crate::parse::go::GoFrontend;
+use crate::parse::cpp::CppIncludePaths;
+use crate::parse::csharp::CSharpWorkspace;
+use crate::parse::custom;
+use crate::parse::elixir::MixProject;
+use crate::parse::factory;
+use crate::parse::go::GoWorkspace;
+use crate::parse::jvm::JvmSourceIndex;
+use crate::parse::php::ComposerAutoload;
+use crate::parse::python::{PythonFileIndex, PythonReexports, PythonSourceRoots};
+use crate::parse::ruby::{RubyConstantIndex, RubyLoadPaths};
 use crate::parse::rust::RustFrontend;
+use crate::parse::typescript::TypeScriptProject;
 use crate::parse::ParseFrontend;
 use crate::walk::Language;
 use petgraph::visit::EdgeRef;
@@ -17,6 +30,7 @@ use petgraph::Direction;
 use serde::Serialize;
 use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use std::time::Instant;
 
 #[derive(Debug, Clone, Serialize)]
@@ -32,6 +46,7 @@ pub struct DiffResult {
     pub new_edges: Vec<EdgeChange>,
     pub removed_edges: Vec<EdgeChange>,
     pub fanout_changes: Vec<FanoutChange>,
+    pub blast_radius_increases: Vec<BlastRadiusChange>,
     pub scc_changes: SccChanges,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub architecture_policy_delta: Option<ArchitecturePolicyDelta>,
@@ -74,6 +89,8 @@ pub struct SummaryDelta {
     pub mean_entropy_delta: f64,
     pub max_depth_delta: isize,
     pub total_complexity_delta: isize,
+    pub propagation_cost_delta: f64,
+    pub max_blast_radius_delta: isize,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -94,6 +111,16 @@ pub struct FanoutChange {
     pub new_targets: Vec<EdgeChange>,
 }
 
+/// A module in both graphs that more modules depend on, directly or
+/// transitively, in head than in base.
+#[derive(Debug, Clone, Serialize)]
+pub struct BlastRadiusChange {
+    pub node: String,
+    pub blast_radius_before: usize,
+    pub blast_radius_after: usize,
+    pub delta: isize,
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct SccChanges {
     pub new_sccs: Vec<SccChange>,
@@ -125,6 +152,7 @@ pub enum FailCondition {
     NewArchitectureViolation,
     NewArchitectureCycle,
     ArchitectureCycleGrowth,
+    BlastRadiusIncrease,
 }
 
 impl FailCondition {
@@ -138,6 +166,7 @@ impl FailCondition {
             "new-architecture-violation" => Some(Self::NewArchitectureViolation),
             "new-architecture-cycle" => Some(Self::NewArchitectureCycle),
             "architecture-cycle-growth" => Some(Self::ArchitectureCycleGrowth),
+            "blast-radius-increase" => Some(Self::BlastRadiusIncrease),
             value if value.starts_with("fanout-threshold") => value
                 .split('=')
                 .nth(1)
@@ -157,9 +186,9 @@ pub struct DiffAnalysisRequest<'a> {
     pub include: &'a [String],
     pub exclude: &'a [String],
     pub include_tests: bool,
-    pub go_exclude_stdlib: bool,
-    pub ruby_load_paths: &'a [PathBuf],
-    pub ruby_zeitwerk: bool,
+    pub config: &'a ResolvedConfig,
+    /// Which edges count towards cycles in the SCC comparison
+    pub scc_options: SccOptions,
     pub conditions: &'a [FailCondition],
     pub architecture_config: Option<&'a ResolvedArchitectureConfig>,
 }
@@ -174,9 +203,7 @@ pub fn analyze_repo_diff(request: DiffAnalysisRequest<'_>) -> Result<DiffResult>
         request.include,
         request.exclude,
         request.include_tests,
-        request.go_exclude_stdlib,
-        request.ruby_load_paths,
-        request.ruby_zeitwerk,
+        request.config,
     )?;
     let head_graph = build_graph_at_ref(
         request.repo,
@@ -186,12 +213,10 @@ pub fn analyze_repo_diff(request: DiffAnalysisRequest<'_>) -> Result<DiffResult>
         request.include,
         request.exclude,
         request.include_tests,
-        request.go_exclude_stdlib,
-        request.ruby_load_paths,
-        request.ruby_zeitwerk,
+        request.config,
     )?;
 
-    let diff = compute_raw_diff(&base_graph, &head_graph);
+    let diff = compute_raw_diff(&base_graph, &head_graph, request.scc_options);
     let architecture_policy_delta = request.architecture_config.map(|config| {
         compute_architecture_policy_delta(&base_graph, &head_graph, request.root, config)
     });
@@ -220,6 +245,7 @@ pub fn analyze_repo_diff(request: DiffAnalysisRequest<'_>) -> Result<DiffResult>
         new_edges: diff.new_edges,
         removed_edges: diff.removed_edges,
         fanout_changes: diff.fanout_changes,
+        blast_radius_increases: diff.blast_radius_increases,
         scc_changes: diff.scc_changes,
         architecture_policy_delta,
     })
@@ -365,13 +391,11 @@ fn build_graph_at_ref(
     include: &[String],
     exclude: &[String],
     include_tests: bool,
-    go_exclude_stdlib: bool,
-    ruby_load_paths: &[PathBuf],
-    zeitwerk: bool,
+    config: &ResolvedConfig,
 ) -> Result<DepGraph> {
     let extensions: Vec<&str> = langs
         .iter()
-        .flat_map(|lang| lang.extensions().iter().copied())
+        .flat_map(|&lang| crate::walk::language_extensions(lang, &config.frontends))
         .collect();
     let all_files = crate::git::list_files_at_ref(repo, reference, &extensions)?;
 
@@ -398,15 +422,37 @@ fn build_graph_at_ref(
         None
     };
 
+    let test_excludes: HashMap<Language, globset::GlobSet> = if include_tests {
+        HashMap::new()
+    } else {
+        langs
+            .iter()
+            .filter_map(|&lang| {
+                let mut builder = globset::GlobSetBuilder::new();
+                for pattern in lang.default_excludes() {
+                    if let Ok(glob) = globset::Glob::new(&pattern) {
+                        builder.add(glob);
+                    }
+                }
+                builder.build().ok().map(|set| (lang, set))
+            })
+            .collect()
+    };
+
     let mut files_by_lang: HashMap<Language, Vec<PathBuf>> = HashMap::new();
     for file in all_files {
-        let lang = match crate::walk::language_for_file(&file) {
+        let lang = match crate::walk::language_for_file(&file, &config.frontends) {
             Some(lang) if langs.contains(&lang) => lang,
             _ => continue,
         };
 
-        let path_str = file.to_string_lossy();
-        if !include_tests && lang == Language::Go && path_str.ends_with("_test.go") {
+        if let Some(set) = test_excludes.get(&lang) {
+            let file_name = file.file_name().map(Path::new).unwrap_or(&file);
+            if set.is_match(&file) || set.is_match(file_name) {
+                continue;
+            }
+        }
+        if lang.is_vendored(&file) {
             continue;
         }
         if let Some(ref set) = exclude_set {
@@ -423,52 +469,32 @@ fn build_graph_at_ref(
         files_by_lang.entry(lang).or_default().push(file);
     }
 
-    let go_module_map: HashMap<PathBuf, String> = if langs.contains(&Language::Go) {
-        crate::git::find_files_by_name_at_ref(repo, reference, "go.mod")
-            .unwrap_or_default()
-            .into_iter()
-            .filter_map(|(path, content)| {
-                let dir = path.parent()?.to_path_buf();
-                let source = String::from_utf8(content).ok()?;
-                let module_path = crate::parse::go::parse_go_mod_module(&source)?;
-                Some((dir, module_path))
-            })
-            .collect()
-    } else {
-        HashMap::new()
-    };
+    let context = context_at_ref(repo, reference, root, langs, files_by_lang, config)?;
 
-    let root_go_module = go_module_map
-        .get(Path::new(""))
-        .or_else(|| go_module_map.get(Path::new(".")))
-        .cloned()
-        .or_else(|| {
-            crate::git::read_file_at_ref(repo, reference, Path::new("go.mod"))
-                .ok()
-                .and_then(|content| {
-                    String::from_utf8(content)
-                        .ok()
-                        .and_then(|source| crate::parse::go::parse_go_mod_module(&source))
-                })
-        });
+    let frontends: HashMap<Language, Box<dyn ParseFrontend>> = langs
+        .iter()
+        .map(|&lang| {
+            let frontend =
+                factory::create_frontend(lang, config, &context.go_module_path, &context)?;
+            Ok((lang, frontend))
+        })
+        .collect::<Result<_>>()?;
 
-    let go_resolvers: HashMap<PathBuf, Box<dyn ParseFrontend>> = go_module_map
+    let go_resolvers: HashMap<PathBuf, Box<dyn ParseFrontend>> = context
+        .go_modules
         .iter()
         .map(|(mod_root, mod_path)| {
-            let frontend = GoFrontend::with_module_path(mod_path.clone())
-                .with_exclude_stdlib(go_exclude_stdlib);
-            (
-                mod_root.clone(),
-                Box::new(frontend) as Box<dyn ParseFrontend>,
-            )
+            let frontend =
+                factory::create_frontend(Language::Go, config, &Some(mod_path.clone()), &context)?;
+            Ok((mod_root.clone(), frontend))
         })
-        .collect();
+        .collect::<Result<_>>()?;
 
     let go_files_by_module: HashMap<PathBuf, Vec<PathBuf>> = {
         let mut by_module: HashMap<PathBuf, Vec<PathBuf>> = HashMap::new();
-        if let Some(go_files) = files_by_lang.get(&Language::Go) {
+        if let Some(go_files) = context.files_by_lang.get(&Language::Go) {
             for file in go_files {
-                let mod_root = crate::walk::find_go_module_root(file, &go_module_map)
+                let mod_root = crate::walk::find_go_module_root(file, &context.go_modules)
                     .map(|(root, _)| root.to_path_buf())
                     .unwrap_or_default();
                 by_module
@@ -480,47 +506,10 @@ fn build_graph_at_ref(
         by_module
     };
 
-    let mut frontends: HashMap<Language, Box<dyn ParseFrontend>> = HashMap::new();
-    for &lang in langs {
-        if lang == Language::Go {
-            let frontend = match &root_go_module {
-                Some(module_path) => GoFrontend::with_module_path(module_path.clone()),
-                None => GoFrontend::new(),
-            };
-            frontends.insert(
-                lang,
-                Box::new(frontend.with_exclude_stdlib(go_exclude_stdlib)),
-            );
-            continue;
-        }
-
-        let frontend: Box<dyn ParseFrontend> = match lang {
-            Language::Python => Box::new(crate::parse::python::PythonFrontend::new()),
-            Language::Ruby => Box::new(
-                crate::parse::ruby::RubyFrontend::with_load_paths(ruby_load_paths.to_vec())
-                    .with_zeitwerk(zeitwerk),
-            ),
-            Language::Rust => {
-                let cargo_toml =
-                    crate::git::read_file_at_ref(repo, reference, Path::new("Cargo.toml")).ok();
-                let crate_name = cargo_toml.and_then(|content| {
-                    String::from_utf8(content)
-                        .ok()
-                        .and_then(|source| RustFrontend::parse_crate_name(&source))
-                });
-                Box::new(match crate_name {
-                    Some(name) => RustFrontend::with_crate_name(name),
-                    None => RustFrontend::new(),
-                })
-            }
-            Language::Go => unreachable!(),
-        };
-        frontends.insert(lang, frontend);
-    }
-
+    let cfg_selection = config.rust.cfg_selection();
     let mut builder = GraphBuilder::new();
 
-    for (&lang, files) in &files_by_lang {
+    for (&lang, files) in &context.files_by_lang {
         for file_path in files {
             let source = match crate::git::read_file_at_ref(repo, reference, file_path) {
                 Ok(source) => source,
@@ -529,7 +518,7 @@ fn build_graph_at_ref(
 
             let (frontend, resolve_files): (&dyn ParseFrontend, &[PathBuf]) =
                 if lang == Language::Go {
-                    let mod_root = crate::walk::find_go_module_root(file_path, &go_module_map)
+                    let mod_root = crate::walk::find_go_module_root(file_path, &context.go_modules)
                         .map(|(root, _)| root.to_path_buf())
                         .unwrap_or_default();
                     let resolver = go_resolvers
@@ -549,21 +538,25 @@ fn build_graph_at_ref(
             let imports = frontend.extract_imports(&source, file_path);
 
             for raw in &imports {
-                if matches!(
-                    raw.confidence,
-                    ImportConfidence::External
-                        | ImportConfidence::Dynamic
-                        | ImportConfidence::Unresolvable
-                ) {
+                let Some(kind) = edge_admitted(raw, config, &cfg_selection) else {
+                    continue;
+                };
+                if raw.confidence == ImportConfidence::External {
                     continue;
                 }
 
-                if let Some(target) = frontend.resolve(raw, root, resolve_files) {
-                    let source_module = if lang == Language::Go {
-                        file_path.parent().unwrap_or(file_path).to_path_buf()
-                    } else {
-                        file_path.to_path_buf()
-                    };
+                for target in frontend.resolve_targets(raw, root, resolve_files) {
+                    let source_module = frontend.source_module(
+                        &source,
+                        file_path,
+                        factory::source_module_path(
+                            file_path,
+                            Path::new(""),
+                            lang,
+                            None,
+                            context.csharp_workspace.as_deref(),
+                        ),
+                    );
                     builder.add_import(&ResolvedImport {
                         source_module,
                         target_module: target,
@@ -571,8 +564,11 @@ fn build_graph_at_ref(
                             file: file_path.to_path_buf(),
                             line: raw.line,
                             column: raw.column,
+                            cfg: raw.cfg.as_ref().map(ToString::to_string),
                         },
                         language: Some(lang),
+                        kind,
+                        context: raw.context,
                     });
                 }
             }
@@ -582,6 +578,187 @@ fn build_graph_at_ref(
     Ok(builder.build())
 }
 
+/// The analysis context of the files in `files_by_lang` as they are at
+/// `reference`, read from git instead of the working tree.
+fn context_at_ref(
+    repo: &git2::Repository,
+    reference: &str,
+    root: &Path,
+    langs: &[Language],
+    files_by_lang: HashMap<Language, Vec<PathBuf>>,
+    config: &ResolvedConfig,
+) -> Result<AnalysisContext> {
+    let read = |path: &Path| {
+        crate::git::read_file_at_ref(repo, reference, path)
+            .ok()
+            .and_then(|content| String::from_utf8(content).ok())
+    };
+    let manifests_at_ref = |lang: Language, name: &str| -> Vec<(PathBuf, String)> {
+        if !langs.contains(&lang) {
+            return Vec::new();
+        }
+        crate::git::find_files_by_name_at_ref(repo, reference, name)
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|(path, content)| Some((path, String::from_utf8(content).ok()?)))
+            .collect()
+    };
+    let manifest_paths = |lang: Language, name: &str| -> Vec<PathBuf> {
+        manifests_at_ref(lang, name)
+            .into_iter()
+            .map(|(path, _)| path)
+            .filter(|path| !lang.is_vendored(path))
+            .collect()
+    };
+
+    let go_mods = manifests_at_ref(Language::Go, crate::parse::go::GO_MOD_MANIFEST);
+    let go_workspace = GoWorkspace::load(
+        &go_mods,
+        &manifests_at_ref(Language::Go, crate::parse::go::GO_WORK_MANIFEST),
+    );
+    let go_modules: HashMap<PathBuf, String> = go_mods
+        .iter()
+        .filter_map(|(path, source)| {
+            let dir = path.parent()?.to_path_buf();
+            let module_path = crate::parse::go::parse_go_mod_module(source)?;
+            Some((dir, module_path))
+        })
+        .collect();
+    let go_module_path = go_modules
+        .get(Path::new(""))
+        .or_else(|| go_modules.get(Path::new(".")))
+        .cloned()
+        .or_else(|| {
+            read(Path::new("go.mod"))
+                .and_then(|source| crate::parse::go::parse_go_mod_module(&source))
+        });
+
+    let rust_workspace = langs
+        .contains(&Language::Rust)
+        .then(|| read(Path::new("Cargo.toml")))
+        .flatten()
+        .and_then(|source| RustFrontend::parse_crate_name(&source))
+        .map(RustWorkspaceContext::single_crate);
+
+    let typescript_project = files_by_lang.get(&Language::TypeScript).map(|files| {
+        let manifests: Vec<PathBuf> = crate::parse::typescript::PROJECT_MANIFESTS
+            .iter()
+            .flat_map(|name| manifest_paths(Language::TypeScript, name))
+            .collect();
+        Arc::new(TypeScriptProject::load(&manifests, &read).with_files(Path::new(""), files))
+    });
+
+    let jvm_index =
+        (langs.contains(&Language::Java) || langs.contains(&Language::Kotlin)).then(|| {
+            let jvm_files = [Language::Java, Language::Kotlin]
+                .iter()
+                .filter_map(|lang| files_by_lang.get(lang))
+                .flatten();
+            Arc::new(JvmSourceIndex::from_files(jvm_files, Path::new("")))
+        });
+
+    let csharp_workspace = files_by_lang.get(&Language::CSharp).map(|sources| {
+        let manifests: Vec<PathBuf> = crate::git::list_files_at_ref(
+            repo,
+            reference,
+            crate::parse::csharp::MANIFEST_EXTENSIONS,
+        )
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|path| !Language::CSharp.is_vendored(path))
+        .collect();
+        Arc::new(CSharpWorkspace::load(&manifests, sources, &|path| {
+            crate::git::read_file_at_ref(repo, reference, path).ok()
+        }))
+    });
+
+    let cpp_include_paths = langs.contains(&Language::Cpp).then(|| {
+        let compile_commands = crate::parse::cpp::COMPILE_COMMANDS_LOCATIONS
+            .iter()
+            .find_map(|location| read(Path::new(location)));
+        CppIncludePaths::load(
+            compile_commands.as_deref(),
+            repo.workdir().unwrap_or(root),
+            &config.cpp.include_paths,
+        )
+    });
+
+    let php_autoload = langs.contains(&Language::Php).then(|| {
+        let mut manifests = manifest_paths(Language::Php, crate::parse::php::COMPOSER_MANIFEST);
+        manifests.sort();
+        Arc::new(ComposerAutoload::load(&manifests, &read))
+    });
+
+    let mix_project = files_by_lang.get(&Language::Elixir).map(|sources| {
+        let manifests = manifest_paths(Language::Elixir, crate::parse::elixir::MIX_MANIFEST);
+        Arc::new(MixProject::from_files(&manifests, sources, Path::new("")))
+    });
+
+    let python_source_roots = files_by_lang.get(&Language::Python).map(|files| {
+        let manifests = manifest_paths(Language::Python, crate::parse::python::PYPROJECT_MANIFEST);
+        PythonSourceRoots::load(&config.python.source_roots, &manifests, files, &read)
+    });
+    let python_reexports = python_source_roots
+        .as_ref()
+        .filter(|_| config.python.follow_reexports)
+        .zip(files_by_lang.get(&Language::Python))
+        .map(|(roots, files)| Arc::new(PythonReexports::load(files, roots, &read)));
+    let python_file_index = python_source_roots
+        .as_ref()
+        .zip(files_by_lang.get(&Language::Python))
+        .map(|(roots, files)| Arc::new(PythonFileIndex::load(files, roots)));
+
+    let ruby_load_paths = files_by_lang.get(&Language::Ruby).map(|files| {
+        RubyLoadPaths::load(
+            &config.ruby.load_path,
+            config.ruby.detect_rails,
+            files,
+            &read,
+        )
+    });
+    let ruby_constants = ruby_load_paths
+        .as_ref()
+        .filter(|_| config.ruby.zeitwerk)
+        .zip(files_by_lang.get(&Language::Ruby))
+        .map(|(load_paths, files)| Arc::new(RubyConstantIndex::load(&load_paths.paths(), files)));
+
+    let custom_grammars = custom::load_grammars(langs, config)?;
+
+    let all_files = langs
+        .iter()
+        .flat_map(|&lang| {
+            files_by_lang
+                .get(&lang)
+                .into_iter()
+                .flat_map(move |files| files.iter().cloned().map(move |file| (lang, file)))
+        })
+        .collect();
+
+    Ok(AnalysisContext {
+        scan_root: root.to_path_buf(),
+        project_root: root.to_path_buf(),
+        langs: langs.to_vec(),
+        files_by_lang,
+        all_files,
+        go_modules,
+        go_module_path,
+        go_workspace: Some(go_workspace),
+        rust_workspace,
+        typescript_project,
+        jvm_index,
+        csharp_workspace,
+        cpp_include_paths,
+        php_autoload,
+        mix_project,
+        python_source_roots,
+        python_reexports,
+        python_file_index,
+        ruby_load_paths,
+        ruby_constants,
+        custom_grammars,
+    })
+}
+
 struct RawDiff {
     summary_delta: SummaryDelta,
     new_edges: Vec<EdgeChange>,
@@ -589,9 +766,10 @@ struct RawDiff {
     fanout_changes: Vec<FanoutChange>,
     scc_changes: SccChanges,
     head_node_fanouts: Vec<(String, usize)>,
+    blast_radius_increases: Vec<BlastRadiusChange>,
 }
 
-fn compute_raw_diff(base: &DepGraph, head: &DepGraph) -> RawDiff {
+fn compute_raw_diff(base: &DepGraph, head: &DepGraph, scc_options: SccOptions) -> RawDiff {
     let base_nodes: HashSet<String> = base
         .node_indices()
         .map(|idx| base[idx].name.clone())
@@ -723,10 +901,10 @@ fn compute_raw_diff(base: &DepGraph, head: &DepGraph) -> RawDiff {
         }
     }
 
-    let base_sccs = find_non_trivial_sccs(base);
-    let head_sccs = find_non_trivial_sccs(head);
-    let base_summary = Summary::from_graph(base);
-    let head_summary = Summary::from_graph(head);
+    let base_sccs = find_non_trivial_sccs_with(base, scc_options);
+    let head_sccs = find_non_trivial_sccs_with(head, scc_options);
+    let base_summary = Summary::from_graph_with(base, scc_options);
+    let head_summary = Summary::from_graph_with(head, scc_options);
 
     let base_mean_entropy = mean_entropy(base);
     let head_mean_entropy = mean_entropy(head);
@@ -740,6 +918,25 @@ fn compute_raw_diff(base: &DepGraph, head: &DepGraph) -> RawDiff {
         })
         .collect();
 
+    let base_reachability = Reachability::compute(base);
+    let head_reachability = Reachability::compute(head);
+    let mut blast_radius_increases: Vec<BlastRadiusChange> = base_nodes
+        .intersection(&head_nodes)
+        .filter_map(|name| {
+            let blast_radius_before =
+                base_reachability.transitive_fanin[base_node_map[name].index()];
+            let blast_radius_after =
+                head_reachability.transitive_fanin[head_node_map[name].index()];
+            (blast_radius_after > blast_radius_before).then(|| BlastRadiusChange {
+                node: name.clone(),
+                blast_radius_before,
+                blast_radius_after,
+                delta: blast_radius_after as isize - blast_radius_before as isize,
+            })
+        })
+        .collect();
+    blast_radius_increases.sort_by(|a, b| b.delta.cmp(&a.delta).then(a.node.cmp(&b.node)));
+
     let mut matched_base: HashSet<usize> = HashSet::new();
     let mut matched_head: HashSet<usize> = HashSet::new();
     let mut enlarged_sccs = Vec::new();
@@ -819,6 +1016,13 @@ fn compute_raw_diff(base: &DepGraph, head: &DepGraph) -> RawDiff {
             max_depth_delta: head_summary.max_depth as isize - base_summary.max_depth as isize,
             total_complexity_delta: head_summary.total_complexity as isize
                 - base_summary.total_complexity as isize,
+            propagation_cost_delta: ((head_summary.propagation_cost
+                - base_summary.propagation_cost)
+                * 10000.0)
+                .round()
+                / 10000.0,
+            max_blast_radius_delta: head_summary.max_blast_radius as isize
+                - base_summary.max_blast_radius as isize,
         },
         new_edges,
         removed_edges,
@@ -829,6 +1033,7 @@ fn compute_raw_diff(base: &DepGraph, head: &DepGraph) -> RawDiff {
             resolved_sccs,
         },
         head_node_fanouts,
+        blast_radius_increases,
     }
 }
 
@@ -978,6 +1183,11 @@ fn evaluate_policies(
                     reasons.push("architecture-cycle-growth".to_string());
                 }
             }
+            FailCondition::BlastRadiusIncrease => {
+                if !diff.blast_radius_increases.is_empty() {
+                    reasons.push("blast-radius-increase".to_string());
+                }
+            }
         }
     }
 
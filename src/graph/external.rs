@@ -0,0 +1,160 @@
+use crate::graph::ir::{DepGraph, NodeKind};
+use crate::walk::Language;
+use petgraph::visit::EdgeRef;
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+/// Third-party packages the project imports, split out of the module graph.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ExternalDependencies {
+    /// Packages, most widely imported first
+    pub packages: Vec<ExternalPackage>,
+    /// Modules importing third-party packages, highest fan-out first
+    pub modules: Vec<ExternalFanout>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ExternalPackage {
+    pub package: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language: Option<Language>,
+    /// Number of modules importing the package
+    pub fanin: usize,
+    pub importers: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ExternalFanout {
+    pub module: String,
+    /// Number of distinct third-party packages the module imports
+    pub fanout: usize,
+    pub packages: Vec<String>,
+}
+
+/// Remove the external package nodes from `graph`, returning the module
+/// graph and the third-party coupling they recorded.
+pub fn split_external(mut graph: DepGraph) -> (DepGraph, ExternalDependencies) {
+    let mut importers: BTreeMap<&str, (Option<Language>, Vec<String>)> = BTreeMap::new();
+    let mut packages: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for edge in graph.edge_references() {
+        let (source, target) = (&graph[edge.source()], &graph[edge.target()]);
+        if target.kind != NodeKind::External {
+            continue;
+        }
+        let entry = importers
+            .entry(target.name.as_str())
+            .or_insert((target.language, Vec::new()));
+        entry.1.push(source.name.clone());
+        packages
+            .entry(source.name.clone())
+            .or_default()
+            .push(target.name.clone());
+    }
+
+    let mut dependencies = ExternalDependencies {
+        packages: importers
+            .into_iter()
+            .map(|(package, (language, mut importers))| {
+                importers.sort();
+                ExternalPackage {
+                    package: package.to_string(),
+                    language,
+                    fanin: importers.len(),
+                    importers,
+                }
+            })
+            .collect(),
+        modules: packages
+            .into_iter()
+            .map(|(module, mut packages)| {
+                packages.sort();
+                ExternalFanout {
+                    module,
+                    fanout: packages.len(),
+                    packages,
+                }
+            })
+            .collect(),
+    };
+    dependencies.packages.sort_by(|a, b| {
+        b.fanin
+            .cmp(&a.fanin)
+            .then_with(|| a.package.cmp(&b.package))
+    });
+    dependencies.modules.sort_by(|a, b| {
+        b.fanout
+            .cmp(&a.fanout)
+            .then_with(|| a.module.cmp(&b.module))
+    });
+
+    graph.retain_nodes(|graph, idx| graph[idx].kind != NodeKind::External);
+    (graph, dependencies)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::builder::{ExternalImport, GraphBuilder, ResolvedImport};
+    use crate::graph::ir::EdgeKind;
+    use crate::parse::common::{ImportContext, SourceLocation};
+    use std::path::PathBuf;
+
+    fn location(file: &str, line: usize) -> SourceLocation {
+        SourceLocation {
+            file: PathBuf::from(file),
+            line,
+            column: None,
+            cfg: None,
+        }
+    }
+
+    fn external(module: &str, package: &str, line: usize) -> ExternalImport {
+        ExternalImport {
+            source_module: PathBuf::from(module),
+            package: package.to_string(),
+            location: location(module, line),
+            language: Some(Language::Python),
+            context: ImportContext::Runtime,
+        }
+    }
+
+    #[test]
+    fn splits_external_packages_from_the_module_graph() {
+        let mut builder = GraphBuilder::new();
+        builder.add_import(&ResolvedImport {
+            source_module: PathBuf::from("app/api.py"),
+            target_module: PathBuf::from("app/db.py"),
+            location: location("app/api.py", 1),
+            language: Some(Language::Python),
+            kind: EdgeKind::Import,
+            context: ImportContext::Runtime,
+        });
+        builder.add_external(&external("app/api.py", "requests", 2));
+        builder.add_external(&external("app/api.py", "requests", 3));
+        builder.add_external(&external("app/api.py", "pydantic", 4));
+        builder.add_external(&external("app/db.py", "sqlalchemy", 1));
+        builder.add_external(&external("app/db.py", "pydantic", 2));
+        let graph = builder.build();
+        assert_eq!(graph.node_count(), 5);
+
+        let (graph, dependencies) = split_external(graph);
+        assert_eq!(graph.node_count(), 2);
+        assert_eq!(graph.edge_count(), 1);
+
+        let packages: Vec<(&str, usize)> = dependencies
+            .packages
+            .iter()
+            .map(|package| (package.package.as_str(), package.fanin))
+            .collect();
+        assert_eq!(
+            packages,
+            vec![("pydantic", 2), ("requests", 1), ("sqlalchemy", 1)]
+        );
+        assert_eq!(dependencies.modules[0].module, "app.api");
+        assert_eq!(
+            dependencies.modules[0].packages,
+            vec!["pydantic", "requests"]
+        );
+        assert_eq!(dependencies.modules[1].fanout, 2);
+    }
+}
@@ -0,0 +1,311 @@
+use crate::analysis_context::AnalysisContext;
+use crate::config::ResolvedConfig;
+use crate::graph::builder::{GraphBuilder, ResolvedReference, SymbolRef};
+use crate::graph::ir::{DepGraph, EdgeKind, NodeKind};
+use crate::graph::symbols::{
+    extract_source_files, module_imports, module_labels, module_scopes, narrow, normalize, owner,
+    SourceFile,
+};
+use crate::parse::common::SourceLocation;
+use crate::quality::complexity::{last_segment, CallSite};
+use crate::quality::FunctionInfo;
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+
+/// Functions and call sites of one source file.
+#[derive(Debug, Clone)]
+pub struct FileFunctions {
+    pub functions: Vec<FunctionInfo>,
+    pub calls: Vec<CallSite>,
+}
+
+/// Function-level call graph: one node per function or method that calls or
+/// is called by another, one `call` edge per caller/callee pair.
+///
+/// Calls are matched by name against the functions of the caller's module
+/// and of the modules it imports, so the module graph bounds what a call can
+/// reach. A call is only linked when exactly one candidate remains.
+pub fn build_function_graph(
+    context: &AnalysisContext,
+    config: &ResolvedConfig,
+    module_graph: &DepGraph,
+) -> DepGraph {
+    let files = extract_source_files(context, config, |frontend, source, file_path| {
+        FileFunctions {
+            functions: frontend.extract_functions(source, file_path),
+            calls: frontend.extract_calls(source),
+        }
+    });
+
+    let mut builder = GraphBuilder::new();
+    for call in link_calls(&files, &module_imports(module_graph)) {
+        builder.add_reference(&call);
+    }
+    builder.build()
+}
+
+/// Resolve the call sites in `files` to the functions they call. `imports`
+/// maps each module to the modules it depends on.
+pub fn link_calls(
+    files: &[SourceFile<FileFunctions>],
+    imports: &HashMap<PathBuf, HashSet<PathBuf>>,
+) -> Vec<ResolvedReference> {
+    let mut calls = Vec::new();
+    for (file, scope) in files.iter().zip(module_scopes(files, imports)) {
+        for call in &file.symbols.calls {
+            let Some(caller) = enclosing_function(&file.symbols.functions, call.line) else {
+                continue;
+            };
+            let candidates: Vec<(&SourceFile<FileFunctions>, &FunctionInfo)> = scope
+                .iter()
+                .flat_map(|candidate_file| {
+                    candidate_file
+                        .symbols
+                        .functions
+                        .iter()
+                        .filter(|function| last_segment(&function.name) == call.name)
+                        .map(move |function| (*candidate_file, function))
+                })
+                .collect();
+            let Some((callee_file, callee)) = select_callee(file, caller, call, candidates) else {
+                continue;
+            };
+            calls.push(ResolvedReference {
+                source: function_ref(file, caller),
+                target: function_ref(callee_file, callee),
+                kind: EdgeKind::Call,
+                location: SourceLocation {
+                    file: file.file.clone(),
+                    line: call.line,
+                    column: None,
+                    cfg: None,
+                },
+            });
+        }
+    }
+    calls
+}
+
+/// Innermost function whose span contains `line`.
+fn enclosing_function(functions: &[FunctionInfo], line: usize) -> Option<&FunctionInfo> {
+    functions
+        .iter()
+        .filter(|function| function.start_line <= line && line <= function.end_line)
+        .min_by_key(|function| function.end_line - function.start_line)
+}
+
+fn select_callee<'a>(
+    file: &SourceFile<FileFunctions>,
+    caller: &FunctionInfo,
+    call: &CallSite,
+    mut candidates: Vec<(&'a SourceFile<FileFunctions>, &'a FunctionInfo)>,
+) -> Option<(&'a SourceFile<FileFunctions>, &'a FunctionInfo)> {
+    let qualifier = call
+        .qualifier
+        .as_deref()
+        .map(|qualifier| qualifier.trim_end_matches([':', '.']));
+    match qualifier {
+        None | Some("self" | "this" | "Self" | "$this" | "static") => {
+            narrow(&mut candidates, |(candidate_file, _)| {
+                candidate_file.file == file.file
+            });
+            if let Some(caller_owner) = owner(&caller.name) {
+                narrow(&mut candidates, |(_, function)| {
+                    owner(&function.name) == Some(caller_owner)
+                });
+            }
+            if qualifier.is_none() {
+                narrow(&mut candidates, |(_, function)| {
+                    owner(&function.name).is_none()
+                });
+            }
+        }
+        Some(qualifier) => {
+            let label = normalize(last_segment(qualifier));
+            let is_member = |function: &FunctionInfo| {
+                owner(&function.name).is_some_and(|owner| normalize(owner) == label)
+            };
+            if candidates.iter().any(|(_, function)| is_member(function)) {
+                candidates.retain(|(_, function)| is_member(function));
+            } else if candidates
+                .iter()
+                .any(|(candidate_file, _)| module_labels(candidate_file).contains(&label))
+            {
+                candidates
+                    .retain(|(candidate_file, _)| module_labels(candidate_file).contains(&label));
+                narrow(&mut candidates, |(_, function)| {
+                    owner(&function.name).is_none()
+                });
+            } else {
+                // A method called on a value of unknown type.
+                candidates.retain(|(_, function)| owner(&function.name).is_some());
+            }
+        }
+    }
+    match candidates.as_slice() {
+        [only] => Some(*only),
+        _ => None,
+    }
+}
+
+fn function_ref(file: &SourceFile<FileFunctions>, function: &FunctionInfo) -> SymbolRef {
+    SymbolRef {
+        kind: NodeKind::Function,
+        path: file.file.clone(),
+        name: function.name.clone(),
+        span: (function.start_line, function.end_line),
+        language: Some(file.language),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metrics::scc::find_non_trivial_sccs;
+    use crate::walk::Language;
+
+    fn function(file: &str, name: &str, start_line: usize, end_line: usize) -> FunctionInfo {
+        FunctionInfo {
+            name: name.to_string(),
+            file: PathBuf::from(file),
+            start_line,
+            end_line,
+            cyclomatic_complexity: 1,
+            language: Language::Python,
+        }
+    }
+
+    fn call(name: &str, qualifier: Option<&str>, line: usize) -> CallSite {
+        CallSite {
+            name: name.to_string(),
+            qualifier: qualifier.map(str::to_string),
+            line,
+        }
+    }
+
+    fn file(
+        path: &str,
+        functions: Vec<FunctionInfo>,
+        calls: Vec<CallSite>,
+    ) -> SourceFile<FileFunctions> {
+        SourceFile {
+            file: PathBuf::from(path),
+            module: PathBuf::from(path),
+            language: Language::Python,
+            symbols: FileFunctions { functions, calls },
+        }
+    }
+
+    fn imports(edges: &[(&str, &str)]) -> HashMap<PathBuf, HashSet<PathBuf>> {
+        let mut imports: HashMap<PathBuf, HashSet<PathBuf>> = HashMap::new();
+        for (source, target) in edges {
+            imports
+                .entry(PathBuf::from(source))
+                .or_default()
+                .insert(PathBuf::from(target));
+        }
+        imports
+    }
+
+    fn edges(calls: &[ResolvedReference]) -> Vec<(String, String)> {
+        calls
+            .iter()
+            .map(|call| (call.source.name.clone(), call.target.name.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn links_calls_within_a_file_and_across_imports() {
+        let files = vec![
+            file(
+                "orders.py",
+                vec![
+                    function("orders.py", "place", 1, 5),
+                    function("orders.py", "validate", 7, 9),
+                ],
+                vec![
+                    call("validate", None, 2),
+                    call("charge", Some("billing"), 3),
+                ],
+            ),
+            file(
+                "billing.py",
+                vec![function("billing.py", "charge", 1, 3)],
+                vec![],
+            ),
+            // Not imported by orders.py
+            file(
+                "refunds.py",
+                vec![function("refunds.py", "validate", 1, 3)],
+                vec![],
+            ),
+        ];
+        let calls = link_calls(&files, &imports(&[("orders.py", "billing.py")]));
+        assert_eq!(
+            edges(&calls),
+            vec![
+                ("place".to_string(), "validate".to_string()),
+                ("place".to_string(), "charge".to_string()),
+            ]
+        );
+        assert_eq!(calls[1].target.path, PathBuf::from("billing.py"));
+        assert_eq!(calls[1].location.line, 3);
+    }
+
+    #[test]
+    fn self_calls_prefer_the_callers_class() {
+        let files = vec![file(
+            "shapes.py",
+            vec![
+                function("shapes.py", "Square.area", 1, 3),
+                function("shapes.py", "Square.describe", 4, 6),
+                function("shapes.py", "Circle.area", 7, 9),
+            ],
+            vec![call("area", Some("self"), 5)],
+        )];
+        let calls = link_calls(&files, &HashMap::new());
+        assert_eq!(
+            edges(&calls),
+            vec![("Square.describe".to_string(), "Square.area".to_string())]
+        );
+    }
+
+    #[test]
+    fn ambiguous_and_top_level_calls_are_not_linked() {
+        let files = vec![file(
+            "shapes.py",
+            vec![
+                function("shapes.py", "Square.area", 1, 3),
+                function("shapes.py", "Circle.area", 4, 6),
+                function("shapes.py", "report", 7, 9),
+            ],
+            vec![call("area", Some("shape"), 8), call("report", None, 11)],
+        )];
+        assert!(link_calls(&files, &HashMap::new()).is_empty());
+    }
+
+    #[test]
+    fn call_cycles_are_strongly_connected() {
+        let files = vec![
+            file(
+                "a.py",
+                vec![function("a.py", "ping", 1, 3)],
+                vec![call("pong", Some("b"), 2)],
+            ),
+            file(
+                "b.py",
+                vec![function("b.py", "pong", 1, 3)],
+                vec![call("ping", Some("a"), 2)],
+            ),
+        ];
+        let mut builder = GraphBuilder::new();
+        for call in link_calls(&files, &imports(&[("a.py", "b.py"), ("b.py", "a.py")])) {
+            builder.add_reference(&call);
+        }
+        let graph = builder.build();
+        assert_eq!(graph.node_count(), 2);
+        let sccs = find_non_trivial_sccs(&graph);
+        assert_eq!(sccs.len(), 1);
+        assert_eq!(sccs[0].size, 2);
+    }
+}
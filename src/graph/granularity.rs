@@ -0,0 +1,300 @@
+use crate::analysis_context::AnalysisContext;
+use crate::config::ResolvedConfig;
+use crate::graph::builder::{ExternalImport, GraphBuilder, ResolvedImport};
+use crate::graph::classes::build_class_graph;
+use crate::graph::functions::build_function_graph;
+use crate::graph::ir::{DepGraph, NodeKind};
+use petgraph::visit::EdgeRef;
+use std::path::{Component, Path, PathBuf};
+
+/// What the nodes of a dependency graph stand for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Granularity {
+    /// One node per module (file, package or namespace, per language)
+    #[default]
+    Module,
+    /// One node per directory, cut off after `depth` path components
+    Directory { depth: Option<usize> },
+    /// One node per class, module, struct, trait or interface
+    Class,
+    /// One node per function or method, linked by calls
+    Function,
+}
+
+impl Granularity {
+    /// Derive the graph at this granularity from the module graph.
+    pub fn project(
+        self,
+        context: &AnalysisContext,
+        config: &ResolvedConfig,
+        module_graph: DepGraph,
+    ) -> DepGraph {
+        match self {
+            Granularity::Module => module_graph,
+            Granularity::Directory { depth } => collapse_to_directories(&module_graph, depth),
+            Granularity::Class => build_class_graph(context, config, &module_graph),
+            Granularity::Function => build_function_graph(context, config, &module_graph),
+        }
+    }
+}
+
+impl std::fmt::Display for Granularity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Granularity::Module => write!(f, "module"),
+            Granularity::Directory { depth: None } => write!(f, "directory"),
+            Granularity::Directory { depth: Some(depth) } => write!(f, "directory:{depth}"),
+            Granularity::Class => write!(f, "class"),
+            Granularity::Function => write!(f, "function"),
+        }
+    }
+}
+
+impl std::str::FromStr for Granularity {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let lower = s.to_lowercase();
+        let (kind, depth) = match lower.split_once(':') {
+            Some((kind, depth)) => (kind, Some(depth)),
+            None => (lower.as_str(), None),
+        };
+        match (kind, depth) {
+            ("module" | "file", None) => Ok(Granularity::Module),
+            ("directory" | "dir" | "package", None) => Ok(Granularity::Directory { depth: None }),
+            ("directory" | "dir" | "package", Some(depth)) => match depth.parse::<usize>() {
+                Ok(depth) if depth > 0 => Ok(Granularity::Directory { depth: Some(depth) }),
+                _ => Err(format!(
+                    "invalid directory depth in {s}: expected a positive integer"
+                )),
+            },
+            ("class" | "type", None) => Ok(Granularity::Class),
+            ("function" | "method", None) => Ok(Granularity::Function),
+            _ => Err(format!(
+                "unknown granularity: {s} (expected module, directory[:depth], class or function)"
+            )),
+        }
+    }
+}
+
+/// Collapse module nodes into the directory they live in, keeping at most
+/// `depth` leading path components. Edges between modules of the same
+/// directory disappear; the rest merge, keeping all their source locations.
+/// External package nodes are kept as they are.
+pub fn collapse_to_directories(graph: &DepGraph, depth: Option<usize>) -> DepGraph {
+    let directories: Vec<PathBuf> = graph
+        .node_indices()
+        .map(|idx| node_directory(&graph[idx].path, graph[idx].language, depth))
+        .collect();
+
+    let mut builder = GraphBuilder::new();
+    for idx in graph.node_indices() {
+        if graph[idx].kind != NodeKind::External {
+            builder.add_module(&directories[idx.index()], graph[idx].language);
+        }
+    }
+    for edge in graph.edge_references() {
+        let source = &directories[edge.source().index()];
+        let target = &directories[edge.target().index()];
+        if graph[edge.target()].kind == NodeKind::External {
+            for location in &edge.weight().source_locations {
+                builder.add_external(&ExternalImport {
+                    source_module: source.clone(),
+                    package: graph[edge.target()].name.clone(),
+                    location: location.clone(),
+                    language: graph[edge.target()].language,
+                    context: edge.weight().context,
+                });
+            }
+            continue;
+        }
+        if source == target {
+            continue;
+        }
+        for location in &edge.weight().source_locations {
+            builder.add_import(&ResolvedImport {
+                source_module: source.clone(),
+                target_module: target.clone(),
+                location: location.clone(),
+                language: graph[edge.source()].language,
+                kind: edge.weight().kind,
+                context: edge.weight().context,
+            });
+        }
+    }
+
+    let mut collapsed = builder.build();
+    for node in collapsed.node_weights_mut() {
+        if node.kind == NodeKind::Module {
+            node.kind = NodeKind::Directory;
+        }
+    }
+    collapsed
+}
+
+/// Directory a module node belongs to. Nodes that are already directories
+/// (Go packages, JVM and C# namespaces) stay as they are before truncation.
+fn node_directory(
+    path: &Path,
+    language: Option<crate::walk::Language>,
+    depth: Option<usize>,
+) -> PathBuf {
+    let is_file = match (language, path.extension().and_then(|ext| ext.to_str())) {
+        (Some(crate::walk::Language::Custom(_)), Some(_)) => true,
+        (Some(lang), Some(ext)) => lang.extensions().contains(&ext),
+        _ => false,
+    };
+    let directory = if is_file {
+        path.parent().unwrap_or(Path::new(""))
+    } else {
+        path
+    };
+    let components = directory
+        .components()
+        .filter(|component| matches!(component, Component::Normal(_)))
+        .take(depth.unwrap_or(usize::MAX));
+    let directory: PathBuf = components.collect();
+    if directory.as_os_str().is_empty() {
+        PathBuf::from(".")
+    } else {
+        directory
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::ir::EdgeKind;
+    use crate::metrics::scc::find_non_trivial_sccs;
+    use crate::parse::common::{ImportContext, SourceLocation};
+    use crate::walk::Language;
+
+    #[test]
+    fn parses_and_displays_granularities() {
+        assert_eq!("module".parse(), Ok(Granularity::Module));
+        assert_eq!("Function".parse(), Ok(Granularity::Function));
+        assert_eq!("type".parse(), Ok(Granularity::Class));
+        assert_eq!(
+            "directory".parse(),
+            Ok(Granularity::Directory { depth: None })
+        );
+        assert_eq!(
+            "dir:2".parse(),
+            Ok(Granularity::Directory { depth: Some(2) })
+        );
+        assert!("directory:0".parse::<Granularity>().is_err());
+        assert!("function:2".parse::<Granularity>().is_err());
+        assert!("package:x".parse::<Granularity>().is_err());
+        assert!("namespace".parse::<Granularity>().is_err());
+        assert_eq!(Granularity::Function.to_string(), "function");
+        assert_eq!(
+            Granularity::Directory { depth: Some(2) }.to_string(),
+            "directory:2"
+        );
+    }
+
+    fn import(source: &str, target: &str, line: usize) -> ResolvedImport {
+        ResolvedImport {
+            source_module: PathBuf::from(source),
+            target_module: PathBuf::from(target),
+            location: SourceLocation {
+                file: PathBuf::from(source),
+                line,
+                column: None,
+                cfg: None,
+            },
+            language: Some(Language::Python),
+            kind: EdgeKind::Import,
+            context: ImportContext::Runtime,
+        }
+    }
+
+    fn module_graph() -> DepGraph {
+        let mut builder = GraphBuilder::new();
+        builder.add_import(&import("src/api/handlers.py", "src/api/models.py", 1));
+        builder.add_import(&import("src/api/handlers.py", "src/db/session.py", 2));
+        builder.add_import(&import("src/api/models.py", "src/db/tables/user.py", 1));
+        builder.add_import(&import("src/db/session.py", "src/api/models.py", 3));
+        builder.add_import(&import("main.py", "src/api/handlers.py", 1));
+        builder.build()
+    }
+
+    #[test]
+    fn collapses_modules_into_directories() {
+        let graph = collapse_to_directories(&module_graph(), None);
+        let mut names: Vec<_> = graph.node_weights().map(|n| n.name.as_str()).collect();
+        names.sort();
+        assert_eq!(names, vec![".", "src.api", "src.db", "src.db.tables"]);
+        assert!(graph.node_weights().all(|n| n.kind == NodeKind::Directory));
+
+        // Imports within src/api are gone; the one into src/db remains.
+        let api = graph
+            .node_indices()
+            .find(|&i| graph[i].name == "src.api")
+            .unwrap();
+        let db = graph
+            .node_indices()
+            .find(|&i| graph[i].name == "src.db")
+            .unwrap();
+        let edge = graph.find_edge(api, db).unwrap();
+        assert_eq!(graph[edge].source_locations.len(), 1);
+        assert!(graph.find_edge(db, api).is_some());
+
+        let sccs = find_non_trivial_sccs(&graph);
+        assert_eq!(sccs.len(), 1);
+        assert_eq!(sccs[0].size, 2);
+    }
+
+    #[test]
+    fn depth_limits_directory_components() {
+        let graph = collapse_to_directories(&module_graph(), Some(1));
+        let mut names: Vec<_> = graph.node_weights().map(|n| n.name.as_str()).collect();
+        names.sort();
+        assert_eq!(names, vec![".", "src"]);
+        assert_eq!(graph.edge_count(), 1);
+    }
+
+    #[test]
+    fn external_packages_survive_directory_collapse() {
+        let mut builder = GraphBuilder::new();
+        builder.add_import(&import("src/api/handlers.py", "src/db/session.py", 1));
+        for (module, line) in [("src/api/handlers.py", 2), ("src/api/models.py", 1)] {
+            builder.add_external(&ExternalImport {
+                source_module: PathBuf::from(module),
+                package: "requests".to_string(),
+                location: import(module, module, line).location,
+                language: Some(Language::Python),
+                context: ImportContext::Runtime,
+            });
+        }
+        let graph = collapse_to_directories(&builder.build(), None);
+
+        let requests = graph
+            .node_indices()
+            .find(|&i| graph[i].name == "requests")
+            .unwrap();
+        assert_eq!(graph[requests].kind, NodeKind::External);
+        let api = graph
+            .node_indices()
+            .find(|&i| graph[i].name == "src.api")
+            .unwrap();
+        let edge = graph.find_edge(api, requests).unwrap();
+        assert_eq!(graph[edge].source_locations.len(), 2);
+    }
+
+    #[test]
+    fn package_nodes_are_already_directories() {
+        assert_eq!(
+            node_directory(Path::new("pkg/js"), Some(Language::Go), None),
+            PathBuf::from("pkg/js")
+        );
+        assert_eq!(
+            node_directory(
+                Path::new("internal/store/cache"),
+                Some(Language::Go),
+                Some(2)
+            ),
+            PathBuf::from("internal/store")
+        );
+    }
+}
@@ -1,4 +1,5 @@
-use crate::parse::common::SourceLocation;
+use crate::parse::common::{ImportContext, ImportKind, SourceLocation};
+use crate::quality::complexity::TypeRelation;
 use crate::walk::Language;
 use petgraph::graph::DiGraph;
 use serde::Serialize;
@@ -12,13 +13,13 @@ pub type DepGraph = DiGraph<GraphNode, GraphEdge>;
 
 #[derive(Debug, Clone, Serialize)]
 pub struct GraphNode {
-    /// Discriminator for future function-level granularity
+    /// Module, or function or class for finer-grained graphs
     pub kind: NodeKind,
     /// Canonical path relative to project root
     pub path: PathBuf,
     /// Human-readable name (e.g., "src.api.handler" for Python)
     pub name: String,
-    /// Line span — populated for Function nodes (future)
+    /// Line span — populated for Function and Class nodes
     #[serde(skip_serializing_if = "Option::is_none")]
     pub span: Option<(usize, usize)>,
     /// Language of the source file this node came from
@@ -26,13 +27,16 @@ pub struct GraphNode {
     pub language: Option<Language>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
 #[serde(rename_all = "snake_case")]
 pub enum NodeKind {
     Module,
-    Function, // future
-    Service,  // cross-service boundary node
-    Endpoint, // API endpoint node
+    Function,  // function or method
+    Class,     // class, module, struct, trait or interface
+    Directory, // modules collapsed by path prefix
+    External,  // third-party package
+    Service,   // cross-service boundary node
+    Endpoint,  // API endpoint node
 }
 
 /// The kind of dependency relationship an edge represents.
@@ -46,6 +50,38 @@ pub enum EdgeKind {
     GraphqlQuery,
     /// REST/HTTP call to an endpoint
     RestCall,
+    /// Module declaration (Rust `mod foo;`): the parent contains the child
+    Contains,
+    /// Function or method call (function-level graphs)
+    Call,
+    /// Base class, superclass, supertrait or embedded interface (class-level graphs)
+    Inherits,
+    /// Ruby `include`/`extend`/`prepend`, PHP trait `use`, Go struct embedding
+    Mixin,
+    /// Trait or interface implementation
+    Implements,
+    /// Any other use of a type by another
+    References,
+}
+
+impl From<&ImportKind> for EdgeKind {
+    fn from(kind: &ImportKind) -> Self {
+        match kind {
+            ImportKind::ModDeclaration { .. } => EdgeKind::Contains,
+            _ => EdgeKind::Import,
+        }
+    }
+}
+
+impl From<TypeRelation> for EdgeKind {
+    fn from(relation: TypeRelation) -> Self {
+        match relation {
+            TypeRelation::Inherits => EdgeKind::Inherits,
+            TypeRelation::Mixin => EdgeKind::Mixin,
+            TypeRelation::Implements => EdgeKind::Implements,
+            TypeRelation::References => EdgeKind::References,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -57,6 +93,12 @@ pub struct GraphEdge {
     pub source_locations: Vec<SourceLocation>,
     /// Edge weight (always 1 for binary weighting in v1)
     pub weight: usize,
+    /// `cfg` predicate the edge exists under; None if it is unconditional
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cfg: Option<String>,
+    /// When the edge's imports execute; the strongest across its locations
+    #[serde(skip_serializing_if = "ImportContext::is_runtime")]
+    pub context: ImportContext,
 }
 
 fn is_default_edge_kind(kind: &EdgeKind) -> bool {
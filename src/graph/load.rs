@@ -1,11 +1,11 @@
-use crate::analysis_context::build_analysis_context;
+use crate::analysis_context::{build_analysis_context, AnalysisContext};
 use crate::config::ResolvedConfig;
 use crate::errors::Result;
-use crate::graph::builder::{GraphBuilder, ResolvedImport};
-use crate::graph::ir::DepGraph;
-use crate::parse::common::{ImportConfidence, SourceLocation};
+use crate::graph::builder::{ExternalImport, GraphBuilder, ResolvedImport};
+use crate::graph::ir::{DepGraph, EdgeKind};
+use crate::parse::cfg::CfgSelection;
+use crate::parse::common::{ImportConfidence, RawImport, SourceLocation};
 use crate::parse::factory;
-use crate::parse::go::GoFrontend;
 use crate::parse::ParseFrontend;
 use crate::walk::{self, Language};
 use std::collections::HashMap;
@@ -17,40 +17,99 @@ pub fn load_dependency_graph(
     config: &ResolvedConfig,
 ) -> Result<DepGraph> {
     let context = build_analysis_context(scan_root, project_root, config)?;
+    build_dependency_graph(&context, config)
+}
+
+/// Frontend for one file. Go files get the module they belong to, so nested
+/// modules resolve against their own `go.mod`.
+pub fn file_frontend(
+    context: &AnalysisContext,
+    config: &ResolvedConfig,
+    lang: Language,
+    file_path: &Path,
+) -> Result<Box<dyn ParseFrontend>> {
+    let file_go_module = if lang == Language::Go {
+        walk::find_go_module_root(file_path, &context.go_modules)
+            .map(|(_, module_path)| module_path.to_string())
+            .or_else(|| context.go_module_path.clone())
+    } else {
+        context.go_module_path.clone()
+    };
+    factory::create_frontend(lang, config, &file_go_module, context)
+}
 
+/// Edge kind for a raw import the graph admits. Imports the parser could not
+/// pin down, `mod` declarations without `[rust] mod_edges`, and imports under
+/// a deselected `cfg` are left out.
+pub fn edge_admitted(
+    raw: &RawImport,
+    config: &ResolvedConfig,
+    cfg_selection: &CfgSelection,
+) -> Option<EdgeKind> {
+    if matches!(
+        raw.confidence,
+        ImportConfidence::Dynamic | ImportConfidence::Ambiguous | ImportConfidence::Unresolvable
+    ) {
+        return None;
+    }
+    let kind = EdgeKind::from(&raw.kind);
+    if kind == EdgeKind::Contains && !config.rust.mod_edges {
+        return None;
+    }
+    raw.cfg
+        .as_ref()
+        .is_none_or(|cfg| cfg_selection.allows(cfg))
+        .then_some(kind)
+}
+
+/// Graph node a file belongs to.
+pub fn file_module(
+    context: &AnalysisContext,
+    frontend: &dyn ParseFrontend,
+    lang: Language,
+    file_path: &Path,
+    source: &[u8],
+) -> PathBuf {
+    frontend.source_module(
+        source,
+        file_path,
+        factory::source_module_path(
+            file_path,
+            &context.project_root,
+            lang,
+            context.rust_workspace.as_ref(),
+            context.csharp_workspace.as_deref(),
+        ),
+    )
+}
+
+/// Module-level dependency graph of the files in `context`.
+pub fn build_dependency_graph(
+    context: &AnalysisContext,
+    config: &ResolvedConfig,
+) -> Result<DepGraph> {
     let resolvers: HashMap<Language, Box<dyn ParseFrontend>> = context
         .langs
         .iter()
         .filter(|&&lang| lang != Language::Go)
         .map(|&lang| {
-            let frontend = factory::create_frontend(
-                lang,
-                config,
-                &context.go_module_path,
-                &context.rust_workspace,
-            );
-            (lang, frontend)
+            let frontend =
+                factory::create_frontend(lang, config, &context.go_module_path, context)?;
+            Ok((lang, frontend))
         })
-        .collect();
+        .collect::<Result<_>>()?;
 
     let go_resolvers: HashMap<PathBuf, Box<dyn ParseFrontend>> = context
         .go_modules
         .iter()
         .map(|(mod_root, mod_path)| {
-            let frontend = GoFrontend::with_module_path(mod_path.clone())
-                .with_exclude_stdlib(config.go.exclude_stdlib);
-            (
-                mod_root.clone(),
-                Box::new(frontend) as Box<dyn ParseFrontend>,
-            )
+            let frontend =
+                factory::create_frontend(Language::Go, config, &Some(mod_path.clone()), context)?;
+            Ok((mod_root.clone(), frontend))
         })
-        .collect();
-    let fallback_go_resolver: Box<dyn ParseFrontend> = factory::create_frontend(
-        Language::Go,
-        config,
-        &context.go_module_path,
-        &context.rust_workspace,
-    );
+        .collect::<Result<_>>()?;
+    let fallback_go_resolver: Box<dyn ParseFrontend> =
+        factory::create_frontend(Language::Go, config, &context.go_module_path, context)?;
 
     let go_files_by_module: HashMap<PathBuf, Vec<PathBuf>> =
         if context.langs.contains(&Language::Go) {
@@ -68,6 +127,7 @@ pub fn load_dependency_graph(
             HashMap::new()
         };
 
+    let cfg_selection = config.rust.cfg_selection();
     let mut builder = GraphBuilder::new();
 
     for (lang, file_path) in &context.all_files {
@@ -76,23 +136,9 @@ pub fn load_dependency_graph(
             Err(_) => continue,
         };
 
-        let file_go_module = if *lang == Language::Go {
-            walk::find_go_module_root(file_path, &context.go_modules)
-                .map(|(_, module_path)| module_path.to_string())
-                .or_else(|| context.go_module_path.clone())
-        } else {
-            context.go_module_path.clone()
-        };
-
-        let frontend =
-            factory::create_frontend(*lang, config, &file_go_module, &context.rust_workspace);
+        let frontend = file_frontend(context, config, *lang, file_path)?;
         let imports = frontend.extract_imports(&source, file_path);
-        let source_module = factory::source_module_path(
-            file_path,
-            &context.project_root,
-            *lang,
-            context.rust_workspace.as_ref(),
-        );
+        let source_module = file_module(context, frontend.as_ref(), *lang, file_path, &source);
 
         let (resolver, lang_files): (&dyn ParseFrontend, Vec<PathBuf>) = if *lang == Language::Go {
             let mod_root = walk::find_go_module_root(file_path, &context.go_modules)
@@ -114,25 +160,44 @@ pub fn load_dependency_graph(
         };
 
         for raw in &imports {
-            if matches!(
-                raw.confidence,
-                ImportConfidence::External
-                    | ImportConfidence::Dynamic
-                    | ImportConfidence::Unresolvable
-            ) {
+            let Some(kind) = edge_admitted(raw, config, &cfg_selection) else {
                 continue;
-            }
+            };
 
-            if let Some(target) = resolver.resolve(raw, &context.project_root, &lang_files) {
+            let location = SourceLocation {
+                file: source_module.clone(),
+                line: raw.line,
+                column: raw.column,
+                cfg: raw.cfg.as_ref().map(ToString::to_string),
+            };
+            let targets = match raw.confidence {
+                ImportConfidence::External => Vec::new(),
+                _ => resolver.resolve_targets(raw, &context.project_root, &lang_files),
+            };
+            for target in &targets {
                 builder.add_import(&ResolvedImport {
                     source_module: source_module.clone(),
-                    target_module: target,
-                    location: SourceLocation {
-                        file: source_module.clone(),
-                        line: raw.line,
-                        column: raw.column,
-                    },
+                    target_module: target.clone(),
+                    location: location.clone(),
+                    language: Some(*lang),
+                    kind,
+                    context: raw.context,
+                });
+            }
+            if !targets.is_empty() {
+                continue;
+            }
+            if let Some(package) = config
+                .include_external
+                .then(|| resolver.external_package(raw))
+                .flatten()
+            {
+                builder.add_external(&ExternalImport {
+                    source_module: source_module.clone(),
+                    package,
+                    location,
                     language: Some(*lang),
+                    context: raw.context,
                 });
             }
         }
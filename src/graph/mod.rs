@@ -1,4 +1,9 @@
 pub mod builder;
+pub mod classes;
 pub mod diff;
+pub mod external;
+pub mod functions;
+pub mod granularity;
 pub mod ir;
 pub mod load;
+pub mod symbols;
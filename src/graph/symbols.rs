@@ -0,0 +1,142 @@
+use crate::analysis_context::AnalysisContext;
+use crate::config::ResolvedConfig;
+use crate::graph::ir::DepGraph;
+use crate::graph::load::{file_frontend, file_module};
+use crate::quality::complexity::{last_segment, ComplexityFrontend};
+use crate::quality::functions::frontend_for;
+use crate::walk::Language;
+use rayon::prelude::*;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+/// Symbols extracted from one source file, for function- and class-level graphs.
+#[derive(Debug, Clone)]
+pub struct SourceFile<T> {
+    /// File path relative to project root
+    pub file: PathBuf,
+    /// Module graph node the file belongs to
+    pub module: PathBuf,
+    pub language: Language,
+    pub symbols: T,
+}
+
+/// Run `extract` over every file whose language has a complexity frontend.
+pub fn extract_source_files<T, F>(
+    context: &AnalysisContext,
+    config: &ResolvedConfig,
+    extract: F,
+) -> Vec<SourceFile<T>>
+where
+    T: Send,
+    F: Fn(&dyn ComplexityFrontend, &[u8], &Path) -> T + Sync,
+{
+    context
+        .all_files
+        .par_iter()
+        .filter_map(|(lang, file_path)| {
+            let complexity = frontend_for(*lang)?;
+            let source = std::fs::read(file_path).ok()?;
+            let frontend = file_frontend(context, config, *lang, file_path).ok()?;
+            let module = file_module(context, frontend.as_ref(), *lang, file_path, &source);
+            let relative = file_path
+                .strip_prefix(&context.project_root)
+                .unwrap_or(file_path)
+                .to_path_buf();
+            Some(SourceFile {
+                symbols: extract(complexity.as_ref(), &source, &relative),
+                file: relative,
+                module,
+                language: *lang,
+            })
+        })
+        .collect()
+}
+
+/// Modules each module depends on, by node path.
+pub fn module_imports(graph: &DepGraph) -> HashMap<PathBuf, HashSet<PathBuf>> {
+    let mut imports: HashMap<PathBuf, HashSet<PathBuf>> = HashMap::new();
+    for edge in graph.edge_indices() {
+        let (source, target) = graph.edge_endpoints(edge).unwrap();
+        imports
+            .entry(graph[source].path.clone())
+            .or_default()
+            .insert(graph[target].path.clone());
+    }
+    imports
+}
+
+/// For each file, the files whose symbols it can refer to: those of its own
+/// module and of the modules it imports.
+pub fn module_scopes<'a, T>(
+    files: &'a [SourceFile<T>],
+    imports: &HashMap<PathBuf, HashSet<PathBuf>>,
+) -> Vec<Vec<&'a SourceFile<T>>> {
+    let mut files_by_module: HashMap<&Path, Vec<&SourceFile<T>>> = HashMap::new();
+    for file in files {
+        files_by_module
+            .entry(file.module.as_path())
+            .or_default()
+            .push(file);
+    }
+
+    files
+        .iter()
+        .map(|file| {
+            let mut scope = files_by_module
+                .get(file.module.as_path())
+                .cloned()
+                .unwrap_or_default();
+            for imported in imports.get(&file.module).into_iter().flatten() {
+                if imported != &file.module {
+                    scope.extend(
+                        files_by_module
+                            .get(imported.as_path())
+                            .into_iter()
+                            .flatten(),
+                    );
+                }
+            }
+            scope
+        })
+        .collect()
+}
+
+/// Keep the candidates matching `keep`, unless none do.
+pub fn narrow<T>(candidates: &mut Vec<T>, keep: impl Fn(&T) -> bool) {
+    if candidates.iter().any(&keep) {
+        candidates.retain(keep);
+    }
+}
+
+/// Type or class a member name belongs to (`Invoice` for `billing.Invoice.total`).
+pub fn owner(name: &str) -> Option<&str> {
+    let (owner, _) = name.rsplit_once(['.', ':'])?;
+    let owner = last_segment(owner.trim_end_matches(':'));
+    let owner = owner.split('<').next().unwrap_or(owner);
+    Some(owner.trim_start_matches(['*', '&']))
+}
+
+/// Names a module is referred to by in code: the file stem, and the
+/// directory for `mod.rs`, `__init__.py`, `index.*` and Go packages.
+pub fn module_labels<T>(file: &SourceFile<T>) -> Vec<String> {
+    [&file.file, &file.module]
+        .into_iter()
+        .filter_map(|path| {
+            let stem = path.file_stem()?.to_str()?;
+            if matches!(stem, "mod" | "__init__" | "index") {
+                path.parent()?.file_name()?.to_str()
+            } else {
+                Some(stem)
+            }
+        })
+        .map(normalize)
+        .collect()
+}
+
+/// Case- and underscore-insensitive form, so `Billing` matches `billing.rb`.
+pub fn normalize(name: &str) -> String {
+    name.chars()
+        .filter(|c| *c != '_')
+        .flat_map(char::to_lowercase)
+        .collect()
+}
@@ -1,4 +1,5 @@
-use crate::config::{OverrideEntry, ResolvedRules};
+use crate::architecture::policy::ArchitectureComponentMetric;
+use crate::config::{MainSequenceRule, OverrideEntry, ResolvedRules};
 use crate::graph::ir::DepGraph;
 use crate::metrics::entropy::shannon_entropy;
 use crate::metrics::fanout::{fan_in, fan_out};
@@ -23,6 +24,8 @@ pub enum InsightCategory {
     CircularDependency,
     DeepChain,
     HighEntropy,
+    ZoneOfPain,
+    ZoneOfUselessness,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -48,6 +51,12 @@ pub struct InsightMetrics {
     pub scc_size: Option<usize>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub depth: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instability: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub abstractness: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub distance: Option<f64>,
 }
 
 #[derive(Debug, Clone)]
@@ -72,18 +81,29 @@ pub fn generate_insights(graph: &DepGraph, summary: &Summary, sccs: &[SccInfo])
         sccs,
         &ResolvedRules::default(),
         &[],
+        &[],
     )
 }
 
 /// Generate insights using resolved configuration rules and per-path overrides.
+/// `components` are the architecture components checked against the main
+/// sequence; per-path overrides do not apply to them.
 pub fn generate_insights_with_config(
     graph: &DepGraph,
     summary: &Summary,
     sccs: &[SccInfo],
     rules: &ResolvedRules,
     overrides: &[(globset::GlobMatcher, OverrideEntry)],
+    components: &[ArchitectureComponentMetric],
 ) -> Vec<Insight> {
-    generate_insights_internal(collect_node_metrics(graph), summary, sccs, rules, overrides)
+    generate_insights_internal(
+        collect_node_metrics(graph),
+        summary,
+        sccs,
+        rules,
+        overrides,
+        components,
+    )
 }
 
 fn collect_node_metrics(graph: &DepGraph) -> Vec<NodeMetrics> {
@@ -111,6 +131,7 @@ fn generate_insights_internal(
     sccs: &[SccInfo],
     rules: &ResolvedRules,
     overrides: &[(globset::GlobMatcher, OverrideEntry)],
+    components: &[ArchitectureComponentMetric],
 ) -> Vec<Insight> {
     let evaluated = evaluate_nodes(node_metrics, rules, overrides);
     let mut insights = Vec::new();
@@ -122,6 +143,7 @@ fn generate_insights_internal(
         insights.push(insight);
     }
     insights.extend(evaluate_high_entropy(&evaluated, &god_modules));
+    insights.extend(evaluate_main_sequence(components, &rules.main_sequence));
     sort_insights(&mut insights);
     insights
 }
@@ -257,6 +279,26 @@ fn evaluate_high_entropy(nodes: &[EvaluatedNode], god_modules: &HashSet<String>)
     insights
 }
 
+fn evaluate_main_sequence(
+    components: &[ArchitectureComponentMetric],
+    rule: &MainSequenceRule,
+) -> Vec<Insight> {
+    if !rule.enabled {
+        return Vec::new();
+    }
+    components
+        .iter()
+        // Isolated components have no meaningful instability.
+        .filter(|component| component.fan_in + component.fan_out > 0)
+        .filter_map(|component| {
+            let abstractness = component.abstractness?;
+            let distance = component.distance?;
+            (distance > rule.max_distance)
+                .then(|| build_main_sequence_insight(component, abstractness, distance))
+        })
+        .collect()
+}
+
 fn build_god_module_insight(metrics: &NodeMetrics) -> Insight {
     Insight {
         category: InsightCategory::GodModule,
@@ -275,6 +317,9 @@ fn build_god_module_insight(metrics: &NodeMetrics) -> Insight {
             scc_id: None,
             scc_size: None,
             depth: None,
+            instability: None,
+            abstractness: None,
+            distance: None,
         },
     }
 }
@@ -301,6 +346,9 @@ fn build_high_fanout_insight(
             scc_id: None,
             scc_size: None,
             depth: None,
+            instability: None,
+            abstractness: None,
+            distance: None,
         },
     }
 }
@@ -323,6 +371,9 @@ fn build_circular_dependency_insight(scc: &SccInfo, severity: InsightSeverity) -
             scc_id: Some(scc.id),
             scc_size: Some(scc.size),
             depth: None,
+            instability: None,
+            abstractness: None,
+            distance: None,
         },
     }
 }
@@ -345,6 +396,9 @@ fn build_deep_chain_insight(summary: &Summary) -> Insight {
             scc_id: None,
             scc_size: None,
             depth: Some(summary.max_depth),
+            instability: None,
+            abstractness: None,
+            distance: None,
         },
     }
 }
@@ -367,6 +421,55 @@ fn build_high_entropy_insight(metrics: &NodeMetrics) -> Insight {
             scc_id: None,
             scc_size: None,
             depth: None,
+            instability: None,
+            abstractness: None,
+            distance: None,
+        },
+    }
+}
+
+fn build_main_sequence_insight(
+    component: &ArchitectureComponentMetric,
+    abstractness: f64,
+    distance: f64,
+) -> Insight {
+    let (category, message) = if abstractness + component.instability < 1.0 {
+        (
+            InsightCategory::ZoneOfPain,
+            format!(
+                "Component '{}' is concrete (A={:.2}) and stable (I={:.2}), \
+                 with {} dependents (D={:.2}). \
+                 Changes to it may ripple widely; consider introducing \
+                 abstractions for its dependents to rely on.",
+                component.id, abstractness, component.instability, component.fan_in, distance
+            ),
+        )
+    } else {
+        (
+            InsightCategory::ZoneOfUselessness,
+            format!(
+                "Component '{}' is abstract (A={:.2}) but unstable (I={:.2}), \
+                 with {} dependents (D={:.2}). \
+                 Consider whether its abstractions are still needed.",
+                component.id, abstractness, component.instability, component.fan_in, distance
+            ),
+        )
+    };
+    Insight {
+        category,
+        severity: InsightSeverity::Info,
+        module: component.id.clone(),
+        message,
+        metrics: InsightMetrics {
+            fanout: Some(component.fan_out),
+            fanin: Some(component.fan_in),
+            entropy: None,
+            scc_id: None,
+            scc_size: None,
+            depth: None,
+            instability: Some(component.instability),
+            abstractness: Some(abstractness),
+            distance: Some(distance),
         },
     }
 }
@@ -389,6 +492,7 @@ mod tests {
     use crate::graph::ir::{EdgeKind, GraphEdge, GraphNode, NodeKind};
     use crate::metrics::scc::find_non_trivial_sccs;
     use crate::metrics::summary::Summary;
+    use crate::parse::common::ImportContext;
     use globset::Glob;
     use std::path::PathBuf;
 
@@ -407,6 +511,8 @@ mod tests {
             kind: EdgeKind::default(),
             source_locations: vec![],
             weight: 1,
+            cfg: None,
+            context: ImportContext::Runtime,
         }
     }
 
@@ -635,8 +741,14 @@ mod tests {
         let sccs = find_non_trivial_sccs(&graph);
 
         let direct = generate_insights(&graph, &summary, &sccs);
-        let config_aware =
-            generate_insights_with_config(&graph, &summary, &sccs, &ResolvedRules::default(), &[]);
+        let config_aware = generate_insights_with_config(
+            &graph,
+            &summary,
+            &sccs,
+            &ResolvedRules::default(),
+            &[],
+            &[],
+        );
 
         assert_eq!(
             serde_json::to_value(&direct).unwrap(),
@@ -674,6 +786,7 @@ mod tests {
             &sccs,
             &ResolvedRules::default(),
             &overrides,
+            &[],
         );
 
         assert!(
@@ -723,6 +836,7 @@ mod tests {
             &sccs,
             &ResolvedRules::default(),
             &overrides,
+            &[],
         );
 
         assert!(
@@ -732,4 +846,65 @@ mod tests {
             "Expected override threshold to suppress high-fanout insight"
         );
     }
+
+    fn component(
+        id: &str,
+        fan_in: usize,
+        fan_out: usize,
+        abstractness: Option<f64>,
+    ) -> ArchitectureComponentMetric {
+        let instability = fan_out as f64 / (fan_in + fan_out).max(1) as f64;
+        ArchitectureComponentMetric {
+            id: id.to_string(),
+            layer: 0,
+            module_count: 1,
+            fan_in,
+            fan_out,
+            instability,
+            abstractness,
+            distance: abstractness.map(|a| (a + instability - 1.0).abs()),
+            feedback: false,
+        }
+    }
+
+    #[test]
+    fn main_sequence_zone_insights() {
+        let graph = DepGraph::new();
+        let summary = Summary::from_graph(&graph);
+        let components = vec![
+            // Concrete and depended upon: zone of pain
+            component("core", 4, 0, Some(0.0)),
+            // Abstract with nothing depending on it: zone of uselessness
+            component("ports", 0, 2, Some(1.0)),
+            // Balanced
+            component("service", 2, 2, Some(0.5)),
+            // No types, or no dependencies at all
+            component("scripts", 3, 0, None),
+            component("isolated", 0, 0, Some(0.0)),
+        ];
+
+        let mut rules = ResolvedRules::default();
+        assert!(!rules.main_sequence.enabled);
+        rules.main_sequence.enabled = true;
+        let insights =
+            generate_insights_with_config(&graph, &summary, &[], &rules, &[], &components);
+        let found: Vec<_> = insights
+            .iter()
+            .map(|i| (i.category, i.module.as_str()))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (InsightCategory::ZoneOfPain, "core"),
+                (InsightCategory::ZoneOfUselessness, "ports"),
+            ]
+        );
+        assert_eq!(insights[0].metrics.distance, Some(1.0));
+        assert_eq!(insights[0].metrics.fanin, Some(4));
+
+        rules.main_sequence.enabled = false;
+        let insights =
+            generate_insights_with_config(&graph, &summary, &[], &rules, &[], &components);
+        assert!(insights.is_empty());
+    }
 }
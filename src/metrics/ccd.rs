@@ -0,0 +1,175 @@
+use crate::graph::ir::DepGraph;
+use crate::metrics::depth::reverse_topo_order;
+use petgraph::algo::condensation;
+use petgraph::graph::NodeIndex;
+use petgraph::Direction;
+
+/// Lakos' cumulative component dependency metrics.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Ccd {
+    /// Sum over all nodes of the number of nodes each depends on, itself included
+    pub cumulative: usize,
+    /// `cumulative / n`: the number of nodes an average node needs
+    pub average: f64,
+    /// `cumulative` divided by that of a balanced binary tree of the same size
+    pub normalized: f64,
+}
+
+/// Compute CCD, ACD and NCCD on the condensation DAG, weighting each SCC by
+/// its size. Members of a cycle depend on each other, so each one counts the
+/// whole cycle and everything it reaches.
+pub fn cumulative_component_dependency(graph: &DepGraph) -> Ccd {
+    let n = graph.node_count();
+    if n == 0 {
+        return Ccd::default();
+    }
+
+    let dag = condensation(graph.clone(), true);
+    let words = dag.node_count().div_ceil(64);
+    let mut reachable = vec![vec![0u64; words]; dag.node_count()];
+
+    // Successors come first in reverse topological order, so their sets are
+    // complete by the time a node merges them.
+    let mut cumulative = 0;
+    for u in reverse_topo_order(&dag) {
+        let mut set = vec![0u64; words];
+        set[u.index() / 64] |= 1 << (u.index() % 64);
+        for v in dag.neighbors_directed(u, Direction::Outgoing) {
+            for (word, other) in set.iter_mut().zip(&reachable[v.index()]) {
+                *word |= other;
+            }
+        }
+        let mut depends_on = 0;
+        for (offset, &word) in set.iter().enumerate() {
+            let mut bits = word;
+            while bits != 0 {
+                let v = NodeIndex::new(offset * 64 + bits.trailing_zeros() as usize);
+                depends_on += dag[v].len();
+                bits &= bits - 1;
+            }
+        }
+        cumulative += dag[u].len() * depends_on;
+        reachable[u.index()] = set;
+    }
+
+    let average = cumulative as f64 / n as f64;
+    let normalized = cumulative as f64 / balanced_tree_ccd(n);
+    Ccd {
+        cumulative,
+        average: (average * 100.0).round() / 100.0,
+        normalized: (normalized * 100.0).round() / 100.0,
+    }
+}
+
+/// CCD of a balanced binary tree with `n` nodes: `(n + 1) log2(n + 1) - n`.
+fn balanced_tree_ccd(n: usize) -> f64 {
+    let n = n as f64;
+    (n + 1.0) * (n + 1.0).log2() - n
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::ir::{EdgeKind, GraphEdge, GraphNode, NodeKind};
+    use crate::metrics::propagation::Reachability;
+    use crate::parse::common::ImportContext;
+    use std::path::PathBuf;
+
+    fn make_node(name: &str) -> GraphNode {
+        GraphNode {
+            kind: NodeKind::Module,
+            path: PathBuf::from(name),
+            name: name.to_string(),
+            span: None,
+            language: None,
+        }
+    }
+
+    fn make_edge() -> GraphEdge {
+        GraphEdge {
+            kind: EdgeKind::default(),
+            source_locations: vec![],
+            weight: 1,
+            cfg: None,
+            context: ImportContext::Runtime,
+        }
+    }
+
+    fn ccd_of(count: usize, edges: &[(usize, usize)]) -> Ccd {
+        cumulative_component_dependency(&graph_of(count, edges))
+    }
+
+    fn graph_of(count: usize, edges: &[(usize, usize)]) -> DepGraph {
+        let mut graph = DepGraph::new();
+        let nodes: Vec<_> = (0..count)
+            .map(|i| graph.add_node(make_node(&format!("m{i}"))))
+            .collect();
+        for &(from, to) in edges {
+            graph.add_edge(nodes[from], nodes[to], make_edge());
+        }
+        graph
+    }
+
+    #[test]
+    fn empty_graph_has_no_ccd() {
+        assert_eq!(ccd_of(0, &[]), Ccd::default());
+    }
+
+    #[test]
+    fn balanced_binary_tree_normalizes_to_one() {
+        // m0 -> m1, m2; m1 -> m3, m4; m2 -> m5, m6
+        let ccd = ccd_of(7, &[(0, 1), (0, 2), (1, 3), (1, 4), (2, 5), (2, 6)]);
+        // 7 + 3 + 3 + 1 * 4
+        assert_eq!(ccd.cumulative, 17);
+        assert_eq!(ccd.average, 2.43);
+        assert_eq!(ccd.normalized, 1.0);
+    }
+
+    #[test]
+    fn shared_dependencies_are_counted_once() {
+        // m0 -> m1 -> m3 and m0 -> m2 -> m3
+        // 4 + 2 + 2 + 1
+        assert_eq!(ccd_of(4, &[(0, 1), (0, 2), (1, 3), (2, 3)]).cumulative, 9);
+    }
+
+    #[test]
+    fn cycle_members_depend_on_the_whole_cycle() {
+        // m0 -> m1 -> m2 -> m0, m2 -> m3
+        let ccd = ccd_of(4, &[(0, 1), (1, 2), (2, 0), (2, 3)]);
+        // three members each see all four nodes, m3 sees itself
+        assert_eq!(ccd.cumulative, 13);
+        assert_eq!(ccd.average, 3.25);
+    }
+
+    #[test]
+    fn ccd_is_propagation_cost_scaled_by_node_count() {
+        let graph = graph_of(5, &[(0, 1), (1, 2), (2, 1), (3, 4)]);
+        let reach = Reachability::compute(&graph);
+        let ccd = cumulative_component_dependency(&graph);
+        assert_eq!(ccd.cumulative as f64, reach.propagation_cost() * 25.0);
+    }
+
+    #[test]
+    fn condensation_agrees_with_per_node_reachability_on_cycles() {
+        // Two cycles of different sizes, one reaching the other, a chain into
+        // the first and a self-loop
+        let graph = graph_of(
+            9,
+            &[
+                (0, 1),
+                (1, 2),
+                (2, 0),
+                (2, 3),
+                (3, 4),
+                (4, 3),
+                (5, 0),
+                (6, 5),
+                (6, 6),
+                (7, 4),
+            ],
+        );
+        let reach = Reachability::compute(&graph);
+        let per_node = reach.transitive_fanout.iter().sum::<usize>() + graph.node_count();
+        assert_eq!(cumulative_component_dependency(&graph).cumulative, per_node);
+    }
+}
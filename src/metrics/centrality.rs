@@ -0,0 +1,317 @@
+use petgraph::graph::EdgeIndex;
+use petgraph::visit::EdgeRef;
+use petgraph::Direction;
+use rayon::prelude::*;
+use std::collections::{HashSet, VecDeque};
+
+use crate::graph::ir::DepGraph;
+
+const PAGERANK_DAMPING: f64 = 0.85;
+const PAGERANK_MAX_ITERATIONS: usize = 100;
+const PAGERANK_TOLERANCE: f64 = 1e-9;
+
+/// Centrality of every node, indexed by node index.
+#[derive(Debug, Clone, Default)]
+pub struct Centrality {
+    /// Share of shortest paths between other nodes passing through the node
+    pub betweenness: Vec<f64>,
+    /// PageRank over dependency edges: high for nodes many others rely on
+    pub pagerank: Vec<f64>,
+    /// Removing the node splits its weakly connected component
+    pub articulation_points: Vec<bool>,
+    /// Edges whose removal splits their weakly connected component
+    pub bridges: HashSet<EdgeIndex>,
+}
+
+impl Centrality {
+    pub fn compute(graph: &DepGraph) -> Self {
+        let (articulation_points, bridges) = cut_structure(graph);
+        Self {
+            betweenness: betweenness_centrality(graph),
+            pagerank: pagerank(graph),
+            articulation_points,
+            bridges,
+        }
+    }
+}
+
+/// Outgoing neighbours of each node, without duplicates or self-loops.
+fn successors(graph: &DepGraph) -> Vec<Vec<usize>> {
+    graph
+        .node_indices()
+        .map(|idx| {
+            let mut targets: Vec<usize> = graph
+                .neighbors_directed(idx, Direction::Outgoing)
+                .map(|target| target.index())
+                .filter(|&target| target != idx.index())
+                .collect();
+            targets.sort_unstable();
+            targets.dedup();
+            targets
+        })
+        .collect()
+}
+
+/// Betweenness centrality (Brandes), normalised by `(n - 1)(n - 2)` so
+/// that scores fall between 0 and 1. Sources are processed in parallel.
+pub fn betweenness_centrality(graph: &DepGraph) -> Vec<f64> {
+    let n = graph.node_count();
+    let adjacency = successors(graph);
+    let mut scores = (0..n)
+        .into_par_iter()
+        .fold(
+            || vec![0.0; n],
+            |mut scores, source| {
+                accumulate_dependencies(&adjacency, source, &mut scores);
+                scores
+            },
+        )
+        .reduce(
+            || vec![0.0; n],
+            |mut total, partial| {
+                for (score, value) in total.iter_mut().zip(partial) {
+                    *score += value;
+                }
+                total
+            },
+        );
+    if n > 2 {
+        let scale = 1.0 / ((n - 1) * (n - 2)) as f64;
+        for score in &mut scores {
+            *score *= scale;
+        }
+    }
+    scores
+}
+
+/// Add the dependencies of `source` on every other node to `scores`.
+fn accumulate_dependencies(adjacency: &[Vec<usize>], source: usize, scores: &mut [f64]) {
+    let n = adjacency.len();
+    let mut order = Vec::with_capacity(n);
+    let mut predecessors: Vec<Vec<usize>> = vec![Vec::new(); n];
+    let mut paths = vec![0.0_f64; n];
+    let mut distance = vec![usize::MAX; n];
+    paths[source] = 1.0;
+    distance[source] = 0;
+
+    let mut queue = VecDeque::from([source]);
+    while let Some(node) = queue.pop_front() {
+        order.push(node);
+        for &next in &adjacency[node] {
+            if distance[next] == usize::MAX {
+                distance[next] = distance[node] + 1;
+                queue.push_back(next);
+            }
+            if distance[next] == distance[node] + 1 {
+                paths[next] += paths[node];
+                predecessors[next].push(node);
+            }
+        }
+    }
+
+    let mut dependency = vec![0.0_f64; n];
+    for &node in order.iter().rev() {
+        for &previous in &predecessors[node] {
+            dependency[previous] += paths[previous] / paths[node] * (1.0 + dependency[node]);
+        }
+        if node != source {
+            scores[node] += dependency[node];
+        }
+    }
+}
+
+/// PageRank with damping 0.85. Nodes without dependencies spread their
+/// rank evenly over the graph. Scores sum to 1.
+pub fn pagerank(graph: &DepGraph) -> Vec<f64> {
+    let n = graph.node_count();
+    if n == 0 {
+        return Vec::new();
+    }
+    let adjacency = successors(graph);
+    let mut rank = vec![1.0 / n as f64; n];
+    for _ in 0..PAGERANK_MAX_ITERATIONS {
+        let dangling: f64 = adjacency
+            .iter()
+            .zip(&rank)
+            .filter(|(targets, _)| targets.is_empty())
+            .map(|(_, rank)| rank)
+            .sum();
+        let base = (1.0 - PAGERANK_DAMPING + PAGERANK_DAMPING * dangling) / n as f64;
+        let mut next = vec![base; n];
+        for (node, targets) in adjacency.iter().enumerate() {
+            let share = PAGERANK_DAMPING * rank[node] / targets.len().max(1) as f64;
+            for &target in targets {
+                next[target] += share;
+            }
+        }
+        let change: f64 = next.iter().zip(&rank).map(|(a, b)| (a - b).abs()).sum();
+        rank = next;
+        if change < PAGERANK_TOLERANCE {
+            break;
+        }
+    }
+    rank
+}
+
+/// Articulation points and bridges of the graph taken as undirected, so a
+/// pair of modules importing each other is never a bridge.
+pub fn cut_structure(graph: &DepGraph) -> (Vec<bool>, HashSet<EdgeIndex>) {
+    let n = graph.node_count();
+    let mut neighbours: Vec<Vec<(usize, EdgeIndex)>> = vec![Vec::new(); n];
+    for edge in graph.edge_references() {
+        let (source, target) = (edge.source().index(), edge.target().index());
+        if source != target {
+            neighbours[source].push((target, edge.id()));
+            neighbours[target].push((source, edge.id()));
+        }
+    }
+
+    let mut articulation_points = vec![false; n];
+    let mut bridges = HashSet::new();
+    let mut discovered = vec![usize::MAX; n];
+    let mut low = vec![0; n];
+    let mut time = 0;
+
+    for root in 0..n {
+        if discovered[root] != usize::MAX {
+            continue;
+        }
+        discovered[root] = time;
+        low[root] = time;
+        time += 1;
+        let mut root_children = 0;
+        // (node, edge it was reached through, next neighbour to visit)
+        let mut stack: Vec<(usize, Option<EdgeIndex>, usize)> = vec![(root, None, 0)];
+        while let Some(frame) = stack.last_mut() {
+            let (node, via) = (frame.0, frame.1);
+            if let Some(&(next, edge)) = neighbours[node].get(frame.2) {
+                frame.2 += 1;
+                if Some(edge) == via {
+                    continue;
+                }
+                if discovered[next] == usize::MAX {
+                    discovered[next] = time;
+                    low[next] = time;
+                    time += 1;
+                    stack.push((next, Some(edge), 0));
+                } else {
+                    low[node] = low[node].min(discovered[next]);
+                }
+                continue;
+            }
+            stack.pop();
+            let Some(&(parent, _, _)) = stack.last() else {
+                continue;
+            };
+            low[parent] = low[parent].min(low[node]);
+            if low[node] > discovered[parent] {
+                bridges.extend(via);
+            }
+            if parent == root {
+                root_children += 1;
+            } else if low[node] >= discovered[parent] {
+                articulation_points[parent] = true;
+            }
+        }
+        if root_children > 1 {
+            articulation_points[root] = true;
+        }
+    }
+
+    (articulation_points, bridges)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::ir::{EdgeKind, GraphEdge, GraphNode, NodeKind};
+    use crate::parse::common::ImportContext;
+    use petgraph::graph::NodeIndex;
+    use std::path::PathBuf;
+
+    fn make_node(name: &str) -> GraphNode {
+        GraphNode {
+            kind: NodeKind::Module,
+            path: PathBuf::from(name),
+            name: name.to_string(),
+            span: None,
+            language: None,
+        }
+    }
+
+    fn make_edge() -> GraphEdge {
+        GraphEdge {
+            kind: EdgeKind::default(),
+            source_locations: vec![],
+            weight: 1,
+            cfg: None,
+            context: ImportContext::Runtime,
+        }
+    }
+
+    fn graph_of(names: &[&str], edges: &[(usize, usize)]) -> (DepGraph, Vec<NodeIndex>) {
+        let mut graph = DepGraph::new();
+        let nodes: Vec<_> = names
+            .iter()
+            .map(|name| graph.add_node(make_node(name)))
+            .collect();
+        for &(from, to) in edges {
+            graph.add_edge(nodes[from], nodes[to], make_edge());
+        }
+        (graph, nodes)
+    }
+
+    #[test]
+    fn betweenness_peaks_at_the_middle_of_a_chain() {
+        // a -> b -> c -> d
+        let (graph, _) = graph_of(&["a", "b", "c", "d"], &[(0, 1), (1, 2), (2, 3)]);
+        let scores = betweenness_centrality(&graph);
+        // b lies on a->c and a->d; c on a->d and b->d; out of 3 * 2 pairs.
+        assert_eq!(scores, vec![0.0, 2.0 / 6.0, 2.0 / 6.0, 0.0]);
+    }
+
+    #[test]
+    fn betweenness_splits_between_equal_paths() {
+        // a -> b -> d and a -> c -> d
+        let (graph, _) = graph_of(&["a", "b", "c", "d"], &[(0, 1), (0, 2), (1, 3), (2, 3)]);
+        let scores = betweenness_centrality(&graph);
+        assert_eq!(scores[1], 0.5 / 6.0);
+        assert_eq!(scores[2], 0.5 / 6.0);
+    }
+
+    #[test]
+    fn pagerank_favours_widely_imported_modules() {
+        // a, b and c all import core; core imports nothing
+        let (graph, _) = graph_of(&["a", "b", "c", "core"], &[(0, 3), (1, 3), (2, 3)]);
+        let rank = pagerank(&graph);
+        assert!((rank.iter().sum::<f64>() - 1.0).abs() < 1e-6);
+        assert!(rank[3] > rank[0]);
+        assert!((rank[0] - rank[1]).abs() < 1e-12);
+    }
+
+    #[test]
+    fn finds_articulation_points_and_bridges() {
+        // a <-> b form a cycle; b -> c -> d hang off it
+        let (graph, nodes) = graph_of(&["a", "b", "c", "d"], &[(0, 1), (1, 0), (1, 2), (2, 3)]);
+        let (points, bridges) = cut_structure(&graph);
+        assert_eq!(points, vec![false, true, true, false]);
+
+        let mut bridge_ends: Vec<_> = bridges
+            .iter()
+            .map(|&edge| graph.edge_endpoints(edge).unwrap())
+            .collect();
+        bridge_ends.sort();
+        assert_eq!(
+            bridge_ends,
+            vec![(nodes[1], nodes[2]), (nodes[2], nodes[3])]
+        );
+    }
+
+    #[test]
+    fn disconnected_graphs_have_no_cut_between_components() {
+        let (graph, _) = graph_of(&["a", "b", "c"], &[(0, 1)]);
+        let (points, bridges) = cut_structure(&graph);
+        assert_eq!(points, vec![false, false, false]);
+        assert_eq!(bridges.len(), 1);
+    }
+}
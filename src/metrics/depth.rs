@@ -60,7 +60,7 @@ pub fn avg_depth(graph: &DepGraph) -> f64 {
 
 /// Reverse topological order via Kahn's algorithm on the reversed graph.
 /// Nodes are returned such that for each edge u→v, v appears before u.
-fn reverse_topo_order<N, E>(dag: &petgraph::Graph<N, E>) -> Vec<petgraph::graph::NodeIndex> {
+pub fn reverse_topo_order<N, E>(dag: &petgraph::Graph<N, E>) -> Vec<petgraph::graph::NodeIndex> {
     let n = dag.node_count();
     let mut out_degree = vec![0usize; n];
     for edge in dag.edge_references() {
@@ -94,6 +94,7 @@ fn reverse_topo_order<N, E>(dag: &petgraph::Graph<N, E>) -> Vec<petgraph::graph:
 mod tests {
     use super::*;
     use crate::graph::ir::{EdgeKind, GraphEdge, GraphNode, NodeKind};
+    use crate::parse::common::ImportContext;
     use std::path::PathBuf;
 
     fn make_node(name: &str) -> GraphNode {
@@ -111,6 +112,8 @@ mod tests {
             kind: EdgeKind::default(),
             source_locations: vec![],
             weight: 1,
+            cfg: None,
+            context: ImportContext::Runtime,
         }
     }
 
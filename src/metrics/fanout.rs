@@ -16,6 +16,7 @@ pub fn fan_in(graph: &DepGraph, node: petgraph::graph::NodeIndex) -> usize {
 mod tests {
     use super::*;
     use crate::graph::ir::{EdgeKind, GraphEdge, GraphNode, NodeKind};
+    use crate::parse::common::ImportContext;
     use std::path::PathBuf;
 
     fn make_node(name: &str) -> GraphNode {
@@ -33,6 +34,8 @@ mod tests {
             kind: EdgeKind::default(),
             source_locations: vec![],
             weight: 1,
+            cfg: None,
+            context: ImportContext::Runtime,
         }
     }
 
@@ -1,5 +1,8 @@
+pub mod ccd;
+pub mod centrality;
 pub mod depth;
 pub mod entropy;
 pub mod fanout;
+pub mod propagation;
 pub mod scc;
 pub mod summary;
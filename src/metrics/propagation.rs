@@ -0,0 +1,192 @@
+use petgraph::algo::tarjan_scc;
+use petgraph::visit::EdgeRef;
+
+use crate::graph::ir::DepGraph;
+
+/// Transitive reach of every node, indexed by node index.
+#[derive(Debug, Clone, Default)]
+pub struct Reachability {
+    /// Nodes each node depends on, directly or through other nodes
+    pub transitive_fanout: Vec<usize>,
+    /// Nodes depending on each node, directly or through other nodes: the
+    /// blast radius of a change to it
+    pub transitive_fanin: Vec<usize>,
+}
+
+impl Reachability {
+    /// Close over the condensation DAG, one bitset of reachable components
+    /// per component. Members of a cycle reach each other and share
+    /// everything the cycle reaches, so a walk from every node is not needed.
+    pub fn compute(graph: &DepGraph) -> Self {
+        let n = graph.node_count();
+        // Tarjan lists components after every component they reach.
+        let sccs = tarjan_scc(graph);
+        let mut component = vec![0; n];
+        for (id, members) in sccs.iter().enumerate() {
+            for member in members {
+                component[member.index()] = id;
+            }
+        }
+
+        let words = sccs.len().div_ceil(64);
+        let mut reachable = vec![vec![0u64; words]; sccs.len()];
+        let mut fanout = vec![0; sccs.len()];
+        let mut fanin = vec![0; sccs.len()];
+        for (u, members) in sccs.iter().enumerate() {
+            let mut set = vec![0u64; words];
+            for edge in members.iter().flat_map(|&member| graph.edges(member)) {
+                let v = component[edge.target().index()];
+                if v != u {
+                    set[v / 64] |= 1 << (v % 64);
+                    for (word, other) in set.iter_mut().zip(&reachable[v]) {
+                        *word |= other;
+                    }
+                }
+            }
+            for (offset, &word) in set.iter().enumerate() {
+                let mut bits = word;
+                while bits != 0 {
+                    let v = offset * 64 + bits.trailing_zeros() as usize;
+                    fanout[u] += sccs[v].len();
+                    fanin[v] += members.len();
+                    bits &= bits - 1;
+                }
+            }
+            reachable[u] = set;
+        }
+
+        // Other members of the node's own cycle come on top.
+        let others = |node: usize| sccs[component[node]].len() - 1;
+        Self {
+            transitive_fanout: (0..n)
+                .map(|node| fanout[component[node]] + others(node))
+                .collect(),
+            transitive_fanin: (0..n)
+                .map(|node| fanin[component[node]] + others(node))
+                .collect(),
+        }
+    }
+
+    /// Largest blast radius in the graph.
+    pub fn max_blast_radius(&self) -> usize {
+        self.transitive_fanin.iter().copied().max().unwrap_or(0)
+    }
+
+    /// MacCormack's propagation cost: the density of the visibility matrix,
+    /// in which every node sees itself and everything it reaches. It is the
+    /// share of the graph an average node's changes can propagate to.
+    pub fn propagation_cost(&self) -> f64 {
+        let n = self.transitive_fanout.len();
+        if n == 0 {
+            return 0.0;
+        }
+        let visible: usize = self.transitive_fanout.iter().sum::<usize>() + n;
+        visible as f64 / (n * n) as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::ir::{EdgeKind, GraphEdge, GraphNode, NodeKind};
+    use crate::parse::common::ImportContext;
+    use std::path::PathBuf;
+
+    fn make_node(name: &str) -> GraphNode {
+        GraphNode {
+            kind: NodeKind::Module,
+            path: PathBuf::from(name),
+            name: name.to_string(),
+            span: None,
+            language: None,
+        }
+    }
+
+    fn make_edge() -> GraphEdge {
+        GraphEdge {
+            kind: EdgeKind::default(),
+            source_locations: vec![],
+            weight: 1,
+            cfg: None,
+            context: ImportContext::Runtime,
+        }
+    }
+
+    fn graph_of(count: usize, edges: &[(usize, usize)]) -> DepGraph {
+        let mut graph = DepGraph::new();
+        let nodes: Vec<_> = (0..count)
+            .map(|i| graph.add_node(make_node(&format!("m{i}"))))
+            .collect();
+        for &(from, to) in edges {
+            graph.add_edge(nodes[from], nodes[to], make_edge());
+        }
+        graph
+    }
+
+    #[test]
+    fn empty_graph_has_no_propagation_cost() {
+        let reach = Reachability::compute(&DepGraph::new());
+        assert_eq!(reach.propagation_cost(), 0.0);
+        assert_eq!(reach.max_blast_radius(), 0);
+    }
+
+    #[test]
+    fn chain_reaches_everything_downstream() {
+        // m0 -> m1 -> m2 -> m3
+        let reach = Reachability::compute(&graph_of(4, &[(0, 1), (1, 2), (2, 3)]));
+        assert_eq!(reach.transitive_fanout, vec![3, 2, 1, 0]);
+        assert_eq!(reach.transitive_fanin, vec![0, 1, 2, 3]);
+        assert_eq!(reach.max_blast_radius(), 3);
+        // (3 + 2 + 1 + 0 + 4 self) / 16
+        assert_eq!(reach.propagation_cost(), 10.0 / 16.0);
+    }
+
+    #[test]
+    fn cycles_see_each_other_once() {
+        // m0 <-> m1 -> m2, with a duplicate edge and a self-loop
+        let reach = Reachability::compute(&graph_of(3, &[(0, 1), (1, 0), (1, 2), (0, 1), (2, 2)]));
+        assert_eq!(reach.transitive_fanout, vec![2, 2, 0]);
+        assert_eq!(reach.transitive_fanin, vec![1, 1, 2]);
+        assert_eq!(reach.propagation_cost(), 7.0 / 9.0);
+    }
+
+    #[test]
+    fn condensation_matches_a_walk_from_every_node() {
+        // Two cycles joined by a bridge, a chain into them and a self-loop
+        let edges = [
+            (0, 1),
+            (1, 2),
+            (2, 0),
+            (2, 3),
+            (3, 4),
+            (4, 5),
+            (5, 3),
+            (6, 0),
+            (7, 6),
+            (7, 7),
+            (8, 4),
+        ];
+        let graph = graph_of(9, &edges);
+        let reach = Reachability::compute(&graph);
+
+        let mut fanout = vec![0; 9];
+        let mut fanin = vec![0; 9];
+        for source in graph.node_indices() {
+            let mut walk = petgraph::visit::Bfs::new(&graph, source);
+            while let Some(node) = walk.next(&graph) {
+                if node != source {
+                    fanout[source.index()] += 1;
+                    fanin[node.index()] += 1;
+                }
+            }
+        }
+        assert_eq!(reach.transitive_fanout, fanout);
+        assert_eq!(reach.transitive_fanin, fanin);
+    }
+
+    #[test]
+    fn isolated_nodes_only_see_themselves() {
+        let reach = Reachability::compute(&graph_of(4, &[]));
+        assert_eq!(reach.propagation_cost(), 0.25);
+    }
+}
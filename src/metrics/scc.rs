@@ -1,6 +1,7 @@
 use crate::graph::ir::DepGraph;
+use crate::parse::common::ImportContext;
 use petgraph::algo::tarjan_scc;
-use petgraph::visit::EdgeRef;
+use petgraph::visit::{EdgeFiltered, EdgeRef};
 use serde::Serialize;
 
 /// Information about a strongly connected component.
@@ -12,9 +13,25 @@ pub struct SccInfo {
     pub internal_edges: usize,
 }
 
+/// Which edges count towards cycles.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SccOptions {
+    /// Skip edges whose imports only run under a type checker
+    /// (`if TYPE_CHECKING:`), since they never execute.
+    pub ignore_type_only: bool,
+}
+
 /// Compute all non-trivial SCCs (size > 1) in the graph.
 pub fn find_non_trivial_sccs(graph: &DepGraph) -> Vec<SccInfo> {
-    let sccs = tarjan_scc(graph);
+    find_non_trivial_sccs_with(graph, SccOptions::default())
+}
+
+/// Compute all non-trivial SCCs over the edges `options` keeps.
+pub fn find_non_trivial_sccs_with(graph: &DepGraph, options: SccOptions) -> Vec<SccInfo> {
+    let counted = |edge: petgraph::graph::EdgeReference<'_, crate::graph::ir::GraphEdge>| {
+        !(options.ignore_type_only && edge.weight().context == ImportContext::TypeChecking)
+    };
+    let sccs = tarjan_scc(&EdgeFiltered::from_fn(graph, counted));
     let mut result = Vec::new();
     let mut id = 0;
 
@@ -30,7 +47,7 @@ pub fn find_non_trivial_sccs(graph: &DepGraph) -> Vec<SccInfo> {
         let mut internal_edges = 0;
         for &node in &scc {
             for edge in graph.edges_directed(node, petgraph::Direction::Outgoing) {
-                if scc_set.contains(&edge.target()) {
+                if counted(edge) && scc_set.contains(&edge.target()) {
                     internal_edges += 1;
                 }
             }
@@ -48,25 +65,19 @@ pub fn find_non_trivial_sccs(graph: &DepGraph) -> Vec<SccInfo> {
     result
 }
 
-/// Return the SCC id for each node (None if in a trivial SCC).
+/// Return the SCC id for each node in one of `sccs` (absent if in a trivial SCC).
 pub fn node_scc_map(
     graph: &DepGraph,
+    sccs: &[SccInfo],
 ) -> std::collections::HashMap<petgraph::graph::NodeIndex, usize> {
-    let sccs = tarjan_scc(graph);
-    let mut map = std::collections::HashMap::new();
-    let mut id = 0;
-
-    for scc in sccs {
-        if scc.len() <= 1 {
-            continue;
-        }
-        for &node in &scc {
-            map.insert(node, id);
-        }
-        id += 1;
-    }
-
-    map
+    let ids: std::collections::HashMap<&str, usize> = sccs
+        .iter()
+        .flat_map(|scc| scc.members.iter().map(|member| (member.as_str(), scc.id)))
+        .collect();
+    graph
+        .node_indices()
+        .filter_map(|idx| ids.get(graph[idx].name.as_str()).map(|&id| (idx, id)))
+        .collect()
 }
 
 /// Return the SCC size for a node (1 if not in a non-trivial SCC).
@@ -84,6 +95,7 @@ pub fn node_scc_size(graph: &DepGraph, node: petgraph::graph::NodeIndex) -> usiz
 mod tests {
     use super::*;
     use crate::graph::ir::{EdgeKind, GraphEdge, GraphNode, NodeKind};
+    use crate::parse::common::ImportContext;
     use std::path::PathBuf;
 
     fn make_node(name: &str) -> GraphNode {
@@ -97,10 +109,16 @@ mod tests {
     }
 
     fn make_edge() -> GraphEdge {
+        make_edge_with(ImportContext::Runtime)
+    }
+
+    fn make_edge_with(context: ImportContext) -> GraphEdge {
         GraphEdge {
             kind: EdgeKind::default(),
             source_locations: vec![],
             weight: 1,
+            cfg: None,
+            context,
         }
     }
 
@@ -145,4 +163,33 @@ mod tests {
         let sccs = find_non_trivial_sccs(&graph);
         assert_eq!(sccs.len(), 2);
     }
+
+    #[test]
+    fn type_only_edges_can_be_ignored() {
+        let mut graph = DepGraph::new();
+        let a = graph.add_node(make_node("a"));
+        let b = graph.add_node(make_node("b"));
+        let c = graph.add_node(make_node("c"));
+        // a <-> b only through a type-checking import; b <-> c at runtime.
+        graph.add_edge(a, b, make_edge());
+        graph.add_edge(b, a, make_edge_with(ImportContext::TypeChecking));
+        graph.add_edge(b, c, make_edge_with(ImportContext::FunctionLocal));
+        graph.add_edge(c, b, make_edge());
+
+        let sccs = find_non_trivial_sccs(&graph);
+        assert_eq!(sccs.len(), 1);
+        assert_eq!(sccs[0].size, 3);
+
+        let options = SccOptions {
+            ignore_type_only: true,
+        };
+        let sccs = find_non_trivial_sccs_with(&graph, options);
+        assert_eq!(sccs.len(), 1);
+        assert_eq!(sccs[0].size, 2);
+        assert_eq!(sccs[0].internal_edges, 2);
+        let map = node_scc_map(&graph, &sccs);
+        assert_eq!(map.get(&a), None);
+        assert_eq!(map.get(&b), Some(&0));
+        assert_eq!(map.get(&c), Some(&0));
+    }
 }
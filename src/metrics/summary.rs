@@ -1,7 +1,9 @@
 use crate::graph::ir::DepGraph;
+use crate::metrics::ccd::cumulative_component_dependency;
 use crate::metrics::depth;
 use crate::metrics::fanout::{fan_in, fan_out};
-use crate::metrics::scc::find_non_trivial_sccs;
+use crate::metrics::propagation::Reachability;
+use crate::metrics::scc::{find_non_trivial_sccs_with, SccOptions};
 use serde::Serialize;
 
 /// Aggregate statistics for a dependency graph.
@@ -19,11 +21,26 @@ pub struct Summary {
     pub max_depth: usize,
     pub avg_depth: f64,
     pub total_complexity: usize,
+    /// Lakos' cumulative component dependency over the condensation DAG
+    pub ccd: usize,
+    /// Average component dependency: `ccd / nodes`
+    pub acd: f64,
+    /// `ccd` relative to a balanced binary tree of the same size
+    pub nccd: f64,
+    /// Share of the graph a change to an average node can reach (MacCormack)
+    pub propagation_cost: f64,
+    /// Most nodes depending on any single node, directly or transitively
+    pub max_blast_radius: usize,
 }
 
 impl Summary {
     /// Compute summary statistics from a dependency graph.
     pub fn from_graph(graph: &DepGraph) -> Self {
+        Self::from_graph_with(graph, SccOptions::default())
+    }
+
+    /// Compute summary statistics, counting cycles over the edges `options` keeps.
+    pub fn from_graph_with(graph: &DepGraph, options: SccOptions) -> Self {
         let node_count = graph.node_count();
         if node_count == 0 {
             return Self {
@@ -39,6 +56,11 @@ impl Summary {
                 max_depth: 0,
                 avg_depth: 0.0,
                 total_complexity: 0,
+                ccd: 0,
+                acd: 0.0,
+                nccd: 0.0,
+                propagation_cost: 0.0,
+                max_blast_radius: 0,
             };
         }
 
@@ -54,7 +76,7 @@ impl Summary {
         let p90_idx = (node_count as f64 * 0.9).ceil() as usize;
         let p90_idx = p90_idx.min(node_count) - 1;
 
-        let sccs = find_non_trivial_sccs(graph);
+        let sccs = find_non_trivial_sccs_with(graph, options);
         let scc_count = sccs.len();
         let largest_scc_size = sccs.iter().map(|s| s.size).max().unwrap_or(0);
         let total_nodes_in_sccs: usize = sccs.iter().map(|s| s.size).sum();
@@ -63,6 +85,8 @@ impl Summary {
         let avg_depth = depth::avg_depth(graph);
         let edge_count = graph.edge_count();
         let total_complexity = node_count + edge_count + max_depth;
+        let reachability = Reachability::compute(graph);
+        let ccd = cumulative_component_dependency(graph);
 
         Self {
             mean_fanout: (mean_fanout * 100.0).round() / 100.0,
@@ -77,6 +101,11 @@ impl Summary {
             max_depth,
             avg_depth,
             total_complexity,
+            ccd: ccd.cumulative,
+            acd: ccd.average,
+            nccd: ccd.normalized,
+            propagation_cost: (reachability.propagation_cost() * 10000.0).round() / 10000.0,
+            max_blast_radius: reachability.max_blast_radius(),
         }
     }
 }
@@ -85,6 +114,7 @@ impl Summary {
 mod tests {
     use super::*;
     use crate::graph::ir::{EdgeKind, GraphEdge, GraphNode, NodeKind};
+    use crate::parse::common::ImportContext;
     use std::path::PathBuf;
 
     fn make_node(name: &str) -> GraphNode {
@@ -102,6 +132,8 @@ mod tests {
             kind: EdgeKind::default(),
             source_locations: vec![],
             weight: 1,
+            cfg: None,
+            context: ImportContext::Runtime,
         }
     }
 
@@ -114,6 +146,7 @@ mod tests {
         assert_eq!(summary.max_depth, 0);
         assert_eq!(summary.avg_depth, 0.0);
         assert_eq!(summary.total_complexity, 0);
+        assert_eq!(summary.propagation_cost, 0.0);
     }
 
     #[test]
@@ -132,5 +165,12 @@ mod tests {
         assert_eq!(summary.max_depth, 2);
         assert_eq!(summary.avg_depth, 2.0);
         assert_eq!(summary.total_complexity, 7);
+        // a needs 3, b 2, c 1; a balanced tree of 3 has CCD 5
+        assert_eq!(summary.ccd, 6);
+        assert_eq!(summary.acd, 2.0);
+        assert_eq!(summary.nccd, 1.2);
+        // a sees b and c, b sees c, plus each node itself: 6 of 9 cells
+        assert_eq!(summary.propagation_cost, 0.6667);
+        assert_eq!(summary.max_blast_radius, 2);
     }
 }
@@ -1,5 +1,5 @@
 use crate::errors::Result;
-use crate::graph::ir::DepGraph;
+use crate::graph::ir::{DepGraph, EdgeKind, NodeKind};
 use crate::walk::Language;
 use std::io::Write;
 
@@ -9,6 +9,14 @@ fn language_color(lang: Language) -> &'static str {
         Language::Python => "lightyellow",
         Language::Ruby => "lightcoral",
         Language::Rust => "lightsalmon",
+        Language::TypeScript => "lightsteelblue",
+        Language::Java => "wheat",
+        Language::Kotlin => "plum",
+        Language::CSharp => "palegreen",
+        Language::Cpp => "lightgray",
+        Language::Php => "thistle",
+        Language::Elixir => "mediumpurple1",
+        Language::Custom(_) => "white",
     }
 }
 
@@ -26,7 +34,13 @@ pub fn write_dot<W: Write>(writer: &mut W, graph: &DepGraph) -> Result<()> {
     for idx in graph.node_indices() {
         let node = &graph[idx];
         let label = &node.name;
-        if let Some(lang) = node.language {
+        if node.kind == NodeKind::External {
+            writeln!(
+                writer,
+                "    \"{}\" [label=\"{}\" shape=ellipse style=dashed];",
+                node.name, label
+            )?;
+        } else if let Some(lang) = node.language {
             let color = language_color(lang);
             writeln!(
                 writer,
@@ -46,14 +60,23 @@ pub fn write_dot<W: Write>(writer: &mut W, graph: &DepGraph) -> Result<()> {
         let target_name = &graph[target].name;
         let weight = &graph[edge];
         let loc_count = weight.source_locations.len();
+        let mut attrs = Vec::new();
         if loc_count > 1 {
+            attrs.push(format!("label=\"{} refs\"", loc_count));
+        }
+        if weight.kind == EdgeKind::Contains {
+            attrs.push("style=dashed".to_string());
+        }
+        if attrs.is_empty() {
+            writeln!(writer, "    \"{}\" -> \"{}\";", source_name, target_name)?;
+        } else {
             writeln!(
                 writer,
-                "    \"{}\" -> \"{}\" [label=\"{} refs\"];",
-                source_name, target_name, loc_count
+                "    \"{}\" -> \"{}\" [{}];",
+                source_name,
+                target_name,
+                attrs.join(", ")
             )?;
-        } else {
-            writeln!(writer, "    \"{}\" -> \"{}\";", source_name, target_name)?;
         }
     }
 
@@ -64,7 +87,8 @@ pub fn write_dot<W: Write>(writer: &mut W, graph: &DepGraph) -> Result<()> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::graph::ir::{EdgeKind, GraphEdge, GraphNode, NodeKind};
+    use crate::graph::ir::{GraphEdge, GraphNode, NodeKind};
+    use crate::parse::common::ImportContext;
     use std::path::PathBuf;
 
     #[test]
@@ -91,6 +115,8 @@ mod tests {
                 kind: EdgeKind::default(),
                 source_locations: vec![],
                 weight: 1,
+                cfg: None,
+                context: ImportContext::Runtime,
             },
         );
 
@@ -100,4 +126,37 @@ mod tests {
         assert!(dot.contains("digraph dependencies"));
         assert!(dot.contains("\"a\" -> \"b\""));
     }
+
+    #[test]
+    fn dot_output_dashes_containment_edges() {
+        let mut graph = DepGraph::new();
+        let nodes: Vec<_> = ["lib", "config"]
+            .iter()
+            .map(|name| {
+                graph.add_node(GraphNode {
+                    kind: NodeKind::Module,
+                    path: PathBuf::from(name),
+                    name: name.to_string(),
+                    span: None,
+                    language: Some(Language::Rust),
+                })
+            })
+            .collect();
+        graph.add_edge(
+            nodes[0],
+            nodes[1],
+            GraphEdge {
+                kind: EdgeKind::Contains,
+                source_locations: vec![],
+                weight: 1,
+                cfg: None,
+                context: ImportContext::Runtime,
+            },
+        );
+
+        let mut output = Vec::new();
+        write_dot(&mut output, &graph).unwrap();
+        let dot = String::from_utf8(output).unwrap();
+        assert!(dot.contains("\"lib\" -> \"config\" [style=dashed];"));
+    }
 }
@@ -1,13 +1,19 @@
 use crate::errors::Result;
+use crate::formats::HotspotSort;
+use crate::graph::classes::TypeCounts;
 use crate::graph::diff::DiffResult;
-use crate::graph::ir::DepGraph;
+use crate::graph::external::ExternalDependencies;
+use crate::graph::ir::{DepGraph, NodeKind};
 use crate::insights::Insight;
+use crate::metrics::centrality::Centrality;
+use crate::metrics::propagation::Reachability;
 use crate::metrics::scc::SccInfo;
 use crate::metrics::summary::Summary;
 use crate::parse::common::SourceLocation;
 use petgraph::visit::EdgeRef;
 use petgraph::Direction;
 use serde::Serialize;
+use std::collections::HashMap;
 use std::io::Write;
 use std::path::PathBuf;
 
@@ -21,6 +27,8 @@ pub struct AnalyzeOutput {
     pub sccs: Vec<SccInfo>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub insights: Option<Vec<Insight>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external: Option<ExternalDependencies>,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -55,20 +63,49 @@ pub struct Hotspot {
     pub node: String,
     pub fanout: usize,
     pub fanin: usize,
+    /// Nodes reachable through the node's dependencies
+    pub transitive_fanout: usize,
+    /// Nodes that reach the node: the blast radius of changing it
+    pub transitive_fanin: usize,
     pub entropy: f64,
     pub scc_id: Option<usize>,
     pub scc_adjusted_entropy: f64,
+    pub betweenness: f64,
+    pub pagerank: f64,
+    /// Removing the node disconnects part of the graph
+    pub articulation_point: bool,
+    /// Types the module declares, with `[rules.main_sequence]` enabled
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub abstract_types: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub concrete_types: Option<usize>,
+    /// Abstractness and distance from the main sequence, for modules that
+    /// declare at least one type
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub abstractness: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub distance: Option<f64>,
     pub fanout_edges: Vec<FanoutEdge>,
 }
 
 #[derive(Debug, Clone, Serialize)]
 pub struct FanoutEdge {
     pub to: String,
+    /// Removing the edge disconnects part of the graph
+    pub bridge: bool,
     pub source_locations: Vec<SourceLocation>,
 }
 
-pub fn build_hotspots(graph: &DepGraph, sccs: &[SccInfo], top_n: Option<usize>) -> Vec<Hotspot> {
-    let scc_map = crate::metrics::scc::node_scc_map(graph);
+pub fn build_hotspots(
+    graph: &DepGraph,
+    sccs: &[SccInfo],
+    module_types: &HashMap<PathBuf, TypeCounts>,
+    top_n: Option<usize>,
+    sort: HotspotSort,
+) -> Vec<Hotspot> {
+    let scc_map = crate::metrics::scc::node_scc_map(graph, sccs);
+    let centrality = Centrality::compute(graph);
+    let reachability = Reachability::compute(graph);
 
     let mut hotspots: Vec<Hotspot> = graph
         .node_indices()
@@ -96,24 +133,63 @@ pub fn build_hotspots(graph: &DepGraph, sccs: &[SccInfo], top_n: Option<usize>)
                     let target = &graph[e.target()];
                     FanoutEdge {
                         to: target.name.clone(),
+                        bridge: centrality.bridges.contains(&e.id()),
                         source_locations: e.weight().source_locations.clone(),
                     }
                 })
                 .collect();
 
+            let types = (node.kind == NodeKind::Module)
+                .then(|| module_types.get(&node.path))
+                .flatten();
+            let abstractness = types
+                .filter(|counts| counts.types > 0)
+                .map(|counts| counts.abstract_types as f64 / counts.types as f64);
+            let instability = if fanin + fanout == 0 {
+                0.0
+            } else {
+                fanout as f64 / (fanin + fanout) as f64
+            };
+            let distance =
+                abstractness.map(|abstractness| (abstractness + instability - 1.0).abs());
+
             Hotspot {
                 node: node.name.clone(),
                 fanout,
                 fanin,
+                transitive_fanout: reachability.transitive_fanout[idx.index()],
+                transitive_fanin: reachability.transitive_fanin[idx.index()],
                 entropy: (entropy * 100.0).round() / 100.0,
                 scc_id,
                 scc_adjusted_entropy: (scc_adjusted * 100.0).round() / 100.0,
+                betweenness: (centrality.betweenness[idx.index()] * 10000.0).round() / 10000.0,
+                pagerank: (centrality.pagerank[idx.index()] * 10000.0).round() / 10000.0,
+                articulation_point: centrality.articulation_points[idx.index()],
+                abstract_types: types.map(|counts| counts.abstract_types),
+                concrete_types: types.map(|counts| counts.types - counts.abstract_types),
+                abstractness: abstractness.map(|value| (value * 1000.0).round() / 1000.0),
+                distance: distance.map(|value| (value * 1000.0).round() / 1000.0),
                 fanout_edges,
             }
         })
         .collect();
 
-    hotspots.sort_by(|a, b| b.fanout.cmp(&a.fanout).then(b.fanin.cmp(&a.fanin)));
+    let by_fanout = |a: &Hotspot, b: &Hotspot| b.fanout.cmp(&a.fanout).then(b.fanin.cmp(&a.fanin));
+    hotspots.sort_by(|a, b| match sort {
+        HotspotSort::Fanout => by_fanout(a, b),
+        HotspotSort::Fanin => b.fanin.cmp(&a.fanin).then(b.fanout.cmp(&a.fanout)),
+        HotspotSort::Entropy => b.entropy.total_cmp(&a.entropy).then(by_fanout(a, b)),
+        HotspotSort::Betweenness => b
+            .betweenness
+            .total_cmp(&a.betweenness)
+            .then(by_fanout(a, b)),
+        HotspotSort::Pagerank => b.pagerank.total_cmp(&a.pagerank).then(by_fanout(a, b)),
+        HotspotSort::Articulation => b
+            .articulation_point
+            .cmp(&a.articulation_point)
+            .then(b.betweenness.total_cmp(&a.betweenness))
+            .then(by_fanout(a, b)),
+    });
 
     if let Some(n) = top_n {
         hotspots.truncate(n);
@@ -123,23 +199,28 @@ pub fn build_hotspots(graph: &DepGraph, sccs: &[SccInfo], top_n: Option<usize>)
 }
 
 /// Write analyze output as JSON.
+#[allow(clippy::too_many_arguments)]
 pub fn write_analyze_json<W: Write>(
     writer: &mut W,
     graph: &DepGraph,
     summary: &Summary,
     sccs: &[SccInfo],
+    module_types: &HashMap<PathBuf, TypeCounts>,
     metadata: Metadata,
     top_n: Option<usize>,
+    sort: HotspotSort,
     insights: Option<Vec<Insight>>,
+    external: Option<ExternalDependencies>,
 ) -> Result<()> {
     let output = AnalyzeOutput {
         kind: "analyze.report",
         schema_version: 2,
         metadata,
         summary: summary.clone(),
-        hotspots: build_hotspots(graph, sccs, top_n),
+        hotspots: build_hotspots(graph, sccs, module_types, top_n, sort),
         sccs: sccs.to_vec(),
         insights,
+        external,
     };
 
     serde_json::to_writer_pretty(writer, &output)?;
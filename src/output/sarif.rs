@@ -93,7 +93,7 @@ pub fn write_sarif<W: Write>(
 ) -> Result<()> {
     let mut results = Vec::new();
 
-    let scc_map = crate::metrics::scc::node_scc_map(graph);
+    let scc_map = crate::metrics::scc::node_scc_map(graph, sccs);
 
     // Report high fan-out nodes
     for idx in graph.node_indices() {
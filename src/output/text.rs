@@ -1,36 +1,40 @@
 use crate::errors::Result;
+use crate::formats::HotspotSort;
+use crate::graph::classes::TypeCounts;
 use crate::graph::diff::{
-    ComparisonVerdict, DiffResult, EdgeChange, FanoutChange, SccChange, SummaryDelta,
+    BlastRadiusChange, ComparisonVerdict, DiffResult, EdgeChange, FanoutChange, SccChange,
+    SummaryDelta,
 };
+use crate::graph::external::ExternalDependencies;
 use crate::graph::ir::DepGraph;
 use crate::insights::{Insight, InsightSeverity};
 use crate::metrics::scc::SccInfo;
 use crate::metrics::summary::Summary;
-use crate::output::json::{LanguageStats, Metadata};
+use crate::output::json::{build_hotspots, LanguageStats, Metadata};
+use std::collections::HashMap;
 use std::io::Write;
-
-struct HotspotRow {
-    module: String,
-    fanout: usize,
-    fanin: usize,
-    scc_label: String,
-}
+use std::path::PathBuf;
 
 /// Write analyze output as human-readable text.
+#[allow(clippy::too_many_arguments)]
 pub fn write_analyze_text<W: Write>(
     writer: &mut W,
     graph: &DepGraph,
     summary: &Summary,
     sccs: &[SccInfo],
+    module_types: &HashMap<PathBuf, TypeCounts>,
     metadata: &Metadata,
     top_n: Option<usize>,
+    sort: HotspotSort,
     insights: Option<&[Insight]>,
+    external: Option<&ExternalDependencies>,
 ) -> Result<()> {
     write_analyze_header(writer)?;
     write_language_metadata(writer, metadata)?;
     write_analyze_summary(writer, summary)?;
-    write_hotspots(writer, collect_hotspots(graph), top_n)?;
+    write_hotspots(writer, graph, sccs, module_types, top_n, sort)?;
     write_sccs(writer, sccs)?;
+    write_external(writer, external, top_n)?;
     write_insights(writer, insights)?;
     write_footer(writer, metadata.elapsed_ms, metadata.modules_per_second)?;
     Ok(())
@@ -108,53 +112,75 @@ fn write_analyze_summary<W: Write>(writer: &mut W, summary: &Summary) -> Result<
         "Complexity: {} (nodes + edges + max_depth)",
         summary.total_complexity
     )?;
+    writeln!(
+        writer,
+        "CCD:      {} (average={:.2}  normalized={:.2})",
+        summary.ccd, summary.acd, summary.nccd
+    )?;
+    writeln!(
+        writer,
+        "Propagation: cost={:.1}%  max blast radius={}",
+        summary.propagation_cost * 100.0,
+        summary.max_blast_radius
+    )?;
     writeln!(writer)?;
     Ok(())
 }
 
-fn collect_hotspots(graph: &DepGraph) -> Vec<HotspotRow> {
-    let scc_map = crate::metrics::scc::node_scc_map(graph);
-    let mut rows: Vec<_> = graph
-        .node_indices()
-        .map(|idx| {
-            let fanout = crate::metrics::fanout::fan_out(graph, idx);
-            let fanin = crate::metrics::fanout::fan_in(graph, idx);
-            let scc_label = scc_map
-                .get(&idx)
-                .map(|id| format!("#{id}"))
-                .unwrap_or_else(|| "-".to_string());
-            HotspotRow {
-                module: graph[idx].name.clone(),
-                fanout,
-                fanin,
-                scc_label,
-            }
-        })
-        .collect();
-    rows.sort_by(|a, b| b.fanout.cmp(&a.fanout));
-    rows
-}
-
 fn write_hotspots<W: Write>(
     writer: &mut W,
-    hotspots: Vec<HotspotRow>,
+    graph: &DepGraph,
+    sccs: &[SccInfo],
+    module_types: &HashMap<PathBuf, TypeCounts>,
     top_n: Option<usize>,
+    sort: HotspotSort,
 ) -> Result<()> {
-    let limit = top_n.unwrap_or(20).min(hotspots.len());
-    if limit > 0 {
-        writeln!(writer, "Top {} Hotspots", limit)?;
-        writeln!(writer, "{:-<60}", "")?;
-        writeln!(
+    let hotspots = build_hotspots(graph, sccs, module_types, Some(top_n.unwrap_or(20)), sort);
+    if !hotspots.is_empty() {
+        // Abstractness and distance only when the types were extracted.
+        let main_sequence = !module_types.is_empty();
+        writeln!(writer, "Top {} Hotspots (by {sort})", hotspots.len())?;
+        writeln!(writer, "{:-<80}", "")?;
+        write!(
             writer,
-            "{:<40} {:>8} {:>8} {:>5}",
-            "Module", "Fan-out", "Fan-in", "SCC"
+            "{:<40} {:>8} {:>8} {:>5} {:>8} {:>8} {:>4}",
+            "Module", "Fan-out", "Fan-in", "SCC", "Between", "PageRank", "Cut"
         )?;
-        for hotspot in hotspots.iter().take(limit) {
-            writeln!(
+        if main_sequence {
+            write!(writer, " {:>5} {:>5}", "A", "D")?;
+        }
+        writeln!(writer)?;
+        let optional =
+            |value: Option<f64>| value.map_or("-".to_string(), |value| format!("{value:.2}"));
+        for hotspot in &hotspots {
+            let scc_label = hotspot
+                .scc_id
+                .map(|id| format!("#{id}"))
+                .unwrap_or_else(|| "-".to_string());
+            write!(
                 writer,
-                "{:<40} {:>8} {:>8} {:>5}",
-                hotspot.module, hotspot.fanout, hotspot.fanin, hotspot.scc_label
+                "{:<40} {:>8} {:>8} {:>5} {:>8.4} {:>8.4} {:>4}",
+                hotspot.node,
+                hotspot.fanout,
+                hotspot.fanin,
+                scc_label,
+                hotspot.betweenness,
+                hotspot.pagerank,
+                if hotspot.articulation_point {
+                    "yes"
+                } else {
+                    "-"
+                }
             )?;
+            if main_sequence {
+                write!(
+                    writer,
+                    " {:>5} {:>5}",
+                    optional(hotspot.abstractness),
+                    optional(hotspot.distance)
+                )?;
+            }
+            writeln!(writer)?;
         }
         writeln!(writer)?;
     }
@@ -180,6 +206,35 @@ fn write_sccs<W: Write>(writer: &mut W, sccs: &[SccInfo]) -> Result<()> {
     Ok(())
 }
 
+fn write_external<W: Write>(
+    writer: &mut W,
+    external: Option<&ExternalDependencies>,
+    top_n: Option<usize>,
+) -> Result<()> {
+    let Some(external) = external else {
+        return Ok(());
+    };
+    let limit = top_n.unwrap_or(20);
+    if limit > 0 && !external.packages.is_empty() {
+        writeln!(writer, "External Packages")?;
+        writeln!(writer, "{:-<60}", "")?;
+        writeln!(writer, "{:<40} {:>8}", "Package", "Fan-in")?;
+        for package in external.packages.iter().take(limit) {
+            writeln!(writer, "{:<40} {:>8}", package.package, package.fanin)?;
+        }
+        writeln!(writer)?;
+
+        writeln!(writer, "Third-Party Fan-out")?;
+        writeln!(writer, "{:-<60}", "")?;
+        writeln!(writer, "{:<40} {:>8}", "Module", "Fan-out")?;
+        for module in external.modules.iter().take(limit) {
+            writeln!(writer, "{:<40} {:>8}", module.module, module.fanout)?;
+        }
+        writeln!(writer)?;
+    }
+    Ok(())
+}
+
 fn write_insights<W: Write>(writer: &mut W, insights: Option<&[Insight]>) -> Result<()> {
     if let Some(insights) = insights {
         if !insights.is_empty() {
@@ -218,6 +273,7 @@ pub fn write_diff_text<W: Write>(writer: &mut W, result: &DiffResult) -> Result<
     write_edge_changes(writer, "New Edges", &result.new_edges)?;
     write_edge_changes(writer, "Removed Edges", &result.removed_edges)?;
     write_fanout_changes(writer, &result.fanout_changes)?;
+    write_blast_radius_increases(writer, &result.blast_radius_increases)?;
     write_scc_changes(writer, "New SCCs", &result.scc_changes.new_sccs)?;
     write_scc_changes(writer, "Enlarged SCCs", &result.scc_changes.enlarged_sccs)?;
     write_footer(writer, result.elapsed_ms, result.modules_per_second)?;
@@ -281,6 +337,12 @@ fn write_diff_summary<W: Write>(writer: &mut W, delta: &SummaryDelta) -> Result<
         "SCCs:        {:+} (largest: {:+})",
         delta.scc_count_delta, delta.largest_scc_size_delta
     )?;
+    writeln!(
+        writer,
+        "Propagation: {:+.1} points (max blast radius: {:+})",
+        delta.propagation_cost_delta * 100.0,
+        delta.max_blast_radius_delta
+    )?;
     writeln!(writer)?;
     Ok(())
 }
@@ -313,6 +375,25 @@ fn write_fanout_changes<W: Write>(writer: &mut W, changes: &[FanoutChange]) -> R
     Ok(())
 }
 
+fn write_blast_radius_increases<W: Write>(
+    writer: &mut W,
+    changes: &[BlastRadiusChange],
+) -> Result<()> {
+    if !changes.is_empty() {
+        writeln!(writer, "Blast Radius Increases ({})", changes.len())?;
+        writeln!(writer, "{:-<60}", "")?;
+        for change in changes {
+            writeln!(
+                writer,
+                "  {} : {} -> {} ({:+})",
+                change.node, change.blast_radius_before, change.blast_radius_after, change.delta
+            )?;
+        }
+        writeln!(writer)?;
+    }
+    Ok(())
+}
+
 fn write_scc_changes<W: Write>(writer: &mut W, label: &str, changes: &[SccChange]) -> Result<()> {
     if !changes.is_empty() {
         writeln!(writer, "{} ({})", label, changes.len())?;
@@ -329,10 +410,11 @@ fn write_scc_changes<W: Write>(writer: &mut W, label: &str, changes: &[SccChange
 mod tests {
     use super::*;
     use crate::graph::diff::{
-        Comparison, ComparisonVerdict, EdgeChange, FanoutChange, SccChange, SummaryDelta, Verdict,
+        BlastRadiusChange, Comparison, ComparisonVerdict, EdgeChange, FanoutChange, SccChange,
+        SummaryDelta, Verdict,
     };
     use crate::graph::ir::{EdgeKind, GraphEdge, GraphNode, NodeKind};
-    use crate::parse::common::SourceLocation;
+    use crate::parse::common::{ImportContext, SourceLocation};
     use std::path::PathBuf;
 
     fn make_node(name: &str) -> GraphNode {
@@ -350,6 +432,8 @@ mod tests {
             kind: EdgeKind::default(),
             source_locations: vec![],
             weight: 1,
+            cfg: None,
+            context: ImportContext::Runtime,
         }
     }
 
@@ -395,6 +479,9 @@ mod tests {
                 scc_id: None,
                 scc_size: None,
                 depth: None,
+                instability: None,
+                abstractness: None,
+                distance: None,
             },
         }
     }
@@ -426,6 +513,8 @@ mod tests {
                 mean_entropy_delta: 0.25,
                 max_depth_delta: 0,
                 total_complexity_delta: 0,
+                propagation_cost_delta: 0.0,
+                max_blast_radius_delta: 0,
             },
             new_edges: vec![EdgeChange {
                 from: "a".to_string(),
@@ -434,6 +523,7 @@ mod tests {
                     file: PathBuf::from("src/lib.rs"),
                     line: 1,
                     column: Some(1),
+                    cfg: None,
                 }],
             }],
             removed_edges: vec![EdgeChange {
@@ -450,6 +540,12 @@ mod tests {
                 entropy_after: 1.0,
                 new_targets: vec![],
             }],
+            blast_radius_increases: vec![BlastRadiusChange {
+                node: "b".to_string(),
+                blast_radius_before: 1,
+                blast_radius_after: 3,
+                delta: 2,
+            }],
             scc_changes: crate::graph::diff::SccChanges {
                 new_sccs: vec![SccChange {
                     members: vec!["a".to_string(), "b".to_string()],
@@ -475,8 +571,11 @@ mod tests {
             &graph,
             &summary,
             &[],
+            &HashMap::new(),
             &make_metadata(),
             Some(0),
+            HotspotSort::Fanout,
+            None,
             None,
         )
         .unwrap();
@@ -522,9 +621,12 @@ mod tests {
             &graph,
             &summary,
             &sccs,
+            &HashMap::new(),
             &metadata,
             Some(2),
+            HotspotSort::Fanout,
             Some(&insights),
+            None,
         )
         .unwrap();
         let output = String::from_utf8(buf).unwrap();
@@ -549,6 +651,7 @@ mod tests {
         result.new_edges.clear();
         result.removed_edges.clear();
         result.fanout_changes.clear();
+        result.blast_radius_increases.clear();
         result.scc_changes.new_sccs.clear();
         result.scc_changes.enlarged_sccs.clear();
         let mut buf = Vec::new();
@@ -560,6 +663,7 @@ mod tests {
         assert!(!output.contains("Violations:"));
         assert!(!output.contains("New Edges"));
         assert!(!output.contains("Fan-out Changes"));
+        assert!(!output.contains("Blast Radius Increases"));
     }
 
     #[test]
@@ -574,6 +678,8 @@ mod tests {
         assert!(output.contains("New Edges (1)"));
         assert!(output.contains("Removed Edges (1)"));
         assert!(output.contains("Fan-out Changes (1)"));
+        assert!(output.contains("Blast Radius Increases (1)"));
+        assert!(output.contains("  b : 1 -> 3 (+2)"));
         assert!(output.contains("New SCCs (1)"));
         assert!(output.contains("Enlarged SCCs (1)"));
         assert!(output.contains("Completed in 0.85s (12 modules/sec)"));
@@ -0,0 +1,319 @@
+use std::fmt;
+
+/// A Rust `#[cfg(...)]` predicate.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CfgPredicate {
+    /// `test`, `unix`, `debug_assertions`
+    Name(String),
+    /// `feature = "serde"`, `target_os = "linux"`
+    KeyValue(String, String),
+    All(Vec<CfgPredicate>),
+    Any(Vec<CfgPredicate>),
+    Not(Box<CfgPredicate>),
+}
+
+impl CfgPredicate {
+    /// Parse the inside of `cfg(...)`, e.g. `all(test, feature = "x")`.
+    pub fn parse(input: &str) -> Option<Self> {
+        let tokens = tokenize(input)?;
+        let mut pos = 0;
+        let predicate = parse_predicate(&tokens, &mut pos)?;
+        (pos == tokens.len()).then_some(predicate)
+    }
+
+    /// Conjunction of two predicates, flattening nested `all(...)`.
+    pub fn and(self, other: CfgPredicate) -> CfgPredicate {
+        let mut terms = Vec::new();
+        for predicate in [self, other] {
+            match predicate {
+                CfgPredicate::All(inner) => terms.extend(inner),
+                other => terms.push(other),
+            }
+        }
+        combine(terms, CfgPredicate::All)
+    }
+
+    /// Disjunction of two predicates, flattening nested `any(...)`.
+    pub fn or(self, other: CfgPredicate) -> CfgPredicate {
+        let mut terms = Vec::new();
+        for predicate in [self, other] {
+            match predicate {
+                CfgPredicate::Any(inner) => terms.extend(inner),
+                other => terms.push(other),
+            }
+        }
+        combine(terms, CfgPredicate::Any)
+    }
+}
+
+fn combine(terms: Vec<CfgPredicate>, list: fn(Vec<CfgPredicate>) -> CfgPredicate) -> CfgPredicate {
+    let mut unique: Vec<CfgPredicate> = Vec::new();
+    for term in terms {
+        if !unique.contains(&term) {
+            unique.push(term);
+        }
+    }
+    if unique.len() == 1 {
+        unique.remove(0)
+    } else {
+        list(unique)
+    }
+}
+
+impl fmt::Display for CfgPredicate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let list = |f: &mut fmt::Formatter<'_>, name: &str, items: &[CfgPredicate]| {
+            let items: Vec<String> = items.iter().map(ToString::to_string).collect();
+            write!(f, "{name}({})", items.join(", "))
+        };
+        match self {
+            CfgPredicate::Name(name) => write!(f, "{name}"),
+            CfgPredicate::KeyValue(key, value) => write!(f, "{key} = \"{value}\""),
+            CfgPredicate::All(items) => list(f, "all", items),
+            CfgPredicate::Any(items) => list(f, "any", items),
+            CfgPredicate::Not(inner) => write!(f, "not({inner})"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Ident(String),
+    Str(String),
+    Eq,
+    Open,
+    Close,
+    Comma,
+}
+
+fn tokenize(input: &str) -> Option<Vec<Token>> {
+    let mut tokens = Vec::new();
+    let mut chars = input.chars().peekable();
+    while let Some(&c) = chars.peek() {
+        match c {
+            c if c.is_whitespace() => {
+                chars.next();
+            }
+            '=' => {
+                chars.next();
+                tokens.push(Token::Eq);
+            }
+            '(' => {
+                chars.next();
+                tokens.push(Token::Open);
+            }
+            ')' => {
+                chars.next();
+                tokens.push(Token::Close);
+            }
+            ',' => {
+                chars.next();
+                tokens.push(Token::Comma);
+            }
+            '"' => {
+                chars.next();
+                let mut value = String::new();
+                loop {
+                    match chars.next()? {
+                        '"' => break,
+                        '\\' => value.push(chars.next()?),
+                        c => value.push(c),
+                    }
+                }
+                tokens.push(Token::Str(value));
+            }
+            c if c.is_alphanumeric() || c == '_' => {
+                let mut ident = String::new();
+                while let Some(&c) = chars.peek() {
+                    if !(c.is_alphanumeric() || c == '_') {
+                        break;
+                    }
+                    ident.push(c);
+                    chars.next();
+                }
+                tokens.push(Token::Ident(ident));
+            }
+            _ => return None,
+        }
+    }
+    Some(tokens)
+}
+
+fn parse_predicate(tokens: &[Token], pos: &mut usize) -> Option<CfgPredicate> {
+    let Token::Ident(name) = tokens.get(*pos)? else {
+        return None;
+    };
+    *pos += 1;
+    match tokens.get(*pos) {
+        Some(Token::Eq) => {
+            let Token::Str(value) = tokens.get(*pos + 1)? else {
+                return None;
+            };
+            *pos += 2;
+            Some(CfgPredicate::KeyValue(name.clone(), value.clone()))
+        }
+        Some(Token::Open) => {
+            *pos += 1;
+            let mut items = Vec::new();
+            while tokens.get(*pos) != Some(&Token::Close) {
+                items.push(parse_predicate(tokens, pos)?);
+                match tokens.get(*pos)? {
+                    Token::Comma => *pos += 1,
+                    Token::Close => {}
+                    _ => return None,
+                }
+            }
+            *pos += 1;
+            match name.as_str() {
+                "all" => Some(CfgPredicate::All(items)),
+                "any" => Some(CfgPredicate::Any(items)),
+                "not" if items.len() == 1 => Some(CfgPredicate::Not(Box::new(items.remove(0)))),
+                _ => None,
+            }
+        }
+        _ => Some(CfgPredicate::Name(name.clone())),
+    }
+}
+
+/// A build configuration chosen with `--cfg` / `--exclude-cfg`.
+///
+/// Options not mentioned are unknown, and edges under an unknown predicate
+/// are kept. Enabling any value of a key (`--cfg feature=serde`) disables
+/// the key's other values, so a feature set describes one build.
+#[derive(Debug, Clone, Default)]
+pub struct CfgSelection {
+    enabled: Vec<CfgOption>,
+    disabled: Vec<CfgOption>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct CfgOption {
+    name: String,
+    value: Option<String>,
+}
+
+impl CfgOption {
+    /// `test`, `feature=serde` or `feature="serde"`.
+    fn parse(spec: &str) -> Self {
+        match spec.split_once('=') {
+            Some((name, value)) => Self {
+                name: name.trim().to_string(),
+                value: Some(value.trim().trim_matches('"').to_string()),
+            },
+            None => Self {
+                name: spec.trim().to_string(),
+                value: None,
+            },
+        }
+    }
+}
+
+impl CfgSelection {
+    pub fn new(enabled: &[String], disabled: &[String]) -> Self {
+        Self {
+            enabled: enabled.iter().map(|spec| CfgOption::parse(spec)).collect(),
+            disabled: disabled.iter().map(|spec| CfgOption::parse(spec)).collect(),
+        }
+    }
+
+    /// Whether code under `predicate` may be compiled in this configuration.
+    pub fn allows(&self, predicate: &CfgPredicate) -> bool {
+        self.evaluate(predicate) != Some(false)
+    }
+
+    /// Three-valued evaluation: None when the selection doesn't decide it.
+    fn evaluate(&self, predicate: &CfgPredicate) -> Option<bool> {
+        match predicate {
+            CfgPredicate::Name(name) => self.lookup(name, None),
+            CfgPredicate::KeyValue(key, value) => self.lookup(key, Some(value)),
+            CfgPredicate::All(items) => {
+                let results: Vec<_> = items.iter().map(|item| self.evaluate(item)).collect();
+                if results.contains(&Some(false)) {
+                    Some(false)
+                } else if results.iter().all(|result| *result == Some(true)) {
+                    Some(true)
+                } else {
+                    None
+                }
+            }
+            CfgPredicate::Any(items) => {
+                let results: Vec<_> = items.iter().map(|item| self.evaluate(item)).collect();
+                if results.contains(&Some(true)) {
+                    Some(true)
+                } else if results.iter().all(|result| *result == Some(false)) {
+                    Some(false)
+                } else {
+                    None
+                }
+            }
+            CfgPredicate::Not(inner) => self.evaluate(inner).map(|result| !result),
+        }
+    }
+
+    fn lookup(&self, name: &str, value: Option<&String>) -> Option<bool> {
+        let matches = |option: &CfgOption| option.name == name && option.value.as_ref() == value;
+        // Enabling one value of a key (`feature=serde`) closes that key.
+        let key_closed = value.is_some()
+            && self
+                .enabled
+                .iter()
+                .any(|option| option.name == name && option.value.is_some());
+        if self.enabled.iter().any(matches) {
+            Some(true)
+        } else if key_closed || self.disabled.iter().any(matches) {
+            Some(false)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_and_displays_nested_predicates() {
+        let predicate =
+            CfgPredicate::parse(r#"all(unix, not(test), any(feature = "a", feature="b"))"#)
+                .unwrap();
+        assert_eq!(
+            predicate.to_string(),
+            r#"all(unix, not(test), any(feature = "a", feature = "b"))"#
+        );
+        assert_eq!(
+            CfgPredicate::parse("test"),
+            Some(CfgPredicate::Name("test".into()))
+        );
+        assert_eq!(CfgPredicate::parse("all(test"), None);
+        assert_eq!(CfgPredicate::parse("not(a, b)"), None);
+    }
+
+    #[test]
+    fn and_or_flatten_nested_lists() {
+        let test = CfgPredicate::Name("test".into());
+        let unix = CfgPredicate::Name("unix".into());
+        let combined = test.clone().and(unix.clone()).and(test.clone());
+        assert_eq!(combined.to_string(), "all(test, unix)");
+        assert_eq!(test.clone().and(test.clone()), test);
+        let either = test.clone().or(unix.clone()).or(unix.clone());
+        assert_eq!(either.to_string(), "any(test, unix)");
+    }
+
+    #[test]
+    fn selection_keeps_undecided_predicates() {
+        let selection = CfgSelection::new(&["feature=serde".into()], &["test".into()]);
+        let parse = |text: &str| CfgPredicate::parse(text).unwrap();
+
+        assert!(!selection.allows(&parse("test")));
+        assert!(selection.allows(&parse("not(test)")));
+        assert!(selection.allows(&parse(r#"feature = "serde""#)));
+        // Enabling one feature closes the feature set.
+        assert!(!selection.allows(&parse(r#"feature = "tokio""#)));
+        assert!(selection.allows(&parse(r#"any(feature = "tokio", feature = "serde")"#)));
+        // `unix` was never mentioned, so it stays in.
+        assert!(selection.allows(&parse("unix")));
+        assert!(!selection.allows(&parse("all(unix, test)")));
+        assert!(CfgSelection::default().allows(&parse("test")));
+    }
+}
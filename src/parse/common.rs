@@ -1,5 +1,6 @@
+use crate::parse::cfg::CfgPredicate;
 use serde::Serialize;
-use std::path::PathBuf;
+use std::path::{Component, Path, PathBuf};
 
 /// Raw import extracted from a single source file.
 #[derive(Debug, Clone)]
@@ -16,6 +17,10 @@ pub struct RawImport {
     pub kind: ImportKind,
     /// Parser confidence
     pub confidence: ImportConfidence,
+    /// `#[cfg(...)]` predicate the import is compiled under (Rust)
+    pub cfg: Option<CfgPredicate>,
+    /// When the import executes
+    pub context: ImportContext,
 }
 
 #[derive(Debug, Clone)]
@@ -30,12 +35,39 @@ pub enum ImportKind {
         module: Option<String>,
         names: Vec<String>,
     },
-    /// `require_relative "./foo"` (Ruby)
+    /// `require_relative "./foo"` (Ruby), `require __DIR__ . '/foo.php'` (PHP)
     RequireRelative,
     /// `autoload :Foo, "path"` (Ruby)
     Autoload { constant: String },
     /// Ruby constant reference resolved via Zeitwerk convention (CamelCase → snake_case)
     ZeitwerkConstant,
+    /// `#include <foo.h>` (C/C++); `#include "foo.h"` is `Direct`
+    AngleInclude,
+    /// Fully-qualified path used inline without a `use` (Rust `crate::a::f()`)
+    QualifiedPath,
+    /// `mod foo;` (Rust), with the `#[path = "..."]` override if present
+    ModDeclaration { path: Option<String> },
+}
+
+/// When an import executes, ordered from weakest to strongest dependency.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ImportContext {
+    /// Only seen by type checkers (`if TYPE_CHECKING:` in Python)
+    TypeChecking,
+    /// Deferred until the enclosing function runs
+    FunctionLocal,
+    /// Wrapped in `try`/`except`, so the program tolerates it failing
+    Guarded,
+    /// Executed unconditionally at load time
+    #[default]
+    Runtime,
+}
+
+impl ImportContext {
+    pub fn is_runtime(&self) -> bool {
+        *self == ImportContext::Runtime
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -46,6 +78,8 @@ pub enum ImportConfidence {
     External,
     /// Contains dynamic component — unresolvable
     Dynamic,
+    /// Several project targets match; left unresolved rather than guessed
+    Ambiguous,
     /// String interpolation or metaprogramming
     Unresolvable,
 }
@@ -57,4 +91,26 @@ pub struct SourceLocation {
     pub line: usize,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub column: Option<usize>,
+    /// `cfg` predicate gating the import, e.g. `test` or `feature = "serde"`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cfg: Option<String>,
+}
+
+/// Lexically normalize a project-relative path, folding `.` and `..`
+/// components. Returns None if the path escapes the project root.
+pub fn normalize_relative(path: &Path) -> Option<PathBuf> {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if !normalized.pop() {
+                    return None;
+                }
+            }
+            Component::Normal(part) => normalized.push(part),
+            Component::RootDir | Component::Prefix(_) => return None,
+        }
+    }
+    Some(normalized)
 }
@@ -0,0 +1,475 @@
+use crate::parse::common::{
+    normalize_relative, ImportConfidence, ImportContext, ImportKind, RawImport,
+};
+use crate::parse::ParseFrontend;
+use serde::Deserialize;
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+/// Locations, relative to the project root, searched for a compilation database.
+pub const COMPILE_COMMANDS_LOCATIONS: &[&str] =
+    &["compile_commands.json", "build/compile_commands.json"];
+
+/// C standard library and common POSIX headers. C++ standard headers have no
+/// extension and are recognised by that instead.
+const SYSTEM_HEADERS: &[&str] = &[
+    "assert.h",
+    "complex.h",
+    "ctype.h",
+    "errno.h",
+    "fenv.h",
+    "float.h",
+    "inttypes.h",
+    "iso646.h",
+    "limits.h",
+    "locale.h",
+    "math.h",
+    "setjmp.h",
+    "signal.h",
+    "stdalign.h",
+    "stdarg.h",
+    "stdatomic.h",
+    "stdbool.h",
+    "stddef.h",
+    "stdint.h",
+    "stdio.h",
+    "stdlib.h",
+    "stdnoreturn.h",
+    "string.h",
+    "tgmath.h",
+    "threads.h",
+    "time.h",
+    "uchar.h",
+    "wchar.h",
+    "wctype.h",
+    "dirent.h",
+    "dlfcn.h",
+    "fcntl.h",
+    "netdb.h",
+    "poll.h",
+    "pthread.h",
+    "sched.h",
+    "semaphore.h",
+    "strings.h",
+    "syslog.h",
+    "termios.h",
+    "unistd.h",
+    "windows.h",
+];
+
+/// Header directory prefixes that belong to the platform.
+const SYSTEM_PREFIXES: &[&str] = &["sys/", "bits/", "arpa/", "netinet/", "linux/", "mach/"];
+
+/// Include search paths for C/C++ sources.
+///
+/// Directories come from `-I`/`-iquote` flags in `compile_commands.json`
+/// (per translation unit) and from `[cpp] include_paths` in `.untangle.toml`.
+/// All paths are relative to the project root; directories outside the
+/// project are dropped since nothing in them can become a graph node.
+#[derive(Clone, Debug, Default)]
+pub struct CppIncludePaths {
+    /// Include directories for each translation unit in the compilation database
+    pub per_file: HashMap<PathBuf, Vec<PathBuf>>,
+    /// Union of every include directory in the compilation database, used for
+    /// headers and files that have no compile command of their own
+    pub database: Vec<PathBuf>,
+    /// Include directories configured in `.untangle.toml`
+    pub configured: Vec<PathBuf>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CompileCommand {
+    directory: PathBuf,
+    file: PathBuf,
+    #[serde(default)]
+    arguments: Vec<String>,
+    #[serde(default)]
+    command: Option<String>,
+}
+
+impl CppIncludePaths {
+    /// Build include paths from the contents of a `compile_commands.json`
+    /// (if any) and configured include paths (relative to the project root).
+    ///
+    /// `base` is the absolute directory that the database's absolute paths
+    /// should be made relative to — normally the project root.
+    pub fn load(compile_commands: Option<&str>, base: &Path, configured: &[String]) -> Self {
+        let mut paths = Self {
+            configured: configured
+                .iter()
+                .filter_map(|dir| normalize_relative(Path::new(dir)))
+                .collect(),
+            ..Self::default()
+        };
+
+        let commands: Vec<CompileCommand> = compile_commands
+            .and_then(|content| serde_json::from_str(content).ok())
+            .unwrap_or_default();
+        for command in commands {
+            let Some(file) = relative_to(base, &command.directory.join(&command.file)) else {
+                continue;
+            };
+            let arguments = if command.arguments.is_empty() {
+                command
+                    .command
+                    .as_deref()
+                    .map(split_command)
+                    .unwrap_or_default()
+            } else {
+                command.arguments
+            };
+            let dirs: Vec<PathBuf> = include_flags(&arguments)
+                .into_iter()
+                .filter_map(|dir| relative_to(base, &command.directory.join(dir)))
+                .collect();
+            for dir in &dirs {
+                if !paths.database.contains(dir) {
+                    paths.database.push(dir.clone());
+                }
+            }
+            paths.per_file.insert(file, dirs);
+        }
+
+        paths
+    }
+
+    /// Read the first compilation database found in `project_root` and
+    /// combine it with the configured include paths.
+    pub fn discover(project_root: &Path, configured: &[String]) -> Self {
+        let compile_commands = COMPILE_COMMANDS_LOCATIONS
+            .iter()
+            .find_map(|location| std::fs::read_to_string(project_root.join(location)).ok());
+        Self::load(compile_commands.as_deref(), project_root, configured)
+    }
+
+    /// Include directories searched for `file`, in order.
+    fn search_dirs(&self, file: &Path) -> impl Iterator<Item = &PathBuf> {
+        self.per_file
+            .get(file)
+            .unwrap_or(&self.database)
+            .iter()
+            .chain(self.configured.iter())
+    }
+}
+
+/// Make a (possibly absolute, possibly `..`-laden) path relative to `base`.
+fn relative_to(base: &Path, path: &Path) -> Option<PathBuf> {
+    let relative = if path.is_absolute() {
+        path.strip_prefix(base).ok()?
+    } else {
+        path
+    };
+    normalize_relative(relative)
+}
+
+/// Split a shell command line into arguments, honouring simple quoting.
+fn split_command(command: &str) -> Vec<String> {
+    let mut args = Vec::new();
+    let mut current = String::new();
+    let mut quote: Option<char> = None;
+    for ch in command.chars() {
+        match (quote, ch) {
+            (Some(q), c) if c == q => quote = None,
+            (None, '"' | '\'') => quote = Some(ch),
+            (None, c) if c.is_whitespace() => {
+                if !current.is_empty() {
+                    args.push(std::mem::take(&mut current));
+                }
+            }
+            (_, c) => current.push(c),
+        }
+    }
+    if !current.is_empty() {
+        args.push(current);
+    }
+    args
+}
+
+/// Directories passed with `-I`/`-iquote` (joined or as the next argument).
+/// `-isystem` directories are deliberately skipped: headers found only
+/// there are system headers.
+fn include_flags(arguments: &[String]) -> Vec<String> {
+    let mut dirs = Vec::new();
+    let mut iter = arguments.iter();
+    while let Some(arg) = iter.next() {
+        for flag in ["-iquote", "-I", "/I"] {
+            if let Some(rest) = arg.strip_prefix(flag) {
+                if rest.is_empty() {
+                    dirs.extend(iter.next().cloned());
+                } else {
+                    dirs.push(rest.trim_start_matches('=').to_string());
+                }
+                break;
+            }
+        }
+    }
+    dirs
+}
+
+fn is_system_header(header: &str) -> bool {
+    Path::new(header).extension().is_none()
+        || SYSTEM_HEADERS.contains(&header)
+        || SYSTEM_PREFIXES
+            .iter()
+            .any(|prefix| header.starts_with(prefix))
+}
+
+pub struct CppFrontend {
+    include_paths: CppIncludePaths,
+    angle_includes: bool,
+    file_index: RefCell<Option<HashSet<PathBuf>>>,
+}
+
+impl CppFrontend {
+    pub fn new() -> Self {
+        Self::with_include_paths(CppIncludePaths::default())
+    }
+
+    pub fn with_include_paths(include_paths: CppIncludePaths) -> Self {
+        Self {
+            include_paths,
+            angle_includes: false,
+            file_index: RefCell::new(None),
+        }
+    }
+
+    /// Also resolve `#include <...>` against include directories. Angle
+    /// includes of system headers stay external either way.
+    pub fn with_angle_includes(mut self, enabled: bool) -> Self {
+        self.angle_includes = enabled;
+        self
+    }
+
+    fn classify_include(&self, header: &str, angle: bool) -> ImportConfidence {
+        if is_system_header(header) || (angle && !self.angle_includes) {
+            ImportConfidence::External
+        } else {
+            ImportConfidence::Resolved
+        }
+    }
+}
+
+impl Default for CppFrontend {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ParseFrontend for CppFrontend {
+    fn language(&self) -> tree_sitter::Language {
+        tree_sitter_cpp::LANGUAGE.into()
+    }
+
+    fn extract_imports(&self, source: &[u8], file_path: &Path) -> Vec<RawImport> {
+        let mut parser = tree_sitter::Parser::new();
+        parser
+            .set_language(&self.language())
+            .expect("failed to set C++ language");
+
+        let tree = match parser.parse(source, None) {
+            Some(t) => t,
+            None => return vec![],
+        };
+
+        let mut imports = Vec::new();
+        let mut stack = vec![tree.root_node()];
+        while let Some(node) = stack.pop() {
+            if node.kind() == "preproc_include" {
+                let Some(path) = node.child_by_field_name("path") else {
+                    continue;
+                };
+                let text = path.utf8_text(source).unwrap_or_default();
+                let (header, kind, confidence) = match path.kind() {
+                    "string_literal" => {
+                        let header = text.trim_matches('"');
+                        let confidence = self.classify_include(header, false);
+                        (header, ImportKind::Direct, confidence)
+                    }
+                    "system_lib_string" => {
+                        let header = text.trim_start_matches('<').trim_end_matches('>');
+                        let confidence = self.classify_include(header, true);
+                        (header, ImportKind::AngleInclude, confidence)
+                    }
+                    // `#include MACRO` is only known after preprocessing.
+                    _ => (text, ImportKind::Direct, ImportConfidence::Dynamic),
+                };
+                imports.push(RawImport {
+                    raw_path: header.to_string(),
+                    source_file: file_path.to_path_buf(),
+                    line: node.start_position().row + 1,
+                    column: Some(node.start_position().column),
+                    kind,
+                    confidence,
+                    cfg: None,
+                    context: ImportContext::Runtime,
+                });
+                continue;
+            }
+            let mut cursor = node.walk();
+            let children: Vec<_> = node.children(&mut cursor).collect();
+            stack.extend(children.into_iter().rev());
+        }
+
+        imports
+    }
+
+    fn resolve(
+        &self,
+        raw: &RawImport,
+        project_root: &Path,
+        project_files: &[PathBuf],
+    ) -> Option<PathBuf> {
+        if raw.confidence != ImportConfidence::Resolved {
+            return None;
+        }
+
+        let mut index = self.file_index.borrow_mut();
+        let files = index.get_or_insert_with(|| {
+            project_files
+                .iter()
+                .map(|file| {
+                    file.strip_prefix(project_root)
+                        .unwrap_or(file)
+                        .to_path_buf()
+                })
+                .collect()
+        });
+
+        let source_file = raw
+            .source_file
+            .strip_prefix(project_root)
+            .unwrap_or(&raw.source_file);
+        let header = Path::new(&raw.raw_path);
+
+        // Quoted includes search the including file's directory first; both
+        // forms then search the include directories in order.
+        let quoted_dir = source_file
+            .parent()
+            .filter(|_| !matches!(raw.kind, ImportKind::AngleInclude));
+        quoted_dir
+            .into_iter()
+            .chain(
+                self.include_paths
+                    .search_dirs(source_file)
+                    .map(PathBuf::as_path),
+            )
+            .filter_map(|dir| normalize_relative(&dir.join(header)))
+            .find(|candidate| files.contains(candidate) && candidate != source_file)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn paths(imports: &[RawImport]) -> Vec<(&str, ImportConfidence)> {
+        imports
+            .iter()
+            .map(|i| (i.raw_path.as_str(), i.confidence.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn extracts_quoted_and_angle_includes() {
+        let source = br#"#include <vector>
+#include <stdio.h>
+#include <fmt/core.h>
+#include "net/socket.h"
+#include PLATFORM_HEADER
+
+#ifdef _WIN32
+#include "platform/win.h"
+#endif
+
+int main() { return 0; }
+"#;
+        let imports = CppFrontend::new().extract_imports(source, Path::new("src/main.cc"));
+        assert_eq!(
+            paths(&imports),
+            vec![
+                ("vector", ImportConfidence::External),
+                ("stdio.h", ImportConfidence::External),
+                ("fmt/core.h", ImportConfidence::External),
+                ("net/socket.h", ImportConfidence::Resolved),
+                ("PLATFORM_HEADER", ImportConfidence::Dynamic),
+                ("platform/win.h", ImportConfidence::Resolved),
+            ]
+        );
+        assert_eq!(imports[3].line, 4);
+
+        let with_angle = CppFrontend::new()
+            .with_angle_includes(true)
+            .extract_imports(source, Path::new("src/main.cc"));
+        assert_eq!(with_angle[0].confidence, ImportConfidence::External);
+        assert_eq!(with_angle[2].confidence, ImportConfidence::Resolved);
+    }
+
+    #[test]
+    fn reads_include_dirs_from_compile_commands() {
+        let database = r#"[
+            {
+                "directory": "/work/build",
+                "file": "../src/main.cc",
+                "arguments": ["c++", "-I../include", "-iquote", "../src", "-isystem", "/usr/include", "-c", "../src/main.cc"]
+            },
+            {
+                "directory": "/work",
+                "file": "/work/lib/util.c",
+                "command": "cc -I third_party/fmt/include -I/opt/sdk/include -c lib/util.c"
+            }
+        ]"#;
+        let paths = CppIncludePaths::load(Some(database), Path::new("/work"), &["vendor".into()]);
+
+        assert_eq!(
+            paths.per_file[Path::new("src/main.cc")],
+            vec![PathBuf::from("include"), PathBuf::from("src")]
+        );
+        assert_eq!(
+            paths.per_file[Path::new("lib/util.c")],
+            vec![PathBuf::from("third_party/fmt/include")]
+        );
+        assert_eq!(paths.database.len(), 3);
+        assert_eq!(paths.configured, vec![PathBuf::from("vendor")]);
+    }
+
+    #[test]
+    fn resolves_relative_then_include_dirs() {
+        let files: Vec<PathBuf> = [
+            "src/main.cc",
+            "src/config.h",
+            "include/net/socket.h",
+            "vendor/json.h",
+        ]
+        .iter()
+        .map(PathBuf::from)
+        .collect();
+        let include_paths =
+            CppIncludePaths::load(None, Path::new(""), &["include".into(), "vendor".into()]);
+        let frontend = CppFrontend::with_include_paths(include_paths);
+        let resolve = |header: &str| {
+            let raw = RawImport {
+                raw_path: header.to_string(),
+                source_file: PathBuf::from("src/main.cc"),
+                line: 1,
+                column: None,
+                kind: ImportKind::Direct,
+                confidence: ImportConfidence::Resolved,
+                cfg: None,
+                context: ImportContext::Runtime,
+            };
+            frontend.resolve(&raw, Path::new(""), &files)
+        };
+
+        assert_eq!(resolve("config.h"), Some(PathBuf::from("src/config.h")));
+        assert_eq!(
+            resolve("net/socket.h"),
+            Some(PathBuf::from("include/net/socket.h"))
+        );
+        assert_eq!(
+            resolve("../vendor/json.h"),
+            Some(PathBuf::from("vendor/json.h"))
+        );
+        assert_eq!(resolve("missing.h"), None);
+    }
+}
@@ -0,0 +1,618 @@
+use crate::parse::common::{
+    normalize_relative, ImportConfidence, ImportContext, ImportKind, RawImport,
+};
+use crate::parse::ParseFrontend;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// Manifest extensions that define C# project boundaries.
+pub const MANIFEST_EXTENSIONS: &[&str] = &["sln", "csproj"];
+
+/// Top-level namespaces provided by the .NET runtime and SDKs.
+const PLATFORM_NAMESPACES: &[&str] = &["System", "Microsoft", "Windows"];
+
+/// Declarations whose enclosing namespace decides a file's module.
+const TYPE_DECLARATIONS: &[&str] = &[
+    "class_declaration",
+    "struct_declaration",
+    "interface_declaration",
+    "enum_declaration",
+    "record_declaration",
+    "delegate_declaration",
+];
+
+/// A `.csproj` project, i.e. one assembly.
+#[derive(Clone, Debug)]
+pub struct CSharpProject {
+    /// `<AssemblyName>`, defaulting to the .csproj file stem
+    pub assembly: String,
+    /// `<RootNamespace>`, defaulting to the assembly name
+    pub root_namespace: String,
+    /// Project directory, relative to the project root
+    pub dir: PathBuf,
+}
+
+/// Project boundaries and namespace declarations for the C# sources in a tree.
+///
+/// C# is modelled at namespace level, grouped per assembly: every file maps
+/// to `<assembly>/<namespace below the root namespace>`, so the first path
+/// segment of a node is always the project that compiles it.
+#[derive(Clone, Debug, Default)]
+pub struct CSharpWorkspace {
+    pub projects: Vec<CSharpProject>,
+    /// Module path of each source file, keyed by path relative to the project root
+    pub file_modules: HashMap<PathBuf, PathBuf>,
+    /// Declared namespace → module paths that declare it
+    pub namespaces: HashMap<String, Vec<PathBuf>>,
+}
+
+impl CSharpWorkspace {
+    /// Build the workspace from manifest and source paths (relative to the
+    /// project root) and a reader that returns file contents for a relative path.
+    ///
+    /// When any `.sln` is present, only the projects it lists are used;
+    /// otherwise every `.csproj` is a project.
+    pub fn load(
+        manifests: &[PathBuf],
+        sources: &[PathBuf],
+        read: &dyn Fn(&Path) -> Option<Vec<u8>>,
+    ) -> Self {
+        let read_text = |path: &Path| read(path).and_then(|bytes| String::from_utf8(bytes).ok());
+
+        let solutions: Vec<&PathBuf> = manifests
+            .iter()
+            .filter(|path| has_extension(path, "sln"))
+            .collect();
+        let mut project_files: Vec<PathBuf> = if solutions.is_empty() {
+            manifests
+                .iter()
+                .filter(|path| has_extension(path, "csproj"))
+                .cloned()
+                .collect()
+        } else {
+            solutions
+                .iter()
+                .filter_map(|sln| {
+                    let dir = sln.parent().unwrap_or(Path::new(""));
+                    read_text(sln).map(|content| solution_projects(&content, dir))
+                })
+                .flatten()
+                .collect()
+        };
+        project_files.sort();
+        project_files.dedup();
+
+        let mut projects: Vec<CSharpProject> = project_files
+            .iter()
+            .map(|path| load_project(path, read_text(path).as_deref().unwrap_or_default()))
+            .collect();
+        // Deepest directories first so nested projects win over their parents.
+        projects.sort_by_key(|project| std::cmp::Reverse(project.dir.components().count()));
+
+        let mut workspace = Self {
+            projects,
+            ..Self::default()
+        };
+        for source in sources {
+            let namespace = read(source)
+                .and_then(|bytes| declared_namespace(&bytes))
+                .unwrap_or_default();
+            let module = workspace.module_path(source, &namespace);
+            if !namespace.is_empty() {
+                let modules = workspace.namespaces.entry(namespace).or_default();
+                if !modules.contains(&module) {
+                    modules.push(module.clone());
+                    modules.sort();
+                }
+            }
+            workspace.file_modules.insert(source.clone(), module);
+        }
+        workspace
+    }
+
+    /// Discover `.sln`/`.csproj` manifests under `project_root` and scan the
+    /// given source files (absolute or relative) for namespace declarations.
+    pub fn discover(project_root: &Path, sources: &[PathBuf]) -> Self {
+        let walker = ignore::WalkBuilder::new(project_root)
+            .hidden(false)
+            .git_ignore(true)
+            .filter_entry(|entry| !matches!(entry.file_name().to_str(), Some("bin" | "obj")))
+            .build();
+
+        let mut manifests: Vec<PathBuf> = walker
+            .flatten()
+            .filter(|entry| entry.path().is_file())
+            .filter(|entry| {
+                MANIFEST_EXTENSIONS
+                    .iter()
+                    .any(|ext| has_extension(entry.path(), ext))
+            })
+            .filter_map(|entry| {
+                entry
+                    .path()
+                    .strip_prefix(project_root)
+                    .ok()
+                    .map(Path::to_path_buf)
+            })
+            .collect();
+        manifests.sort();
+
+        let sources: Vec<PathBuf> = sources
+            .iter()
+            .map(|file| {
+                file.strip_prefix(project_root)
+                    .unwrap_or(file)
+                    .to_path_buf()
+            })
+            .collect();
+
+        Self::load(&manifests, &sources, &|path| {
+            std::fs::read(project_root.join(path)).ok()
+        })
+    }
+
+    /// The innermost project whose directory contains `file`.
+    pub fn project_for(&self, file: &Path) -> Option<&CSharpProject> {
+        self.projects
+            .iter()
+            .find(|project| file.starts_with(&project.dir))
+    }
+
+    /// Module path for a source file (relative to the project root).
+    pub fn module_for_file(&self, file: &Path) -> Option<&PathBuf> {
+        self.file_modules.get(file)
+    }
+
+    /// Module path for a file declaring `namespace`: the owning assembly
+    /// followed by the namespace segments below its root namespace.
+    fn module_path(&self, file: &Path, namespace: &str) -> PathBuf {
+        let Some(project) = self.project_for(file) else {
+            return if namespace.is_empty() {
+                file.to_path_buf()
+            } else {
+                namespace.split('.').collect()
+            };
+        };
+
+        let relative = if namespace == project.root_namespace {
+            ""
+        } else {
+            namespace
+                .strip_prefix(&project.root_namespace)
+                .and_then(|rest| rest.strip_prefix('.'))
+                .unwrap_or(namespace)
+        };
+        let mut module = PathBuf::from(&project.assembly);
+        module.extend(relative.split('.').filter(|segment| !segment.is_empty()));
+        module
+    }
+
+    /// Resolve a dotted name to the module declaring the longest matching
+    /// namespace, preferring one in the same assembly as `source_module`.
+    pub fn resolve_namespace(&self, name: &str, source_module: Option<&Path>) -> Option<PathBuf> {
+        let segments: Vec<&str> = name.split('.').collect();
+        (1..=segments.len()).rev().find_map(|len| {
+            let modules = self.namespaces.get(&segments[..len].join("."))?;
+            let same_assembly = source_module.and_then(|source| {
+                let assembly = source.components().next()?;
+                modules
+                    .iter()
+                    .find(|module| module.components().next() == Some(assembly))
+            });
+            same_assembly.or_else(|| modules.first()).cloned()
+        })
+    }
+}
+
+fn has_extension(path: &Path, ext: &str) -> bool {
+    path.extension().and_then(|e| e.to_str()) == Some(ext)
+}
+
+/// Project paths listed in a `.sln` file, relative to the project root.
+///
+/// ```text
+/// Project("{FAE04EC0-...}") = "Acme.Core", "src\Acme.Core\Acme.Core.csproj", "{...}"
+/// ```
+fn solution_projects(content: &str, sln_dir: &Path) -> Vec<PathBuf> {
+    content
+        .lines()
+        .filter(|line| line.trim_start().starts_with("Project("))
+        .filter_map(|line| {
+            let (_, rhs) = line.split_once('=')?;
+            let path = rhs.split(',').nth(1)?.trim().trim_matches('"');
+            let path = path.replace('\\', "/");
+            path.ends_with(".csproj")
+                .then(|| normalize_relative(&sln_dir.join(path)))
+                .flatten()
+        })
+        .collect()
+}
+
+fn load_project(path: &Path, content: &str) -> CSharpProject {
+    let stem = path
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .unwrap_or_default()
+        .to_string();
+    let assembly = xml_element(content, "AssemblyName").unwrap_or(stem);
+    let root_namespace = xml_element(content, "RootNamespace").unwrap_or_else(|| assembly.clone());
+    CSharpProject {
+        assembly,
+        root_namespace,
+        dir: path.parent().map(Path::to_path_buf).unwrap_or_default(),
+    }
+}
+
+/// Text of the first `<tag>…</tag>` element, ignoring MSBuild property references.
+fn xml_element(content: &str, tag: &str) -> Option<String> {
+    let open = format!("<{tag}>");
+    let start = content.find(&open)? + open.len();
+    let end = content[start..].find("</")? + start;
+    let value = content[start..end].trim();
+    (!value.is_empty() && !value.contains("$(")).then(|| value.to_string())
+}
+
+fn parse(source: &[u8]) -> Option<tree_sitter::Tree> {
+    let mut parser = tree_sitter::Parser::new();
+    parser
+        .set_language(&tree_sitter_c_sharp::LANGUAGE.into())
+        .expect("failed to set C# language");
+    parser.parse(source, None)
+}
+
+/// The namespace a file belongs to: the namespace enclosing its first type
+/// declaration, falling back to the first namespace it declares.
+pub fn declared_namespace(source: &[u8]) -> Option<String> {
+    let tree = parse(source)?;
+    let root = tree.root_node();
+    first_type_namespace(root, source, "")
+        .or_else(|| first_namespace(root, source))
+        .filter(|namespace| !namespace.is_empty())
+}
+
+fn first_type_namespace(node: tree_sitter::Node, source: &[u8], prefix: &str) -> Option<String> {
+    let mut scope = prefix.to_string();
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        match child.kind() {
+            "file_scoped_namespace_declaration" => {
+                scope = qualify(prefix, &namespace_name(child, source));
+            }
+            "namespace_declaration" => {
+                let namespace = qualify(&scope, &namespace_name(child, source));
+                if let Some(body) = child.child_by_field_name("body") {
+                    if let Some(found) = first_type_namespace(body, source, &namespace) {
+                        return Some(found);
+                    }
+                }
+            }
+            kind if TYPE_DECLARATIONS.contains(&kind) => return Some(scope),
+            _ => {}
+        }
+    }
+    None
+}
+
+fn first_namespace(root: tree_sitter::Node, source: &[u8]) -> Option<String> {
+    let mut cursor = root.walk();
+    let found = root.named_children(&mut cursor).find(|child| {
+        matches!(
+            child.kind(),
+            "namespace_declaration" | "file_scoped_namespace_declaration"
+        )
+    });
+    found.map(|node| namespace_name(node, source))
+}
+
+fn namespace_name(node: tree_sitter::Node, source: &[u8]) -> String {
+    node.child_by_field_name("name")
+        .and_then(|name| name.utf8_text(source).ok())
+        .map(|text| text.chars().filter(|c| !c.is_whitespace()).collect())
+        .unwrap_or_default()
+}
+
+fn qualify(prefix: &str, name: &str) -> String {
+    if prefix.is_empty() {
+        name.to_string()
+    } else {
+        format!("{prefix}.{name}")
+    }
+}
+
+pub struct CSharpFrontend {
+    workspace: Option<Arc<CSharpWorkspace>>,
+}
+
+impl CSharpFrontend {
+    pub fn new() -> Self {
+        Self { workspace: None }
+    }
+
+    /// Resolve `using` directives against the given workspace. Without one,
+    /// no directive resolves.
+    pub fn with_workspace(workspace: Arc<CSharpWorkspace>) -> Self {
+        Self {
+            workspace: Some(workspace),
+        }
+    }
+
+    fn classify_import(name: &str) -> ImportConfidence {
+        let first_segment = name.split('.').next().unwrap_or(name);
+        if PLATFORM_NAMESPACES.contains(&first_segment) {
+            ImportConfidence::External
+        } else {
+            ImportConfidence::Resolved
+        }
+    }
+
+    /// Normalize a using directive to its dotted name:
+    /// `global using static A.B.C;` → `A.B.C`, `using X = A.B.C<T>;` → `A.B.C`.
+    fn using_path(text: &str) -> String {
+        let text = text.trim().trim_end_matches(';').trim();
+        let text = text.strip_prefix("global").unwrap_or(text).trim_start();
+        let text = text.strip_prefix("using").unwrap_or(text).trim_start();
+        let text = text.strip_prefix("static ").unwrap_or(text);
+        let text = text.split_once('=').map_or(text, |(_, target)| target);
+        let text = text.split('<').next().unwrap_or(text);
+        let text = text.rsplit("::").next().unwrap_or(text);
+        text.chars().filter(|c| !c.is_whitespace()).collect()
+    }
+}
+
+impl Default for CSharpFrontend {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ParseFrontend for CSharpFrontend {
+    fn language(&self) -> tree_sitter::Language {
+        tree_sitter_c_sharp::LANGUAGE.into()
+    }
+
+    fn extract_imports(&self, source: &[u8], file_path: &Path) -> Vec<RawImport> {
+        let tree = match parse(source) {
+            Some(t) => t,
+            None => return vec![],
+        };
+
+        let mut imports = Vec::new();
+        let mut stack = vec![tree.root_node()];
+        while let Some(node) = stack.pop() {
+            if node.kind() == "using_directive" {
+                let text = node.utf8_text(source).unwrap_or_default();
+                let raw_path = Self::using_path(text);
+                if !raw_path.is_empty() {
+                    imports.push(RawImport {
+                        confidence: Self::classify_import(&raw_path),
+                        raw_path,
+                        source_file: file_path.to_path_buf(),
+                        line: node.start_position().row + 1,
+                        column: Some(node.start_position().column),
+                        kind: ImportKind::Direct,
+                        cfg: None,
+                        context: ImportContext::Runtime,
+                    });
+                }
+                continue;
+            }
+            // Directives only appear at file and namespace scope.
+            if matches!(
+                node.kind(),
+                "compilation_unit"
+                    | "namespace_declaration"
+                    | "file_scoped_namespace_declaration"
+                    | "declaration_list"
+            ) {
+                let mut cursor = node.walk();
+                let children: Vec<_> = node.named_children(&mut cursor).collect();
+                stack.extend(children.into_iter().rev());
+            }
+        }
+
+        imports
+    }
+
+    fn resolve(
+        &self,
+        raw: &RawImport,
+        project_root: &Path,
+        _project_files: &[PathBuf],
+    ) -> Option<PathBuf> {
+        if raw.confidence != ImportConfidence::Resolved {
+            return None;
+        }
+
+        let workspace = self.workspace.as_ref()?;
+
+        let source_file = raw
+            .source_file
+            .strip_prefix(project_root)
+            .unwrap_or(&raw.source_file);
+        let source_module = workspace.module_for_file(source_file);
+        let target =
+            workspace.resolve_namespace(&raw.raw_path, source_module.map(PathBuf::as_path))?;
+
+        // Using a namespace from the same module is not a dependency.
+        (Some(&target) != source_module).then_some(target)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn paths(imports: &[RawImport]) -> Vec<&str> {
+        imports.iter().map(|i| i.raw_path.as_str()).collect()
+    }
+
+    fn workspace(files: &[(&str, &str)]) -> CSharpWorkspace {
+        let files: HashMap<PathBuf, String> = files
+            .iter()
+            .map(|(path, content)| (PathBuf::from(path), content.to_string()))
+            .collect();
+        let mut manifests: Vec<PathBuf> = files
+            .keys()
+            .filter(|path| {
+                MANIFEST_EXTENSIONS
+                    .iter()
+                    .any(|ext| has_extension(path, ext))
+            })
+            .cloned()
+            .collect();
+        manifests.sort();
+        let mut sources: Vec<PathBuf> = files
+            .keys()
+            .filter(|path| has_extension(path, "cs"))
+            .cloned()
+            .collect();
+        sources.sort();
+        CSharpWorkspace::load(&manifests, &sources, &|path| {
+            files.get(path).map(|content| content.clone().into_bytes())
+        })
+    }
+
+    #[test]
+    fn extracts_using_directives() {
+        let source = br#"global using System.Linq;
+using Acme.Core;
+using static Acme.Core.Strings;
+using Db = Acme.Data.Context<Acme.Core.User>;
+
+namespace Acme.Web
+{
+    using Acme.Web.Views;
+
+    public class Handler
+    {
+        public void Run()
+        {
+            using var scope = Open();
+        }
+    }
+}
+"#;
+        let imports = CSharpFrontend::new().extract_imports(source, Path::new("Handler.cs"));
+        assert_eq!(
+            paths(&imports),
+            vec![
+                "System.Linq",
+                "Acme.Core",
+                "Acme.Core.Strings",
+                "Acme.Data.Context",
+                "Acme.Web.Views"
+            ]
+        );
+        assert_eq!(imports[0].confidence, ImportConfidence::External);
+        assert_eq!(imports[1].confidence, ImportConfidence::Resolved);
+        assert_eq!(imports[4].line, 8);
+    }
+
+    #[test]
+    fn finds_namespace_of_first_type() {
+        assert_eq!(
+            declared_namespace(b"namespace Acme.Core;\npublic class A {}\n"),
+            Some("Acme.Core".to_string())
+        );
+        assert_eq!(
+            declared_namespace(b"namespace Acme { namespace Core.Models { class A {} } }"),
+            Some("Acme.Core.Models".to_string())
+        );
+        assert_eq!(declared_namespace(b"class Program {}"), None);
+    }
+
+    #[test]
+    fn reads_solution_and_project_files() {
+        let workspace = workspace(&[
+            (
+                "Acme.sln",
+                "Project(\"{FAE04EC0}\") = \"Acme.Core\", \"src\\Acme.Core\\Acme.Core.csproj\", \"{1}\"\nEndProject\n",
+            ),
+            (
+                "src/Acme.Core/Acme.Core.csproj",
+                "<Project><PropertyGroup><RootNamespace>Acme</RootNamespace></PropertyGroup></Project>",
+            ),
+            ("tools/Scratch/Scratch.csproj", "<Project />"),
+        ]);
+        assert_eq!(workspace.projects.len(), 1);
+        assert_eq!(workspace.projects[0].assembly, "Acme.Core");
+        assert_eq!(workspace.projects[0].root_namespace, "Acme");
+        assert_eq!(workspace.projects[0].dir, PathBuf::from("src/Acme.Core"));
+    }
+
+    #[test]
+    fn groups_namespaces_under_their_assembly() {
+        let workspace = workspace(&[
+            ("Acme.Core/Acme.Core.csproj", "<Project />"),
+            (
+                "Acme.Core/Service.cs",
+                "namespace Acme.Core;\nclass Service {}",
+            ),
+            (
+                "Acme.Core/Models/User.cs",
+                "namespace Acme.Core.Models;\nclass User {}",
+            ),
+            ("Acme.Web/Acme.Web.csproj", "<Project />"),
+            (
+                "Acme.Web/Handler.cs",
+                "namespace Acme.Web { class Handler {} }",
+            ),
+        ]);
+        assert_eq!(
+            workspace.module_for_file(Path::new("Acme.Core/Service.cs")),
+            Some(&PathBuf::from("Acme.Core"))
+        );
+        assert_eq!(
+            workspace.module_for_file(Path::new("Acme.Core/Models/User.cs")),
+            Some(&PathBuf::from("Acme.Core/Models"))
+        );
+        assert_eq!(
+            workspace.module_for_file(Path::new("Acme.Web/Handler.cs")),
+            Some(&PathBuf::from("Acme.Web"))
+        );
+    }
+
+    #[test]
+    fn resolves_usings_to_declaring_namespace() {
+        let workspace = workspace(&[
+            ("Acme.Core/Acme.Core.csproj", "<Project />"),
+            (
+                "Acme.Core/Service.cs",
+                "namespace Acme.Core;\nclass Service {}",
+            ),
+            (
+                "Acme.Core/Models/User.cs",
+                "namespace Acme.Core.Models;\nclass User {}",
+            ),
+            ("Acme.Web/Acme.Web.csproj", "<Project />"),
+            (
+                "Acme.Web/Handler.cs",
+                "namespace Acme.Web;\nclass Handler {}",
+            ),
+        ]);
+        let frontend = CSharpFrontend::with_workspace(Arc::new(workspace));
+        let resolve = |name: &str| {
+            let raw = RawImport {
+                raw_path: name.to_string(),
+                source_file: PathBuf::from("Acme.Web/Handler.cs"),
+                line: 1,
+                column: None,
+                kind: ImportKind::Direct,
+                confidence: CSharpFrontend::classify_import(name),
+                cfg: None,
+                context: ImportContext::Runtime,
+            };
+            frontend.resolve(&raw, Path::new(""), &[])
+        };
+
+        assert_eq!(resolve("Acme.Core"), Some(PathBuf::from("Acme.Core")));
+        assert_eq!(
+            resolve("Acme.Core.Models.User"),
+            Some(PathBuf::from("Acme.Core/Models"))
+        );
+        assert_eq!(resolve("Acme.Web"), None);
+        assert_eq!(resolve("Newtonsoft.Json"), None);
+        assert_eq!(resolve("System.Text"), None);
+    }
+}
@@ -0,0 +1,481 @@
+use crate::config::{ResolvedConfig, ResolvedFrontend};
+use crate::errors::{Result, UntangleError};
+use crate::parse::common::{
+    normalize_relative, ImportConfidence, ImportContext, ImportKind, RawImport,
+};
+use crate::parse::ParseFrontend;
+use crate::walk::Language;
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use streaming_iterator::StreamingIterator;
+
+/// Capture that marks an import in a frontend's query.
+const IMPORT_CAPTURE: &str = "import";
+
+/// Templates tried when a frontend sets no `resolve`: the import as a path
+/// relative to the importing file, then to the project root.
+const DEFAULT_RESOLVE: &[&str] = &["{dir}/{import}", "{import}"];
+
+/// Longest custom language name, in bytes.
+pub const MAX_NAME_LEN: usize = 31;
+
+/// Name of a custom language declared in `[frontends.<name>]`. The name is
+/// stored inline so `Language` stays `Copy`; the definition itself is looked
+/// up in `ResolvedConfig::frontends`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CustomLanguageId {
+    len: u8,
+    name: [u8; MAX_NAME_LEN],
+}
+
+impl CustomLanguageId {
+    /// Names are ASCII letters, digits, `-` and `_`, at most
+    /// [`MAX_NAME_LEN`] bytes long.
+    pub fn new(name: &str) -> Option<Self> {
+        let valid = !name.is_empty()
+            && name.len() <= MAX_NAME_LEN
+            && name
+                .bytes()
+                .all(|byte| byte.is_ascii_alphanumeric() || byte == b'-' || byte == b'_');
+        valid.then(|| {
+            let mut id = Self {
+                len: name.len() as u8,
+                name: [0; MAX_NAME_LEN],
+            };
+            id.name[..name.len()].copy_from_slice(name.as_bytes());
+            id
+        })
+    }
+
+    pub fn as_str(&self) -> &str {
+        // Only ASCII is accepted by `new`.
+        std::str::from_utf8(&self.name[..self.len as usize]).unwrap()
+    }
+}
+
+impl std::fmt::Debug for CustomLanguageId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.as_str())
+    }
+}
+
+/// Definition of the custom language `id` among `frontends`.
+pub fn definition(
+    id: CustomLanguageId,
+    frontends: &[ResolvedFrontend],
+) -> Option<&ResolvedFrontend> {
+    frontends
+        .iter()
+        .find(|frontend| frontend.name == id.as_str())
+}
+
+/// Custom language in `frontends` claiming the file extension `ext`.
+pub fn for_extension(ext: &str, frontends: &[ResolvedFrontend]) -> Option<Language> {
+    frontends
+        .iter()
+        .find(|frontend| frontend.extensions.iter().any(|claimed| claimed == ext))
+        .and_then(|frontend| CustomLanguageId::new(&frontend.name))
+        .map(Language::Custom)
+}
+
+/// Load the grammar and query of every custom language in `langs`.
+///
+/// Grammar libraries are native code that runs as soon as it is opened, so
+/// they are only loaded when `--allow-custom-grammars` or the user config's
+/// `allow_custom_grammars` is set. A repository's `.untangle.toml` cannot
+/// allow them itself.
+pub fn load_grammars(
+    langs: &[Language],
+    config: &ResolvedConfig,
+) -> Result<HashMap<Language, Arc<CustomGrammar>>> {
+    langs
+        .iter()
+        .filter_map(|&lang| match lang {
+            Language::Custom(id) => Some((lang, id)),
+            _ => None,
+        })
+        .map(|(lang, id)| {
+            let name = id.as_str();
+            let frontend = definition(id, &config.frontends)
+                .ok_or_else(|| UntangleError::Config(format!("unsupported language: {name}")))?;
+            if !config.allow_custom_grammars {
+                return Err(UntangleError::Config(format!(
+                    "frontend `{name}`: not loading grammar {} without --allow-custom-grammars \
+                     (or `allow_custom_grammars = true` under [defaults] in the user config)",
+                    frontend.grammar.display()
+                )));
+            }
+            let grammar = CustomGrammar::load(frontend).map_err(|message| {
+                UntangleError::Config(format!("frontend `{name}`: {message}"))
+            })?;
+            Ok((lang, Arc::new(grammar)))
+        })
+        .collect()
+}
+
+/// A tree-sitter grammar with the query that finds imports and the
+/// templates that resolve them.
+pub struct CustomGrammar {
+    language: tree_sitter::Language,
+    query: tree_sitter::Query,
+    import_capture: u32,
+    resolve: Vec<String>,
+    /// Library the grammar's tables live in. Fields drop in order, so it is
+    /// unloaded after the language and query that point into it.
+    _library: Option<libloading::Library>,
+}
+
+impl CustomGrammar {
+    pub fn new(
+        language: tree_sitter::Language,
+        query_source: &str,
+        resolve: Vec<String>,
+    ) -> std::result::Result<Self, String> {
+        let version = language.version();
+        if !(tree_sitter::MIN_COMPATIBLE_LANGUAGE_VERSION..=tree_sitter::LANGUAGE_VERSION)
+            .contains(&version)
+        {
+            return Err(format!(
+                "grammar ABI version {version} is not supported (expected {}..={})",
+                tree_sitter::MIN_COMPATIBLE_LANGUAGE_VERSION,
+                tree_sitter::LANGUAGE_VERSION
+            ));
+        }
+        let query = tree_sitter::Query::new(&language, query_source)
+            .map_err(|error| format!("invalid query: {error}"))?;
+        let import_capture = query
+            .capture_index_for_name(IMPORT_CAPTURE)
+            .ok_or_else(|| format!("query has no @{IMPORT_CAPTURE} capture"))?;
+        let resolve = if resolve.is_empty() {
+            DEFAULT_RESOLVE
+                .iter()
+                .map(|template| template.to_string())
+                .collect()
+        } else {
+            resolve
+        };
+        Ok(Self {
+            language,
+            query,
+            import_capture,
+            resolve,
+            _library: None,
+        })
+    }
+
+    fn load(frontend: &ResolvedFrontend) -> std::result::Result<Self, String> {
+        let query_source = std::fs::read_to_string(&frontend.query)
+            .map_err(|error| format!("cannot read query {}: {error}", frontend.query.display()))?;
+        let cannot_load = |error: String| {
+            format!(
+                "cannot load grammar {}: {error}",
+                frontend.grammar.display()
+            )
+        };
+
+        // SAFETY: opening the library runs its initialisers; the user allowed
+        // grammar libraries to be loaded.
+        let library = unsafe { libloading::Library::new(&frontend.grammar) }
+            .map_err(|error| cannot_load(error.to_string()))?;
+        // SAFETY: tree-sitter grammars export `tree_sitter_<name>` as
+        // `const TSLanguage *(void)`.
+        let entry_point = unsafe {
+            library.get::<unsafe extern "C" fn() -> *const ()>(frontend.symbol.as_bytes())
+        }
+        .map_err(|error| cannot_load(error.to_string()))?;
+        // SAFETY: as above; the entry point only returns a pointer to static
+        // tables, which is checked before tree-sitter dereferences it.
+        if unsafe { entry_point() }.is_null() {
+            return Err(cannot_load(format!("`{}` returned null", frontend.symbol)));
+        }
+        // SAFETY: the entry point returns a valid `TSLanguage`, and the
+        // library outlives the language because the grammar owns both.
+        let language = unsafe {
+            tree_sitter::Language::new(tree_sitter_language::LanguageFn::from_raw(*entry_point))
+        };
+
+        let mut grammar = Self::new(language, &query_source, frontend.resolve.clone())?;
+        grammar._library = Some(library);
+        Ok(grammar)
+    }
+}
+
+/// Import text without the quotes or brackets around it.
+fn unquote(text: &str) -> &str {
+    let text = text.trim();
+    for (open, close) in [('"', '"'), ('\'', '\''), ('`', '`'), ('<', '>')] {
+        if let Some(inner) = text
+            .strip_prefix(open)
+            .and_then(|rest| rest.strip_suffix(close))
+        {
+            return inner;
+        }
+    }
+    text
+}
+
+/// Frontend for a language declared in `[frontends.<name>]`: imports are the
+/// `@import` captures of its query, resolved by the first template naming a
+/// project file.
+pub struct CustomFrontend {
+    grammar: Arc<CustomGrammar>,
+    /// Project files relative to the project root, built on first resolve
+    file_index: RefCell<Option<HashSet<PathBuf>>>,
+}
+
+impl CustomFrontend {
+    pub fn new(grammar: Arc<CustomGrammar>) -> Self {
+        Self {
+            grammar,
+            file_index: RefCell::new(None),
+        }
+    }
+
+    /// Candidate paths for an import, in template order. Templates may use
+    /// `{import}` (as written), `{import_path}` (with `.` and `::`
+    /// separators turned into `/`) and `{dir}` (the importing file's
+    /// directory).
+    fn candidates(&self, import: &str, source_file: &Path) -> Vec<PathBuf> {
+        let dir = source_file
+            .parent()
+            .map(|dir| dir.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let import_path = import.replace("::", "/").replace('.', "/");
+        self.grammar
+            .resolve
+            .iter()
+            .filter_map(|template| {
+                let rendered = template
+                    .replace("{dir}", &dir)
+                    .replace("{import_path}", &import_path)
+                    .replace("{import}", import);
+                normalize_relative(Path::new(&rendered))
+            })
+            .collect()
+    }
+}
+
+impl ParseFrontend for CustomFrontend {
+    fn language(&self) -> tree_sitter::Language {
+        self.grammar.language.clone()
+    }
+
+    fn extract_imports(&self, source: &[u8], file_path: &Path) -> Vec<RawImport> {
+        let mut parser = tree_sitter::Parser::new();
+        if parser.set_language(&self.grammar.language).is_err() {
+            return vec![];
+        }
+
+        let tree = match parser.parse(source, None) {
+            Some(t) => t,
+            None => return vec![],
+        };
+
+        let mut cursor = tree_sitter::QueryCursor::new();
+        let mut matches = cursor.matches(&self.grammar.query, tree.root_node(), source);
+
+        let mut imports = Vec::new();
+        while let Some(m) = matches.next() {
+            for capture in m.captures {
+                if capture.index != self.grammar.import_capture {
+                    continue;
+                }
+                let node = capture.node;
+                let Ok(text) = node.utf8_text(source) else {
+                    continue;
+                };
+                let path = unquote(text);
+                if path.is_empty() {
+                    continue;
+                }
+                imports.push(RawImport {
+                    raw_path: path.to_string(),
+                    source_file: file_path.to_path_buf(),
+                    line: node.start_position().row + 1,
+                    column: Some(node.start_position().column),
+                    kind: ImportKind::Direct,
+                    confidence: ImportConfidence::Resolved,
+                    cfg: None,
+                    context: ImportContext::Runtime,
+                });
+            }
+        }
+        imports
+    }
+
+    fn resolve(
+        &self,
+        raw: &RawImport,
+        project_root: &Path,
+        project_files: &[PathBuf],
+    ) -> Option<PathBuf> {
+        if raw.confidence != ImportConfidence::Resolved {
+            return None;
+        }
+
+        let mut index = self.file_index.borrow_mut();
+        let files = index.get_or_insert_with(|| {
+            project_files
+                .iter()
+                .map(|file| {
+                    file.strip_prefix(project_root)
+                        .unwrap_or(file)
+                        .to_path_buf()
+                })
+                .collect()
+        });
+        let source_file = raw
+            .source_file
+            .strip_prefix(project_root)
+            .unwrap_or(&raw.source_file);
+
+        self.candidates(&raw.raw_path, source_file)
+            .into_iter()
+            .find(|candidate| files.contains(candidate))
+            .filter(|target| target != source_file)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn python_grammar(resolve: &[&str]) -> Arc<CustomGrammar> {
+        Arc::new(
+            CustomGrammar::new(
+                tree_sitter_python::LANGUAGE.into(),
+                r#"
+                (import_statement name: (dotted_name) @import)
+                (call function: (identifier) @fn arguments: (argument_list (string) @import)
+                  (#eq? @fn "load"))
+                "#,
+                resolve
+                    .iter()
+                    .map(|template| template.to_string())
+                    .collect(),
+            )
+            .unwrap(),
+        )
+    }
+
+    fn frontend(definition: &str) -> ResolvedFrontend {
+        ResolvedFrontend {
+            name: definition.to_string(),
+            extensions: vec![format!("{definition}x")],
+            grammar: PathBuf::from("grammars/missing.so"),
+            symbol: format!("tree_sitter_{definition}"),
+            query: PathBuf::from("grammars/imports.scm"),
+            resolve: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn extracts_import_captures_without_quotes() {
+        let frontend = CustomFrontend::new(python_grammar(&[]));
+        let imports = frontend.extract_imports(
+            b"import shop.cart\nload(\"../rules/tax.star\")\nprint(\"x\")\n",
+            Path::new("app/main.py"),
+        );
+        let paths: Vec<(&str, usize)> = imports
+            .iter()
+            .map(|import| (import.raw_path.as_str(), import.line))
+            .collect();
+        assert_eq!(paths, vec![("shop.cart", 1), ("../rules/tax.star", 2)]);
+    }
+
+    #[test]
+    fn resolves_imports_through_templates_in_order() {
+        let frontend =
+            CustomFrontend::new(python_grammar(&["{dir}/{import}", "src/{import_path}.py"]));
+        let files: Vec<PathBuf> = ["app/main.py", "rules/tax.star", "src/shop/cart.py"]
+            .iter()
+            .map(PathBuf::from)
+            .collect();
+        let resolve = |raw_path: &str| {
+            frontend.resolve(
+                &RawImport {
+                    raw_path: raw_path.to_string(),
+                    source_file: PathBuf::from("app/main.py"),
+                    line: 1,
+                    column: None,
+                    kind: ImportKind::Direct,
+                    confidence: ImportConfidence::Resolved,
+                    cfg: None,
+                    context: ImportContext::Runtime,
+                },
+                Path::new(""),
+                &files,
+            )
+        };
+
+        assert_eq!(
+            resolve("../rules/tax.star"),
+            Some(PathBuf::from("rules/tax.star"))
+        );
+        assert_eq!(
+            resolve("shop.cart"),
+            Some(PathBuf::from("src/shop/cart.py"))
+        );
+        assert_eq!(resolve("shop.orders"), None);
+        assert_eq!(resolve("main.py"), None);
+    }
+
+    #[test]
+    fn rejects_queries_without_import_capture() {
+        let error = CustomGrammar::new(
+            tree_sitter_python::LANGUAGE.into(),
+            "(import_statement) @statement",
+            Vec::new(),
+        )
+        .err()
+        .unwrap();
+        assert_eq!(error, "query has no @import capture");
+    }
+
+    #[test]
+    fn languages_are_named_without_a_registry() {
+        let lang = "starlark".parse::<Language>().unwrap();
+        assert_eq!(
+            lang,
+            Language::Custom(CustomLanguageId::new("starlark").unwrap())
+        );
+        assert_eq!(lang.to_string(), "starlark");
+        assert!("a".repeat(MAX_NAME_LEN + 1).parse::<Language>().is_err());
+        assert!("lib/dsl".parse::<Language>().is_err());
+
+        let frontends = [frontend("starlark"), frontend("other")];
+        assert_eq!(for_extension("starlarkx", &frontends), Some(lang));
+        assert_eq!(for_extension("py", &frontends), None);
+        let Language::Custom(id) = lang else {
+            unreachable!()
+        };
+        assert_eq!(definition(id, &frontends), Some(&frontends[0]));
+        assert_eq!(definition(id, &frontends[1..]), None);
+    }
+
+    #[test]
+    fn load_requires_custom_grammars_to_be_allowed() {
+        let tmp = tempfile::tempdir().unwrap();
+        std::fs::write(tmp.path().join("imports.scm"), "(import) @import").unwrap();
+        let mut definition = frontend("custom-load-test");
+        definition.query = tmp.path().join("imports.scm");
+        definition.grammar = tmp.path().join("libmissing.so");
+        let lang = "custom-load-test".parse::<Language>().unwrap();
+        let mut config =
+            crate::config::resolve::resolve_config(tmp.path(), &Default::default()).unwrap();
+        config.frontends = vec![definition];
+
+        let error = load_grammars(&[lang], &config).err().unwrap().to_string();
+        assert!(error.contains("--allow-custom-grammars"), "{error}");
+
+        config.allow_custom_grammars = true;
+        let error = load_grammars(&[lang], &config).err().unwrap().to_string();
+        assert!(
+            error.contains("frontend `custom-load-test`: cannot load grammar"),
+            "{error}"
+        );
+        assert!(error.contains("libmissing.so"), "{error}");
+    }
+}
@@ -0,0 +1,578 @@
+use crate::parse::common::{ImportConfidence, ImportContext, ImportKind, RawImport};
+use crate::parse::resolver::camel_to_snake;
+use crate::parse::ParseFrontend;
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// Mix project manifest file name.
+pub const MIX_MANIFEST: &str = "mix.exs";
+
+/// Top-level modules from Elixir's standard library, OTP wrappers and the
+/// bundled applications (ExUnit, Mix, Logger, ...).
+const ELIXIR_STDLIB_MODULES: &[&str] = &[
+    "Access",
+    "Agent",
+    "Application",
+    "ArgumentError",
+    "Atom",
+    "Base",
+    "Bitwise",
+    "Calendar",
+    "Code",
+    "Collectable",
+    "Config",
+    "Date",
+    "DateTime",
+    "DynamicSupervisor",
+    "EEx",
+    "Enum",
+    "Enumerable",
+    "Exception",
+    "ExUnit",
+    "File",
+    "Float",
+    "Function",
+    "GenServer",
+    "IEx",
+    "IO",
+    "Inspect",
+    "Integer",
+    "Kernel",
+    "Keyword",
+    "List",
+    "Logger",
+    "Macro",
+    "Map",
+    "MapSet",
+    "Mix",
+    "Module",
+    "NaiveDateTime",
+    "Node",
+    "OptionParser",
+    "Path",
+    "Port",
+    "Process",
+    "Protocol",
+    "Range",
+    "Record",
+    "Regex",
+    "Registry",
+    "RuntimeError",
+    "Stream",
+    "String",
+    "StringIO",
+    "Supervisor",
+    "System",
+    "Task",
+    "Time",
+    "Tuple",
+    "URI",
+    "Version",
+];
+
+/// Directives that make another module's name or macros available.
+const DIRECTIVES: &[&str] = &["alias", "import", "require", "use"];
+
+/// `lib/` directories and top-level module names of a Mix project.
+///
+/// Every `mix.exs` contributes `<dir>/lib` as a source root, so umbrella
+/// apps under `apps/*/` are covered. A module name maps to a file by Mix
+/// convention: `MyApp.Accounts.User` lives in `lib/my_app/accounts/user.ex`.
+#[derive(Clone, Debug, Default)]
+pub struct MixProject {
+    /// `lib/` directories relative to the project root
+    pub lib_roots: Vec<PathBuf>,
+    /// snake_case names of the files and directories directly under a lib
+    /// root (`my_app` for `lib/my_app.ex` and `lib/my_app/`)
+    pub top_level: HashSet<String>,
+}
+
+impl MixProject {
+    /// Build the project layout from `mix.exs` paths and Elixir sources
+    /// (absolute or relative to `project_root`).
+    pub fn from_files<'a>(
+        manifests: &[PathBuf],
+        sources: impl IntoIterator<Item = &'a PathBuf>,
+        project_root: &Path,
+    ) -> Self {
+        let mut lib_roots: Vec<PathBuf> = manifests
+            .iter()
+            .map(|manifest| {
+                let manifest = manifest.strip_prefix(project_root).unwrap_or(manifest);
+                manifest.parent().unwrap_or(Path::new("")).join("lib")
+            })
+            .collect();
+        if lib_roots.is_empty() {
+            lib_roots.push(PathBuf::from("lib"));
+        }
+        lib_roots.sort();
+        lib_roots.dedup();
+
+        let mut top_level = HashSet::new();
+        for source in sources {
+            let relative = source.strip_prefix(project_root).unwrap_or(source);
+            for root in &lib_roots {
+                let Ok(below) = relative.strip_prefix(root) else {
+                    continue;
+                };
+                if let Some(first) = below.iter().next() {
+                    let first = Path::new(first);
+                    let stem = first.file_stem().unwrap_or(first.as_os_str());
+                    top_level.insert(stem.to_string_lossy().into_owned());
+                }
+            }
+        }
+
+        Self {
+            lib_roots,
+            top_level,
+        }
+    }
+
+    /// Find every `mix.exs` under `project_root` (skipping `deps/` and
+    /// `_build/`) and index `sources` against their lib roots.
+    pub fn discover(project_root: &Path, sources: &[PathBuf]) -> Self {
+        let walker = ignore::WalkBuilder::new(project_root)
+            .hidden(false)
+            .git_ignore(true)
+            .filter_entry(|entry| entry.file_name() != "deps" && entry.file_name() != "_build")
+            .build();
+
+        let mut manifests: Vec<PathBuf> = walker
+            .flatten()
+            .filter(|entry| entry.path().is_file() && entry.file_name() == MIX_MANIFEST)
+            .filter_map(|entry| {
+                entry
+                    .path()
+                    .strip_prefix(project_root)
+                    .ok()
+                    .map(Path::to_path_buf)
+            })
+            .collect();
+        manifests.sort();
+
+        Self::from_files(&manifests, sources, project_root)
+    }
+
+    /// Whether a module's top-level segment names something in `lib/`.
+    /// Without any indexed sources every module is assumed to be internal.
+    fn owns(&self, module: &str) -> bool {
+        let first = module.split('.').next().unwrap_or(module);
+        self.top_level.is_empty() || self.top_level.contains(&camel_to_snake(first))
+    }
+
+    /// Candidate files for a module, most specific first. Nested modules
+    /// (`MyApp.User.Query`) are usually defined in their parent's file, so
+    /// each enclosing module's file is tried after the exact match.
+    fn candidates(&self, module: &str) -> Vec<PathBuf> {
+        let segments: Vec<String> = module.split('.').map(camel_to_snake).collect();
+        (1..=segments.len())
+            .rev()
+            .flat_map(|len| {
+                let relative: PathBuf = segments[..len].iter().collect();
+                self.lib_roots
+                    .iter()
+                    .map(move |root| root.join(&relative).with_extension("ex"))
+            })
+            .collect()
+    }
+}
+
+/// Per-file alias state: the enclosing `defmodule` names and the aliases
+/// introduced so far.
+#[derive(Default)]
+struct ModuleScope {
+    modules: Vec<String>,
+    aliases: HashMap<String, String>,
+}
+
+impl ModuleScope {
+    /// Expand a module name as written: `__MODULE__` becomes the enclosing
+    /// module and a first segment matching an alias is replaced.
+    fn expand(&self, written: &str) -> String {
+        let (first, rest) = match written.split_once('.') {
+            Some((first, rest)) => (first, Some(rest)),
+            None => (written, None),
+        };
+        let head = if first == "__MODULE__" {
+            self.modules.last().cloned()
+        } else {
+            self.aliases.get(first).cloned()
+        };
+        match (head, rest) {
+            (Some(head), Some(rest)) => format!("{head}.{rest}"),
+            (Some(head), None) => head,
+            (None, _) => written.to_string(),
+        }
+    }
+}
+
+pub struct ElixirFrontend {
+    project: Arc<MixProject>,
+    file_index: RefCell<Option<HashSet<PathBuf>>>,
+}
+
+impl ElixirFrontend {
+    pub fn new() -> Self {
+        Self::with_project(Arc::default())
+    }
+
+    pub fn with_project(project: Arc<MixProject>) -> Self {
+        Self {
+            project,
+            file_index: RefCell::new(None),
+        }
+    }
+
+    fn walk(
+        &self,
+        node: tree_sitter::Node,
+        source: &[u8],
+        file_path: &Path,
+        scope: &mut ModuleScope,
+        imports: &mut Vec<RawImport>,
+    ) {
+        if node.kind() == "call" {
+            let target = node
+                .child_by_field_name("target")
+                .filter(|target| target.kind() == "identifier")
+                .and_then(|target| target.utf8_text(source).ok())
+                .unwrap_or_default();
+            let arguments = node
+                .named_children(&mut node.walk())
+                .find(|child| child.kind() == "arguments");
+
+            if target == "defmodule" {
+                let name = arguments
+                    .and_then(|args| args.named_child(0))
+                    .filter(|arg| arg.kind() == "alias")
+                    .and_then(|arg| arg.utf8_text(source).ok())
+                    .map(|name| match scope.modules.last() {
+                        Some(outer) => format!("{outer}.{name}"),
+                        None => name.to_string(),
+                    });
+                if let Some(name) = name {
+                    // A nested `defmodule Inner` is aliased in its parent.
+                    if let Some(last) = name
+                        .rsplit('.')
+                        .next()
+                        .filter(|_| !scope.modules.is_empty())
+                    {
+                        scope.aliases.insert(last.to_string(), name.clone());
+                    }
+                    scope.modules.push(name);
+                    let mut cursor = node.walk();
+                    for child in node.children(&mut cursor) {
+                        self.walk(child, source, file_path, scope, imports);
+                    }
+                    scope.modules.pop();
+                    return;
+                }
+            }
+
+            if DIRECTIVES.contains(&target) {
+                if let Some(arguments) = arguments {
+                    self.extract_directive(
+                        target, node, arguments, source, file_path, scope, imports,
+                    );
+                }
+                return;
+            }
+        }
+
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            self.walk(child, source, file_path, scope, imports);
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn extract_directive(
+        &self,
+        directive: &str,
+        call: tree_sitter::Node,
+        arguments: tree_sitter::Node,
+        source: &[u8],
+        file_path: &Path,
+        scope: &mut ModuleScope,
+        imports: &mut Vec<RawImport>,
+    ) {
+        let Some(first) = arguments.named_child(0) else {
+            return;
+        };
+        let text = |node: tree_sitter::Node| node.utf8_text(source).unwrap_or_default();
+
+        // `alias Foo.{Bar, Baz}` expands to one module per tuple element.
+        let modules: Vec<String> = match first.kind() {
+            "alias" => vec![scope.expand(text(first))],
+            "dot" => {
+                let (Some(left), Some(right)) = (
+                    first.child_by_field_name("left"),
+                    first.child_by_field_name("right"),
+                ) else {
+                    return;
+                };
+                let base = scope.expand(text(left));
+                match right.kind() {
+                    "alias" => vec![format!("{base}.{}", text(right))],
+                    "tuple" => right
+                        .named_children(&mut right.walk())
+                        .filter(|child| child.kind() == "alias")
+                        .map(|child| format!("{base}.{}", text(child)))
+                        .collect(),
+                    _ => return,
+                }
+            }
+            // Erlang modules (`:crypto`) and computed names are not project modules.
+            _ => return,
+        };
+
+        if directive == "alias" {
+            let explicit_as = arguments
+                .named_children(&mut arguments.walk())
+                .filter(|child| child.kind() == "keywords")
+                .flat_map(|keywords| {
+                    let mut cursor = keywords.walk();
+                    keywords.named_children(&mut cursor).collect::<Vec<_>>()
+                })
+                .find(|pair| {
+                    pair.child_by_field_name("key")
+                        .is_some_and(|key| text(key).trim_end_matches([':', ' ']) == "as")
+                })
+                .and_then(|pair| pair.child_by_field_name("value"))
+                .filter(|value| value.kind() == "alias")
+                .map(|value| text(value).to_string());
+            for module in &modules {
+                let name = match (&explicit_as, modules.len()) {
+                    (Some(name), 1) => name.clone(),
+                    _ => module.rsplit('.').next().unwrap_or(module).to_string(),
+                };
+                scope.aliases.insert(name, module.clone());
+            }
+        }
+
+        for module in modules {
+            let first_segment = module.split('.').next().unwrap_or(&module);
+            let confidence =
+                if ELIXIR_STDLIB_MODULES.contains(&first_segment) || !self.project.owns(&module) {
+                    ImportConfidence::External
+                } else {
+                    ImportConfidence::Resolved
+                };
+            imports.push(RawImport {
+                raw_path: module,
+                source_file: file_path.to_path_buf(),
+                line: call.start_position().row + 1,
+                column: Some(call.start_position().column),
+                kind: ImportKind::Direct,
+                confidence,
+                cfg: None,
+                context: ImportContext::Runtime,
+            });
+        }
+    }
+}
+
+impl Default for ElixirFrontend {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ParseFrontend for ElixirFrontend {
+    fn language(&self) -> tree_sitter::Language {
+        tree_sitter_elixir::LANGUAGE.into()
+    }
+
+    fn extract_imports(&self, source: &[u8], file_path: &Path) -> Vec<RawImport> {
+        let mut parser = tree_sitter::Parser::new();
+        parser
+            .set_language(&self.language())
+            .expect("failed to set Elixir language");
+
+        let tree = match parser.parse(source, None) {
+            Some(t) => t,
+            None => return vec![],
+        };
+
+        let mut imports = Vec::new();
+        let mut scope = ModuleScope::default();
+        self.walk(
+            tree.root_node(),
+            source,
+            file_path,
+            &mut scope,
+            &mut imports,
+        );
+        imports
+    }
+
+    fn resolve(
+        &self,
+        raw: &RawImport,
+        project_root: &Path,
+        project_files: &[PathBuf],
+    ) -> Option<PathBuf> {
+        if raw.confidence != ImportConfidence::Resolved {
+            return None;
+        }
+
+        let mut index = self.file_index.borrow_mut();
+        let files = index.get_or_insert_with(|| {
+            project_files
+                .iter()
+                .map(|file| {
+                    file.strip_prefix(project_root)
+                        .unwrap_or(file)
+                        .to_path_buf()
+                })
+                .collect()
+        });
+        let source_file = raw
+            .source_file
+            .strip_prefix(project_root)
+            .unwrap_or(&raw.source_file);
+
+        self.project
+            .candidates(&raw.raw_path)
+            .into_iter()
+            .find(|candidate| files.contains(candidate))
+            .filter(|target| target != source_file)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn project() -> MixProject {
+        let sources: Vec<PathBuf> = [
+            "apps/shop/lib/shop.ex",
+            "apps/shop/lib/shop/accounts.ex",
+            "apps/shop/lib/shop/accounts/user.ex",
+            "apps/shop_web/lib/shop_web/router.ex",
+        ]
+        .iter()
+        .map(PathBuf::from)
+        .collect();
+        MixProject::from_files(
+            &[
+                PathBuf::from("mix.exs"),
+                PathBuf::from("apps/shop/mix.exs"),
+                PathBuf::from("apps/shop_web/mix.exs"),
+            ],
+            &sources,
+            Path::new(""),
+        )
+    }
+
+    fn extract(source: &str) -> Vec<(String, ImportConfidence)> {
+        ElixirFrontend::with_project(Arc::new(project()))
+            .extract_imports(
+                source.as_bytes(),
+                Path::new("apps/shop_web/lib/shop_web/router.ex"),
+            )
+            .into_iter()
+            .map(|import| (import.raw_path, import.confidence))
+            .collect()
+    }
+
+    #[test]
+    fn indexes_umbrella_lib_roots() {
+        let project = project();
+        assert_eq!(
+            project.lib_roots,
+            vec![
+                PathBuf::from("apps/shop/lib"),
+                PathBuf::from("apps/shop_web/lib"),
+                PathBuf::from("lib"),
+            ]
+        );
+        assert!(project.owns("ShopWeb.Router"));
+        assert!(!project.owns("Phoenix.Router"));
+    }
+
+    #[test]
+    fn extracts_directives_with_alias_expansion() {
+        let imports = extract(
+            r#"defmodule ShopWeb.Router do
+  use Phoenix.Router
+  alias Shop.Accounts
+  alias Shop.Accounts.{User, Token}
+  alias Shop.Mailer, as: M
+  import Accounts.Helpers, only: [fetch: 1]
+  require Logger
+  alias __MODULE__.Helpers
+  alias :crypto, as: Crypto
+
+  defmodule Plug do
+    use M
+  end
+end
+"#,
+        );
+
+        assert_eq!(
+            imports,
+            vec![
+                ("Phoenix.Router".into(), ImportConfidence::External),
+                ("Shop.Accounts".into(), ImportConfidence::Resolved),
+                ("Shop.Accounts.User".into(), ImportConfidence::Resolved),
+                ("Shop.Accounts.Token".into(), ImportConfidence::Resolved),
+                ("Shop.Mailer".into(), ImportConfidence::Resolved),
+                ("Shop.Accounts.Helpers".into(), ImportConfidence::Resolved),
+                ("Logger".into(), ImportConfidence::External),
+                ("ShopWeb.Router.Helpers".into(), ImportConfidence::Resolved),
+                ("Shop.Mailer".into(), ImportConfidence::Resolved),
+            ]
+        );
+    }
+
+    #[test]
+    fn resolves_modules_by_mix_convention() {
+        let frontend = ElixirFrontend::with_project(Arc::new(project()));
+        let files: Vec<PathBuf> = [
+            "apps/shop/lib/shop.ex",
+            "apps/shop/lib/shop/accounts.ex",
+            "apps/shop/lib/shop/accounts/user.ex",
+            "apps/shop_web/lib/shop_web/router.ex",
+        ]
+        .iter()
+        .map(PathBuf::from)
+        .collect();
+        let resolve = |module: &str| {
+            frontend.resolve(
+                &RawImport {
+                    raw_path: module.to_string(),
+                    source_file: PathBuf::from("apps/shop_web/lib/shop_web/router.ex"),
+                    line: 1,
+                    column: None,
+                    kind: ImportKind::Direct,
+                    confidence: ImportConfidence::Resolved,
+                    cfg: None,
+                    context: ImportContext::Runtime,
+                },
+                Path::new(""),
+                &files,
+            )
+        };
+
+        assert_eq!(
+            resolve("Shop.Accounts.User"),
+            Some(PathBuf::from("apps/shop/lib/shop/accounts/user.ex"))
+        );
+        // nested modules fall back to the file of the enclosing module
+        assert_eq!(
+            resolve("Shop.Accounts.User.Query"),
+            Some(PathBuf::from("apps/shop/lib/shop/accounts/user.ex"))
+        );
+        assert_eq!(
+            resolve("Shop.Billing"),
+            Some(PathBuf::from("apps/shop/lib/shop.ex"))
+        );
+        // a module nested in the importing file is not a dependency
+        assert_eq!(resolve("ShopWeb.Router.Helpers"), None);
+    }
+}
@@ -1,46 +1,123 @@
-use crate::analysis_context::RustWorkspaceContext;
+use crate::analysis_context::{AnalysisContext, RustWorkspaceContext};
 use crate::config::ResolvedConfig;
+use crate::errors::{Result, UntangleError};
+use crate::parse::cpp::CppFrontend;
+use crate::parse::csharp::{CSharpFrontend, CSharpWorkspace};
+use crate::parse::custom::CustomFrontend;
+use crate::parse::elixir::ElixirFrontend;
 use crate::parse::go::GoFrontend;
+use crate::parse::jvm::JvmFrontend;
+use crate::parse::php::PhpFrontend;
 use crate::parse::python::PythonFrontend;
 use crate::parse::ruby::RubyFrontend;
 use crate::parse::rust::RustFrontend;
+use crate::parse::typescript::TypeScriptFrontend;
 use crate::parse::ParseFrontend;
 use crate::walk::Language;
 use std::path::PathBuf;
 
-/// Create a ParseFrontend for a given language and config.
+/// Create a ParseFrontend for a given language and config, using the
+/// project-wide resolution data collected in `context`.
 pub fn create_frontend(
     lang: Language,
     config: &ResolvedConfig,
     go_module_path: &Option<String>,
-    rust_workspace: &Option<RustWorkspaceContext>,
-) -> Box<dyn ParseFrontend> {
-    match lang {
+    context: &AnalysisContext,
+) -> Result<Box<dyn ParseFrontend>> {
+    let frontend: Box<dyn ParseFrontend> = match lang {
         Language::Go => {
             let fe = match go_module_path {
-                Some(mp) => GoFrontend::with_module_path(mp.clone()),
+                Some(mp) => GoFrontend::with_module_path(mp.clone()).with_local_modules(
+                    context
+                        .go_workspace
+                        .as_ref()
+                        .map(|workspace| workspace.local_modules(mp))
+                        .unwrap_or_default(),
+                ),
                 None => GoFrontend::new(),
             };
-            Box::new(fe.with_exclude_stdlib(config.go.exclude_stdlib))
+            Box::new(
+                fe.with_exclude_stdlib(config.go.exclude_stdlib)
+                    .with_build_tags(&config.go.build_tags),
+            )
         }
-        Language::Python => Box::new(PythonFrontend::new()),
-        Language::Ruby => Box::new(
-            RubyFrontend::with_load_paths(config.ruby_load_paths())
-                .with_zeitwerk(config.ruby.zeitwerk),
-        ),
-        Language::Rust => Box::new(match rust_workspace {
+        Language::Python => {
+            let fe = match &context.python_source_roots {
+                Some(roots) => PythonFrontend::with_source_roots(roots.clone()),
+                None => PythonFrontend::new(),
+            };
+            let fe = match &context.python_file_index {
+                Some(index) => fe.with_file_index(index.clone()),
+                None => fe,
+            };
+            Box::new(match &context.python_reexports {
+                Some(reexports) => fe.with_reexports(reexports.clone()),
+                None => fe,
+            })
+        }
+        Language::Ruby => {
+            let load_paths = match &context.ruby_load_paths {
+                Some(load_paths) => load_paths.paths(),
+                None => config.ruby_load_paths(),
+            };
+            let fe = RubyFrontend::with_load_paths(load_paths).with_zeitwerk(config.ruby.zeitwerk);
+            Box::new(match &context.ruby_constants {
+                Some(constants) => fe.with_constant_index(constants.clone()),
+                None => fe,
+            })
+        }
+        Language::Rust => Box::new(match &context.rust_workspace {
             Some(workspace) => RustFrontend::with_workspace(workspace.clone()),
             None => RustFrontend::new(),
         }),
-    }
+        Language::TypeScript => Box::new(match &context.typescript_project {
+            Some(project) => TypeScriptFrontend::with_project(project.clone()),
+            None => TypeScriptFrontend::new(),
+        }),
+        Language::Java | Language::Kotlin => {
+            let fe = JvmFrontend::for_language(lang);
+            Box::new(match &context.jvm_index {
+                Some(index) => fe.with_index(index.clone()),
+                None => fe,
+            })
+        }
+        Language::CSharp => Box::new(match &context.csharp_workspace {
+            Some(workspace) => CSharpFrontend::with_workspace(workspace.clone()),
+            None => CSharpFrontend::new(),
+        }),
+        Language::Cpp => Box::new(
+            match &context.cpp_include_paths {
+                Some(paths) => CppFrontend::with_include_paths(paths.clone()),
+                None => CppFrontend::new(),
+            }
+            .with_angle_includes(config.cpp.angle_includes),
+        ),
+        Language::Elixir => Box::new(match &context.mix_project {
+            Some(project) => ElixirFrontend::with_project(project.clone()),
+            None => ElixirFrontend::new(),
+        }),
+        Language::Php => Box::new(match &context.php_autoload {
+            Some(autoload) => PhpFrontend::with_autoload(autoload.clone()),
+            None => PhpFrontend::new(),
+        }),
+        // Grammars are loaded for the languages the context was built for.
+        Language::Custom(_) => Box::new(CustomFrontend::new(
+            context.custom_grammars.get(&lang).cloned().ok_or_else(|| {
+                UntangleError::Config(format!("frontend `{lang}`: grammar is not loaded"))
+            })?,
+        )),
+    };
+    Ok(frontend)
 }
 
-/// Compute the source module path for Go (package-level) vs other languages (file-level).
+/// Compute the source module path for package-level languages (Go, Java,
+/// Kotlin), namespace-level C#, and other languages (file-level).
 pub fn source_module_path(
     file_path: &std::path::Path,
     root: &std::path::Path,
     lang: Language,
     rust_workspace: Option<&RustWorkspaceContext>,
+    csharp_workspace: Option<&CSharpWorkspace>,
 ) -> PathBuf {
     if lang == Language::Rust {
         if let Some(workspace) = rust_workspace {
@@ -53,7 +130,12 @@ pub fn source_module_path(
     }
 
     let relative = file_path.strip_prefix(root).unwrap_or(file_path);
-    if lang == Language::Go {
+    if lang == Language::CSharp {
+        if let Some(module) = csharp_workspace.and_then(|ws| ws.module_for_file(relative)) {
+            return module.clone();
+        }
+    }
+    if matches!(lang, Language::Go | Language::Java | Language::Kotlin) {
         relative.parent().unwrap_or(relative).to_path_buf()
     } else {
         relative.to_path_buf()
@@ -1,8 +1,14 @@
-use crate::parse::common::{ImportConfidence, ImportKind, RawImport};
+use crate::parse::common::{
+    normalize_relative, ImportConfidence, ImportContext, ImportKind, RawImport,
+};
 use crate::parse::ParseFrontend;
+use std::collections::{BTreeMap, HashSet};
 use std::path::{Path, PathBuf};
 use streaming_iterator::StreamingIterator;
 
+pub const GO_MOD_MANIFEST: &str = "go.mod";
+pub const GO_WORK_MANIFEST: &str = "go.work";
+
 /// Parse the module path from go.mod content string.
 pub fn parse_go_mod_module(content: &str) -> Option<String> {
     for line in content.lines() {
@@ -14,11 +20,438 @@ pub fn parse_go_mod_module(content: &str) -> Option<String> {
     None
 }
 
+/// Module path of a third-party package, guessed from the import path:
+/// `host/owner/repo` (two segments for `gopkg.in`), plus a `/vN` major
+/// version suffix.
+fn external_module_path(import_path: &str) -> String {
+    let segments: Vec<&str> = import_path.split('/').collect();
+    let mut len = if segments[0] == "gopkg.in" { 2 } else { 3 }.min(segments.len());
+    let is_major_version = |segment: &&str| {
+        segment
+            .strip_prefix('v')
+            .is_some_and(|n| !n.is_empty() && n.chars().all(|c| c.is_ascii_digit()))
+    };
+    if segments.get(len).is_some_and(is_major_version) {
+        len += 1;
+    }
+    segments[..len].join("/")
+}
+
+/// Operating systems recognised in `_GOOS` file name suffixes.
+const KNOWN_GOOS: &[&str] = &[
+    "aix",
+    "android",
+    "darwin",
+    "dragonfly",
+    "freebsd",
+    "hurd",
+    "illumos",
+    "ios",
+    "js",
+    "linux",
+    "nacl",
+    "netbsd",
+    "openbsd",
+    "plan9",
+    "solaris",
+    "wasip1",
+    "windows",
+    "zos",
+];
+
+/// Architectures recognised in `_GOARCH` file name suffixes.
+const KNOWN_GOARCH: &[&str] = &[
+    "386",
+    "amd64",
+    "amd64p32",
+    "arm",
+    "armbe",
+    "arm64",
+    "arm64be",
+    "loong64",
+    "mips",
+    "mipsle",
+    "mips64",
+    "mips64le",
+    "mips64p32",
+    "mips64p32le",
+    "ppc",
+    "ppc64",
+    "ppc64le",
+    "riscv",
+    "riscv64",
+    "s390",
+    "s390x",
+    "sparc",
+    "sparc64",
+    "wasm",
+];
+
+/// Operating systems that satisfy the `unix` build tag.
+const UNIX_GOOS: &[&str] = &[
+    "aix",
+    "android",
+    "darwin",
+    "dragonfly",
+    "freebsd",
+    "hurd",
+    "illumos",
+    "ios",
+    "linux",
+    "netbsd",
+    "openbsd",
+    "solaris",
+];
+
+/// The `//go:build` expression and package name from a Go file's header
+/// (the comments and blank lines before the package clause).
+fn file_header(source: &str) -> (Option<&str>, Option<&str>) {
+    let mut constraint = None;
+    let mut in_block_comment = false;
+    for line in source.lines() {
+        let line = line.trim();
+        if in_block_comment {
+            in_block_comment = !line.contains("*/");
+            continue;
+        }
+        if let Some(expr) = line.strip_prefix("//go:build ") {
+            constraint.get_or_insert(expr.trim());
+        } else if line.starts_with("/*") {
+            in_block_comment = !line.contains("*/");
+        } else if let Some(rest) = line.strip_prefix("package ") {
+            return (constraint, rest.split_whitespace().next());
+        } else if !line.is_empty() && !line.starts_with("//") {
+            break;
+        }
+    }
+    (constraint, None)
+}
+
+/// The build tags a set of selected tags satisfies, with the tags Go implies
+/// (`android` → `linux`, any Unix GOOS → `unix`, ...).
+#[derive(Clone, Debug)]
+pub struct BuildTags {
+    tags: HashSet<String>,
+}
+
+impl BuildTags {
+    pub fn new(selected: &[String]) -> Self {
+        let mut tags: HashSet<String> = selected.iter().map(|tag| tag.trim().to_string()).collect();
+        for (tag, implied) in [
+            ("android", "linux"),
+            ("ios", "darwin"),
+            ("illumos", "solaris"),
+        ] {
+            if tags.contains(tag) {
+                tags.insert(implied.to_string());
+            }
+        }
+        if UNIX_GOOS.iter().any(|goos| tags.contains(*goos)) {
+            tags.insert("unix".to_string());
+        }
+        Self { tags }
+    }
+
+    fn satisfies(&self, tag: &str) -> bool {
+        // Release tags (`go1.21`) hold for any toolchain new enough to matter.
+        self.tags.contains(tag) || tag.starts_with("go1.")
+    }
+
+    /// Whether a file with this name and contents is part of the build.
+    pub fn includes(&self, file_path: &Path, source: &str) -> bool {
+        self.file_name_satisfied(file_path)
+            && file_header(source).0.is_none_or(|expr| {
+                eval_build_expr(expr, &|tag| self.satisfies(tag)).unwrap_or(true)
+            })
+    }
+
+    /// `name_GOOS.go`, `name_GOARCH.go` and `name_GOOS_GOARCH.go`, also with
+    /// `_test` before the extension.
+    fn file_name_satisfied(&self, file_path: &Path) -> bool {
+        let Some(stem) = file_path.file_stem().and_then(|stem| stem.to_str()) else {
+            return true;
+        };
+        let stem = stem.strip_suffix("_test").unwrap_or(stem);
+        let parts: Vec<&str> = stem.split('_').skip(1).collect();
+        match parts.as_slice() {
+            [.., goos, goarch] if KNOWN_GOOS.contains(goos) && KNOWN_GOARCH.contains(goarch) => {
+                self.satisfies(goos) && self.satisfies(goarch)
+            }
+            [.., last] if KNOWN_GOOS.contains(last) || KNOWN_GOARCH.contains(last) => {
+                self.satisfies(last)
+            }
+            _ => true,
+        }
+    }
+}
+
+/// Evaluate a `//go:build` expression (`linux && (amd64 || arm64) && !cgo`).
+/// Returns None if the expression does not parse.
+fn eval_build_expr(expr: &str, satisfied: &dyn Fn(&str) -> bool) -> Option<bool> {
+    let mut tokens = Vec::new();
+    let mut chars = expr.char_indices().peekable();
+    while let Some((start, c)) = chars.next() {
+        match c {
+            ' ' | '\t' => {}
+            '(' | ')' | '!' => tokens.push(&expr[start..start + 1]),
+            '&' | '|' => {
+                chars.next_if(|(_, next)| *next == c)?;
+                tokens.push(&expr[start..start + 2]);
+            }
+            _ if c.is_alphanumeric() || c == '_' || c == '.' => {
+                let mut end = start + c.len_utf8();
+                while let Some((at, next)) = chars
+                    .next_if(|(_, next)| next.is_alphanumeric() || *next == '_' || *next == '.')
+                {
+                    end = at + next.len_utf8();
+                }
+                tokens.push(&expr[start..end]);
+            }
+            _ => return None,
+        }
+    }
+
+    let mut position = 0;
+    let value = parse_or(&tokens, &mut position, satisfied)?;
+    (position == tokens.len()).then_some(value)
+}
+
+fn parse_or(
+    tokens: &[&str],
+    position: &mut usize,
+    satisfied: &dyn Fn(&str) -> bool,
+) -> Option<bool> {
+    let mut value = parse_and(tokens, position, satisfied)?;
+    while tokens.get(*position) == Some(&"||") {
+        *position += 1;
+        value |= parse_and(tokens, position, satisfied)?;
+    }
+    Some(value)
+}
+
+fn parse_and(
+    tokens: &[&str],
+    position: &mut usize,
+    satisfied: &dyn Fn(&str) -> bool,
+) -> Option<bool> {
+    let mut value = parse_not(tokens, position, satisfied)?;
+    while tokens.get(*position) == Some(&"&&") {
+        *position += 1;
+        value &= parse_not(tokens, position, satisfied)?;
+    }
+    Some(value)
+}
+
+fn parse_not(
+    tokens: &[&str],
+    position: &mut usize,
+    satisfied: &dyn Fn(&str) -> bool,
+) -> Option<bool> {
+    let token = *tokens.get(*position)?;
+    *position += 1;
+    match token {
+        "!" => parse_not(tokens, position, satisfied).map(|value| !value),
+        "(" => {
+            let value = parse_or(tokens, position, satisfied)?;
+            (tokens.get(*position) == Some(&")")).then(|| {
+                *position += 1;
+                value
+            })
+        }
+        ")" | "&&" | "||" => None,
+        tag => Some(satisfied(tag)),
+    }
+}
+
+/// Arguments of a `go.mod`/`go.work` directive, in both the single-line
+/// (`use ./api`) and block (`use ( ./api ./web )`) forms.
+fn directive_args(content: &str, directive: &str) -> Vec<String> {
+    let mut args = Vec::new();
+    let mut in_block = false;
+    for line in content.lines() {
+        let line = line.split("//").next().unwrap_or_default().trim();
+        if in_block {
+            if line.starts_with(')') {
+                in_block = false;
+            } else if !line.is_empty() {
+                args.push(line.to_string());
+            }
+            continue;
+        }
+        let Some(rest) = line.strip_prefix(directive) else {
+            continue;
+        };
+        if !rest.starts_with([' ', '\t', '(']) {
+            continue;
+        }
+        let rest = rest.trim();
+        if rest == "(" {
+            in_block = true;
+        } else if !rest.is_empty() {
+            args.push(rest.to_string());
+        }
+    }
+    args
+}
+
+/// Local `replace` targets: `example.com/x [v1.0.0] => ../x` becomes
+/// `("example.com/x", "../x")`. Replacements by another module version are
+/// skipped.
+fn local_replaces(content: &str) -> Vec<(String, String)> {
+    directive_args(content, "replace")
+        .iter()
+        .filter_map(|arg| {
+            let (from, to) = arg.split_once("=>")?;
+            let module = from.split_whitespace().next()?;
+            let target = to.split_whitespace().next()?;
+            let target = target.trim_matches('"');
+            let is_local = target.starts_with("./") || target.starts_with("../") || target == ".";
+            is_local.then(|| (module.to_string(), target.to_string()))
+        })
+        .collect()
+}
+
+/// A module path that resolves to a directory in the project.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LocalModule {
+    pub path: String,
+    /// Module directory relative to the project root
+    pub dir: PathBuf,
+}
+
+#[derive(Clone, Debug, Default)]
+struct GoModule {
+    path: String,
+    replaces: Vec<LocalModule>,
+}
+
+/// The Go modules of a project, with the `go.work` workspaces and local
+/// `replace` directives that make them visible to each other.
+#[derive(Clone, Debug, Default)]
+pub struct GoWorkspace {
+    /// Module root → module, keyed by directory relative to the project root
+    modules: BTreeMap<PathBuf, GoModule>,
+    /// `go.work` members and replacements, one entry per `go.work`
+    workspaces: Vec<(Vec<PathBuf>, Vec<LocalModule>)>,
+}
+
+impl GoWorkspace {
+    /// Build from `go.mod` and `go.work` files given as (path relative to
+    /// the project root, contents) pairs.
+    pub fn load(go_mods: &[(PathBuf, String)], go_works: &[(PathBuf, String)]) -> Self {
+        let mut modules = BTreeMap::new();
+        for (manifest, content) in go_mods {
+            let Some(path) = parse_go_mod_module(content) else {
+                continue;
+            };
+            let dir = manifest.parent().unwrap_or(Path::new("")).to_path_buf();
+            let replaces = Self::replaces(&dir, content);
+            modules.insert(dir, GoModule { path, replaces });
+        }
+
+        let workspaces = go_works
+            .iter()
+            .map(|(manifest, content)| {
+                let dir = manifest.parent().unwrap_or(Path::new(""));
+                let members = directive_args(content, "use")
+                    .iter()
+                    .filter_map(|member| normalize_relative(&dir.join(member.trim_matches('"'))))
+                    .collect();
+                (members, Self::replaces(dir, content))
+            })
+            .collect();
+
+        Self {
+            modules,
+            workspaces,
+        }
+    }
+
+    /// Find every `go.mod` and `go.work` under `project_root`.
+    pub fn discover(project_root: &Path) -> Self {
+        let walker = ignore::WalkBuilder::new(project_root)
+            .hidden(false)
+            .git_ignore(true)
+            .build();
+
+        let mut go_mods = Vec::new();
+        let mut go_works = Vec::new();
+        for entry in walker.flatten() {
+            let path = entry.path();
+            if !path.is_file() {
+                continue;
+            }
+            let manifests = match entry.file_name().to_str() {
+                Some(GO_MOD_MANIFEST) => &mut go_mods,
+                Some(GO_WORK_MANIFEST) => &mut go_works,
+                _ => continue,
+            };
+            if let (Ok(relative), Ok(content)) = (
+                path.strip_prefix(project_root),
+                std::fs::read_to_string(path),
+            ) {
+                manifests.push((relative.to_path_buf(), content));
+            }
+        }
+        Self::load(&go_mods, &go_works)
+    }
+
+    fn replaces(dir: &Path, content: &str) -> Vec<LocalModule> {
+        local_replaces(content)
+            .into_iter()
+            .filter_map(|(path, target)| {
+                Some(LocalModule {
+                    path,
+                    dir: normalize_relative(&dir.join(target))?,
+                })
+            })
+            .collect()
+    }
+
+    /// Modules whose imports resolve inside the project for code in the
+    /// module with path `module_path`: the module itself, the other members
+    /// of a `go.work` that uses it, and local `replace` targets.
+    pub fn local_modules(&self, module_path: &str) -> Vec<LocalModule> {
+        let Some((dir, module)) = self
+            .modules
+            .iter()
+            .find(|(_, module)| module.path == module_path)
+        else {
+            return Vec::new();
+        };
+
+        let mut local = vec![LocalModule {
+            path: module.path.clone(),
+            dir: dir.clone(),
+        }];
+        for (members, replaces) in &self.workspaces {
+            if !members.contains(dir) {
+                continue;
+            }
+            for member in members {
+                if let Some(other) = self.modules.get(member) {
+                    local.push(LocalModule {
+                        path: other.path.clone(),
+                        dir: member.clone(),
+                    });
+                }
+            }
+            local.extend(replaces.iter().cloned());
+        }
+        local.extend(module.replaces.iter().cloned());
+        local
+    }
+}
+
 pub struct GoFrontend {
     /// Module path from go.mod (e.g., "github.com/user/project")
     module_path: Option<String>,
     /// Whether to exclude stdlib imports (default: true)
     exclude_stdlib: bool,
+    /// Modules resolved to project directories, including `module_path`
+    local_modules: Vec<LocalModule>,
+    /// Selected build tags; None keeps every file
+    build_tags: Option<BuildTags>,
 }
 
 impl GoFrontend {
@@ -26,17 +459,40 @@ impl GoFrontend {
         Self {
             module_path: None,
             exclude_stdlib: true,
+            local_modules: Vec::new(),
+            build_tags: None,
         }
     }
 
     /// Create a GoFrontend with the module path read from go.mod.
     pub fn with_module_path(module_path: String) -> Self {
         Self {
+            local_modules: vec![LocalModule {
+                path: module_path.clone(),
+                dir: PathBuf::new(),
+            }],
             module_path: Some(module_path),
             exclude_stdlib: true,
+            build_tags: None,
         }
     }
 
+    /// Skip files excluded by `build_tags` (`[go] build_tags`); an empty set
+    /// keeps every file.
+    pub fn with_build_tags(mut self, build_tags: &[String]) -> Self {
+        self.build_tags = (!build_tags.is_empty()).then(|| BuildTags::new(build_tags));
+        self
+    }
+
+    /// Resolve imports of `local_modules` (from [`GoWorkspace::local_modules`])
+    /// to their directories. Earlier entries win over later ones.
+    pub fn with_local_modules(mut self, local_modules: Vec<LocalModule>) -> Self {
+        if !local_modules.is_empty() {
+            self.local_modules = local_modules;
+        }
+        self
+    }
+
     /// Set whether to exclude stdlib imports.
     pub fn with_exclude_stdlib(mut self, exclude: bool) -> Self {
         self.exclude_stdlib = exclude;
@@ -50,12 +506,22 @@ impl GoFrontend {
         parse_go_mod_module(&content)
     }
 
+    /// The local module providing `import_path`: the one with the longest
+    /// matching module path, as Go picks.
+    fn local_module<'a>(&self, import_path: &'a str) -> Option<(&LocalModule, &'a str)> {
+        self.local_modules
+            .iter()
+            .filter_map(|module| {
+                let rest = import_path.strip_prefix(module.path.as_str())?;
+                (rest.is_empty() || rest.starts_with('/')).then_some((module, rest))
+            })
+            .max_by_key(|(module, _)| module.path.len())
+    }
+
     /// Classify an import path as internal, stdlib, or external.
     fn classify_import(&self, import_path: &str) -> ImportConfidence {
-        if let Some(ref module_path) = self.module_path {
-            if import_path.starts_with(module_path) {
-                return ImportConfidence::Resolved;
-            }
+        if self.local_module(import_path).is_some() {
+            return ImportConfidence::Resolved;
         }
         // stdlib packages have no dots in their path
         if !import_path.contains('.') {
@@ -82,6 +548,12 @@ impl ParseFrontend for GoFrontend {
     }
 
     fn extract_imports(&self, source: &[u8], file_path: &Path) -> Vec<RawImport> {
+        if let Some(build_tags) = &self.build_tags {
+            if !build_tags.includes(file_path, &String::from_utf8_lossy(source)) {
+                return vec![];
+            }
+        }
+
         let mut parser = tree_sitter::Parser::new();
         parser
             .set_language(&self.language())
@@ -122,6 +594,8 @@ impl ParseFrontend for GoFrontend {
                     column: Some(node.start_position().column),
                     kind: ImportKind::Direct,
                     confidence,
+                    cfg: None,
+                    context: ImportContext::Runtime,
                 });
             }
         }
@@ -129,6 +603,27 @@ impl ParseFrontend for GoFrontend {
         imports
     }
 
+    /// Black-box tests (`package foo_test` in `foo_test.go`) form their own
+    /// package next to `foo`, named `<dir>_test`.
+    fn source_module(&self, source: &[u8], file_path: &Path, module: PathBuf) -> PathBuf {
+        let is_test_file = file_path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| name.ends_with("_test.go"));
+        if !is_test_file {
+            return module;
+        }
+        let source = String::from_utf8_lossy(source);
+        match (file_header(&source).1, module.file_name()) {
+            (Some(package), Some(dir)) if package.ends_with("_test") => {
+                let mut name = dir.to_os_string();
+                name.push("_test");
+                module.with_file_name(name)
+            }
+            _ => module,
+        }
+    }
+
     fn resolve(
         &self,
         raw: &RawImport,
@@ -139,17 +634,15 @@ impl ParseFrontend for GoFrontend {
             return None;
         }
 
-        if let Some(ref module_path) = self.module_path {
-            let relative = raw
-                .raw_path
-                .strip_prefix(module_path)?
-                .trim_start_matches('/');
+        if self.module_path.is_some() {
+            let (module, relative) = self.local_module(&raw.raw_path)?;
+            let target = module.dir.join(relative.trim_start_matches('/'));
 
-            if relative.is_empty() {
+            if target.as_os_str().is_empty() {
                 return None;
             }
 
-            Some(PathBuf::from(relative))
+            Some(target)
         } else {
             // Fallback: no go.mod — try directory-based matching
             // Check if any project file lives under a directory matching the import path
@@ -167,6 +660,15 @@ impl ParseFrontend for GoFrontend {
             None
         }
     }
+
+    fn external_package(&self, raw: &RawImport) -> Option<String> {
+        let import_path = raw.raw_path.as_str();
+        let host = import_path.split('/').next()?;
+        if self.local_module(import_path).is_some() || !host.contains('.') {
+            return None;
+        }
+        Some(external_module_path(import_path))
+    }
 }
 
 #[cfg(test)]
@@ -230,6 +732,41 @@ import (
         );
     }
 
+    #[test]
+    fn external_packages_are_grouped_by_module_path() {
+        let frontend = GoFrontend::with_module_path("github.com/user/project".into());
+        let package = |path: &str| {
+            frontend.external_package(&RawImport {
+                raw_path: path.to_string(),
+                source_file: PathBuf::from("main.go"),
+                line: 1,
+                column: None,
+                kind: ImportKind::Direct,
+                confidence: frontend.classify_import(path),
+                cfg: None,
+                context: ImportContext::Runtime,
+            })
+        };
+        assert_eq!(package("fmt"), None);
+        assert_eq!(package("github.com/user/project/pkg/foo"), None);
+        assert_eq!(
+            package("github.com/aws/aws-sdk-go-v2/service/s3"),
+            Some("github.com/aws/aws-sdk-go-v2".to_string())
+        );
+        assert_eq!(
+            package("github.com/jackc/pgx/v5/pgxpool"),
+            Some("github.com/jackc/pgx/v5".to_string())
+        );
+        assert_eq!(
+            package("gopkg.in/yaml.v3"),
+            Some("gopkg.in/yaml.v3".to_string())
+        );
+        assert_eq!(
+            package("golang.org/x/sync"),
+            Some("golang.org/x/sync".to_string())
+        );
+    }
+
     #[test]
     fn classifies_internal_as_resolved() {
         let frontend = GoFrontend::with_module_path("github.com/user/project".into());
@@ -249,6 +786,8 @@ import (
             column: None,
             kind: ImportKind::Direct,
             confidence: ImportConfidence::Resolved,
+            cfg: None,
+            context: ImportContext::Runtime,
         };
         let resolved = frontend.resolve(&raw, Path::new("."), &[]);
         assert_eq!(resolved, Some(PathBuf::from("pkg/foo")));
@@ -264,7 +803,174 @@ import (
             column: None,
             kind: ImportKind::Direct,
             confidence: ImportConfidence::External,
+            cfg: None,
+            context: ImportContext::Runtime,
         };
         assert_eq!(frontend.resolve(&raw, Path::new("."), &[]), None);
     }
+
+    #[test]
+    fn parses_go_work_and_replace_directives() {
+        let go_work =
+            "go 1.22\n\nuse ./tools // generators\nuse (\n\t./services/api\n\t./libs/auth\n)\n";
+        assert_eq!(
+            directive_args(go_work, "use"),
+            vec!["./tools", "./services/api", "./libs/auth"]
+        );
+
+        let go_mod = r#"module example.com/api
+
+replace example.com/billing => ../../libs/billing
+replace (
+	example.com/auth v0.1.0 => ../../libs/auth
+	example.com/log => example.com/log/v2 v2.0.0
+)
+"#;
+        assert_eq!(
+            local_replaces(go_mod),
+            vec![
+                ("example.com/billing".into(), "../../libs/billing".into()),
+                ("example.com/auth".into(), "../../libs/auth".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn resolves_imports_across_workspace_and_replaced_modules() {
+        let workspace = GoWorkspace::load(
+            &[
+                (
+                    PathBuf::from("services/api/go.mod"),
+                    "module example.com/api\n\nreplace example.com/billing => ../../libs/billing\n"
+                        .into(),
+                ),
+                (
+                    PathBuf::from("libs/auth/go.mod"),
+                    "module example.com/auth\n".into(),
+                ),
+                (
+                    PathBuf::from("libs/billing/go.mod"),
+                    "module example.com/billing\n".into(),
+                ),
+            ],
+            &[(
+                PathBuf::from("go.work"),
+                "use (\n\t./services/api\n\t./libs/auth\n)\n".into(),
+            )],
+        );
+        let frontend = GoFrontend::with_module_path("example.com/api".into())
+            .with_local_modules(workspace.local_modules("example.com/api"));
+        let resolve = |path: &str| {
+            let raw = RawImport {
+                raw_path: path.into(),
+                source_file: PathBuf::from("services/api/main.go"),
+                line: 3,
+                column: None,
+                kind: ImportKind::Direct,
+                confidence: frontend.classify_import(path),
+                cfg: None,
+                context: ImportContext::Runtime,
+            };
+            frontend.resolve(&raw, Path::new("."), &[])
+        };
+
+        assert_eq!(
+            resolve("example.com/api/internal/handlers"),
+            Some(PathBuf::from("services/api/internal/handlers"))
+        );
+        assert_eq!(
+            resolve("example.com/auth/token"),
+            Some(PathBuf::from("libs/auth/token"))
+        );
+        assert_eq!(
+            resolve("example.com/billing/invoice"),
+            Some(PathBuf::from("libs/billing/invoice"))
+        );
+        assert_eq!(resolve("example.com/authz/policy"), None);
+
+        // `libs/auth` is not in a workspace with `libs/billing`.
+        let auth = GoFrontend::with_module_path("example.com/auth".into())
+            .with_local_modules(workspace.local_modules("example.com/auth"));
+        assert_eq!(
+            auth.classify_import("example.com/api/internal/handlers"),
+            ImportConfidence::Resolved
+        );
+        assert_eq!(
+            auth.classify_import("example.com/billing/invoice"),
+            ImportConfidence::External
+        );
+    }
+
+    #[test]
+    fn build_tags_evaluate_constraints_and_file_suffixes() {
+        let tags = BuildTags::new(&["android".to_string(), "arm64".to_string()]);
+        let includes = |name: &str, source: &str| tags.includes(Path::new(name), source);
+
+        assert!(includes("fs.go", "package fs\n"));
+        assert!(includes("fs_linux.go", "package fs\n"));
+        assert!(includes("fs_android_arm64_test.go", "package fs\n"));
+        assert!(!includes("fs_windows.go", "package fs\n"));
+        assert!(!includes("fs_linux_amd64.go", "package fs\n"));
+        // A lone GOOS/GOARCH name is not a suffix.
+        assert!(includes("windows.go", "package fs\n"));
+
+        assert!(includes(
+            "fs.go",
+            "// Copyright\n\n//go:build unix && (arm64 || amd64) && go1.21\n\npackage fs\n"
+        ));
+        assert!(!includes(
+            "fs.go",
+            "//go:build linux && !cgo && cgo\npackage fs\n"
+        ));
+        assert!(!includes(
+            "fs.go",
+            "/* generated\n*/\n//go:build !linux\npackage fs\n"
+        ));
+        // Only the header counts.
+        assert!(includes("fs.go", "package fs\n\n//go:build windows\n"));
+        // Malformed constraints keep the file.
+        assert!(includes("fs.go", "//go:build linux &&\npackage fs\n"));
+    }
+
+    #[test]
+    fn build_tags_skip_imports_of_excluded_files() {
+        let frontend = GoFrontend::with_module_path("example.com/shop".into())
+            .with_build_tags(&["windows".to_string()]);
+        let source =
+            b"//go:build linux\n\npackage store\n\nimport \"example.com/shop/platform/linux\"\n";
+        assert!(frontend
+            .extract_imports(source, Path::new("store/fs.go"))
+            .is_empty());
+        assert_eq!(
+            GoFrontend::with_module_path("example.com/shop".into())
+                .extract_imports(source, Path::new("store/fs.go"))
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn external_test_packages_get_their_own_module() {
+        let frontend = GoFrontend::new();
+        let module = |name: &str, source: &str| {
+            frontend.source_module(
+                source.as_bytes(),
+                Path::new(name),
+                PathBuf::from("pkg/store"),
+            )
+        };
+
+        assert_eq!(
+            module("pkg/store/api_test.go", "package store_test\n"),
+            PathBuf::from("pkg/store_test")
+        );
+        assert_eq!(
+            module("pkg/store/store_test.go", "package store\n"),
+            PathBuf::from("pkg/store")
+        );
+        assert_eq!(
+            module("pkg/store/store.go", "package store_test\n"),
+            PathBuf::from("pkg/store")
+        );
+    }
 }
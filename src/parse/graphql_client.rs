@@ -83,6 +83,7 @@ fn detect_operation_in_line(
                         file: file_path.to_path_buf(),
                         line: line_num,
                         column: None,
+                        cfg: None,
                     },
                 });
             }
@@ -257,6 +258,7 @@ GRAPHQL
                     file: PathBuf::from("app.py"),
                     line: 10,
                     column: None,
+                    cfg: None,
                 },
             },
             GraphqlClientUsage {
@@ -267,6 +269,7 @@ GRAPHQL
                     file: PathBuf::from("app.py"),
                     line: 20,
                     column: None,
+                    cfg: None,
                 },
             },
         ];
@@ -0,0 +1,360 @@
+use crate::parse::common::{ImportConfidence, ImportContext, ImportKind, RawImport};
+use crate::parse::ParseFrontend;
+use crate::walk::Language;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// Top-level package segments that belong to the JDK or language runtimes.
+const PLATFORM_PACKAGES: &[&str] = &["java", "javax", "jdk", "sun", "kotlin", "kotlinx"];
+
+/// Package directories and source roots for the Java/Kotlin files in a project.
+///
+/// JVM sources are modelled at package level (like Go): every directory that
+/// contains a source file is a node, and imports resolve to the directory of
+/// the imported package beneath one of the detected source roots.
+#[derive(Clone, Debug, Default)]
+pub struct JvmSourceIndex {
+    /// Source roots relative to the project root (e.g. `service/src/main/java`)
+    pub roots: Vec<PathBuf>,
+    /// Directories (relative to the project root) that contain source files
+    pub package_dirs: HashSet<PathBuf>,
+}
+
+impl JvmSourceIndex {
+    /// Build the index from Java/Kotlin source files (absolute or relative).
+    pub fn from_files<'a>(
+        files: impl IntoIterator<Item = &'a PathBuf>,
+        project_root: &Path,
+    ) -> Self {
+        let mut roots: Vec<PathBuf> = Vec::new();
+        let mut package_dirs = HashSet::new();
+
+        for file in files {
+            let relative = file.strip_prefix(project_root).unwrap_or(file);
+            let Some(dir) = relative.parent() else {
+                continue;
+            };
+            if let Some(root) = source_root_of(dir) {
+                if !roots.contains(&root) {
+                    roots.push(root);
+                }
+            }
+            package_dirs.insert(dir.to_path_buf());
+        }
+        roots.sort();
+
+        Self {
+            roots,
+            package_dirs,
+        }
+    }
+
+    /// Resolve a dotted import path to the package directory it refers to.
+    ///
+    /// The longest prefix that names a known package wins, so class imports
+    /// (`a.b.C`), nested classes (`a.b.C.D`), static members (`a.b.C.m`) and
+    /// wildcards (`a.b.*`) all land on package `a.b`.
+    pub fn resolve_package(&self, import_path: &str) -> Option<PathBuf> {
+        let segments: Vec<&str> = import_path
+            .split('.')
+            .filter(|segment| !segment.is_empty() && *segment != "*")
+            .collect();
+
+        for len in (1..=segments.len()).rev() {
+            let relative: PathBuf = segments[..len].iter().collect();
+            for root in &self.roots {
+                let candidate = root.join(&relative);
+                if self.package_dirs.contains(&candidate) {
+                    return Some(candidate);
+                }
+            }
+        }
+
+        // Non-standard layouts: match package directories by path suffix.
+        for len in (2..=segments.len()).rev() {
+            let relative: PathBuf = segments[..len].iter().collect();
+            let mut matches: Vec<&PathBuf> = self
+                .package_dirs
+                .iter()
+                .filter(|dir| dir.ends_with(&relative))
+                .collect();
+            matches.sort();
+            if let Some(found) = matches.first() {
+                return Some((*found).clone());
+            }
+        }
+
+        None
+    }
+}
+
+/// Return the Maven/Gradle source root (`.../src/<set>/java|kotlin`) that
+/// contains `dir`, if any.
+fn source_root_of(dir: &Path) -> Option<PathBuf> {
+    let components: Vec<&std::ffi::OsStr> = dir.iter().collect();
+    (2..components.len()).find_map(|idx| {
+        let is_root = matches!(components[idx].to_str(), Some("java" | "kotlin"))
+            && components[idx - 2] == "src";
+        is_root.then(|| components[..=idx].iter().collect())
+    })
+}
+
+pub struct JvmFrontend {
+    language: Language,
+    index: Option<Arc<JvmSourceIndex>>,
+}
+
+impl JvmFrontend {
+    pub fn java() -> Self {
+        Self {
+            language: Language::Java,
+            index: None,
+        }
+    }
+
+    pub fn kotlin() -> Self {
+        Self {
+            language: Language::Kotlin,
+            index: None,
+        }
+    }
+
+    /// Create a frontend for `language` (Java or Kotlin).
+    pub fn for_language(language: Language) -> Self {
+        match language {
+            Language::Kotlin => Self::kotlin(),
+            _ => Self::java(),
+        }
+    }
+
+    /// Resolve imports against the given project-wide source index. Without
+    /// one, no import resolves.
+    pub fn with_index(mut self, index: Arc<JvmSourceIndex>) -> Self {
+        self.index = Some(index);
+        self
+    }
+
+    fn classify_import(import_path: &str) -> ImportConfidence {
+        let first_segment = import_path.split('.').next().unwrap_or(import_path);
+        if PLATFORM_PACKAGES.contains(&first_segment) {
+            ImportConfidence::External
+        } else {
+            ImportConfidence::Resolved
+        }
+    }
+
+    /// Normalize the text of an import declaration to its dotted path:
+    /// `import static a.b.C.m;` → `a.b.C.m`, `import a.b.C as D` → `a.b.C`.
+    fn import_path(text: &str) -> String {
+        let text = text.trim().trim_end_matches(';');
+        let text = text.strip_prefix("import").unwrap_or(text).trim_start();
+        let text = text.strip_prefix("static ").unwrap_or(text);
+        let text = text.split(" as ").next().unwrap_or(text);
+        text.chars().filter(|c| !c.is_whitespace()).collect()
+    }
+}
+
+impl ParseFrontend for JvmFrontend {
+    fn language(&self) -> tree_sitter::Language {
+        match self.language {
+            Language::Kotlin => tree_sitter_kotlin_ng::LANGUAGE.into(),
+            _ => tree_sitter_java::LANGUAGE.into(),
+        }
+    }
+
+    fn extract_imports(&self, source: &[u8], file_path: &Path) -> Vec<RawImport> {
+        let mut parser = tree_sitter::Parser::new();
+        parser
+            .set_language(&self.language())
+            .expect("failed to set JVM language");
+
+        let tree = match parser.parse(source, None) {
+            Some(t) => t,
+            None => return vec![],
+        };
+
+        let import_kind = match self.language {
+            Language::Kotlin => "import",
+            _ => "import_declaration",
+        };
+
+        let mut imports = Vec::new();
+        let mut stack = vec![tree.root_node()];
+        while let Some(node) = stack.pop() {
+            if node.kind() == import_kind && node.is_named() {
+                let text = node.utf8_text(source).unwrap_or_default();
+                let raw_path = Self::import_path(text);
+                if !raw_path.is_empty() {
+                    imports.push(RawImport {
+                        confidence: Self::classify_import(&raw_path),
+                        raw_path,
+                        source_file: file_path.to_path_buf(),
+                        line: node.start_position().row + 1,
+                        column: Some(node.start_position().column),
+                        kind: ImportKind::Direct,
+                        cfg: None,
+                        context: ImportContext::Runtime,
+                    });
+                }
+                continue;
+            }
+            let mut cursor = node.walk();
+            let children: Vec<_> = node.children(&mut cursor).collect();
+            stack.extend(children.into_iter().rev());
+        }
+
+        imports
+    }
+
+    fn resolve(
+        &self,
+        raw: &RawImport,
+        project_root: &Path,
+        _project_files: &[PathBuf],
+    ) -> Option<PathBuf> {
+        if raw.confidence != ImportConfidence::Resolved {
+            return None;
+        }
+
+        let index = self.index.as_ref()?;
+        let target = index.resolve_package(&raw.raw_path)?;
+        let source_dir = raw
+            .source_file
+            .strip_prefix(project_root)
+            .unwrap_or(&raw.source_file)
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or_default();
+
+        // References within the same package are not dependencies.
+        (target != source_dir).then_some(target)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn paths(imports: &[RawImport]) -> Vec<&str> {
+        imports.iter().map(|i| i.raw_path.as_str()).collect()
+    }
+
+    fn index(files: &[&str]) -> JvmSourceIndex {
+        let files: Vec<PathBuf> = files.iter().map(PathBuf::from).collect();
+        JvmSourceIndex::from_files(&files, Path::new(""))
+    }
+
+    fn raw(import_path: &str, source_file: &str) -> RawImport {
+        RawImport {
+            raw_path: import_path.to_string(),
+            source_file: PathBuf::from(source_file),
+            line: 1,
+            column: None,
+            kind: ImportKind::Direct,
+            confidence: JvmFrontend::classify_import(import_path),
+            cfg: None,
+            context: ImportContext::Runtime,
+        }
+    }
+
+    #[test]
+    fn extracts_java_imports() {
+        let source = br#"package com.acme.api;
+
+import java.util.List;
+import com.acme.core.Service;
+import static com.acme.core.Util.format;
+import com.acme.model.*;
+
+public class Handler {}
+"#;
+        let imports = JvmFrontend::java().extract_imports(source, Path::new("Handler.java"));
+        assert_eq!(
+            paths(&imports),
+            vec![
+                "java.util.List",
+                "com.acme.core.Service",
+                "com.acme.core.Util.format",
+                "com.acme.model.*"
+            ]
+        );
+        assert_eq!(imports[0].confidence, ImportConfidence::External);
+        assert_eq!(imports[1].confidence, ImportConfidence::Resolved);
+        assert_eq!(imports[1].line, 4);
+    }
+
+    #[test]
+    fn extracts_kotlin_imports() {
+        let source = br#"package com.acme.api
+
+import kotlin.collections.List
+import com.acme.core.Service
+import com.acme.core.Util as U
+import com.acme.model.*
+
+class Handler
+"#;
+        let imports = JvmFrontend::kotlin().extract_imports(source, Path::new("Handler.kt"));
+        assert_eq!(
+            paths(&imports),
+            vec![
+                "kotlin.collections.List",
+                "com.acme.core.Service",
+                "com.acme.core.Util",
+                "com.acme.model.*"
+            ]
+        );
+        assert_eq!(imports[0].confidence, ImportConfidence::External);
+    }
+
+    #[test]
+    fn detects_maven_and_gradle_source_roots() {
+        let index = index(&[
+            "svc/src/main/java/com/acme/api/Handler.java",
+            "svc/src/main/kotlin/com/acme/core/Service.kt",
+        ]);
+        assert_eq!(
+            index.roots,
+            vec![
+                PathBuf::from("svc/src/main/java"),
+                PathBuf::from("svc/src/main/kotlin")
+            ]
+        );
+    }
+
+    #[test]
+    fn resolves_class_static_and_wildcard_imports_to_packages() {
+        let index = index(&[
+            "src/main/java/com/acme/api/Handler.java",
+            "src/main/java/com/acme/core/Service.java",
+            "src/main/java/com/acme/core/Util.java",
+            "src/main/java/com/acme/model/User.java",
+        ]);
+        let frontend = JvmFrontend::java().with_index(Arc::new(index));
+        let source = "src/main/java/com/acme/api/Handler.java";
+        let resolve = |path: &str| frontend.resolve(&raw(path, source), Path::new(""), &[]);
+
+        let core = Some(PathBuf::from("src/main/java/com/acme/core"));
+        assert_eq!(resolve("com.acme.core.Service"), core);
+        assert_eq!(resolve("com.acme.core.Util.format"), core);
+        assert_eq!(resolve("com.acme.core.Service.Inner"), core);
+        assert_eq!(
+            resolve("com.acme.model.*"),
+            Some(PathBuf::from("src/main/java/com/acme/model"))
+        );
+        assert_eq!(resolve("com.acme.api.Other"), None);
+        assert_eq!(resolve("org.thirdparty.Client"), None);
+        assert_eq!(resolve("java.util.List"), None);
+    }
+
+    #[test]
+    fn resolves_non_standard_layouts_by_suffix() {
+        let index = index(&["app/com/acme/core/Service.kt", "app/com/acme/api/Api.kt"]);
+        assert!(index.roots.is_empty());
+        assert_eq!(
+            index.resolve_package("com.acme.core.Service"),
+            Some(PathBuf::from("app/com/acme/core"))
+        );
+    }
+}
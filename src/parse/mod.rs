@@ -1,14 +1,22 @@
+pub mod cfg;
 pub mod common;
+pub mod cpp;
+pub mod csharp;
+pub mod custom;
+pub mod elixir;
 pub mod factory;
 pub mod go;
 pub mod graphql;
 pub mod graphql_client;
+pub mod jvm;
 pub mod openapi;
+pub mod php;
 pub mod python;
 pub mod resolver;
 pub mod rest_client;
 pub mod ruby;
 pub mod rust;
+pub mod typescript;
 
 pub use common::RawImport;
 
@@ -22,6 +30,18 @@ pub trait ParseFrontend {
     /// Extract raw imports from a single file's source bytes.
     fn extract_imports(&self, source: &[u8], file_path: &Path) -> Vec<RawImport>;
 
+    /// Graph node for a file, given `module`, the node derived from its path.
+    /// Frontends override this when the contents decide, e.g. Go external
+    /// test packages.
+    fn source_module(
+        &self,
+        _source: &[u8],
+        _file_path: &Path,
+        module: std::path::PathBuf,
+    ) -> std::path::PathBuf {
+        module
+    }
+
     /// Resolve a raw import to a canonical project-internal module path.
     /// Returns None if the import is external/unresolvable.
     fn resolve(
@@ -30,4 +50,26 @@ pub trait ParseFrontend {
         project_root: &Path,
         project_files: &[std::path::PathBuf],
     ) -> Option<std::path::PathBuf>;
+
+    /// Every module a raw import resolves to. A Python `from pkg import a, b`
+    /// statement can name several submodules; other imports resolve to at
+    /// most one, the result of `resolve`.
+    fn resolve_targets(
+        &self,
+        raw: &RawImport,
+        project_root: &Path,
+        project_files: &[std::path::PathBuf],
+    ) -> Vec<std::path::PathBuf> {
+        self.resolve(raw, project_root, project_files)
+            .into_iter()
+            .collect()
+    }
+
+    /// Third-party package an import outside the project belongs to: the
+    /// top-level package, Go module path or crate name. None for project
+    /// imports, the standard library, and languages without a notion of
+    /// packages.
+    fn external_package(&self, _raw: &RawImport) -> Option<String> {
+        None
+    }
 }
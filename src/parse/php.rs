@@ -0,0 +1,672 @@
+use crate::parse::common::{
+    normalize_relative, ImportConfidence, ImportContext, ImportKind, RawImport,
+};
+use crate::parse::ParseFrontend;
+use serde::Deserialize;
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// Composer manifest file name.
+pub const COMPOSER_MANIFEST: &str = "composer.json";
+
+/// PSR-4 autoload maps collected from every `composer.json` in a project.
+///
+/// Each entry maps a namespace prefix (without trailing `\`) to base
+/// directories relative to the project root. Entries are kept sorted by
+/// prefix length, longest first, so the most specific prefix wins.
+#[derive(Clone, Debug, Default)]
+pub struct ComposerAutoload {
+    pub psr4: Vec<(String, Vec<PathBuf>)>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct ComposerManifest {
+    #[serde(default)]
+    autoload: ComposerAutoloadSection,
+    #[serde(default, rename = "autoload-dev")]
+    autoload_dev: ComposerAutoloadSection,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct ComposerAutoloadSection {
+    #[serde(default, rename = "psr-4")]
+    psr4: HashMap<String, Psr4Dirs>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum Psr4Dirs {
+    One(String),
+    Many(Vec<String>),
+}
+
+impl ComposerAutoload {
+    /// Build autoload maps from `composer.json` files (paths relative to
+    /// the project root). `read` returns a manifest's contents.
+    pub fn load(manifests: &[PathBuf], read: &dyn Fn(&Path) -> Option<String>) -> Self {
+        let mut psr4: Vec<(String, Vec<PathBuf>)> = Vec::new();
+        for manifest_path in manifests {
+            let Some(manifest) = read(manifest_path)
+                .and_then(|content| serde_json::from_str::<ComposerManifest>(&content).ok())
+            else {
+                continue;
+            };
+            let package_dir = manifest_path.parent().unwrap_or(Path::new(""));
+            for (prefix, dirs) in manifest
+                .autoload
+                .psr4
+                .into_iter()
+                .chain(manifest.autoload_dev.psr4)
+            {
+                let dirs = match dirs {
+                    Psr4Dirs::One(dir) => vec![dir],
+                    Psr4Dirs::Many(dirs) => dirs,
+                };
+                let dirs: Vec<PathBuf> = dirs
+                    .iter()
+                    .filter_map(|dir| normalize_relative(&package_dir.join(dir)))
+                    .collect();
+                let prefix = prefix.trim_matches('\\').to_string();
+                match psr4.iter_mut().find(|(existing, _)| *existing == prefix) {
+                    Some((_, existing_dirs)) => existing_dirs.extend(dirs),
+                    None => psr4.push((prefix, dirs)),
+                }
+            }
+        }
+        psr4.sort_by(|(a, _), (b, _)| b.len().cmp(&a.len()).then_with(|| a.cmp(b)));
+        Self { psr4 }
+    }
+
+    /// Find and load every `composer.json` under `project_root`, skipping
+    /// installed packages in `vendor/`.
+    pub fn discover(project_root: &Path) -> Self {
+        let walker = ignore::WalkBuilder::new(project_root)
+            .hidden(false)
+            .git_ignore(true)
+            .filter_entry(|entry| entry.file_name() != "vendor")
+            .build();
+
+        let mut manifests: Vec<PathBuf> = walker
+            .flatten()
+            .filter(|entry| entry.path().is_file() && entry.file_name() == COMPOSER_MANIFEST)
+            .filter_map(|entry| {
+                entry
+                    .path()
+                    .strip_prefix(project_root)
+                    .ok()
+                    .map(Path::to_path_buf)
+            })
+            .collect();
+        manifests.sort();
+
+        Self::load(&manifests, &|path| {
+            std::fs::read_to_string(project_root.join(path)).ok()
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.psr4.is_empty()
+    }
+
+    /// Whether a fully-qualified class name falls under a project prefix.
+    fn covers(&self, class: &str) -> bool {
+        self.psr4
+            .iter()
+            .any(|(prefix, _)| strip_namespace_prefix(class, prefix).is_some())
+    }
+
+    /// Candidate files for a fully-qualified class name, most specific
+    /// prefix first.
+    fn candidates<'a>(&'a self, class: &'a str) -> impl Iterator<Item = PathBuf> + 'a {
+        self.psr4.iter().flat_map(move |(prefix, dirs)| {
+            let rest = strip_namespace_prefix(class, prefix);
+            dirs.iter().filter_map(move |dir| {
+                let rest = rest?;
+                Some(dir.join(format!("{}.php", rest.replace('\\', "/"))))
+            })
+        })
+    }
+}
+
+/// Strip a PSR-4 namespace prefix from a class name, respecting segment
+/// boundaries. The empty prefix matches every class.
+fn strip_namespace_prefix<'a>(class: &'a str, prefix: &str) -> Option<&'a str> {
+    if prefix.is_empty() {
+        return Some(class);
+    }
+    class.strip_prefix(prefix)?.strip_prefix('\\')
+}
+
+/// Per-file name resolution state: the current namespace and the `use`
+/// aliases in effect.
+#[derive(Default)]
+struct NameScope {
+    namespace: String,
+    aliases: HashMap<String, String>,
+}
+
+impl NameScope {
+    /// Resolve a class name as written in code to a fully-qualified name,
+    /// following PHP's rules: a leading `\` is fully qualified, a first
+    /// segment matching a `use` alias is expanded, and anything else is
+    /// relative to the current namespace.
+    fn qualify(&self, name: &str) -> String {
+        if let Some(absolute) = name.strip_prefix('\\') {
+            return absolute.to_string();
+        }
+        let (first, rest) = match name.split_once('\\') {
+            Some((first, rest)) => (first, Some(rest)),
+            None => (name, None),
+        };
+        if let Some(target) = self.aliases.get(&first.to_ascii_lowercase()) {
+            return match rest {
+                Some(rest) => format!("{target}\\{rest}"),
+                None => target.clone(),
+            };
+        }
+        if self.namespace.is_empty() {
+            name.to_string()
+        } else {
+            format!("{}\\{name}", self.namespace)
+        }
+    }
+}
+
+/// Names that look like classes but never refer to project code.
+const RESERVED_CLASS_NAMES: &[&str] = &[
+    "self", "static", "parent", "array", "callable", "iterable", "bool", "int", "float", "string",
+    "void", "null", "mixed", "never", "object", "false", "true",
+];
+
+pub struct PhpFrontend {
+    autoload: Arc<ComposerAutoload>,
+    file_index: RefCell<Option<HashSet<PathBuf>>>,
+}
+
+impl PhpFrontend {
+    pub fn new() -> Self {
+        Self::with_autoload(Arc::default())
+    }
+
+    pub fn with_autoload(autoload: Arc<ComposerAutoload>) -> Self {
+        Self {
+            autoload,
+            file_index: RefCell::new(None),
+        }
+    }
+
+    fn class_import(&self, class: String, node: tree_sitter::Node, file_path: &Path) -> RawImport {
+        let confidence = if self.autoload.covers(&class) {
+            ImportConfidence::Resolved
+        } else {
+            ImportConfidence::External
+        };
+        RawImport {
+            raw_path: class,
+            source_file: file_path.to_path_buf(),
+            line: node.start_position().row + 1,
+            column: Some(node.start_position().column),
+            kind: ImportKind::Direct,
+            confidence,
+            cfg: None,
+            context: ImportContext::Runtime,
+        }
+    }
+
+    fn walk(
+        &self,
+        node: tree_sitter::Node,
+        source: &[u8],
+        file_path: &Path,
+        scope: &mut NameScope,
+        imports: &mut Vec<RawImport>,
+    ) {
+        match node.kind() {
+            "namespace_definition" => {
+                let name = node
+                    .child_by_field_name("name")
+                    .and_then(|n| n.utf8_text(source).ok())
+                    .unwrap_or_default()
+                    .to_string();
+                match node.child_by_field_name("body") {
+                    // `namespace Foo { ... }` scopes its own block.
+                    Some(body) => {
+                        let mut inner = NameScope {
+                            namespace: name,
+                            aliases: HashMap::new(),
+                        };
+                        self.walk(body, source, file_path, &mut inner, imports);
+                    }
+                    // `namespace Foo;` applies to the rest of the file.
+                    None => {
+                        scope.namespace = name;
+                        scope.aliases.clear();
+                    }
+                }
+                return;
+            }
+            "namespace_use_declaration" => {
+                self.extract_use(node, source, file_path, scope, imports);
+                return;
+            }
+            "require_expression"
+            | "require_once_expression"
+            | "include_expression"
+            | "include_once_expression" => {
+                imports.push(Self::extract_include(node, source, file_path));
+                return;
+            }
+            "qualified_name" | "name" if Self::is_class_reference(node) => {
+                let written = node.utf8_text(source).unwrap_or_default();
+                if !RESERVED_CLASS_NAMES.contains(&written.to_ascii_lowercase().as_str()) {
+                    // Unqualified names bound by `use` were recorded at the
+                    // `use` statement itself.
+                    let aliased = !written.contains('\\')
+                        && scope.aliases.contains_key(&written.to_ascii_lowercase());
+                    if !aliased {
+                        imports.push(self.class_import(scope.qualify(written), node, file_path));
+                    }
+                }
+                return;
+            }
+            _ => {}
+        }
+
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            self.walk(child, source, file_path, scope, imports);
+        }
+    }
+
+    /// Whether a `name`/`qualified_name` node sits in a position that can
+    /// only hold a class, interface, trait, or enum name.
+    fn is_class_reference(node: tree_sitter::Node) -> bool {
+        let Some(parent) = node.parent() else {
+            return false;
+        };
+        match parent.kind() {
+            "object_creation_expression"
+            | "base_clause"
+            | "class_interface_clause"
+            | "named_type"
+            | "use_declaration" => true,
+            // `Foo::BAR` — only the left-hand side is a class.
+            "class_constant_access_expression" => {
+                parent.child(0).is_some_and(|first| first.id() == node.id())
+            }
+            "scoped_call_expression" | "scoped_property_access_expression" => parent
+                .child_by_field_name("scope")
+                .is_some_and(|scope| scope.id() == node.id()),
+            "binary_expression" => {
+                parent
+                    .child_by_field_name("operator")
+                    .is_some_and(|op| op.kind() == "instanceof")
+                    && parent
+                        .child_by_field_name("right")
+                        .is_some_and(|right| right.id() == node.id())
+            }
+            _ => false,
+        }
+    }
+
+    fn extract_use(
+        &self,
+        node: tree_sitter::Node,
+        source: &[u8],
+        file_path: &Path,
+        scope: &mut NameScope,
+        imports: &mut Vec<RawImport>,
+    ) {
+        let group_imports_classes = Self::imports_classes(node);
+
+        // `use Foo\{Bar, Baz}` puts the shared prefix before the group.
+        let mut cursor = node.walk();
+        let group_prefix = node
+            .children(&mut cursor)
+            .find(|child| child.kind() == "namespace_name")
+            .and_then(|n| n.utf8_text(source).ok())
+            .map(|prefix| prefix.trim_matches('\\').to_string());
+        let clauses_parent = node.child_by_field_name("body").unwrap_or(node);
+
+        let mut cursor = clauses_parent.walk();
+        for clause in clauses_parent.children(&mut cursor) {
+            if clause.kind() != "namespace_use_clause"
+                || !group_imports_classes
+                || !Self::imports_classes(clause)
+            {
+                continue;
+            }
+            let mut clause_cursor = clause.walk();
+            let Some(name_node) = clause
+                .children(&mut clause_cursor)
+                .find(|child| matches!(child.kind(), "qualified_name" | "name"))
+            else {
+                continue;
+            };
+            let written = name_node
+                .utf8_text(source)
+                .unwrap_or_default()
+                .trim_start_matches('\\');
+            let class = match &group_prefix {
+                Some(prefix) => format!("{prefix}\\{written}"),
+                None => written.to_string(),
+            };
+            let alias = clause
+                .child_by_field_name("alias")
+                .and_then(|n| n.utf8_text(source).ok())
+                .unwrap_or_else(|| class.rsplit('\\').next().unwrap_or(&class));
+            scope
+                .aliases
+                .insert(alias.to_ascii_lowercase(), class.clone());
+            imports.push(self.class_import(class, clause, file_path));
+        }
+    }
+
+    /// `use function` / `use const` import functions and constants, which
+    /// Composer's PSR-4 autoloader never loads. The keyword sits on the
+    /// declaration for group uses and on each clause otherwise.
+    fn imports_classes(node: tree_sitter::Node) -> bool {
+        let mut cursor = node.walk();
+        let imports_classes = !node
+            .children(&mut cursor)
+            .any(|child| matches!(child.kind(), "function" | "const"));
+        imports_classes
+    }
+
+    /// `require`/`include` with a literal path, optionally anchored with
+    /// `__DIR__ . '/...'`. Anything else is only known at runtime.
+    fn extract_include(node: tree_sitter::Node, source: &[u8], file_path: &Path) -> RawImport {
+        let argument = node.named_child(0);
+        let literal = argument.and_then(|arg| match arg.kind() {
+            "string" | "encapsed_string" => Self::string_literal(arg, source),
+            "binary_expression" => {
+                let left = arg.child_by_field_name("left")?;
+                let right = arg.child_by_field_name("right")?;
+                let anchored = left.utf8_text(source).ok()? == "__DIR__";
+                anchored
+                    .then(|| Self::string_literal(right, source))
+                    .flatten()
+                    .map(|path| path.trim_start_matches('/').to_string())
+            }
+            "parenthesized_expression" => arg
+                .named_child(0)
+                .filter(|inner| inner.kind() == "string")
+                .and_then(|inner| Self::string_literal(inner, source)),
+            _ => None,
+        });
+        let (raw_path, confidence) = match literal {
+            Some(path) => (path, ImportConfidence::Resolved),
+            None => (
+                argument
+                    .and_then(|arg| arg.utf8_text(source).ok())
+                    .unwrap_or_default()
+                    .to_string(),
+                ImportConfidence::Dynamic,
+            ),
+        };
+        RawImport {
+            raw_path,
+            source_file: file_path.to_path_buf(),
+            line: node.start_position().row + 1,
+            column: Some(node.start_position().column),
+            kind: ImportKind::RequireRelative,
+            confidence,
+            cfg: None,
+            context: ImportContext::Runtime,
+        }
+    }
+
+    /// Contents of a string without interpolation.
+    fn string_literal(node: tree_sitter::Node, source: &[u8]) -> Option<String> {
+        if node.kind() != "string" && node.kind() != "encapsed_string" {
+            return None;
+        }
+        let mut cursor = node.walk();
+        let mut content = String::new();
+        for child in node.named_children(&mut cursor) {
+            match child.kind() {
+                "string_content" | "string_value" => {
+                    content.push_str(child.utf8_text(source).ok()?)
+                }
+                _ => return None,
+            }
+        }
+        Some(content)
+    }
+}
+
+impl Default for PhpFrontend {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ParseFrontend for PhpFrontend {
+    fn language(&self) -> tree_sitter::Language {
+        tree_sitter_php::LANGUAGE_PHP.into()
+    }
+
+    fn extract_imports(&self, source: &[u8], file_path: &Path) -> Vec<RawImport> {
+        let mut parser = tree_sitter::Parser::new();
+        parser
+            .set_language(&self.language())
+            .expect("failed to set PHP language");
+
+        let tree = match parser.parse(source, None) {
+            Some(t) => t,
+            None => return vec![],
+        };
+
+        let mut imports = Vec::new();
+        let mut scope = NameScope::default();
+        self.walk(
+            tree.root_node(),
+            source,
+            file_path,
+            &mut scope,
+            &mut imports,
+        );
+        imports
+    }
+
+    fn resolve(
+        &self,
+        raw: &RawImport,
+        project_root: &Path,
+        project_files: &[PathBuf],
+    ) -> Option<PathBuf> {
+        if raw.confidence != ImportConfidence::Resolved {
+            return None;
+        }
+
+        let mut index = self.file_index.borrow_mut();
+        let files = index.get_or_insert_with(|| {
+            project_files
+                .iter()
+                .map(|file| {
+                    file.strip_prefix(project_root)
+                        .unwrap_or(file)
+                        .to_path_buf()
+                })
+                .collect()
+        });
+        let source_file = raw
+            .source_file
+            .strip_prefix(project_root)
+            .unwrap_or(&raw.source_file);
+
+        match raw.kind {
+            // Relative include paths are tried against the including file's
+            // directory, then the project root (the usual include_path).
+            ImportKind::RequireRelative => {
+                let source_dir = source_file.parent().unwrap_or(Path::new(""));
+                [source_dir.join(&raw.raw_path), PathBuf::from(&raw.raw_path)]
+                    .iter()
+                    .filter_map(|candidate| normalize_relative(candidate))
+                    .find(|candidate| files.contains(candidate))
+            }
+            _ => self
+                .autoload
+                .candidates(&raw.raw_path)
+                .find(|candidate| files.contains(candidate) && candidate != source_file),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn autoload() -> ComposerAutoload {
+        let manifests = HashMap::from([
+            (
+                PathBuf::from("composer.json"),
+                r#"{
+                    "autoload": {"psr-4": {"Acme\\": "src/", "Acme\\Legacy\\": ["legacy/", "lib/"]}},
+                    "autoload-dev": {"psr-4": {"Acme\\Tests\\": "tests/"}}
+                }"#,
+            ),
+            (
+                PathBuf::from("packages/billing/composer.json"),
+                r#"{"autoload": {"psr-4": {"Billing\\": "src"}}}"#,
+            ),
+        ]);
+        let paths: Vec<PathBuf> = manifests.keys().cloned().collect();
+        ComposerAutoload::load(&paths, &|path| {
+            manifests.get(path).map(|content| content.to_string())
+        })
+    }
+
+    fn extract(source: &str) -> Vec<(String, ImportConfidence)> {
+        PhpFrontend::with_autoload(Arc::new(autoload()))
+            .extract_imports(source.as_bytes(), Path::new("src/Http/Controller.php"))
+            .into_iter()
+            .map(|import| (import.raw_path, import.confidence))
+            .collect()
+    }
+
+    #[test]
+    fn loads_psr4_prefixes_longest_first() {
+        let autoload = autoload();
+        let prefixes: Vec<&str> = autoload.psr4.iter().map(|(p, _)| p.as_str()).collect();
+        assert_eq!(
+            prefixes,
+            vec!["Acme\\Legacy", "Acme\\Tests", "Billing", "Acme"]
+        );
+        let candidates: Vec<PathBuf> = autoload.candidates("Acme\\Legacy\\Cart").collect();
+        assert_eq!(
+            candidates,
+            vec![
+                PathBuf::from("legacy/Cart.php"),
+                PathBuf::from("lib/Cart.php"),
+                PathBuf::from("src/Legacy/Cart.php"),
+            ]
+        );
+        assert_eq!(
+            autoload.candidates("Billing\\Invoice").collect::<Vec<_>>(),
+            vec![PathBuf::from("packages/billing/src/Invoice.php")]
+        );
+        assert!(!autoload.covers("AcmeVendor\\Thing"));
+    }
+
+    #[test]
+    fn extracts_uses_class_references_and_includes() {
+        let imports = extract(
+            r#"<?php
+namespace Acme\Http;
+
+use Acme\Models\{Post, Comment as C};
+use Acme\Services as Svc;
+use function Acme\Support\helper;
+use Symfony\Component\HttpFoundation\Response;
+
+require_once __DIR__ . '/../bootstrap.php';
+require $path;
+
+class Controller extends BaseController implements \Acme\Contracts\Handler {
+    public function show(Post $post, C $comment, self $other): Response {
+        $mailer = new Svc\Mailer();
+        Helper::run();
+        return $post instanceof \DateTimeInterface ? 1 : 2;
+    }
+}
+"#,
+        );
+
+        assert_eq!(
+            imports,
+            vec![
+                ("Acme\\Models\\Post".into(), ImportConfidence::Resolved),
+                ("Acme\\Models\\Comment".into(), ImportConfidence::Resolved),
+                ("Acme\\Services".into(), ImportConfidence::Resolved),
+                (
+                    "Symfony\\Component\\HttpFoundation\\Response".into(),
+                    ImportConfidence::External
+                ),
+                ("../bootstrap.php".into(), ImportConfidence::Resolved),
+                ("$path".into(), ImportConfidence::Dynamic),
+                (
+                    "Acme\\Http\\BaseController".into(),
+                    ImportConfidence::Resolved
+                ),
+                (
+                    "Acme\\Contracts\\Handler".into(),
+                    ImportConfidence::Resolved
+                ),
+                ("Acme\\Services\\Mailer".into(), ImportConfidence::Resolved),
+                ("Acme\\Http\\Helper".into(), ImportConfidence::Resolved),
+                ("DateTimeInterface".into(), ImportConfidence::External),
+            ]
+        );
+    }
+
+    #[test]
+    fn resolves_classes_through_psr4_and_includes_by_path() {
+        let frontend = PhpFrontend::with_autoload(Arc::new(autoload()));
+        let files: Vec<PathBuf> = [
+            "src/Http/Controller.php",
+            "src/Models/Post.php",
+            "lib/Cart.php",
+            "bootstrap.php",
+            "config/app.php",
+        ]
+        .iter()
+        .map(|file| Path::new("/repo").join(file))
+        .collect();
+        let raw = |raw_path: &str, kind: ImportKind| RawImport {
+            raw_path: raw_path.to_string(),
+            source_file: PathBuf::from("/repo/src/Http/Controller.php"),
+            line: 1,
+            column: None,
+            kind,
+            confidence: ImportConfidence::Resolved,
+            cfg: None,
+            context: ImportContext::Runtime,
+        };
+        let resolve = |import: RawImport| frontend.resolve(&import, Path::new("/repo"), &files);
+
+        assert_eq!(
+            resolve(raw("Acme\\Models\\Post", ImportKind::Direct)),
+            Some(PathBuf::from("src/Models/Post.php"))
+        );
+        // falls through to the second directory of a multi-directory prefix
+        assert_eq!(
+            resolve(raw("Acme\\Legacy\\Cart", ImportKind::Direct)),
+            Some(PathBuf::from("lib/Cart.php"))
+        );
+        assert_eq!(
+            resolve(raw("Acme\\Models\\Missing", ImportKind::Direct)),
+            None
+        );
+        assert_eq!(
+            resolve(raw("../../bootstrap.php", ImportKind::RequireRelative)),
+            Some(PathBuf::from("bootstrap.php"))
+        );
+        assert_eq!(
+            resolve(raw("config/app.php", ImportKind::RequireRelative)),
+            Some(PathBuf::from("config/app.php"))
+        );
+    }
+}
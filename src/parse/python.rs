@@ -1,13 +1,798 @@
-use crate::parse::common::{ImportConfidence, ImportKind, RawImport};
+use crate::parse::common::{
+    normalize_relative, ImportConfidence, ImportContext, ImportKind, RawImport,
+};
 use crate::parse::ParseFrontend;
+use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use streaming_iterator::StreamingIterator;
 
-pub struct PythonFrontend;
+/// Python project manifest file name.
+pub const PYPROJECT_MANIFEST: &str = "pyproject.toml";
+
+/// Top-level modules of the Python standard library (`sys.stdlib_module_names`
+/// without the private ones).
+const PYTHON_STDLIB_MODULES: &[&str] = &[
+    "__future__",
+    "abc",
+    "aifc",
+    "antigravity",
+    "argparse",
+    "array",
+    "ast",
+    "asynchat",
+    "asyncio",
+    "asyncore",
+    "atexit",
+    "audioop",
+    "base64",
+    "bdb",
+    "binascii",
+    "bisect",
+    "builtins",
+    "bz2",
+    "cProfile",
+    "calendar",
+    "cgi",
+    "cgitb",
+    "chunk",
+    "cmath",
+    "cmd",
+    "code",
+    "codecs",
+    "codeop",
+    "collections",
+    "colorsys",
+    "compileall",
+    "concurrent",
+    "configparser",
+    "contextlib",
+    "contextvars",
+    "copy",
+    "copyreg",
+    "crypt",
+    "csv",
+    "ctypes",
+    "curses",
+    "dataclasses",
+    "datetime",
+    "dbm",
+    "decimal",
+    "difflib",
+    "dis",
+    "distutils",
+    "doctest",
+    "email",
+    "encodings",
+    "ensurepip",
+    "enum",
+    "errno",
+    "faulthandler",
+    "fcntl",
+    "filecmp",
+    "fileinput",
+    "fnmatch",
+    "fractions",
+    "ftplib",
+    "functools",
+    "gc",
+    "genericpath",
+    "getopt",
+    "getpass",
+    "gettext",
+    "glob",
+    "graphlib",
+    "grp",
+    "gzip",
+    "hashlib",
+    "heapq",
+    "hmac",
+    "html",
+    "http",
+    "idlelib",
+    "imaplib",
+    "imghdr",
+    "imp",
+    "importlib",
+    "inspect",
+    "io",
+    "ipaddress",
+    "itertools",
+    "json",
+    "keyword",
+    "lib2to3",
+    "linecache",
+    "locale",
+    "logging",
+    "lzma",
+    "mailbox",
+    "mailcap",
+    "marshal",
+    "math",
+    "mimetypes",
+    "mmap",
+    "modulefinder",
+    "msilib",
+    "msvcrt",
+    "multiprocessing",
+    "netrc",
+    "nis",
+    "nntplib",
+    "nt",
+    "ntpath",
+    "nturl2path",
+    "numbers",
+    "opcode",
+    "operator",
+    "optparse",
+    "os",
+    "ossaudiodev",
+    "pathlib",
+    "pdb",
+    "pickle",
+    "pickletools",
+    "pipes",
+    "pkgutil",
+    "platform",
+    "plistlib",
+    "poplib",
+    "posix",
+    "posixpath",
+    "pprint",
+    "profile",
+    "pstats",
+    "pty",
+    "pwd",
+    "py_compile",
+    "pyclbr",
+    "pydoc",
+    "pydoc_data",
+    "pyexpat",
+    "queue",
+    "quopri",
+    "random",
+    "re",
+    "readline",
+    "reprlib",
+    "resource",
+    "rlcompleter",
+    "runpy",
+    "sched",
+    "secrets",
+    "select",
+    "selectors",
+    "shelve",
+    "shlex",
+    "shutil",
+    "signal",
+    "site",
+    "smtpd",
+    "smtplib",
+    "sndhdr",
+    "socket",
+    "socketserver",
+    "spwd",
+    "sqlite3",
+    "sre_compile",
+    "sre_constants",
+    "sre_parse",
+    "ssl",
+    "stat",
+    "statistics",
+    "string",
+    "stringprep",
+    "struct",
+    "subprocess",
+    "sunau",
+    "symtable",
+    "sys",
+    "sysconfig",
+    "syslog",
+    "tabnanny",
+    "tarfile",
+    "telnetlib",
+    "tempfile",
+    "termios",
+    "textwrap",
+    "this",
+    "threading",
+    "time",
+    "timeit",
+    "tkinter",
+    "token",
+    "tokenize",
+    "tomllib",
+    "trace",
+    "traceback",
+    "tracemalloc",
+    "tty",
+    "turtle",
+    "turtledemo",
+    "types",
+    "typing",
+    "unicodedata",
+    "unittest",
+    "urllib",
+    "uu",
+    "uuid",
+    "venv",
+    "warnings",
+    "wave",
+    "weakref",
+    "webbrowser",
+    "winreg",
+    "winsound",
+    "wsgiref",
+    "xdrlib",
+    "xml",
+    "xmlrpc",
+    "zipapp",
+    "zipfile",
+    "zipimport",
+    "zlib",
+    "zoneinfo",
+];
+
+/// Directories that absolute imports are resolved against, relative to the
+/// project root. Configured roots come first, then package directories
+/// declared in `pyproject.toml` files, then each manifest's directory and
+/// the project root itself.
+#[derive(Clone, Debug)]
+pub struct PythonSourceRoots {
+    pub roots: Vec<PathBuf>,
+}
+
+impl Default for PythonSourceRoots {
+    fn default() -> Self {
+        Self {
+            roots: vec![PathBuf::new()],
+        }
+    }
+}
+
+impl PythonSourceRoots {
+    /// Build source roots from `[python] source_roots` and `pyproject.toml`
+    /// files (paths relative to the project root). Configured roots may be
+    /// globs such as `services/*/src`, matched against the directories of
+    /// `files`. `read` returns a manifest's contents.
+    pub fn load(
+        configured: &[String],
+        manifests: &[PathBuf],
+        files: &[PathBuf],
+        read: &dyn Fn(&Path) -> Option<String>,
+    ) -> Self {
+        let mut roots = Vec::new();
+        let mut push = |root: PathBuf| {
+            if !roots.contains(&root) {
+                roots.push(root);
+            }
+        };
+
+        let directories: HashSet<&Path> = files
+            .iter()
+            .flat_map(|file| file.ancestors().skip(1))
+            .collect();
+        let mut directories: Vec<&Path> = directories.into_iter().collect();
+        directories.sort();
+        for pattern in configured {
+            let pattern = pattern.trim_end_matches('/');
+            match globset::GlobBuilder::new(pattern)
+                .literal_separator(true)
+                .build()
+            {
+                Ok(glob) if pattern.contains(['*', '?', '[', '{']) => {
+                    let matcher = glob.compile_matcher();
+                    for dir in &directories {
+                        if matcher.is_match(dir) {
+                            push(dir.to_path_buf());
+                        }
+                    }
+                }
+                _ => {
+                    if let Some(root) = normalize_relative(Path::new(pattern)) {
+                        push(root);
+                    }
+                }
+            }
+        }
+
+        let mut manifest_dirs = Vec::new();
+        for manifest_path in manifests {
+            let package_dir = manifest_path.parent().unwrap_or(Path::new(""));
+            if let Some(manifest) =
+                read(manifest_path).and_then(|content| content.parse::<toml::Table>().ok())
+            {
+                for dir in package_dirs(&manifest) {
+                    if let Some(root) = normalize_relative(&package_dir.join(dir)) {
+                        push(root);
+                    }
+                }
+            }
+            manifest_dirs.push(package_dir.to_path_buf());
+        }
+        for dir in manifest_dirs {
+            push(dir);
+        }
+        push(PathBuf::new());
+        Self { roots }
+    }
+
+    /// Find and load every `pyproject.toml` under `project_root`.
+    pub fn discover(project_root: &Path, configured: &[String], files: &[PathBuf]) -> Self {
+        let walker = ignore::WalkBuilder::new(project_root)
+            .hidden(false)
+            .git_ignore(true)
+            .build();
+
+        let mut manifests: Vec<PathBuf> = walker
+            .flatten()
+            .filter(|entry| entry.path().is_file() && entry.file_name() == PYPROJECT_MANIFEST)
+            .filter_map(|entry| {
+                entry
+                    .path()
+                    .strip_prefix(project_root)
+                    .ok()
+                    .map(Path::to_path_buf)
+            })
+            .filter(|path| !crate::walk::Language::Python.is_vendored(path))
+            .collect();
+        manifests.sort();
+
+        let files: Vec<PathBuf> = files
+            .iter()
+            .map(|file| {
+                file.strip_prefix(project_root)
+                    .unwrap_or(file)
+                    .to_path_buf()
+            })
+            .collect();
+        Self::load(configured, &manifests, &files, &|path| {
+            std::fs::read_to_string(project_root.join(path)).ok()
+        })
+    }
+}
+
+/// Directories holding top-level packages, as declared by setuptools
+/// (`package-dir`, `packages.find.where`), Poetry (`packages[].from`) or
+/// Hatch (`targets.wheel.packages`). Relative to the manifest.
+fn package_dirs(manifest: &toml::Table) -> Vec<String> {
+    let lookup = |path: &[&str]| {
+        path.iter()
+            .try_fold(None::<&toml::Value>, |value, key| {
+                let table = match value {
+                    None => manifest,
+                    Some(value) => value.as_table()?,
+                };
+                table.get(*key).map(Some)
+            })
+            .flatten()
+    };
+    let strings = |value: Option<&toml::Value>| -> Vec<String> {
+        match value {
+            Some(toml::Value::String(dir)) => vec![dir.clone()],
+            Some(toml::Value::Array(items)) => items
+                .iter()
+                .filter_map(|item| item.as_str().map(str::to_string))
+                .collect(),
+            _ => Vec::new(),
+        }
+    };
+
+    let mut dirs = Vec::new();
+    // setuptools: `package-dir = {"" = "src"}`
+    if let Some(dir) = lookup(&["tool", "setuptools", "package-dir", ""]).and_then(|v| v.as_str()) {
+        dirs.push(dir.to_string());
+    }
+    dirs.extend(strings(lookup(&[
+        "tool",
+        "setuptools",
+        "packages",
+        "find",
+        "where",
+    ])));
+    // poetry: `packages = [{ include = "app", from = "src" }]`
+    if let Some(packages) = lookup(&["tool", "poetry", "packages"]).and_then(|v| v.as_array()) {
+        dirs.extend(
+            packages
+                .iter()
+                .filter_map(|package| package.get("from")?.as_str())
+                .map(str::to_string),
+        );
+    }
+    // hatch: `packages = ["src/app"]` names the package, so its parent is the root
+    dirs.extend(
+        strings(lookup(&[
+            "tool", "hatch", "build", "targets", "wheel", "packages",
+        ]))
+        .iter()
+        .filter_map(|package| {
+            Path::new(package.trim_end_matches('/'))
+                .parent()
+                .map(|parent| parent.to_string_lossy().into_owned())
+        }),
+    );
+    dirs
+}
+
+/// Names that packages re-export from other modules, read from every
+/// `__init__.py` in the project.
+#[derive(Clone, Debug, Default)]
+pub struct PythonReexports {
+    /// `__init__.py` path (relative to the project root) → exported name → source
+    by_package: HashMap<PathBuf, HashMap<String, Reexport>>,
+}
+
+/// Where a re-exported name comes from.
+#[derive(Clone, Debug, PartialEq)]
+struct Reexport {
+    /// Candidate paths of the source module, without extension
+    bases: Vec<PathBuf>,
+    /// Name inside the source module; None when the module itself is exported
+    name: Option<String>,
+}
+
+impl PythonReexports {
+    /// Index the re-exports of each `__init__.py` in `files` (paths relative
+    /// to the project root). Absolute imports are resolved against `roots`.
+    /// `read` returns a file's contents.
+    pub fn load(
+        files: &[PathBuf],
+        roots: &PythonSourceRoots,
+        read: &dyn Fn(&Path) -> Option<String>,
+    ) -> Self {
+        let mut parser = tree_sitter::Parser::new();
+        parser
+            .set_language(&tree_sitter_python::LANGUAGE.into())
+            .expect("failed to set Python language");
+
+        let mut by_package = HashMap::new();
+        for init in files.iter().filter(|file| is_package_init(file)) {
+            let Some(source) = read(init) else {
+                continue;
+            };
+            let Some(tree) = parser.parse(source.as_bytes(), None) else {
+                continue;
+            };
+            let package = init.parent().unwrap_or(Path::new(""));
+            let mut exports = HashMap::new();
+            let mut cursor = tree.root_node().walk();
+            for statement in tree.root_node().children(&mut cursor) {
+                if statement.kind() != "import_from_statement" {
+                    continue;
+                }
+                let Some(bases) =
+                    reexport_bases(statement, source.as_bytes(), package, &roots.roots)
+                else {
+                    continue;
+                };
+                // `from . import sub` and `from .. import sub` name submodules.
+                let from_package = statement
+                    .child_by_field_name("module_name")
+                    .and_then(|module| module.utf8_text(source.as_bytes()).ok())
+                    .is_some_and(|module| module.chars().all(|c| c == '.'));
+
+                let mut names = statement.walk();
+                let mut bound = false;
+                for name_node in statement.children_by_field_name("name", &mut names) {
+                    bound = true;
+                    let (name, alias) = match name_node.kind() {
+                        "aliased_import" => (
+                            name_node.child_by_field_name("name"),
+                            name_node.child_by_field_name("alias"),
+                        ),
+                        _ => (Some(name_node), None),
+                    };
+                    let Some(name) = name.and_then(|name| name.utf8_text(source.as_bytes()).ok())
+                    else {
+                        continue;
+                    };
+                    let exported = alias
+                        .and_then(|alias| alias.utf8_text(source.as_bytes()).ok())
+                        .unwrap_or(name);
+                    let reexport = if from_package {
+                        Reexport {
+                            bases: bases.iter().map(|base| base.join(name)).collect(),
+                            name: None,
+                        }
+                    } else {
+                        Reexport {
+                            bases: bases.clone(),
+                            name: Some(name.to_string()),
+                        }
+                    };
+                    exports.insert(exported.to_string(), reexport);
+                }
+                if bound {
+                    continue;
+                }
+                // `from .impl import *` exports whatever `impl` defines.
+                let Some((base, star_source)) = bases.iter().find_map(|base| {
+                    read(&base.with_extension("py"))
+                        .or_else(|| read(&base.join("__init__.py")))
+                        .map(|source| (base, source))
+                }) else {
+                    continue;
+                };
+                let Some(star_tree) = parser.parse(star_source.as_bytes(), None) else {
+                    continue;
+                };
+                for name in module_exports(star_tree.root_node(), star_source.as_bytes()) {
+                    exports.entry(name.clone()).or_insert(Reexport {
+                        bases: vec![base.clone()],
+                        name: Some(name),
+                    });
+                }
+            }
+            if !exports.is_empty() {
+                by_package.insert(init.clone(), exports);
+            }
+        }
+        Self { by_package }
+    }
+
+    /// Index the `__init__.py` files under `project_root`.
+    pub fn discover(project_root: &Path, files: &[PathBuf], roots: &PythonSourceRoots) -> Self {
+        let files: Vec<PathBuf> = files
+            .iter()
+            .map(|file| {
+                file.strip_prefix(project_root)
+                    .unwrap_or(file)
+                    .to_path_buf()
+            })
+            .collect();
+        Self::load(&files, roots, &|path| {
+            std::fs::read_to_string(project_root.join(path)).ok()
+        })
+    }
+
+    fn lookup(&self, init: &Path, name: &str) -> Option<&Reexport> {
+        self.by_package.get(init)?.get(name)
+    }
+}
+
+/// Candidate source-module paths for a `from ... import` in a package's
+/// `__init__.py`.
+fn reexport_bases(
+    statement: tree_sitter::Node,
+    source: &[u8],
+    package: &Path,
+    roots: &[PathBuf],
+) -> Option<Vec<PathBuf>> {
+    let module = statement.child_by_field_name("module_name")?;
+    let text = module.utf8_text(source).ok()?;
+    if module.kind() == "relative_import" {
+        let level = text.chars().take_while(|&c| c == '.').count();
+        let mut base = package.to_path_buf();
+        for _ in 1..level {
+            base = base.parent()?.to_path_buf();
+        }
+        let rest = &text[level..];
+        if !rest.is_empty() {
+            base = base.join(rest.replace('.', "/"));
+        }
+        Some(vec![base])
+    } else {
+        let module_path = text.replace('.', "/");
+        Some(roots.iter().map(|root| root.join(&module_path)).collect())
+    }
+}
+
+/// Public names a module defines: its `__all__` when present, otherwise its
+/// top-level functions, classes and assignments not starting with `_`.
+fn module_exports(root: tree_sitter::Node, source: &[u8]) -> Vec<String> {
+    let mut defined = Vec::new();
+    let mut cursor = root.walk();
+    for statement in root.children(&mut cursor) {
+        let definition = match statement.kind() {
+            "decorated_definition" => statement.child_by_field_name("definition"),
+            "function_definition" | "class_definition" => Some(statement),
+            "expression_statement" => {
+                let Some(assignment) = statement
+                    .named_child(0)
+                    .filter(|child| child.kind() == "assignment")
+                else {
+                    continue;
+                };
+                let Some(left) = assignment
+                    .child_by_field_name("left")
+                    .filter(|left| left.kind() == "identifier")
+                else {
+                    continue;
+                };
+                if left.utf8_text(source) == Ok("__all__") {
+                    if let Some(names) = assignment.child_by_field_name("right") {
+                        return string_items(names, source);
+                    }
+                }
+                defined.push(left);
+                continue;
+            }
+            _ => None,
+        };
+        if let Some(name) = definition.and_then(|definition| definition.child_by_field_name("name"))
+        {
+            defined.push(name);
+        }
+    }
+    defined
+        .into_iter()
+        .filter_map(|name| name.utf8_text(source).ok())
+        .filter(|name| !name.starts_with('_'))
+        .map(str::to_string)
+        .collect()
+}
+
+/// String literals in a `["a", "b"]` list or `("a", "b")` tuple.
+fn string_items(node: tree_sitter::Node, source: &[u8]) -> Vec<String> {
+    let mut cursor = node.walk();
+    node.named_children(&mut cursor)
+        .filter(|item| item.kind() == "string")
+        .filter_map(|item| item.utf8_text(source).ok())
+        .map(|text| text.trim_matches(|c| c == '"' || c == '\'').to_string())
+        .collect()
+}
+
+/// Project files relative to the project root, and the top-level packages
+/// and modules they define under the source roots.
+#[derive(Clone, Debug, Default)]
+pub struct PythonFileIndex {
+    files: HashSet<PathBuf>,
+    packages: HashSet<String>,
+}
+
+impl PythonFileIndex {
+    /// Index `files`, relative to the project root.
+    pub fn load(files: &[PathBuf], roots: &PythonSourceRoots) -> Self {
+        let files: HashSet<PathBuf> = files.iter().cloned().collect();
+        let packages = files
+            .iter()
+            .flat_map(|file| {
+                roots
+                    .roots
+                    .iter()
+                    .filter_map(move |root| file.strip_prefix(root).ok())
+            })
+            .filter_map(|relative| {
+                let mut components = relative.components();
+                let first = Path::new(components.next()?.as_os_str());
+                let name = if components.next().is_some() {
+                    first.to_str()?
+                } else {
+                    first.file_stem()?.to_str()?
+                };
+                Some(name.to_string())
+            })
+            .collect();
+        Self { files, packages }
+    }
+
+    /// Index the project's Python files, given as found under `project_root`.
+    pub fn discover(project_root: &Path, files: &[PathBuf], roots: &PythonSourceRoots) -> Self {
+        let files: Vec<PathBuf> = files
+            .iter()
+            .map(|file| {
+                file.strip_prefix(project_root)
+                    .unwrap_or(file)
+                    .to_path_buf()
+            })
+            .collect();
+        Self::load(&files, roots)
+    }
+}
+
+pub struct PythonFrontend {
+    source_roots: PythonSourceRoots,
+    reexports: Option<Arc<PythonReexports>>,
+    file_index: Option<Arc<PythonFileIndex>>,
+}
 
 impl PythonFrontend {
     pub fn new() -> Self {
-        Self
+        Self::with_source_roots(PythonSourceRoots::default())
+    }
+
+    pub fn with_source_roots(source_roots: PythonSourceRoots) -> Self {
+        Self {
+            source_roots,
+            reexports: None,
+            file_index: None,
+        }
+    }
+
+    /// Follow `__init__.py` re-exports (`from .impl import thing`) to the
+    /// module that defines each imported name.
+    pub fn with_reexports(mut self, reexports: Arc<PythonReexports>) -> Self {
+        self.reexports = Some(reexports);
+        self
+    }
+
+    /// Resolve against a shared index of the project's files, which also
+    /// tells the project's own packages apart from third-party ones.
+    pub fn with_file_index(mut self, file_index: Arc<PythonFileIndex>) -> Self {
+        self.file_index = Some(file_index);
+        self
+    }
+
+    /// Distinct modules the names of `from <module> import <names>` resolve
+    /// to, in order; the module itself for `import *`.
+    fn resolve_names(
+        &self,
+        bases: &[PathBuf],
+        names: &[String],
+        files: &HashSet<PathBuf>,
+    ) -> Vec<PathBuf> {
+        if names.is_empty() {
+            return self.resolve_from(bases, None, files).into_iter().collect();
+        }
+        let mut targets: Vec<PathBuf> = Vec::new();
+        for target in names
+            .iter()
+            .filter_map(|name| self.resolve_from(bases, Some(name), files))
+        {
+            if !targets.contains(&target) {
+                targets.push(target);
+            }
+        }
+        targets
+    }
+
+    /// Resolve `from <module> import <name>`, where `bases` are the module's
+    /// candidate paths (without extension) under each source root.
+    fn resolve_from(
+        &self,
+        bases: &[PathBuf],
+        name: Option<&String>,
+        files: &HashSet<PathBuf>,
+    ) -> Option<PathBuf> {
+        if let Some(module) = bases.iter().find_map(|base| find_module(base, files)) {
+            return Some(match name {
+                Some(name) => self.follow_name(module, name, files),
+                None => module,
+            });
+        }
+        // A PEP 420 namespace package has no `__init__.py`, so
+        // `from ns import mod` can only name a submodule.
+        let name = name?;
+        bases
+            .iter()
+            .find_map(|base| find_module(&base.join(name), files))
+    }
+
+    /// Narrow an import of `name` from a package's `__init__.py` to the
+    /// re-exporting module's source, or to the submodule called `name`.
+    fn follow_name(&self, module: PathBuf, name: &str, files: &HashSet<PathBuf>) -> PathBuf {
+        let mut module = module;
+        let mut name = name.to_string();
+        for _ in 0..MAX_REEXPORT_HOPS {
+            if !is_package_init(&module) {
+                break;
+            }
+            if let Some(reexport) = self
+                .reexports
+                .as_ref()
+                .and_then(|reexports| reexports.lookup(&module, &name))
+            {
+                let Some(target) = reexport
+                    .bases
+                    .iter()
+                    .find_map(|base| find_module(base, files))
+                else {
+                    break;
+                };
+                module = target;
+                match &reexport.name {
+                    Some(original) => name = original.clone(),
+                    None => break,
+                }
+                continue;
+            }
+            let package = module.parent().unwrap_or(Path::new(""));
+            if let Some(submodule) = find_module(&package.join(&name), files) {
+                module = submodule;
+            }
+            break;
+        }
+        module
     }
 
     fn extract_relative_imports(
@@ -35,16 +820,11 @@ impl PythonFrontend {
                     let level = text.chars().take_while(|&c| c == '.').count();
                     let module_part = text[level..].to_string();
 
-                    let mut names = Vec::new();
-                    let mut cursor = node.walk();
-                    for child in node.children(&mut cursor) {
-                        if child.kind() == "dotted_name" {
-                            if let Ok(name) = child.utf8_text(source) {
-                                names.push(name.to_string());
-                            }
-                        }
-                    }
-
+                    let module = if module_part.is_empty() {
+                        None
+                    } else {
+                        Some(module_part)
+                    };
                     imports.push(RawImport {
                         raw_path: text,
                         source_file: file_path.to_path_buf(),
@@ -52,14 +832,12 @@ impl PythonFrontend {
                         column: Some(module_node.start_position().column),
                         kind: ImportKind::RelativeImport {
                             level,
-                            module: if module_part.is_empty() {
-                                None
-                            } else {
-                                Some(module_part)
-                            },
-                            names,
+                            module,
+                            names: imported_names(node, source),
                         },
                         confidence: ImportConfidence::Resolved,
+                        cfg: None,
+                        context: import_context(node, source),
                     });
                     return;
                 }
@@ -73,6 +851,80 @@ impl PythonFrontend {
     }
 }
 
+/// Classify when an import runs from the statements enclosing it. Nested
+/// contexts keep the weakest: a guarded import inside a function is still
+/// deferred until the function runs.
+fn import_context(node: tree_sitter::Node, source: &[u8]) -> ImportContext {
+    let mut context = ImportContext::Runtime;
+    let mut child = node;
+    while let Some(parent) = child.parent() {
+        let found = match parent.kind() {
+            "if_statement"
+                if parent.child_by_field_name("consequence") == Some(child)
+                    && parent
+                        .child_by_field_name("condition")
+                        .is_some_and(|condition| is_type_checking_guard(condition, source)) =>
+            {
+                ImportContext::TypeChecking
+            }
+            "function_definition" => ImportContext::FunctionLocal,
+            // `else` and `finally` run whether or not the import can fail.
+            "try_statement"
+                if parent.child_by_field_name("body") == Some(child)
+                    || matches!(child.kind(), "except_clause" | "except_group_clause") =>
+            {
+                ImportContext::Guarded
+            }
+            _ => ImportContext::Runtime,
+        };
+        context = context.min(found);
+        child = parent;
+    }
+    context
+}
+
+/// How many `__init__.py` re-exports to follow before giving up.
+const MAX_REEXPORT_HOPS: usize = 8;
+
+fn is_package_init(path: &Path) -> bool {
+    path.file_name().is_some_and(|name| name == "__init__.py")
+}
+
+/// `module/__init__.py` or `module.py`, whichever is a project file.
+fn find_module(base: &Path, files: &HashSet<PathBuf>) -> Option<PathBuf> {
+    let init_path = base.join("__init__.py");
+    if files.contains(&init_path) {
+        return Some(init_path);
+    }
+    let file_path = base.with_extension("py");
+    files.contains(&file_path).then_some(file_path)
+}
+
+/// Names a `from ... import` statement imports, without aliases. Empty for
+/// `import *`.
+fn imported_names(statement: tree_sitter::Node, source: &[u8]) -> Vec<String> {
+    let mut cursor = statement.walk();
+    statement
+        .children_by_field_name("name", &mut cursor)
+        .filter_map(|name| match name.kind() {
+            "aliased_import" => name.child_by_field_name("name"),
+            _ => Some(name),
+        })
+        .filter_map(|name| name.utf8_text(source).ok())
+        .map(str::to_string)
+        .collect()
+}
+
+/// `TYPE_CHECKING` or `typing.TYPE_CHECKING` (under any module alias).
+fn is_type_checking_guard(condition: tree_sitter::Node, source: &[u8]) -> bool {
+    let name = match condition.kind() {
+        "identifier" => Some(condition),
+        "attribute" => condition.child_by_field_name("attribute"),
+        _ => None,
+    };
+    name.and_then(|name| name.utf8_text(source).ok()) == Some("TYPE_CHECKING")
+}
+
 impl Default for PythonFrontend {
     fn default() -> Self {
         Self::new()
@@ -117,43 +969,46 @@ impl ParseFrontend for PythonFrontend {
                         column: Some(node.start_position().column),
                         kind: ImportKind::Direct,
                         confidence: ImportConfidence::Resolved,
+                        cfg: None,
+                        context: import_context(node, source),
                     });
                 }
             }
         }
 
         // Query for `from x import y` (absolute)
-        let from_query_str = r#"(import_from_statement module_name: (dotted_name) @module name: (dotted_name) @name)"#;
+        let from_query_str = r#"(import_from_statement module_name: (dotted_name) @module)"#;
         let from_query = tree_sitter::Query::new(&lang, from_query_str)
             .expect("failed to compile Python from-import query");
 
         let mut cursor2 = tree_sitter::QueryCursor::new();
         let mut matches2 = cursor2.matches(&from_query, tree.root_node(), source);
         while let Some(m) = matches2.next() {
-            let module_capture = m.captures.iter().find(|c| c.index == 0);
-            let name_captures: Vec<_> = m.captures.iter().filter(|c| c.index == 1).collect();
-
-            if let Some(module_cap) = module_capture {
-                let module = module_cap
-                    .node
+            for capture in m.captures {
+                let module_node = capture.node;
+                let module = module_node
                     .utf8_text(source)
                     .unwrap_or_default()
                     .to_string();
-                let names: Vec<String> = name_captures
-                    .iter()
-                    .map(|c| c.node.utf8_text(source).unwrap_or_default().to_string())
-                    .collect();
-
-                if !module.is_empty() {
-                    imports.push(RawImport {
-                        raw_path: module.clone(),
-                        source_file: file_path.to_path_buf(),
-                        line: module_cap.node.start_position().row + 1,
-                        column: Some(module_cap.node.start_position().column),
-                        kind: ImportKind::FromImport { module, names },
-                        confidence: ImportConfidence::Resolved,
-                    });
+                let Some(statement) = module_node.parent() else {
+                    continue;
+                };
+                if module.is_empty() {
+                    continue;
                 }
+                imports.push(RawImport {
+                    raw_path: module.clone(),
+                    source_file: file_path.to_path_buf(),
+                    line: module_node.start_position().row + 1,
+                    column: Some(module_node.start_position().column),
+                    kind: ImportKind::FromImport {
+                        module,
+                        names: imported_names(statement, source),
+                    },
+                    confidence: ImportConfidence::Resolved,
+                    cfg: None,
+                    context: import_context(module_node, source),
+                });
             }
         }
 
@@ -169,64 +1024,88 @@ impl ParseFrontend for PythonFrontend {
         project_root: &Path,
         project_files: &[PathBuf],
     ) -> Option<PathBuf> {
+        self.resolve_targets(raw, project_root, project_files)
+            .into_iter()
+            .next()
+    }
+
+    /// Each name of a `from` import resolves on its own, so one statement
+    /// can point at several submodules. Names resolving to the same module
+    /// give one target.
+    fn resolve_targets(
+        &self,
+        raw: &RawImport,
+        project_root: &Path,
+        project_files: &[PathBuf],
+    ) -> Vec<PathBuf> {
+        // Without a shared index, index the files given for this import.
+        let local_index;
+        let index = match &self.file_index {
+            Some(index) => index.as_ref(),
+            None => {
+                local_index =
+                    PythonFileIndex::discover(project_root, project_files, &self.source_roots);
+                &local_index
+            }
+        };
+        let roots = &self.source_roots.roots;
+        let files = &index.files;
         match &raw.kind {
-            ImportKind::Direct | ImportKind::FromImport { .. } => {
-                let module_path = raw.raw_path.replace('.', "/");
-                // Try as package (__init__.py)
-                let init_path = PathBuf::from(format!("{module_path}/__init__.py"));
-                if project_files
+            ImportKind::Direct => {
+                let module_path = PathBuf::from(raw.raw_path.replace('.', "/"));
+                roots
                     .iter()
-                    .any(|f| f.strip_prefix(project_root).unwrap_or(f) == init_path)
-                {
-                    return Some(init_path);
-                }
-                // Try as module file
-                let file_path = PathBuf::from(format!("{module_path}.py"));
-                if project_files
-                    .iter()
-                    .any(|f| f.strip_prefix(project_root).unwrap_or(f) == file_path)
-                {
-                    return Some(file_path);
-                }
-                None
+                    .find_map(|root| find_module(&root.join(&module_path), files))
+                    .into_iter()
+                    .collect()
             }
-            ImportKind::RelativeImport { level, module, .. } => {
-                let source_dir = raw.source_file.parent()?;
-                let mut base = source_dir.to_path_buf();
+            ImportKind::FromImport { names, .. } => {
+                let module_path = PathBuf::from(raw.raw_path.replace('.', "/"));
+                let bases: Vec<PathBuf> =
+                    roots.iter().map(|root| root.join(&module_path)).collect();
+                self.resolve_names(&bases, names, files)
+            }
+            ImportKind::RelativeImport {
+                level,
+                module,
+                names,
+            } => {
+                let source_file = raw
+                    .source_file
+                    .strip_prefix(project_root)
+                    .unwrap_or(&raw.source_file);
+                let Some(mut base) = source_file.parent().map(Path::to_path_buf) else {
+                    return Vec::new();
+                };
                 for _ in 1..*level {
-                    base = base.parent()?.to_path_buf();
+                    let Some(parent) = base.parent() else {
+                        return Vec::new();
+                    };
+                    base = parent.to_path_buf();
                 }
                 if let Some(mod_name) = module {
-                    let mod_path = mod_name.replace('.', "/");
-                    base = base.join(mod_path);
-                }
-                let init_path = base.join("__init__.py");
-                let relative_init = init_path
-                    .strip_prefix(project_root)
-                    .unwrap_or(&init_path)
-                    .to_path_buf();
-                if project_files
-                    .iter()
-                    .any(|f| f.strip_prefix(project_root).unwrap_or(f) == relative_init)
-                {
-                    return Some(relative_init);
+                    base = base.join(mod_name.replace('.', "/"));
                 }
-                let py_path = base.with_extension("py");
-                let relative_py = py_path
-                    .strip_prefix(project_root)
-                    .unwrap_or(&py_path)
-                    .to_path_buf();
-                if project_files
-                    .iter()
-                    .any(|f| f.strip_prefix(project_root).unwrap_or(f) == relative_py)
-                {
-                    return Some(relative_py);
-                }
-                None
+                self.resolve_names(&[base], names, files)
             }
-            _ => None,
+            _ => Vec::new(),
         }
     }
+
+    fn external_package(&self, raw: &RawImport) -> Option<String> {
+        if !matches!(raw.kind, ImportKind::Direct | ImportKind::FromImport { .. }) {
+            return None;
+        }
+        let package = raw.raw_path.split('.').next()?;
+        // Unresolved imports of the project's own packages, such as a
+        // misspelled or deleted submodule, are not third-party.
+        let own = self
+            .file_index
+            .as_ref()
+            .is_some_and(|index| index.packages.contains(package));
+        (!package.is_empty() && !own && !PYTHON_STDLIB_MODULES.contains(&package))
+            .then(|| package.to_string())
+    }
 }
 
 #[cfg(test)]
@@ -272,4 +1151,369 @@ mod tests {
             ImportKind::RelativeImport { level: 1, .. }
         ));
     }
+
+    #[test]
+    fn external_packages_are_top_level_third_party_modules() {
+        let source = b"import os.path\nimport requests.adapters\nfrom sqlalchemy.orm import Session\nfrom . import utils\n";
+        let frontend = PythonFrontend::new();
+        let packages: Vec<Option<String>> = frontend
+            .extract_imports(source, Path::new("pkg/test.py"))
+            .iter()
+            .map(|import| frontend.external_package(import))
+            .collect();
+        assert_eq!(
+            packages,
+            vec![
+                None,
+                Some("requests".to_string()),
+                Some("sqlalchemy".to_string()),
+                None,
+            ]
+        );
+    }
+
+    #[test]
+    fn unresolved_imports_of_own_packages_are_not_external() {
+        let root = Path::new("/project");
+        let files: Vec<PathBuf> = [
+            "/project/src/shop/__init__.py",
+            "/project/src/shop/cart.py",
+            "/project/src/settings.py",
+            "/project/scripts/run.py",
+        ]
+        .iter()
+        .map(PathBuf::from)
+        .collect();
+        let roots = PythonSourceRoots {
+            roots: vec![PathBuf::from("src")],
+        };
+        let index = PythonFileIndex::discover(root, &files, &roots);
+        let frontend = PythonFrontend::with_source_roots(roots).with_file_index(Arc::new(index));
+        let source = b"import shop.missing_mod\nfrom settings.local import DEBUG\nimport scripts.gone\nimport requests.adapters\n";
+        let mut packages: Vec<(String, Option<String>)> = frontend
+            .extract_imports(source, Path::new("/project/scripts/run.py"))
+            .iter()
+            .map(|import| {
+                assert_eq!(frontend.resolve(import, root, &files), None);
+                (import.raw_path.clone(), frontend.external_package(import))
+            })
+            .collect();
+        packages.sort();
+        assert_eq!(
+            packages,
+            vec![
+                (
+                    "requests.adapters".to_string(),
+                    Some("requests".to_string())
+                ),
+                // Directories outside the source roots are not importable.
+                ("scripts.gone".to_string(), Some("scripts".to_string())),
+                ("settings.local".to_string(), None),
+                ("shop.missing_mod".to_string(), None),
+            ]
+        );
+
+        // Without the shared index, nothing is known to be the project's own.
+        let frontend = PythonFrontend::with_source_roots(PythonSourceRoots {
+            roots: vec![PathBuf::from("src")],
+        });
+        let import =
+            &frontend.extract_imports(b"import shop.missing_mod\n", Path::new("run.py"))[0];
+        assert_eq!(frontend.external_package(import), Some("shop".to_string()));
+    }
+
+    #[test]
+    fn classifies_import_context() {
+        let source = br#"
+from typing import TYPE_CHECKING
+import typing
+if TYPE_CHECKING:
+    import checked
+else:
+    import fallback
+if typing.TYPE_CHECKING:
+    from . import qualified
+try:
+    import optional
+except ImportError:
+    import replacement
+finally:
+    import cleanup
+def handler():
+    import lazy
+    try:
+        import lazy_optional
+    except ImportError:
+        pass
+class Model:
+    import eager
+"#;
+        let frontend = PythonFrontend::new();
+        let imports = frontend.extract_imports(source, Path::new("pkg/app.py"));
+        let context = |path: &str| {
+            imports
+                .iter()
+                .find(|import| import.raw_path == path)
+                .unwrap_or_else(|| panic!("missing import {path}"))
+                .context
+        };
+        assert_eq!(context("typing"), ImportContext::Runtime);
+        assert_eq!(context("checked"), ImportContext::TypeChecking);
+        assert_eq!(context("fallback"), ImportContext::Runtime);
+        assert_eq!(context("."), ImportContext::TypeChecking);
+        assert_eq!(context("optional"), ImportContext::Guarded);
+        assert_eq!(context("replacement"), ImportContext::Guarded);
+        assert_eq!(context("cleanup"), ImportContext::Runtime);
+        assert_eq!(context("lazy"), ImportContext::FunctionLocal);
+        assert_eq!(context("lazy_optional"), ImportContext::FunctionLocal);
+        assert_eq!(context("eager"), ImportContext::Runtime);
+    }
+
+    fn from_import(module: &str, names: &[&str], source_file: &str) -> RawImport {
+        RawImport {
+            raw_path: module.to_string(),
+            source_file: PathBuf::from(source_file),
+            line: 1,
+            column: None,
+            kind: ImportKind::FromImport {
+                module: module.to_string(),
+                names: names.iter().map(|name| name.to_string()).collect(),
+            },
+            confidence: ImportConfidence::Resolved,
+            cfg: None,
+            context: ImportContext::Runtime,
+        }
+    }
+
+    #[test]
+    fn source_roots_come_from_pyproject_and_config() {
+        let manifests = [
+            (
+                "pyproject.toml",
+                "[tool.setuptools]\npackage-dir = {\"\" = \"src\"}\n",
+            ),
+            (
+                "libs/core/pyproject.toml",
+                "[tool.poetry]\npackages = [{ include = \"core\", from = \"lib\" }]\n",
+            ),
+            (
+                "tools/cli/pyproject.toml",
+                "[tool.hatch.build.targets.wheel]\npackages = [\"source/cli\"]\n",
+            ),
+            (
+                "apps/web/pyproject.toml",
+                "[tool.setuptools.packages.find]\nwhere = [\"python\"]\n",
+            ),
+        ];
+        let paths: Vec<PathBuf> = manifests
+            .iter()
+            .map(|(path, _)| PathBuf::from(path))
+            .collect();
+        let files = [
+            PathBuf::from("services/billing/src/acme/billing/invoice.py"),
+            PathBuf::from("services/auth/src/acme/auth/tokens.py"),
+        ];
+        let roots = PythonSourceRoots::load(&["services/*/src".into()], &paths, &files, &|path| {
+            manifests
+                .iter()
+                .find(|(manifest, _)| Path::new(manifest) == path)
+                .map(|(_, content)| content.to_string())
+        });
+
+        let roots: Vec<&str> = roots
+            .roots
+            .iter()
+            .map(|root| root.to_str().unwrap())
+            .collect();
+        assert_eq!(
+            roots,
+            [
+                "services/auth/src",
+                "services/billing/src",
+                "src",
+                "libs/core/lib",
+                "tools/cli/source",
+                "apps/web/python",
+                "",
+                "libs/core",
+                "tools/cli",
+                "apps/web",
+            ]
+        );
+    }
+
+    #[test]
+    fn resolves_against_every_source_root() {
+        let frontend = PythonFrontend::with_source_roots(PythonSourceRoots {
+            roots: vec![
+                PathBuf::from("src"),
+                PathBuf::from("services/auth/src"),
+                PathBuf::new(),
+            ],
+        });
+        let root = Path::new("/project");
+        let files: Vec<PathBuf> = [
+            "src/shop/__init__.py",
+            "src/shop/cart.py",
+            "services/auth/src/acme/auth/tokens.py",
+            "scripts/deploy.py",
+        ]
+        .iter()
+        .map(|file| root.join(file))
+        .collect();
+
+        let resolve = |raw: RawImport| frontend.resolve(&raw, root, &files);
+        assert_eq!(
+            resolve(from_import("shop.cart", &["Cart"], "/project/src/app.py")),
+            Some(PathBuf::from("src/shop/cart.py"))
+        );
+        assert_eq!(
+            resolve(from_import("shop", &["cart"], "/project/src/app.py")),
+            Some(PathBuf::from("src/shop/cart.py"))
+        );
+        assert_eq!(
+            resolve(from_import(
+                "scripts.deploy",
+                &["run"],
+                "/project/src/app.py"
+            )),
+            Some(PathBuf::from("scripts/deploy.py"))
+        );
+        // `acme` and `acme.auth` are namespace packages without `__init__.py`.
+        assert_eq!(
+            resolve(from_import("acme.auth", &["tokens"], "/project/src/app.py")),
+            Some(PathBuf::from("services/auth/src/acme/auth/tokens.py"))
+        );
+        assert_eq!(
+            resolve(from_import(
+                "acme.auth",
+                &["missing"],
+                "/project/src/app.py"
+            )),
+            None
+        );
+    }
+
+    #[test]
+    fn from_imports_keep_one_import_per_statement() {
+        let source = b"from pkg import a, b as c\nfrom pkg.sub import *\nfrom . import x, y\n";
+        let frontend = PythonFrontend::new();
+        let imports = frontend.extract_imports(source, Path::new("app/main.py"));
+        let names: Vec<(String, Vec<String>)> = imports
+            .iter()
+            .map(|import| match &import.kind {
+                ImportKind::FromImport { module, names } => (module.clone(), names.clone()),
+                ImportKind::RelativeImport { names, .. } => {
+                    (import.raw_path.clone(), names.clone())
+                }
+                _ => unreachable!(),
+            })
+            .collect();
+        assert_eq!(
+            names,
+            [
+                ("pkg".to_string(), vec!["a".to_string(), "b".to_string()]),
+                ("pkg.sub".to_string(), vec![]),
+                (".".to_string(), vec!["x".to_string(), "y".to_string()]),
+            ]
+        );
+    }
+
+    #[test]
+    fn each_imported_name_resolves_to_a_distinct_target() {
+        let frontend = PythonFrontend::new();
+        let root = Path::new("/project");
+        let files: Vec<PathBuf> = ["pkg/__init__.py", "pkg/a.py", "pkg/b.py"]
+            .iter()
+            .map(|file| root.join(file))
+            .collect();
+
+        let targets = |names: &[&str]| {
+            frontend.resolve_targets(&from_import("pkg", names, "/project/app.py"), root, &files)
+        };
+        assert_eq!(
+            targets(&["a", "b", "a"]),
+            [PathBuf::from("pkg/a.py"), PathBuf::from("pkg/b.py")]
+        );
+        // Names defined in `__init__.py` share one target.
+        assert_eq!(
+            targets(&["helper", "other"]),
+            [PathBuf::from("pkg/__init__.py")]
+        );
+        assert_eq!(targets(&[]), [PathBuf::from("pkg/__init__.py")]);
+        assert_eq!(
+            frontend.resolve(
+                &from_import("pkg", &["b", "a"], "/project/app.py"),
+                root,
+                &files
+            ),
+            Some(PathBuf::from("pkg/b.py"))
+        );
+    }
+
+    #[test]
+    fn follows_init_reexports_to_defining_module() {
+        let sources = [
+            (
+                "pkg/__init__.py",
+                "from .impl import thing, other as alias\nfrom . import tools\nfrom .star import *\nfrom pkg.nested import deep\n",
+            ),
+            ("pkg/impl.py", "def thing(): pass\ndef other(): pass\n"),
+            ("pkg/tools.py", ""),
+            (
+                "pkg/star.py",
+                "__all__ = ['exported']\ndef exported(): pass\ndef hidden(): pass\n",
+            ),
+            ("pkg/nested/__init__.py", "from .leaf import deep\n"),
+            ("pkg/nested/leaf.py", "def deep(): pass\n"),
+            ("pkg/local.py", ""),
+        ];
+        let files: Vec<PathBuf> = sources
+            .iter()
+            .map(|(path, _)| PathBuf::from(path))
+            .collect();
+        let read = |path: &Path| {
+            sources
+                .iter()
+                .find(|(file, _)| Path::new(file) == path)
+                .map(|(_, source)| source.to_string())
+        };
+        let roots = PythonSourceRoots::default();
+        let reexports = PythonReexports::load(&files, &roots, &read);
+        let root = Path::new("/project");
+        let project_files: Vec<PathBuf> = files.iter().map(|file| root.join(file)).collect();
+
+        let resolve = |frontend: &PythonFrontend, name: &str| {
+            frontend
+                .resolve(
+                    &from_import("pkg", &[name], "/project/app.py"),
+                    root,
+                    &project_files,
+                )
+                .unwrap()
+        };
+        let following = PythonFrontend::new().with_reexports(Arc::new(reexports));
+        assert_eq!(resolve(&following, "thing"), PathBuf::from("pkg/impl.py"));
+        assert_eq!(resolve(&following, "alias"), PathBuf::from("pkg/impl.py"));
+        assert_eq!(resolve(&following, "tools"), PathBuf::from("pkg/tools.py"));
+        assert_eq!(
+            resolve(&following, "exported"),
+            PathBuf::from("pkg/star.py")
+        );
+        assert_eq!(
+            resolve(&following, "hidden"),
+            PathBuf::from("pkg/__init__.py")
+        );
+        // Re-exports chain through nested packages.
+        assert_eq!(
+            resolve(&following, "deep"),
+            PathBuf::from("pkg/nested/leaf.py")
+        );
+        // Submodules resolve whether or not re-exports are followed.
+        assert_eq!(resolve(&following, "local"), PathBuf::from("pkg/local.py"));
+
+        let plain = PythonFrontend::new();
+        assert_eq!(resolve(&plain, "thing"), PathBuf::from("pkg/__init__.py"));
+        assert_eq!(resolve(&plain, "local"), PathBuf::from("pkg/local.py"));
+    }
 }
@@ -6,7 +6,8 @@ pub fn is_go_stdlib(import_path: &str) -> bool {
     !import_path.contains('.')
 }
 
-/// Convert a CamelCase constant to snake_case (for Ruby Zeitwerk convention).
+/// Convert a CamelCase constant to snake_case (Ruby Zeitwerk and Elixir Mix
+/// file naming conventions).
 pub fn camel_to_snake(name: &str) -> String {
     let mut result = String::new();
     for (i, c) in name.chars().enumerate() {
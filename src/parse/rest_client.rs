@@ -99,6 +99,7 @@ fn detect_http_calls_in_line(
                         file: file_path.to_path_buf(),
                         line: line_num,
                         column: None,
+                        cfg: None,
                     },
                 });
                 break; // Only report one match per line
@@ -282,6 +283,7 @@ response = HTTParty.get("https://api.example.com/posts")
                     file: std::path::PathBuf::from("app.py"),
                     line: 10,
                     column: None,
+                    cfg: None,
                 },
             },
             RestClientUsage {
@@ -291,6 +293,7 @@ response = HTTParty.get("https://api.example.com/posts")
                     file: std::path::PathBuf::from("app.py"),
                     line: 20,
                     column: None,
+                    cfg: None,
                 },
             },
         ];
@@ -1,6 +1,14 @@
-use crate::parse::common::{ImportConfidence, ImportKind, RawImport};
+use crate::parse::common::{ImportConfidence, ImportContext, ImportKind, RawImport};
 use crate::parse::ParseFrontend;
+use std::collections::{BTreeSet, HashMap};
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// File whose presence marks the root of a Rails application.
+pub const RAILS_APPLICATION_MANIFEST: &str = "config/application.rb";
+
+/// Directories under `app/` that Rails does not autoload.
+const RAILS_NON_AUTOLOAD_DIRS: &[&str] = &["assets", "javascript", "views"];
 
 /// Ruby stdlib/builtin constants to exclude from Zeitwerk resolution.
 const RUBY_STDLIB_CONSTANTS: &[&str] = &[
@@ -64,23 +72,401 @@ const RUBY_STDLIB_CONSTANTS: &[&str] = &[
     "NIL",
 ];
 
+/// A load path inferred from the project layout rather than configured.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InferredLoadPath {
+    pub path: PathBuf,
+    /// The file that implied this path, e.g. `config/application.rb`
+    pub origin: PathBuf,
+}
+
+/// Ruby load paths: `[ruby] load_path` followed by the autoload paths of
+/// any Rails applications and engines found in the project.
+#[derive(Clone, Debug, Default)]
+pub struct RubyLoadPaths {
+    pub configured: Vec<PathBuf>,
+    pub inferred: Vec<InferredLoadPath>,
+}
+
+impl RubyLoadPaths {
+    /// Build load paths from `[ruby] load_path` and, when `detect_rails` is
+    /// set, the Rails applications and engines among `files` (paths relative
+    /// to the project root). `read` returns a file's contents.
+    pub fn load(
+        configured: &[String],
+        detect_rails: bool,
+        files: &[PathBuf],
+        read: &dyn Fn(&Path) -> Option<String>,
+    ) -> Self {
+        let configured: Vec<PathBuf> = configured.iter().map(PathBuf::from).collect();
+        let mut inferred: Vec<InferredLoadPath> = Vec::new();
+        if detect_rails {
+            for (root, origin) in rails_roots(files) {
+                for path in rails_autoload_paths(&root, &origin, files, read) {
+                    if !configured.contains(&path) && !inferred.iter().any(|p| p.path == path) {
+                        inferred.push(InferredLoadPath {
+                            path,
+                            origin: origin.clone(),
+                        });
+                    }
+                }
+            }
+        }
+        Self {
+            configured,
+            inferred,
+        }
+    }
+
+    /// Detect Rails applications and engines among the Ruby `files` under
+    /// `project_root`.
+    pub fn discover(
+        project_root: &Path,
+        configured: &[String],
+        detect_rails: bool,
+        files: &[PathBuf],
+    ) -> Self {
+        let files: Vec<PathBuf> = files
+            .iter()
+            .map(|file| {
+                file.strip_prefix(project_root)
+                    .unwrap_or(file)
+                    .to_path_buf()
+            })
+            .collect();
+        Self::load(configured, detect_rails, &files, &|path| {
+            std::fs::read_to_string(project_root.join(path)).ok()
+        })
+    }
+
+    /// All load paths in lookup order.
+    pub fn paths(&self) -> Vec<PathBuf> {
+        self.configured
+            .iter()
+            .cloned()
+            .chain(self.inferred.iter().map(|inferred| inferred.path.clone()))
+            .collect()
+    }
+}
+
+/// Rails application and engine roots, each with the file that marks it.
+/// Applications are marked by `config/application.rb`, engines by
+/// `lib/**/engine.rb` or by an `app/` directory under an application's
+/// `engines/` directory.
+fn rails_roots(files: &[PathBuf]) -> Vec<(PathBuf, PathBuf)> {
+    let mut roots: Vec<(PathBuf, PathBuf)> = Vec::new();
+    let mut push = |root: PathBuf, origin: PathBuf| {
+        if !roots.iter().any(|(existing, _)| *existing == root) {
+            roots.push((root, origin));
+        }
+    };
+
+    let applications: BTreeSet<PathBuf> = files
+        .iter()
+        .filter(|file| file.ends_with(RAILS_APPLICATION_MANIFEST))
+        .filter_map(|file| Some(file.parent()?.parent()?.to_path_buf()))
+        .collect();
+    for app in &applications {
+        push(app.clone(), app.join(RAILS_APPLICATION_MANIFEST));
+    }
+
+    let mut engine_files: Vec<&PathBuf> = files
+        .iter()
+        .filter(|file| file.file_name().is_some_and(|name| name == "engine.rb"))
+        .collect();
+    engine_files.sort();
+    for file in engine_files {
+        let lib = file
+            .ancestors()
+            .skip(1)
+            .find(|dir| dir.file_name().is_some_and(|name| name == "lib"));
+        if let Some(root) = lib.and_then(Path::parent) {
+            push(root.to_path_buf(), file.clone());
+        }
+    }
+
+    let engine_apps: BTreeSet<PathBuf> = applications
+        .iter()
+        .flat_map(|app| {
+            let engines = app.join("engines");
+            files.iter().filter_map(move |file| {
+                let relative = file.strip_prefix(&engines).ok()?;
+                let mut components = relative.components();
+                let engine = components.next()?;
+                (components.next()?.as_os_str() == "app").then(|| engines.join(engine))
+            })
+        })
+        .collect();
+    for engine in engine_apps {
+        let origin = engine.join("app");
+        push(engine, origin);
+    }
+
+    roots
+}
+
+/// Autoload paths of the Rails application or engine at `root`: every
+/// `app/*` directory, the `concerns` directories beneath them, `lib`, and
+/// directories passed to `autoload_paths`, `eager_load_paths` or `collapse`
+/// in the root's configuration.
+fn rails_autoload_paths(
+    root: &Path,
+    origin: &Path,
+    files: &[PathBuf],
+    read: &dyn Fn(&Path) -> Option<String>,
+) -> Vec<PathBuf> {
+    let app = root.join("app");
+    let mut app_dirs: BTreeSet<PathBuf> = BTreeSet::new();
+    let mut has_lib = false;
+    for file in files {
+        if file.starts_with(root.join("lib")) {
+            has_lib = true;
+        }
+        let Ok(relative) = file.strip_prefix(&app) else {
+            continue;
+        };
+        let mut components = relative.components();
+        let (Some(dir), Some(next)) = (components.next(), components.next()) else {
+            continue;
+        };
+        if RAILS_NON_AUTOLOAD_DIRS
+            .iter()
+            .any(|skip| dir.as_os_str() == *skip)
+        {
+            continue;
+        }
+        app_dirs.insert(app.join(dir));
+        if next.as_os_str() == "concerns" && components.next().is_some() {
+            app_dirs.insert(app.join(dir).join("concerns"));
+        }
+    }
+
+    let mut paths: Vec<PathBuf> = app_dirs.into_iter().collect();
+    if has_lib {
+        paths.push(root.join("lib"));
+    }
+
+    let initializers = root.join("config/initializers");
+    let mut config_files: Vec<&Path> = vec![origin];
+    config_files.extend(
+        files
+            .iter()
+            .filter(|file| file.parent() == Some(initializers.as_path()))
+            .map(PathBuf::as_path),
+    );
+    let directories: BTreeSet<&Path> = files
+        .iter()
+        .flat_map(|file| file.ancestors().skip(1))
+        .collect();
+    for config_file in config_files {
+        let Some(source) = read(config_file) else {
+            continue;
+        };
+        for configured in configured_autoload_dirs(&source) {
+            let pattern = root.join(configured);
+            let pattern = pattern.to_string_lossy();
+            if !pattern.contains('*') {
+                paths.push(PathBuf::from(pattern.as_ref()));
+                continue;
+            }
+            let Ok(glob) = globset::GlobBuilder::new(&pattern)
+                .literal_separator(true)
+                .build()
+            else {
+                continue;
+            };
+            let matcher = glob.compile_matcher();
+            paths.extend(
+                directories
+                    .iter()
+                    .filter(|dir| matcher.is_match(dir))
+                    .map(|dir| dir.to_path_buf()),
+            );
+        }
+    }
+
+    paths
+}
+
+/// Directories, relative to the application root, named on lines of a Rails
+/// configuration file that touch `autoload_paths`, `eager_load_paths` or
+/// Zeitwerk's `collapse`. Handles `"#{root}/extras"` and
+/// `Rails.root.join("app", "components")` forms.
+fn configured_autoload_dirs(source: &str) -> Vec<String> {
+    let mut dirs = Vec::new();
+    for line in source.lines() {
+        let line = line.trim();
+        if line.starts_with('#')
+            || !["autoload_paths", "eager_load_paths", "collapse"]
+                .iter()
+                .any(|key| line.contains(key))
+        {
+            continue;
+        }
+        let literals = quoted_strings(line);
+        let literals: Vec<String> = if line.contains(".join(") {
+            vec![literals.join("/")]
+        } else {
+            literals
+        };
+        for literal in literals {
+            let literal = match literal.strip_prefix("#{") {
+                Some(rest) => match rest.split_once("}/") {
+                    Some((_, path)) => path.to_string(),
+                    None => continue,
+                },
+                None => literal,
+            };
+            let literal = literal.trim_end_matches('/');
+            if !literal.is_empty() && !literal.contains("#{") && !literal.starts_with('/') {
+                dirs.push(literal.to_string());
+            }
+        }
+    }
+    dirs
+}
+
+fn quoted_strings(line: &str) -> Vec<String> {
+    let mut strings = Vec::new();
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+        if c == '"' || c == '\'' {
+            let literal: String = chars.by_ref().take_while(|&next| next != c).collect();
+            strings.push(literal);
+        }
+    }
+    strings
+}
+
+/// The files Zeitwerk would autoload, keyed by the underscored path of the
+/// constant each defines (`Billing::Customer` → `billing/customer`).
+#[derive(Clone, Debug, Default)]
+pub struct RubyConstantIndex {
+    files_by_constant: HashMap<String, Vec<PathBuf>>,
+}
+
+impl RubyConstantIndex {
+    /// Index `files` (paths relative to the project root) under the deepest
+    /// load path containing them. Zeitwerk treats a load path nested in
+    /// another as a separate root, not as a namespace.
+    pub fn load(load_paths: &[PathBuf], files: &[PathBuf]) -> Self {
+        let mut files_by_constant: HashMap<String, Vec<PathBuf>> = HashMap::new();
+        for file in files {
+            if file.extension().is_none_or(|ext| ext != "rb") {
+                continue;
+            }
+            let Some(load_path) = load_paths
+                .iter()
+                .filter(|load_path| file.starts_with(load_path))
+                .max_by_key(|load_path| load_path.components().count())
+            else {
+                continue;
+            };
+            let Ok(relative) = file.strip_prefix(load_path) else {
+                continue;
+            };
+            let key = relative
+                .with_extension("")
+                .components()
+                .map(|component| component.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/");
+            let entry = files_by_constant.entry(key).or_default();
+            if !entry.contains(file) {
+                entry.push(file.clone());
+            }
+        }
+        Self { files_by_constant }
+    }
+
+    /// Index the Ruby `files` under `project_root`.
+    pub fn discover(project_root: &Path, load_paths: &[PathBuf], files: &[PathBuf]) -> Self {
+        let files: Vec<PathBuf> = files
+            .iter()
+            .map(|file| {
+                file.strip_prefix(project_root)
+                    .unwrap_or(file)
+                    .to_path_buf()
+            })
+            .collect();
+        Self::load(load_paths, &files)
+    }
+
+    /// Resolve `reference` with Ruby's lexical constant lookup: each
+    /// enclosing namespace in `nesting` from the innermost outwards, then the
+    /// top level. Returns the qualified constant name, `Ambiguous` when it is
+    /// defined by several files or only reachable through a namespace that a
+    /// compact `class A::B` definition leaves out of the lexical scope.
+    fn lookup(&self, reference: &str, nesting: &[String]) -> (String, ImportConfidence) {
+        let confidence = |constant: &str| match self.files(constant) {
+            Some(files) if files.len() > 1 => Some(ImportConfidence::Ambiguous),
+            Some(_) => Some(ImportConfidence::Resolved),
+            None => None,
+        };
+
+        if let Some(absolute) = reference.strip_prefix("::") {
+            let confidence = confidence(absolute).unwrap_or(ImportConfidence::Resolved);
+            return (absolute.to_string(), confidence);
+        }
+
+        let lexical = nesting
+            .iter()
+            .rev()
+            .map(|scope| format!("{scope}::{reference}"))
+            .chain(std::iter::once(reference.to_string()));
+        for candidate in lexical {
+            if let Some(confidence) = confidence(&candidate) {
+                return (candidate, confidence);
+            }
+        }
+
+        let skipped = nesting.iter().flat_map(|scope| {
+            scope
+                .match_indices("::")
+                .map(|(at, _)| &scope[..at])
+                .filter(|namespace| !nesting.iter().any(|scope| scope == namespace))
+        });
+        for namespace in skipped {
+            let candidate = format!("{namespace}::{reference}");
+            if self.files(&candidate).is_some() {
+                return (candidate, ImportConfidence::Ambiguous);
+            }
+        }
+
+        (reference.to_string(), ImportConfidence::Resolved)
+    }
+
+    fn files(&self, constant: &str) -> Option<&Vec<PathBuf>> {
+        self.files_by_constant.get(&constant_path(constant))
+    }
+}
+
+/// `Admin::User` → `admin/user`
+fn constant_path(constant: &str) -> String {
+    constant
+        .trim_start_matches("::")
+        .split("::")
+        .map(crate::parse::resolver::camel_to_snake)
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
 pub struct RubyFrontend {
     load_paths: Vec<PathBuf>,
     zeitwerk: bool,
+    constants: Option<Arc<RubyConstantIndex>>,
 }
 
 impl RubyFrontend {
     pub fn new() -> Self {
-        Self {
-            load_paths: vec![PathBuf::from("lib"), PathBuf::from("app")],
-            zeitwerk: false,
-        }
+        Self::with_load_paths(vec![PathBuf::from("lib"), PathBuf::from("app")])
     }
 
     pub fn with_load_paths(load_paths: Vec<PathBuf>) -> Self {
         Self {
             load_paths,
             zeitwerk: false,
+            constants: None,
         }
     }
 
@@ -88,6 +474,12 @@ impl RubyFrontend {
         self.zeitwerk = zeitwerk;
         self
     }
+
+    /// Resolve Zeitwerk constant references lexically against `constants`.
+    pub fn with_constant_index(mut self, constants: Arc<RubyConstantIndex>) -> Self {
+        self.constants = Some(constants);
+        self
+    }
 }
 
 impl Default for RubyFrontend {
@@ -119,7 +511,7 @@ impl ParseFrontend for RubyFrontend {
 
         // Extract Zeitwerk constants if enabled
         if self.zeitwerk {
-            Self::extract_zeitwerk_constants(tree.root_node(), source, file_path, &mut imports);
+            self.extract_zeitwerk_constants(tree.root_node(), source, file_path, &mut imports);
         }
 
         imports
@@ -193,13 +585,7 @@ impl ParseFrontend for RubyFrontend {
                 None
             }
             ImportKind::ZeitwerkConstant => {
-                // Admin::User → admin/user
-                let parts: Vec<String> = raw
-                    .raw_path
-                    .split("::")
-                    .map(crate::parse::resolver::camel_to_snake)
-                    .collect();
-                let relative = parts.join("/");
+                let relative = constant_path(&raw.raw_path);
                 for load_path in &self.load_paths {
                     let target = load_path.join(&relative).with_extension("rb");
                     if project_files
@@ -253,6 +639,8 @@ impl RubyFrontend {
                                             column: Some(node.start_position().column),
                                             kind,
                                             confidence,
+                                            cfg: None,
+                                            context: ImportContext::Runtime,
                                         });
                                     }
                                 }
@@ -289,6 +677,8 @@ impl RubyFrontend {
                                         constant: constant_name,
                                     },
                                     confidence: ImportConfidence::Resolved,
+                                    cfg: None,
+                                    context: ImportContext::Runtime,
                                 });
                             }
                         }
@@ -309,6 +699,7 @@ impl RubyFrontend {
     /// Walks for `constant` and `scope_resolution` nodes, skipping class/module
     /// definitions and stdlib constants.
     fn extract_zeitwerk_constants(
+        &self,
         node: tree_sitter::Node,
         source: &[u8],
         file_path: &Path,
@@ -316,7 +707,8 @@ impl RubyFrontend {
     ) {
         // Collect all constant references, excluding definitions
         let mut seen = std::collections::HashSet::new();
-        Self::walk_for_constants(node, source, file_path, imports, &mut seen);
+        let mut nesting = Vec::new();
+        self.walk_for_constants(node, source, file_path, &mut nesting, imports, &mut seen);
     }
 
     /// Check if a node is the "name" field of a class or module definition.
@@ -333,66 +725,83 @@ impl RubyFrontend {
         false
     }
 
+    /// `nesting` holds the fully qualified names of the enclosing
+    /// `class`/`module` definitions, outermost first, like the reverse of
+    /// Ruby's `Module.nesting`.
     fn walk_for_constants(
+        &self,
         node: tree_sitter::Node,
         source: &[u8],
         file_path: &Path,
+        nesting: &mut Vec<String>,
         imports: &mut Vec<RawImport>,
         seen: &mut std::collections::HashSet<String>,
     ) {
         let kind = node.kind();
 
-        if kind == "scope_resolution" {
-            // Skip if this is a class/module definition name
-            if Self::is_class_or_module_name(node) {
-                return;
+        if kind == "class" || kind == "module" {
+            let name = node
+                .child_by_field_name("name")
+                .and_then(|name| name.utf8_text(source).ok())
+                .unwrap_or_default();
+            let qualified = match (name.strip_prefix("::"), nesting.last()) {
+                (Some(absolute), _) => absolute.to_string(),
+                (None, Some(outer)) => format!("{outer}::{name}"),
+                (None, None) => name.to_string(),
+            };
+            let mut cursor = node.walk();
+            for child in node.children(&mut cursor) {
+                // A superclass is looked up in the enclosing scope.
+                if child.kind() == "superclass" {
+                    self.walk_for_constants(child, source, file_path, nesting, imports, seen);
+                }
             }
-            // Full scoped constant like Admin::User
-            let text = node.utf8_text(source).unwrap_or_default().to_string();
-            if !text.is_empty() && !Self::is_stdlib_constant(&text) && seen.insert(text.clone()) {
-                imports.push(RawImport {
-                    raw_path: text,
-                    source_file: file_path.to_path_buf(),
-                    line: node.start_position().row + 1,
-                    column: Some(node.start_position().column),
-                    kind: ImportKind::ZeitwerkConstant,
-                    confidence: ImportConfidence::Resolved,
-                });
+            if let Some(body) = node.child_by_field_name("body") {
+                nesting.push(qualified);
+                self.walk_for_constants(body, source, file_path, nesting, imports, seen);
+                nesting.pop();
             }
-            return; // Don't recurse into children of scope_resolution
+            return;
         }
 
-        if kind == "constant" {
+        let is_reference = match kind {
+            "scope_resolution" => true,
+            // Skip if parent is a scope_resolution (handled above)
+            "constant" => node
+                .parent()
+                .is_none_or(|parent| parent.kind() != "scope_resolution"),
+            _ => false,
+        };
+        if is_reference {
             // Skip if this is a class/module definition name
             if Self::is_class_or_module_name(node) {
                 return;
             }
-            let text = node.utf8_text(source).unwrap_or_default().to_string();
-            // Skip if parent is a scope_resolution (handled above)
-            let parent_is_scope = node
-                .parent()
-                .map(|p| p.kind() == "scope_resolution")
-                .unwrap_or(false);
-            if !parent_is_scope
-                && !text.is_empty()
-                && !Self::is_stdlib_constant(&text)
-                && seen.insert(text.clone())
-            {
-                imports.push(RawImport {
-                    raw_path: text,
-                    source_file: file_path.to_path_buf(),
-                    line: node.start_position().row + 1,
-                    column: Some(node.start_position().column),
-                    kind: ImportKind::ZeitwerkConstant,
-                    confidence: ImportConfidence::Resolved,
-                });
+            let text = node.utf8_text(source).unwrap_or_default();
+            if !text.is_empty() && !Self::is_stdlib_constant(text) {
+                let (raw_path, confidence) = match &self.constants {
+                    Some(constants) => constants.lookup(text, nesting),
+                    None => (text.to_string(), ImportConfidence::Resolved),
+                };
+                if seen.insert(raw_path.clone()) {
+                    imports.push(RawImport {
+                        raw_path,
+                        source_file: file_path.to_path_buf(),
+                        line: node.start_position().row + 1,
+                        column: Some(node.start_position().column),
+                        kind: ImportKind::ZeitwerkConstant,
+                        confidence,
+                        cfg: None,
+                        context: ImportContext::Runtime,
+                    });
+                }
             }
-            return;
+            return; // Don't recurse into children of scope_resolution
         }
 
         let mut cursor = node.walk();
         for child in node.children(&mut cursor) {
-            Self::walk_for_constants(child, source, file_path, imports, seen);
+            self.walk_for_constants(child, source, file_path, nesting, imports, seen);
         }
     }
 
@@ -565,6 +974,8 @@ end
             column: None,
             kind: ImportKind::ZeitwerkConstant,
             confidence: ImportConfidence::Resolved,
+            cfg: None,
+            context: ImportContext::Runtime,
         };
         let project_files = vec![PathBuf::from("/project/app/models/user.rb")];
         let resolved = frontend.resolve(&raw, Path::new("/project"), &project_files);
@@ -582,6 +993,8 @@ end
             column: None,
             kind: ImportKind::ZeitwerkConstant,
             confidence: ImportConfidence::Resolved,
+            cfg: None,
+            context: ImportContext::Runtime,
         };
         let project_files = vec![PathBuf::from("/project/app/models/admin/user.rb")];
         let resolved = frontend.resolve(&raw, Path::new("/project"), &project_files);
@@ -630,4 +1043,151 @@ end
             }
         }
     }
+
+    #[test]
+    fn infers_rails_application_and_engine_load_paths() {
+        let files: Vec<PathBuf> = [
+            "config/application.rb",
+            "config/initializers/zeitwerk.rb",
+            "app/models/user.rb",
+            "app/models/concerns/trackable.rb",
+            "app/controllers/users_controller.rb",
+            "app/views/users/helper.rb",
+            "app/components/card/card_component.rb",
+            "lib/tasks/seed.rb",
+            "engines/billing/app/models/billing/invoice.rb",
+            "engines/reports/app/models/report.rb",
+            "engines/reports/lib/reports/engine.rb",
+        ]
+        .into_iter()
+        .map(PathBuf::from)
+        .collect();
+        let read = |path: &Path| {
+            match path.to_str()? {
+            "config/application.rb" => {
+                Some("config.autoload_paths << Rails.root.join(\"app\", \"extras\")\n".into())
+            }
+            "config/initializers/zeitwerk.rb" => Some(
+                "# collapse(\"#{root}/ignored\")\nRails.autoloaders.main.collapse(\"#{Rails.root}/app/components/*/\")\n"
+                    .into(),
+            ),
+            _ => None,
+        }
+        };
+
+        let load_paths = RubyLoadPaths::load(&["lib".into()], true, &files, &read);
+        let inferred: Vec<(&str, &str)> = load_paths
+            .inferred
+            .iter()
+            .map(|inferred| {
+                (
+                    inferred.path.to_str().unwrap(),
+                    inferred.origin.to_str().unwrap(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            inferred,
+            vec![
+                ("app/components", "config/application.rb"),
+                ("app/controllers", "config/application.rb"),
+                ("app/models", "config/application.rb"),
+                ("app/models/concerns", "config/application.rb"),
+                ("app/extras", "config/application.rb"),
+                ("app/components/card", "config/application.rb"),
+                (
+                    "engines/reports/app/models",
+                    "engines/reports/lib/reports/engine.rb"
+                ),
+                (
+                    "engines/reports/lib",
+                    "engines/reports/lib/reports/engine.rb"
+                ),
+                ("engines/billing/app/models", "engines/billing/app"),
+            ]
+        );
+        assert_eq!(load_paths.paths()[0], PathBuf::from("lib"));
+    }
+
+    #[test]
+    fn skips_rails_detection_when_disabled_or_absent() {
+        let files = vec![PathBuf::from("app/models/user.rb")];
+        let read = |_: &Path| None;
+        assert!(RubyLoadPaths::load(&[], true, &files, &read)
+            .inferred
+            .is_empty());
+
+        let files = vec![
+            PathBuf::from("config/application.rb"),
+            PathBuf::from("app/models/user.rb"),
+        ];
+        assert!(RubyLoadPaths::load(&[], false, &files, &read)
+            .inferred
+            .is_empty());
+    }
+
+    #[test]
+    fn zeitwerk_looks_up_constants_through_lexical_nesting() {
+        let files: Vec<PathBuf> = [
+            "app/models/customer.rb",
+            "app/models/billing/customer.rb",
+            "app/models/billing/ledger.rb",
+            "app/models/billing/payment.rb",
+            "app/models/billing/invoice.rb",
+            "app/models/report.rb",
+            "engines/billing/app/models/report.rb",
+        ]
+        .into_iter()
+        .map(PathBuf::from)
+        .collect();
+        let constants = RubyConstantIndex::load(
+            &[
+                PathBuf::from("app"),
+                PathBuf::from("app/models"),
+                PathBuf::from("engines/billing/app/models"),
+            ],
+            &files,
+        );
+        let frontend = RubyFrontend::with_load_paths(vec![PathBuf::from("app/models")])
+            .with_zeitwerk(true)
+            .with_constant_index(Arc::new(constants));
+        let source = br#"
+module Billing
+  class Invoice < Ledger
+    def build
+      Customer.new
+      ::Customer.first
+      Report.generate
+    end
+  end
+end
+
+class Billing::Refund
+  def payment
+    Payment.find(1)
+  end
+end
+"#;
+        let imports = frontend.extract_imports(source, Path::new("app/models/billing/invoice.rb"));
+        let constants: Vec<(&str, ImportConfidence)> = imports
+            .iter()
+            .filter(|i| matches!(i.kind, ImportKind::ZeitwerkConstant))
+            .map(|i| (i.raw_path.as_str(), i.confidence.clone()))
+            .collect();
+
+        assert_eq!(
+            constants,
+            vec![
+                // Superclass looked up from `module Billing`.
+                ("Billing::Ledger", ImportConfidence::Resolved),
+                // Nesting chain before the top level.
+                ("Billing::Customer", ImportConfidence::Resolved),
+                ("Customer", ImportConfidence::Resolved),
+                // Defined under two load paths.
+                ("Report", ImportConfidence::Ambiguous),
+                // `class Billing::Refund` does not open `Billing` lexically.
+                ("Billing::Payment", ImportConfidence::Ambiguous),
+            ]
+        );
+    }
 }
@@ -1,7 +1,9 @@
 use crate::analysis_context::{RustPackage, RustWorkspaceContext};
-use crate::parse::common::{ImportConfidence, ImportKind, RawImport};
+use crate::parse::cfg::CfgPredicate;
+use crate::parse::common::{ImportConfidence, ImportContext, ImportKind, RawImport};
 use crate::parse::ParseFrontend;
-use std::path::{Path, PathBuf};
+use std::collections::HashSet;
+use std::path::{Component, Path, PathBuf};
 use streaming_iterator::StreamingIterator;
 
 pub struct RustFrontend {
@@ -20,13 +22,7 @@ impl RustFrontend {
     }
 
     pub fn with_crate_name(name: String) -> Self {
-        Self::with_workspace(RustWorkspaceContext::from_packages(vec![RustPackage {
-            normalized_name: normalize_crate_segment(&name),
-            name,
-            manifest_dir: PathBuf::new(),
-            source_roots: vec![PathBuf::from("src")],
-            entry_source_root: PathBuf::from("src"),
-        }]))
+        Self::with_workspace(RustWorkspaceContext::single_crate(name))
     }
 
     /// Read the crate name from Cargo.toml at the given project root.
@@ -190,6 +186,250 @@ impl RustFrontend {
             }
         }
     }
+
+    /// Collect project paths written inline (`crate::storage::Pool::new()`,
+    /// `super::util::f()`) outside `use` declarations. Only the outermost
+    /// path of a `a::b::c` chain is recorded; `in_chain` marks its prefixes.
+    fn collect_qualified_paths(
+        &self,
+        node: tree_sitter::Node,
+        source: &[u8],
+        file_path: &Path,
+        in_chain: bool,
+        seen: &mut HashSet<(String, usize)>,
+        imports: &mut Vec<RawImport>,
+    ) {
+        match node.kind() {
+            // Macro arguments are unparsed token trees, and `crate::m!` names
+            // a `#[macro_export]` macro at the crate root, not a module.
+            "use_declaration" | "visibility_modifier" | "macro_invocation" => return,
+            "scoped_identifier" | "scoped_type_identifier" if !in_chain => {
+                if let Some(segments) = qualified_segments(node, source) {
+                    let path = segments.join("::");
+                    let line = node.start_position().row + 1;
+                    let first_segment = segments[0].as_str();
+                    let is_project_path = matches!(first_segment, "crate" | "self" | "super")
+                        || self.classify_import(&path, file_path) == ImportConfidence::Resolved;
+                    if segments.len() > 1 && is_project_path && seen.insert((path.clone(), line)) {
+                        imports.push(RawImport {
+                            raw_path: path,
+                            source_file: file_path.to_path_buf(),
+                            line,
+                            column: Some(node.start_position().column),
+                            kind: ImportKind::QualifiedPath,
+                            confidence: ImportConfidence::Resolved,
+                            cfg: active_cfg(node, source),
+                            context: ImportContext::Runtime,
+                        });
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        let chain_child = match node.kind() {
+            "scoped_identifier" | "scoped_type_identifier" => node.child_by_field_name("path"),
+            "generic_type" if in_chain => node.child_by_field_name("type"),
+            _ => None,
+        };
+        let mut cursor = node.walk();
+        for child in node.named_children(&mut cursor) {
+            let child_in_chain = chain_child == Some(child);
+            self.collect_qualified_paths(child, source, file_path, child_in_chain, seen, imports);
+        }
+    }
+
+    /// Collect `mod foo;` declarations under `node`. Inline modules
+    /// (`mod foo { ... }`) are walked with their name prepended, since their
+    /// out-of-line children live in a subdirectory named after them.
+    fn collect_mod_declarations(
+        node: tree_sitter::Node,
+        source: &[u8],
+        file_path: &Path,
+        prefix: &str,
+        imports: &mut Vec<RawImport>,
+    ) {
+        let mut cursor = node.walk();
+        for item in node.named_children(&mut cursor) {
+            if item.kind() != "mod_item" {
+                continue;
+            }
+            let Some(name) = item
+                .child_by_field_name("name")
+                .and_then(|name| name.utf8_text(source).ok())
+            else {
+                continue;
+            };
+            let module_path = if prefix.is_empty() {
+                name.to_string()
+            } else {
+                format!("{prefix}::{name}")
+            };
+
+            if let Some(body) = item.child_by_field_name("body") {
+                Self::collect_mod_declarations(body, source, file_path, &module_path, imports);
+                continue;
+            }
+
+            imports.push(RawImport {
+                raw_path: module_path,
+                source_file: file_path.to_path_buf(),
+                line: item.start_position().row + 1,
+                column: Some(item.start_position().column),
+                kind: ImportKind::ModDeclaration {
+                    path: path_attribute(item, source),
+                },
+                confidence: ImportConfidence::Resolved,
+                cfg: active_cfg(item, source),
+                context: ImportContext::Runtime,
+            });
+        }
+    }
+
+    /// Map a `mod` declaration to its file: the `#[path]` override relative
+    /// to the declaring file's directory, or `name.rs` / `name/mod.rs` under
+    /// the declaring module's directory.
+    fn resolve_mod_declaration(
+        &self,
+        raw: &RawImport,
+        path_override: Option<&str>,
+        project_root: &Path,
+        project_files: &[PathBuf],
+    ) -> Option<PathBuf> {
+        let package = self.current_package(&raw.source_file, project_root)?;
+        let absolute_source = absolutize(&raw.source_file, project_root);
+        let source_dir = absolute_source.parent()?;
+
+        // `lib.rs`, `main.rs` and `mod.rs` own their directory; any other
+        // `foo.rs` keeps its children in `foo/`.
+        let owns_directory = matches!(
+            absolute_source.file_name()?.to_str()?,
+            "mod.rs" | "lib.rs" | "main.rs" | "build.rs"
+        ) || package.source_roots.iter().any(|root| {
+            root.as_path() == source_dir
+                && root != &package.entry_source_root
+                && root.file_name().is_some_and(|name| {
+                    matches!(
+                        name.to_str(),
+                        Some("bin" | "tests" | "examples" | "benches")
+                    )
+                })
+        });
+        let module_dir = if owns_directory {
+            source_dir.to_path_buf()
+        } else {
+            source_dir.join(absolute_source.file_stem()?)
+        };
+
+        let mut segments: Vec<&str> = raw.raw_path.split("::").collect();
+        let name = segments.pop()?;
+        let inline_dir = segments
+            .iter()
+            .fold(module_dir, |dir, segment| dir.join(segment));
+
+        let candidates = match path_override {
+            // Top-level `#[path]` is relative to the declaring file's directory.
+            Some(path) if segments.is_empty() => vec![lexical_normalize(&source_dir.join(path))],
+            Some(path) => vec![lexical_normalize(&inline_dir.join(path))],
+            None => vec![
+                inline_dir.join(format!("{name}.rs")),
+                inline_dir.join(name).join("mod.rs"),
+            ],
+        };
+
+        candidates
+            .into_iter()
+            .find(|candidate| Self::file_exists_in_project(candidate, project_root, project_files))
+            .and_then(|file| package.module_id_for_file(&file))
+    }
+}
+
+/// Segments of a `a::b::c` path, looking through turbofish generics
+/// (`Pool::<u8>::new`). None for paths rooted in a qualified type
+/// (`<T as Trait>::f`) or the global root (`::std::f`).
+fn qualified_segments(node: tree_sitter::Node, source: &[u8]) -> Option<Vec<String>> {
+    match node.kind() {
+        "scoped_identifier" | "scoped_type_identifier" => {
+            let mut segments = qualified_segments(node.child_by_field_name("path")?, source)?;
+            let name = node.child_by_field_name("name")?;
+            segments.push(name.utf8_text(source).ok()?.to_string());
+            Some(segments)
+        }
+        "generic_type" => qualified_segments(node.child_by_field_name("type")?, source),
+        "identifier" | "type_identifier" | "crate" | "self" | "super" => {
+            Some(vec![node.utf8_text(source).ok()?.to_string()])
+        }
+        _ => None,
+    }
+}
+
+/// Outer attributes (`#[...]`) written directly before `item`, nearest first.
+fn outer_attributes(item: tree_sitter::Node) -> Vec<tree_sitter::Node> {
+    let mut attributes = Vec::new();
+    let mut sibling = item.prev_named_sibling();
+    while let Some(node) = sibling {
+        match node.kind() {
+            "attribute_item" => attributes.extend(node.named_child(0)),
+            "line_comment" | "block_comment" => {}
+            _ => break,
+        }
+        sibling = node.prev_named_sibling();
+    }
+    attributes
+}
+
+fn attribute_name<'a>(attribute: tree_sitter::Node, source: &'a [u8]) -> Option<&'a str> {
+    attribute
+        .named_child(0)
+        .and_then(|name| name.utf8_text(source).ok())
+}
+
+/// Value of a `#[path = "..."]` attribute attached to `item`.
+fn path_attribute(item: tree_sitter::Node, source: &[u8]) -> Option<String> {
+    let attribute = outer_attributes(item)
+        .into_iter()
+        .find(|attribute| attribute_name(*attribute, source) == Some("path"))?;
+    let value = attribute.child_by_field_name("value")?;
+    value
+        .utf8_text(source)
+        .ok()
+        .map(|text| text.trim_matches('"').to_string())
+}
+
+/// Predicate of a `cfg(...)` attribute, or None for any other attribute.
+fn cfg_attribute(attribute: tree_sitter::Node, source: &[u8]) -> Option<CfgPredicate> {
+    if attribute_name(attribute, source) != Some("cfg") {
+        return None;
+    }
+    let arguments = attribute.child_by_field_name("arguments")?;
+    let text = arguments.utf8_text(source).ok()?;
+    CfgPredicate::parse(text.strip_prefix('(')?.strip_suffix(')')?)
+}
+
+/// The conjunction of every `#[cfg]` on `node` and its enclosing items, and
+/// every `#![cfg]` on its enclosing modules and file, outermost first.
+fn active_cfg(node: tree_sitter::Node, source: &[u8]) -> Option<CfgPredicate> {
+    let mut predicates = Vec::new();
+    let mut current = Some(node);
+    while let Some(scope) = current {
+        predicates.extend(
+            outer_attributes(scope)
+                .into_iter()
+                .filter_map(|attribute| cfg_attribute(attribute, source)),
+        );
+        if matches!(scope.kind(), "source_file" | "declaration_list") {
+            let mut cursor = scope.walk();
+            predicates.extend(
+                scope
+                    .named_children(&mut cursor)
+                    .filter(|child| child.kind() == "inner_attribute_item")
+                    .filter_map(|child| child.named_child(0))
+                    .filter_map(|attribute| cfg_attribute(attribute, source)),
+            );
+        }
+        current = scope.parent();
+    }
+    predicates.into_iter().rev().reduce(CfgPredicate::and)
 }
 
 impl Default for RustFrontend {
@@ -230,6 +470,7 @@ impl ParseFrontend for RustFrontend {
 
                 let mut paths = Vec::new();
                 Self::collect_paths(node, source, "", &mut paths);
+                let cfg = active_cfg(node, source);
 
                 for path in paths {
                     if path.is_empty() {
@@ -243,11 +484,24 @@ impl ParseFrontend for RustFrontend {
                         column,
                         kind: ImportKind::Direct,
                         confidence,
+                        cfg: cfg.clone(),
+                        context: ImportContext::Runtime,
                     });
                 }
             }
         }
 
+        let mut seen = HashSet::new();
+        self.collect_qualified_paths(
+            tree.root_node(),
+            source,
+            file_path,
+            false,
+            &mut seen,
+            &mut imports,
+        );
+        Self::collect_mod_declarations(tree.root_node(), source, file_path, "", &mut imports);
+
         imports
     }
 
@@ -261,6 +515,42 @@ impl ParseFrontend for RustFrontend {
             return None;
         }
 
+        if let ImportKind::ModDeclaration { path } = &raw.kind {
+            return self.resolve_mod_declaration(raw, path.as_deref(), project_root, project_files);
+        }
+
+        if let ImportKind::QualifiedPath = raw.kind {
+            // `crate::f()` naming an item of the file itself is not an edge.
+            let own_module = self
+                .current_package(&raw.source_file, project_root)?
+                .module_id_for_file(&absolutize(&raw.source_file, project_root))?;
+            return self
+                .resolve_use_path(raw, project_root, project_files)
+                .filter(|target| *target != own_module);
+        }
+
+        self.resolve_use_path(raw, project_root, project_files)
+    }
+
+    fn external_package(&self, raw: &RawImport) -> Option<String> {
+        if raw.confidence != ImportConfidence::External
+            || matches!(raw.kind, ImportKind::ModDeclaration { .. })
+        {
+            return None;
+        }
+        let crate_name = raw.raw_path.trim_start_matches("::").split("::").next()?;
+        (!matches!(crate_name, "" | "std" | "core" | "alloc")).then(|| crate_name.to_string())
+    }
+}
+
+impl RustFrontend {
+    /// Resolve a `::`-separated path through the module tree of its crate.
+    fn resolve_use_path(
+        &self,
+        raw: &RawImport,
+        project_root: &Path,
+        project_files: &[PathBuf],
+    ) -> Option<PathBuf> {
         let workspace = self.workspace.as_ref()?;
         let current_package = workspace.find_package_for_file(&raw.source_file, project_root)?;
         let current_source_root = self.current_source_root(&raw.source_file, project_root)?;
@@ -277,12 +567,12 @@ impl ParseFrontend for RustFrontend {
             "super" => {
                 let absolute_source = absolutize(&raw.source_file, project_root);
                 let source_dir = absolute_source.parent()?;
-                let base_dir = if absolute_source.file_name()?.to_str()? == "mod.rs" {
+                // `a/b.rs` and `a/b/mod.rs` are both children of the module in `a`.
+                let parent_dir = if absolute_source.file_name()?.to_str()? == "mod.rs" {
                     source_dir.parent()?
                 } else {
                     source_dir
                 };
-                let parent_dir = base_dir.parent()?;
                 let rest = path.strip_prefix("super::")?;
                 let candidate = parent_dir.join(rest.replace("::", "/"));
                 Self::find_module_file(current_package, &candidate, project_files, project_root)
@@ -306,9 +596,7 @@ impl ParseFrontend for RustFrontend {
             }
         }
     }
-}
 
-impl RustFrontend {
     fn find_module_file(
         package: &RustPackage,
         candidate: &Path,
@@ -358,6 +646,21 @@ fn absolutize(path: &Path, project_root: &Path) -> PathBuf {
     }
 }
 
+/// Fold `.` and `..` components without touching the filesystem.
+fn lexical_normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            other => normalized.push(other),
+        }
+    }
+    normalized
+}
+
 fn normalize_crate_segment(segment: &str) -> String {
     segment.replace('-', "_")
 }
@@ -395,6 +698,18 @@ mod tests {
         assert_eq!(imports[0].confidence, ImportConfidence::External);
     }
 
+    #[test]
+    fn external_packages_are_crate_names() {
+        let source = b"use std::fmt;\nuse serde::Serialize;\nuse other_crate::Thing;\nuse crate::module::Item;";
+        let frontend = RustFrontend::with_workspace(workspace());
+        let packages: Vec<Option<String>> = frontend
+            .extract_imports(source, Path::new("/project/src/main.rs"))
+            .iter()
+            .map(|import| frontend.external_package(import))
+            .collect();
+        assert_eq!(packages, vec![None, Some("serde".to_string()), None, None]);
+    }
+
     #[test]
     fn extracts_crate_import() {
         let source = b"use crate::module::Item;";
@@ -459,6 +774,8 @@ mod tests {
             column: None,
             kind: ImportKind::Direct,
             confidence: ImportConfidence::Resolved,
+            cfg: None,
+            context: ImportContext::Runtime,
         };
         let resolved = frontend.resolve(&raw, project_root, &project_files);
         assert_eq!(resolved, Some(PathBuf::from("other_crate/src/module.rs")));
@@ -479,6 +796,8 @@ mod tests {
             column: None,
             kind: ImportKind::Direct,
             confidence: ImportConfidence::Resolved,
+            cfg: None,
+            context: ImportContext::Runtime,
         };
         let resolved = frontend.resolve(&raw, project_root, &project_files);
         assert_eq!(resolved, Some(PathBuf::from("my_crate/src/module.rs")));
@@ -499,6 +818,8 @@ mod tests {
             column: None,
             kind: ImportKind::Direct,
             confidence: ImportConfidence::Resolved,
+            cfg: None,
+            context: ImportContext::Runtime,
         };
         let resolved = frontend.resolve(&raw, project_root, &project_files);
         assert_eq!(resolved, Some(PathBuf::from("my_crate/src/foo/bar.rs")));
@@ -519,6 +840,8 @@ mod tests {
             column: None,
             kind: ImportKind::Direct,
             confidence: ImportConfidence::Resolved,
+            cfg: None,
+            context: ImportContext::Runtime,
         };
         let resolved = frontend.resolve(&raw, project_root, &project_files);
         assert_eq!(resolved, Some(PathBuf::from("my_crate/src/module.rs")));
@@ -534,4 +857,236 @@ mod tests {
         assert!(paths.contains(&"std::collections::HashMap"));
         assert!(paths.contains(&"std::collections::BTreeMap"));
     }
+
+    fn mod_declaration(raw_path: &str, source_file: &str, path: Option<&str>) -> RawImport {
+        RawImport {
+            raw_path: raw_path.into(),
+            source_file: PathBuf::from(source_file),
+            line: 1,
+            column: None,
+            kind: ImportKind::ModDeclaration {
+                path: path.map(str::to_string),
+            },
+            confidence: ImportConfidence::Resolved,
+            cfg: None,
+            context: ImportContext::Runtime,
+        }
+    }
+
+    #[test]
+    fn extracts_mod_declarations_with_path_attributes() {
+        let source = br#"
+#[cfg(unix)]
+// platform backend
+#[path = "sys/unix.rs"]
+mod imp;
+pub(crate) mod config;
+mod inline {
+    mod nested;
+}
+mod tests { fn helper() {} }
+"#;
+        let frontend = RustFrontend::with_workspace(workspace());
+        let imports = frontend.extract_imports(source, Path::new("/project/src/lib.rs"));
+        let declarations: Vec<(&str, Option<&str>)> = imports
+            .iter()
+            .filter_map(|import| match &import.kind {
+                ImportKind::ModDeclaration { path } => {
+                    Some((import.raw_path.as_str(), path.as_deref()))
+                }
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            declarations,
+            vec![
+                ("imp", Some("sys/unix.rs")),
+                ("config", None),
+                ("inline::nested", None),
+            ]
+        );
+    }
+
+    #[test]
+    fn resolves_mod_declarations_by_module_directory() {
+        let frontend = RustFrontend::with_workspace(workspace());
+        let project_root = Path::new("/project");
+        let project_files = vec![
+            PathBuf::from("/project/src/lib.rs"),
+            PathBuf::from("/project/src/config.rs"),
+            PathBuf::from("/project/src/config/loader/mod.rs"),
+            PathBuf::from("/project/src/config/inline/nested.rs"),
+        ];
+        let resolve = |raw: RawImport| frontend.resolve(&raw, project_root, &project_files);
+
+        assert_eq!(
+            resolve(mod_declaration("config", "/project/src/lib.rs", None)),
+            Some(PathBuf::from("my_crate/src/config.rs"))
+        );
+        // Children of a non-mod.rs file live in a directory named after it.
+        assert_eq!(
+            resolve(mod_declaration("loader", "/project/src/config.rs", None)),
+            Some(PathBuf::from("my_crate/src/config/loader/mod.rs"))
+        );
+        assert_eq!(
+            resolve(mod_declaration(
+                "inline::nested",
+                "/project/src/config.rs",
+                None
+            )),
+            Some(PathBuf::from("my_crate/src/config/inline/nested.rs"))
+        );
+        assert_eq!(
+            resolve(mod_declaration("missing", "/project/src/lib.rs", None)),
+            None
+        );
+    }
+
+    #[test]
+    fn resolves_mod_declarations_through_path_attribute() {
+        let frontend = RustFrontend::with_workspace(workspace());
+        let project_root = Path::new("/project");
+        let project_files = vec![
+            PathBuf::from("/project/src/lib.rs"),
+            PathBuf::from("/project/src/net.rs"),
+            PathBuf::from("/project/src/sys/unix.rs"),
+            PathBuf::from("/project/src/imp.rs"),
+        ];
+        let resolve = |raw: RawImport| frontend.resolve(&raw, project_root, &project_files);
+
+        assert_eq!(
+            resolve(mod_declaration(
+                "imp",
+                "/project/src/lib.rs",
+                Some("sys/unix.rs")
+            )),
+            Some(PathBuf::from("my_crate/src/sys/unix.rs"))
+        );
+        // `#[path]` is relative to the declaring file's directory, not `net/`.
+        assert_eq!(
+            resolve(mod_declaration(
+                "imp",
+                "/project/src/net.rs",
+                Some("../src/sys/./unix.rs")
+            )),
+            Some(PathBuf::from("my_crate/src/sys/unix.rs"))
+        );
+    }
+
+    #[test]
+    fn extracts_inline_qualified_paths() {
+        let source = br#"
+use crate::config::Config;
+
+pub(in crate::net) fn open() -> crate::storage::Pool<crate::model::Row> {
+    let pool = crate::storage::Pool::<u8>::new();
+    super::util::f();
+    <crate::codec::Json as Encode>::encode();
+    other_crate::client::connect();
+    std::mem::drop(pool);
+    Self::helper();
+    crate::log!("ignored");
+}
+"#;
+        let frontend = RustFrontend::with_workspace(workspace());
+        let imports = frontend.extract_imports(source, Path::new("/project/src/db.rs"));
+        let inline: Vec<(&str, usize)> = imports
+            .iter()
+            .filter(|import| matches!(import.kind, ImportKind::QualifiedPath))
+            .map(|import| (import.raw_path.as_str(), import.line))
+            .collect();
+        assert_eq!(
+            inline,
+            vec![
+                ("crate::storage::Pool", 4),
+                ("crate::model::Row", 4),
+                ("crate::storage::Pool::new", 5),
+                ("super::util::f", 6),
+                ("crate::codec::Json", 7),
+                ("other_crate::client::connect", 8),
+            ]
+        );
+    }
+
+    #[test]
+    fn resolves_inline_paths_and_skips_own_module() {
+        let frontend = RustFrontend::with_workspace(workspace());
+        let project_root = Path::new("/project");
+        let project_files = vec![
+            PathBuf::from("/project/src/lib.rs"),
+            PathBuf::from("/project/src/db.rs"),
+            PathBuf::from("/project/src/storage.rs"),
+        ];
+        let inline = |raw_path: &str| RawImport {
+            raw_path: raw_path.into(),
+            source_file: PathBuf::from("/project/src/db.rs"),
+            line: 1,
+            column: None,
+            kind: ImportKind::QualifiedPath,
+            confidence: ImportConfidence::Resolved,
+            cfg: None,
+            context: ImportContext::Runtime,
+        };
+
+        assert_eq!(
+            frontend.resolve(
+                &inline("crate::storage::Pool::new"),
+                project_root,
+                &project_files
+            ),
+            Some(PathBuf::from("my_crate/src/storage.rs"))
+        );
+        assert_eq!(
+            frontend.resolve(&inline("crate::db::connect"), project_root, &project_files),
+            None
+        );
+    }
+
+    #[test]
+    fn records_active_cfg_from_enclosing_items() {
+        let source = br#"
+#![cfg(unix)]
+use crate::always::A;
+#[cfg(feature = "yaml")]
+use crate::yaml::Loader;
+#[cfg(test)]
+mod tests {
+    #[cfg(not(miri))]
+    use crate::fixtures::load;
+
+    fn check() {
+        crate::helpers::assert_ok();
+    }
+}
+"#;
+        let frontend = RustFrontend::with_workspace(workspace());
+        let imports = frontend.extract_imports(source, Path::new("/project/src/lib.rs"));
+        let cfgs: Vec<(&str, Option<String>)> = imports
+            .iter()
+            .map(|import| {
+                (
+                    import.raw_path.as_str(),
+                    import.cfg.as_ref().map(ToString::to_string),
+                )
+            })
+            .collect();
+        assert_eq!(
+            cfgs,
+            vec![
+                ("crate::always::A", Some("unix".to_string())),
+                (
+                    "crate::yaml::Loader",
+                    Some(r#"all(unix, feature = "yaml")"#.to_string())
+                ),
+                (
+                    "crate::fixtures::load",
+                    Some("all(unix, test, not(miri))".to_string())
+                ),
+                (
+                    "crate::helpers::assert_ok",
+                    Some("all(unix, test)".to_string())
+                ),
+            ]
+        );
+    }
 }
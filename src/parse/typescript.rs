@@ -0,0 +1,1056 @@
+use crate::parse::common::{
+    normalize_relative, ImportConfidence, ImportContext, ImportKind, RawImport,
+};
+use crate::parse::ParseFrontend;
+use globset::{GlobBuilder, GlobSetBuilder};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// Manifest file names that make up a TypeScript/JavaScript project context.
+pub const PROJECT_MANIFESTS: &[&str] = &[
+    "package.json",
+    "pnpm-workspace.yaml",
+    "tsconfig.json",
+    "jsconfig.json",
+];
+
+/// Extensions tried, in order, when an import specifier omits one.
+const RESOLVE_EXTENSIONS: &[&str] = &["ts", "tsx", "d.ts", "mts", "cts", "js", "jsx", "mjs", "cjs"];
+
+/// Maximum depth of `extends` chains followed when loading a tsconfig.
+const MAX_EXTENDS_DEPTH: usize = 8;
+
+/// Node.js built-in modules, importable without the `node:` prefix.
+const NODE_BUILTIN_MODULES: &[&str] = &[
+    "assert",
+    "async_hooks",
+    "buffer",
+    "child_process",
+    "cluster",
+    "console",
+    "constants",
+    "crypto",
+    "dgram",
+    "diagnostics_channel",
+    "dns",
+    "domain",
+    "events",
+    "fs",
+    "http",
+    "http2",
+    "https",
+    "inspector",
+    "module",
+    "net",
+    "os",
+    "path",
+    "perf_hooks",
+    "process",
+    "punycode",
+    "querystring",
+    "readline",
+    "repl",
+    "stream",
+    "string_decoder",
+    "sys",
+    "timers",
+    "tls",
+    "trace_events",
+    "tty",
+    "url",
+    "util",
+    "v8",
+    "vm",
+    "wasi",
+    "worker_threads",
+    "zlib",
+];
+
+/// Module resolution settings read from a `tsconfig.json` / `jsconfig.json`.
+#[derive(Clone, Debug, Default)]
+pub struct TsConfig {
+    /// Directory containing the config, relative to the project root
+    pub dir: PathBuf,
+    /// `compilerOptions.baseUrl`, relative to the project root
+    pub base_url: Option<PathBuf>,
+    /// `compilerOptions.paths` patterns and their substitutions
+    pub paths: Vec<(String, Vec<String>)>,
+    /// Directory that `paths` substitutions are relative to
+    pub paths_base: PathBuf,
+}
+
+impl TsConfig {
+    /// The project file a bare specifier maps to through `paths` (most
+    /// specific pattern first) or `baseUrl`.
+    fn resolve(&self, specifier: &str, files: &HashSet<PathBuf>) -> Option<PathBuf> {
+        let best = self
+            .paths
+            .iter()
+            .filter_map(|(pattern, targets)| {
+                match_path_pattern(pattern, specifier).map(|captured| (pattern, targets, captured))
+            })
+            .max_by_key(|(pattern, _, _)| pattern.find('*').unwrap_or(pattern.len()));
+        if let Some((_, targets, captured)) = best {
+            for target in targets {
+                let substituted = target.replacen('*', captured, 1);
+                if let Some(found) = find_module_file(&self.paths_base.join(substituted), files) {
+                    return Some(found);
+                }
+            }
+        }
+
+        let base_url = self.base_url.as_ref()?;
+        find_module_file(&base_url.join(specifier), files)
+    }
+}
+
+/// A package declared through `package.json` workspaces.
+#[derive(Clone, Debug)]
+pub struct WorkspacePackage {
+    pub name: String,
+    /// Package directory, relative to the project root
+    pub dir: PathBuf,
+    /// Entry points declared in package.json (`source`, `types`, `module`, `main`)
+    pub entries: Vec<PathBuf>,
+}
+
+/// Project-wide resolution context for TypeScript and JavaScript sources.
+#[derive(Clone, Debug, Default)]
+pub struct TypeScriptProject {
+    pub tsconfigs: Vec<TsConfig>,
+    pub packages: Vec<WorkspacePackage>,
+    /// Source files, relative to the project root
+    pub files: HashSet<PathBuf>,
+}
+
+impl TypeScriptProject {
+    /// Build the project context from manifest paths (relative to the project
+    /// root) and a reader that returns file contents for a relative path.
+    pub fn load(manifests: &[PathBuf], read: &dyn Fn(&Path) -> Option<String>) -> Self {
+        let mut tsconfigs: Vec<TsConfig> = manifests
+            .iter()
+            .filter(|path| {
+                matches!(
+                    path.file_name().and_then(|name| name.to_str()),
+                    Some("tsconfig.json" | "jsconfig.json")
+                )
+            })
+            .filter_map(|path| load_tsconfig(path, read, 0))
+            .collect();
+        tsconfigs.sort_by_key(|config| std::cmp::Reverse(config.dir.components().count()));
+
+        let packages = load_workspace_packages(manifests, read);
+
+        Self {
+            tsconfigs,
+            packages,
+            files: HashSet::new(),
+        }
+    }
+
+    /// Set the source files specifiers resolve against.
+    pub fn with_files(mut self, project_root: &Path, files: &[PathBuf]) -> Self {
+        self.files = files
+            .iter()
+            .map(|file| {
+                file.strip_prefix(project_root)
+                    .unwrap_or(file)
+                    .to_path_buf()
+            })
+            .collect();
+        self
+    }
+
+    /// Discover manifests under `project_root` on disk and build the context.
+    pub fn discover(project_root: &Path) -> Self {
+        let walker = ignore::WalkBuilder::new(project_root)
+            .hidden(false)
+            .git_ignore(true)
+            .filter_entry(|entry| entry.file_name() != "node_modules")
+            .build();
+
+        let mut manifests: Vec<PathBuf> = walker
+            .flatten()
+            .filter(|entry| entry.path().is_file())
+            .filter(|entry| {
+                entry
+                    .file_name()
+                    .to_str()
+                    .is_some_and(|name| PROJECT_MANIFESTS.contains(&name))
+            })
+            .filter_map(|entry| {
+                entry
+                    .path()
+                    .strip_prefix(project_root)
+                    .ok()
+                    .map(Path::to_path_buf)
+            })
+            .collect();
+        manifests.sort();
+
+        Self::load(&manifests, &|path| {
+            std::fs::read_to_string(project_root.join(path)).ok()
+        })
+    }
+
+    /// The innermost tsconfig whose directory contains `file`.
+    fn tsconfig_for(&self, file: &Path) -> Option<&TsConfig> {
+        self.tsconfigs
+            .iter()
+            .find(|config| file.starts_with(&config.dir))
+    }
+
+    fn package_for_specifier<'a>(
+        &'a self,
+        specifier: &'a str,
+    ) -> Option<(&'a WorkspacePackage, &'a str)> {
+        self.packages
+            .iter()
+            .filter_map(|package| {
+                let rest = specifier.strip_prefix(package.name.as_str())?;
+                if rest.is_empty() {
+                    Some((package, ""))
+                } else {
+                    rest.strip_prefix('/').map(|subpath| (package, subpath))
+                }
+            })
+            .max_by_key(|(package, _)| package.name.len())
+    }
+
+    /// Whether a bare specifier imported from `source_file` names a workspace
+    /// package or a project file reachable through the file's tsconfig
+    /// `paths` or `baseUrl`. Anything else, such as `react` under a
+    /// `baseUrl`, is an npm package.
+    fn matches_bare_specifier(&self, specifier: &str, source_file: &Path) -> bool {
+        self.package_for_specifier(specifier).is_some()
+            || self
+                .tsconfig_for(source_file)
+                .is_some_and(|config| config.resolve(specifier, &self.files).is_some())
+    }
+}
+
+fn load_tsconfig(
+    path: &Path,
+    read: &dyn Fn(&Path) -> Option<String>,
+    depth: usize,
+) -> Option<TsConfig> {
+    let content = read(path)?;
+    let value: serde_json::Value = serde_json::from_str(&strip_jsonc(&content)).ok()?;
+    let dir = path.parent().map(Path::to_path_buf).unwrap_or_default();
+
+    let mut config = TsConfig {
+        dir: dir.clone(),
+        paths_base: dir.clone(),
+        ..TsConfig::default()
+    };
+
+    if depth < MAX_EXTENDS_DEPTH {
+        let extends: Vec<&str> = match value.get("extends") {
+            Some(serde_json::Value::String(parent)) => vec![parent.as_str()],
+            Some(serde_json::Value::Array(parents)) => {
+                parents.iter().filter_map(|p| p.as_str()).collect()
+            }
+            _ => Vec::new(),
+        };
+        for parent in extends {
+            if !parent.starts_with('.') {
+                // Package-provided base configs live in node_modules.
+                continue;
+            }
+            let parent_path = if parent.ends_with(".json") {
+                dir.join(parent)
+            } else {
+                dir.join(format!("{parent}.json"))
+            };
+            let Some(parent_path) = normalize_relative(&parent_path) else {
+                continue;
+            };
+            if let Some(inherited) = load_tsconfig(&parent_path, read, depth + 1) {
+                if inherited.base_url.is_some() {
+                    config.base_url = inherited.base_url;
+                }
+                if !inherited.paths.is_empty() {
+                    config.paths = inherited.paths;
+                    config.paths_base = inherited.paths_base;
+                }
+            }
+        }
+    }
+
+    let options = value.get("compilerOptions");
+    if let Some(base_url) = options
+        .and_then(|o| o.get("baseUrl"))
+        .and_then(|b| b.as_str())
+    {
+        config.base_url = normalize_relative(&dir.join(base_url));
+    }
+    if let Some(paths) = options
+        .and_then(|o| o.get("paths"))
+        .and_then(|p| p.as_object())
+    {
+        config.paths = paths
+            .iter()
+            .map(|(pattern, targets)| {
+                let targets = targets
+                    .as_array()
+                    .map(|values| {
+                        values
+                            .iter()
+                            .filter_map(|t| t.as_str().map(str::to_string))
+                            .collect()
+                    })
+                    .unwrap_or_default();
+                (pattern.clone(), targets)
+            })
+            .collect();
+        config.paths_base = dir;
+    }
+    if let Some(base_url) = &config.base_url {
+        config.paths_base = base_url.clone();
+    }
+
+    Some(config)
+}
+
+fn load_workspace_packages(
+    manifests: &[PathBuf],
+    read: &dyn Fn(&Path) -> Option<String>,
+) -> Vec<WorkspacePackage> {
+    let mut patterns: Vec<String> = Vec::new();
+
+    if let Some(root_package) = read(Path::new("package.json"))
+        .and_then(|content| serde_json::from_str::<serde_json::Value>(&content).ok())
+    {
+        let workspaces = root_package.get("workspaces");
+        let list = workspaces
+            .and_then(|w| w.as_array())
+            .or_else(|| workspaces.and_then(|w| w.get("packages")?.as_array()));
+        if let Some(list) = list {
+            patterns.extend(list.iter().filter_map(|p| p.as_str().map(str::to_string)));
+        }
+    }
+    if let Some(pnpm) = read(Path::new("pnpm-workspace.yaml"))
+        .and_then(|content| serde_yaml::from_str::<serde_yaml::Value>(&content).ok())
+    {
+        if let Some(list) = pnpm.get("packages").and_then(|p| p.as_sequence()) {
+            patterns.extend(list.iter().filter_map(|p| p.as_str().map(str::to_string)));
+        }
+    }
+
+    let mut include = GlobSetBuilder::new();
+    let mut exclude = GlobSetBuilder::new();
+    for pattern in &patterns {
+        let (builder, pattern) = match pattern.strip_prefix('!') {
+            Some(negated) => (&mut exclude, negated),
+            None => (&mut include, pattern.as_str()),
+        };
+        let pattern = pattern.trim_start_matches("./").trim_end_matches('/');
+        if let Ok(glob) = GlobBuilder::new(pattern).literal_separator(true).build() {
+            builder.add(glob);
+        }
+    }
+    let (Ok(include), Ok(exclude)) = (include.build(), exclude.build()) else {
+        return Vec::new();
+    };
+    if include.is_empty() {
+        return Vec::new();
+    }
+
+    let mut packages: Vec<WorkspacePackage> = manifests
+        .iter()
+        .filter(|path| path.file_name().and_then(|n| n.to_str()) == Some("package.json"))
+        .filter_map(|path| {
+            let dir = path.parent()?.to_path_buf();
+            if dir.as_os_str().is_empty() || !include.is_match(&dir) || exclude.is_match(&dir) {
+                return None;
+            }
+            let value: serde_json::Value = serde_json::from_str(&read(path)?).ok()?;
+            let name = value.get("name")?.as_str()?.to_string();
+            let entries = ["source", "types", "typings", "module", "main"]
+                .iter()
+                .filter_map(|field| value.get(*field)?.as_str())
+                .filter_map(|entry| normalize_relative(&dir.join(entry)))
+                .collect();
+            Some(WorkspacePackage { name, dir, entries })
+        })
+        .collect();
+    packages.sort_by(|a, b| a.name.cmp(&b.name));
+    packages
+}
+
+/// Strip `//` and `/* */` comments and trailing commas so tsconfig files
+/// (which are JSONC) can be parsed by serde_json.
+fn strip_jsonc(input: &str) -> String {
+    let mut output = String::with_capacity(input.len());
+    let mut chars = input.chars().peekable();
+    let mut in_string = false;
+
+    while let Some(ch) = chars.next() {
+        if in_string {
+            output.push(ch);
+            if ch == '\\' {
+                if let Some(escaped) = chars.next() {
+                    output.push(escaped);
+                }
+            } else if ch == '"' {
+                in_string = false;
+            }
+            continue;
+        }
+        match ch {
+            '"' => {
+                in_string = true;
+                output.push(ch);
+            }
+            '/' if chars.peek() == Some(&'/') => {
+                for next in chars.by_ref() {
+                    if next == '\n' {
+                        output.push('\n');
+                        break;
+                    }
+                }
+            }
+            '/' if chars.peek() == Some(&'*') => {
+                chars.next();
+                let mut previous = '\0';
+                for next in chars.by_ref() {
+                    if previous == '*' && next == '/' {
+                        break;
+                    }
+                    previous = next;
+                }
+            }
+            _ => output.push(ch),
+        }
+    }
+
+    // Drop trailing commas before a closing bracket or brace.
+    let mut cleaned = String::with_capacity(output.len());
+    let mut in_string = false;
+    let mut escaped = false;
+    let bytes: Vec<char> = output.chars().collect();
+    for (idx, &ch) in bytes.iter().enumerate() {
+        if in_string {
+            cleaned.push(ch);
+            if escaped {
+                escaped = false;
+            } else if ch == '\\' {
+                escaped = true;
+            } else if ch == '"' {
+                in_string = false;
+            }
+            continue;
+        }
+        if ch == '"' {
+            in_string = true;
+        }
+        if ch == ',' {
+            let next = bytes[idx + 1..].iter().find(|c| !c.is_whitespace());
+            if matches!(next, Some('}') | Some(']')) {
+                continue;
+            }
+        }
+        cleaned.push(ch);
+    }
+    cleaned
+}
+
+/// Match a tsconfig `paths` pattern (at most one `*`) against a specifier,
+/// returning the text captured by the wildcard.
+fn match_path_pattern<'a>(pattern: &str, specifier: &'a str) -> Option<&'a str> {
+    match pattern.split_once('*') {
+        Some((prefix, suffix)) => {
+            let rest = specifier.strip_prefix(prefix)?;
+            let captured = rest.strip_suffix(suffix)?;
+            Some(captured)
+        }
+        None => (pattern == specifier).then_some(""),
+    }
+}
+
+/// Find the project file a module specifier base path refers to, trying
+/// TypeScript's extension substitution and `index` barrel files.
+fn find_module_file(base: &Path, files: &HashSet<PathBuf>) -> Option<PathBuf> {
+    let base = normalize_relative(base)?;
+    if files.contains(&base) {
+        return Some(base);
+    }
+
+    // `./foo.js` in TypeScript ESM refers to `./foo.ts`.
+    if let Some(ext) = base.extension().and_then(|e| e.to_str()) {
+        let substitutes: &[&str] = match ext {
+            "js" => &["ts", "tsx"],
+            "jsx" => &["tsx"],
+            "mjs" => &["mts"],
+            "cjs" => &["cts"],
+            _ => &[],
+        };
+        for substitute in substitutes {
+            let candidate = base.with_extension(substitute);
+            if files.contains(&candidate) {
+                return Some(candidate);
+            }
+        }
+    }
+
+    let base_str = base.to_string_lossy();
+    for ext in RESOLVE_EXTENSIONS {
+        let candidate = PathBuf::from(format!("{base_str}.{ext}"));
+        if files.contains(&candidate) {
+            return Some(candidate);
+        }
+    }
+    for ext in RESOLVE_EXTENSIONS {
+        let candidate = base.join(format!("index.{ext}"));
+        if files.contains(&candidate) {
+            return Some(candidate);
+        }
+    }
+    None
+}
+
+fn is_relative_specifier(specifier: &str) -> bool {
+    specifier == "."
+        || specifier == ".."
+        || specifier.starts_with("./")
+        || specifier.starts_with("../")
+}
+
+pub struct TypeScriptFrontend {
+    project: Arc<TypeScriptProject>,
+}
+
+impl TypeScriptFrontend {
+    /// Create a frontend without project files: only imports can be
+    /// extracted, none resolve.
+    pub fn new() -> Self {
+        Self::with_project(Arc::new(TypeScriptProject::default()))
+    }
+
+    /// Create a frontend that resolves against the project's files, through
+    /// tsconfig paths and workspaces.
+    pub fn with_project(project: Arc<TypeScriptProject>) -> Self {
+        Self { project }
+    }
+
+    /// Pick the grammar for a file: TSX for `.tsx`, JavaScript (with JSX)
+    /// for `.js`-family files, TypeScript otherwise.
+    fn grammar_for(file_path: &Path) -> tree_sitter::Language {
+        match file_path.extension().and_then(|ext| ext.to_str()) {
+            Some("tsx") => tree_sitter_typescript::LANGUAGE_TSX.into(),
+            Some("js" | "jsx" | "mjs" | "cjs") => tree_sitter_javascript::LANGUAGE.into(),
+            _ => tree_sitter_typescript::LANGUAGE_TYPESCRIPT.into(),
+        }
+    }
+
+    fn classify_import(&self, specifier: &str, source_file: &Path) -> ImportConfidence {
+        if is_relative_specifier(specifier) {
+            return ImportConfidence::Resolved;
+        }
+        if specifier.starts_with("node:") || specifier.contains("://") {
+            return ImportConfidence::External;
+        }
+        if self.project.matches_bare_specifier(specifier, source_file) {
+            ImportConfidence::Resolved
+        } else {
+            ImportConfidence::External
+        }
+    }
+
+    fn walk(
+        &self,
+        node: tree_sitter::Node,
+        source: &[u8],
+        file_path: &Path,
+        imports: &mut Vec<RawImport>,
+    ) {
+        match node.kind() {
+            "import_statement" | "export_statement" => {
+                if let Some(specifier) = node.child_by_field_name("source") {
+                    self.push_specifier(specifier, source, file_path, imports);
+                }
+            }
+            "import_require_clause" => {
+                if let Some(specifier) = node.child_by_field_name("source") {
+                    self.push_specifier(specifier, source, file_path, imports);
+                }
+            }
+            "call_expression" => {
+                let is_import_call = node.child_by_field_name("function").is_some_and(|callee| {
+                    callee.kind() == "import"
+                        || (callee.kind() == "identifier"
+                            && callee.utf8_text(source).unwrap_or_default() == "require")
+                });
+                if is_import_call {
+                    if let Some(argument) = node
+                        .child_by_field_name("arguments")
+                        .and_then(|args| args.named_child(0))
+                    {
+                        self.push_specifier(argument, source, file_path, imports);
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            self.walk(child, source, file_path, imports);
+        }
+    }
+
+    fn push_specifier(
+        &self,
+        node: tree_sitter::Node,
+        source: &[u8],
+        file_path: &Path,
+        imports: &mut Vec<RawImport>,
+    ) {
+        let text = node.utf8_text(source).unwrap_or_default();
+        let (raw_path, confidence) = match node.kind() {
+            "string" => {
+                let specifier = text.trim_matches(['"', '\'']).to_string();
+                let confidence = self.classify_import(&specifier, file_path);
+                (specifier, confidence)
+            }
+            "template_string" if !text.contains("${") => {
+                let specifier = text.trim_matches('`').to_string();
+                let confidence = self.classify_import(&specifier, file_path);
+                (specifier, confidence)
+            }
+            _ => (text.to_string(), ImportConfidence::Dynamic),
+        };
+
+        if raw_path.is_empty() {
+            return;
+        }
+
+        imports.push(RawImport {
+            raw_path,
+            source_file: file_path.to_path_buf(),
+            line: node.start_position().row + 1,
+            column: Some(node.start_position().column),
+            kind: ImportKind::Direct,
+            confidence,
+            cfg: None,
+            context: ImportContext::Runtime,
+        });
+    }
+
+    fn resolve_bare(
+        project: &TypeScriptProject,
+        specifier: &str,
+        source_file: &Path,
+    ) -> Option<PathBuf> {
+        let files = &project.files;
+        if let Some(found) = project
+            .tsconfig_for(source_file)
+            .and_then(|config| config.resolve(specifier, files))
+        {
+            return Some(found);
+        }
+
+        let (package, subpath) = project.package_for_specifier(specifier)?;
+        if subpath.is_empty() {
+            for entry in &package.entries {
+                if let Some(found) = find_module_file(entry, files) {
+                    return Some(found);
+                }
+            }
+            find_module_file(&package.dir.join("src"), files)
+                .or_else(|| find_module_file(&package.dir, files))
+        } else {
+            find_module_file(&package.dir.join(subpath), files)
+                .or_else(|| find_module_file(&package.dir.join("src").join(subpath), files))
+        }
+    }
+}
+
+impl Default for TypeScriptFrontend {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ParseFrontend for TypeScriptFrontend {
+    fn language(&self) -> tree_sitter::Language {
+        tree_sitter_typescript::LANGUAGE_TYPESCRIPT.into()
+    }
+
+    fn extract_imports(&self, source: &[u8], file_path: &Path) -> Vec<RawImport> {
+        let mut parser = tree_sitter::Parser::new();
+        parser
+            .set_language(&Self::grammar_for(file_path))
+            .expect("failed to set TypeScript language");
+
+        let tree = match parser.parse(source, None) {
+            Some(t) => t,
+            None => return vec![],
+        };
+
+        let mut imports = Vec::new();
+        self.walk(tree.root_node(), source, file_path, &mut imports);
+        imports
+    }
+
+    fn resolve(
+        &self,
+        raw: &RawImport,
+        project_root: &Path,
+        _project_files: &[PathBuf],
+    ) -> Option<PathBuf> {
+        if raw.confidence != ImportConfidence::Resolved {
+            return None;
+        }
+
+        let source_file = raw
+            .source_file
+            .strip_prefix(project_root)
+            .unwrap_or(&raw.source_file);
+
+        if is_relative_specifier(&raw.raw_path) {
+            let source_dir = source_file.parent().unwrap_or(Path::new(""));
+            return find_module_file(&source_dir.join(&raw.raw_path), &self.project.files);
+        }
+        Self::resolve_bare(&self.project, &raw.raw_path, source_file)
+    }
+
+    /// The npm package a bare specifier names: `lodash` for `lodash/fp`,
+    /// `@scope/pkg` for `@scope/pkg/sub`.
+    fn external_package(&self, raw: &RawImport) -> Option<String> {
+        let specifier = raw.raw_path.as_str();
+        if raw.confidence != ImportConfidence::External
+            || specifier.starts_with("node:")
+            || specifier.contains("://")
+        {
+            return None;
+        }
+        let mut segments = specifier.split('/');
+        let first = segments.next()?;
+        if first.starts_with('@') {
+            return Some(format!("{first}/{}", segments.next()?));
+        }
+        (!first.is_empty() && !NODE_BUILTIN_MODULES.contains(&first)).then(|| first.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn extract(source: &str, file: &str) -> Vec<RawImport> {
+        TypeScriptFrontend::new().extract_imports(source.as_bytes(), Path::new(file))
+    }
+
+    fn project(manifests: &[(&str, &str)], sources: &[&str]) -> Arc<TypeScriptProject> {
+        let contents: HashMap<PathBuf, String> = manifests
+            .iter()
+            .map(|(path, content)| (PathBuf::from(path), content.to_string()))
+            .collect();
+        let paths: Vec<PathBuf> = contents.keys().cloned().collect();
+        let project = TypeScriptProject::load(&paths, &|path| contents.get(path).cloned())
+            .with_files(Path::new(""), &files(sources));
+        Arc::new(project)
+    }
+
+    fn raw(specifier: &str, source_file: &str, frontend: &TypeScriptFrontend) -> RawImport {
+        RawImport {
+            raw_path: specifier.to_string(),
+            source_file: PathBuf::from(source_file),
+            line: 1,
+            column: None,
+            kind: ImportKind::Direct,
+            confidence: frontend.classify_import(specifier, Path::new(source_file)),
+            cfg: None,
+            context: ImportContext::Runtime,
+        }
+    }
+
+    fn files(paths: &[&str]) -> Vec<PathBuf> {
+        paths.iter().map(PathBuf::from).collect()
+    }
+
+    #[test]
+    fn extracts_es_imports_and_reexports() {
+        let imports = extract(
+            r#"import { a } from "./a";
+import type { B } from './b';
+import "./side-effect";
+export * from "./c";
+export { d } from "./d";
+export const local = 1;
+"#,
+            "src/index.ts",
+        );
+        let paths: Vec<&str> = imports.iter().map(|i| i.raw_path.as_str()).collect();
+        assert_eq!(paths, vec!["./a", "./b", "./side-effect", "./c", "./d"]);
+        assert_eq!(imports[0].line, 1);
+        assert!(imports
+            .iter()
+            .all(|i| i.confidence == ImportConfidence::Resolved));
+    }
+
+    #[test]
+    fn extracts_require_and_dynamic_import() {
+        let imports = extract(
+            r#"const fs = require("fs");
+const util = require("./util");
+async function load(name) {
+  await import("./lazy");
+  await import(`./pages/${name}`);
+  return require(name);
+}
+"#,
+            "lib/main.js",
+        );
+        let by_path: HashMap<&str, &ImportConfidence> = imports
+            .iter()
+            .map(|i| (i.raw_path.as_str(), &i.confidence))
+            .collect();
+        assert_eq!(by_path["fs"], &ImportConfidence::External);
+        assert_eq!(by_path["./util"], &ImportConfidence::Resolved);
+        assert_eq!(by_path["./lazy"], &ImportConfidence::Resolved);
+        assert_eq!(by_path["`./pages/${name}`"], &ImportConfidence::Dynamic);
+        assert_eq!(by_path["name"], &ImportConfidence::Dynamic);
+    }
+
+    #[test]
+    fn external_packages_are_npm_package_names() {
+        let frontend = TypeScriptFrontend::new();
+        let package =
+            |specifier: &str| frontend.external_package(&raw(specifier, "src/a.ts", &frontend));
+        assert_eq!(package("lodash/fp"), Some("lodash".to_string()));
+        assert_eq!(
+            package("@nestjs/core/injector"),
+            Some("@nestjs/core".to_string())
+        );
+        assert_eq!(package("fs/promises"), None);
+        assert_eq!(package("node:path"), None);
+        assert_eq!(package("./util"), None);
+    }
+
+    #[test]
+    fn extracts_import_equals_require() {
+        let imports = extract(r#"import legacy = require("./legacy");"#, "src/a.ts");
+        assert_eq!(imports.len(), 1);
+        assert_eq!(imports[0].raw_path, "./legacy");
+    }
+
+    #[test]
+    fn parses_tsx_files() {
+        let imports = extract(
+            r#"import { Button } from "./Button";
+export const App = () => <Button label="hi" />;
+"#,
+            "src/App.tsx",
+        );
+        assert_eq!(imports.len(), 1);
+        assert_eq!(imports[0].raw_path, "./Button");
+    }
+
+    #[test]
+    fn resolves_relative_imports_with_extensions_and_barrels() {
+        let project_files = files(&[
+            "/p/src/app.ts",
+            "/p/src/util.ts",
+            "/p/src/components/index.tsx",
+            "/p/src/legacy.js",
+        ]);
+        let root = Path::new("/p");
+        let frontend = TypeScriptFrontend::with_project(Arc::new(
+            TypeScriptProject::default().with_files(root, &project_files),
+        ));
+        let resolve = |spec: &str| {
+            frontend.resolve(&raw(spec, "/p/src/app.ts", &frontend), root, &project_files)
+        };
+        assert_eq!(resolve("./util"), Some(PathBuf::from("src/util.ts")));
+        assert_eq!(resolve("./util.js"), Some(PathBuf::from("src/util.ts")));
+        assert_eq!(
+            resolve("./components"),
+            Some(PathBuf::from("src/components/index.tsx"))
+        );
+        assert_eq!(resolve("./legacy"), Some(PathBuf::from("src/legacy.js")));
+        assert_eq!(resolve("../outside"), None);
+        assert_eq!(resolve("./missing"), None);
+    }
+
+    #[test]
+    fn resolves_tsconfig_paths_and_base_url() {
+        let sources = [
+            "src/main.ts",
+            "src/app/service.ts",
+            "src/shared/index.ts",
+            "src/lib/math.ts",
+        ];
+        let project = project(
+            &[
+                (
+                    "tsconfig.base.json",
+                    r#"{
+  // shared options
+  "compilerOptions": {
+    "baseUrl": "./src",
+    "paths": { "@app/*": ["app/*"], "@shared": ["shared/index.ts"], },
+  },
+}"#,
+                ),
+                ("tsconfig.json", r#"{ "extends": "./tsconfig.base.json" }"#),
+            ],
+            &sources,
+        );
+        let frontend = TypeScriptFrontend::with_project(project);
+        let project_files = files(&sources);
+        let resolve = |spec: &str| {
+            frontend.resolve(
+                &raw(spec, "src/main.ts", &frontend),
+                Path::new(""),
+                &project_files,
+            )
+        };
+        assert_eq!(
+            resolve("@app/service"),
+            Some(PathBuf::from("src/app/service.ts"))
+        );
+        assert_eq!(
+            resolve("@shared"),
+            Some(PathBuf::from("src/shared/index.ts"))
+        );
+        assert_eq!(resolve("lib/math"), Some(PathBuf::from("src/lib/math.ts")));
+        assert_eq!(resolve("react"), None);
+    }
+
+    #[test]
+    fn base_url_leaves_npm_packages_external() {
+        let project = project(
+            &[(
+                "tsconfig.json",
+                r#"{ "compilerOptions": { "baseUrl": "./src", "paths": { "@app/*": ["app/*"] } } }"#,
+            )],
+            &["src/main.ts", "src/app/service.ts"],
+        );
+        let frontend = TypeScriptFrontend::with_project(project);
+        let imports = frontend.extract_imports(
+            b"import React from 'react';\nimport { run } from '@app/service';\n",
+            Path::new("src/main.ts"),
+        );
+        assert_eq!(imports[0].raw_path, "react");
+        assert_eq!(imports[0].confidence, ImportConfidence::External);
+        assert_eq!(
+            frontend.external_package(&imports[0]),
+            Some("react".to_string())
+        );
+        assert_eq!(imports[1].confidence, ImportConfidence::Resolved);
+        assert_eq!(
+            frontend.classify_import("@app/missing", Path::new("src/main.ts")),
+            ImportConfidence::External
+        );
+    }
+
+    #[test]
+    fn path_aliases_only_apply_under_their_tsconfig() {
+        let project = project(
+            &[(
+                "web/tsconfig.json",
+                r#"{ "compilerOptions": { "baseUrl": ".", "paths": { "@ui/*": ["ui/*"] } } }"#,
+            )],
+            &["web/main.ts", "web/ui/button.ts", "api/server.ts"],
+        );
+        let frontend = TypeScriptFrontend::with_project(project);
+        let project_files = files(&["web/main.ts", "web/ui/button.ts", "api/server.ts"]);
+        for (source_file, expected) in [
+            ("web/main.ts", Some(PathBuf::from("web/ui/button.ts"))),
+            ("api/server.ts", None),
+        ] {
+            let raw = raw("@ui/button", source_file, &frontend);
+            let confidence = if expected.is_some() {
+                ImportConfidence::Resolved
+            } else {
+                ImportConfidence::External
+            };
+            assert_eq!(raw.confidence, confidence, "{source_file}");
+            assert_eq!(
+                frontend.resolve(&raw, Path::new(""), &project_files),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn resolves_workspace_packages() {
+        let sources = [
+            "packages/core/src/index.ts",
+            "packages/core/src/format.ts",
+            "packages/ui/lib/main.ts",
+        ];
+        let project = project(
+            &[
+                (
+                    "package.json",
+                    r#"{ "private": true, "workspaces": ["packages/*"] }"#,
+                ),
+                (
+                    "packages/core/package.json",
+                    r#"{ "name": "@acme/core", "main": "dist/index.js" }"#,
+                ),
+                (
+                    "packages/ui/package.json",
+                    r#"{ "name": "@acme/ui", "source": "lib/main.ts" }"#,
+                ),
+            ],
+            &sources,
+        );
+        assert_eq!(project.packages.len(), 2);
+
+        let frontend = TypeScriptFrontend::with_project(project);
+        assert_eq!(
+            frontend.classify_import("@acme/core", Path::new("packages/ui/lib/main.ts")),
+            ImportConfidence::Resolved
+        );
+        assert_eq!(
+            frontend.classify_import("react", Path::new("packages/ui/lib/main.ts")),
+            ImportConfidence::External
+        );
+
+        let project_files = files(&sources);
+        let resolve = |spec: &str| {
+            frontend.resolve(
+                &raw(spec, "packages/ui/lib/main.ts", &frontend),
+                Path::new(""),
+                &project_files,
+            )
+        };
+        assert_eq!(
+            resolve("@acme/core"),
+            Some(PathBuf::from("packages/core/src/index.ts"))
+        );
+        assert_eq!(
+            resolve("@acme/core/format"),
+            Some(PathBuf::from("packages/core/src/format.ts"))
+        );
+        assert_eq!(
+            resolve("@acme/ui"),
+            Some(PathBuf::from("packages/ui/lib/main.ts"))
+        );
+    }
+
+    #[test]
+    fn strips_jsonc_comments_and_trailing_commas() {
+        let cleaned = strip_jsonc(
+            r#"{ /* block */ "a": "http://x//y", // line
+  "b": [1, 2,], }"#,
+        );
+        let value: serde_json::Value = serde_json::from_str(&cleaned).unwrap();
+        assert_eq!(value["a"], "http://x//y");
+        assert_eq!(value["b"].as_array().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn matches_wildcard_path_patterns() {
+        assert_eq!(match_path_pattern("@app/*", "@app/a/b"), Some("a/b"));
+        assert_eq!(match_path_pattern("*.css", "x.css"), Some("x"));
+        assert_eq!(match_path_pattern("exact", "exact"), Some(""));
+        assert_eq!(match_path_pattern("@app/*", "@lib/a"), None);
+    }
+}
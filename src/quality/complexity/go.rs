@@ -1,4 +1,7 @@
-use crate::quality::complexity::{binary_has_ops, count_decisions, ComplexityFrontend};
+use crate::quality::complexity::{
+    binary_has_ops, collect_calls, count_decisions, node_text, parse, CallSite, ComplexityFrontend,
+    FileTypes, TypeKind, TypeRelation,
+};
 use crate::quality::FunctionInfo;
 use crate::walk::Language;
 use std::path::Path;
@@ -39,6 +42,99 @@ impl GoComplexity {
         }
         None
     }
+
+    fn collect_types(
+        node: tree_sitter::Node,
+        source: &[u8],
+        owner: Option<&str>,
+        out: &mut FileTypes,
+    ) {
+        match node.kind() {
+            "type_spec" => {
+                let (Some(name), Some(ty)) = (
+                    node.child_by_field_name("name"),
+                    node.child_by_field_name("type"),
+                ) else {
+                    return;
+                };
+                if !matches!(ty.kind(), "struct_type" | "interface_type") {
+                    return;
+                }
+                let name = node_text(&name, source).to_string();
+                let kind = if ty.kind() == "interface_type" {
+                    TypeKind::Abstract
+                } else {
+                    TypeKind::Concrete
+                };
+                out.declare(&name, &node, kind);
+                Self::collect_types(ty, source, Some(&name), out);
+                return;
+            }
+            "method_declaration" => {
+                // Methods count towards their receiver type.
+                let Some(receiver) = Self::receiver_type(node, source) else {
+                    return;
+                };
+                let receiver = receiver.split('[').next().unwrap_or(&receiver);
+                for field in ["parameters", "result", "body"] {
+                    if let Some(child) = node.child_by_field_name(field) {
+                        Self::collect_types(child, source, Some(receiver), out);
+                    }
+                }
+                return;
+            }
+            _ => {}
+        }
+        if let Some(owner) = owner {
+            let relation = match node.kind() {
+                // Embedded struct field
+                "field_declaration" if node.child_by_field_name("name").is_none() => {
+                    Some(TypeRelation::Mixin)
+                }
+                // Embedded interface
+                "type_elem" => Some(TypeRelation::Inherits),
+                "type_identifier" | "qualified_type" => Some(TypeRelation::References),
+                _ => None,
+            };
+            if let Some(relation) = relation {
+                Self::type_references(node, source, owner, relation, out);
+                return;
+            }
+        }
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            Self::collect_types(child, source, owner, out);
+        }
+    }
+
+    fn type_references(
+        node: tree_sitter::Node,
+        source: &[u8],
+        owner: &str,
+        relation: TypeRelation,
+        out: &mut FileTypes,
+    ) {
+        match node.kind() {
+            "type_identifier" => out.reference(owner, &node, None, relation, source),
+            "qualified_type" => {
+                if let Some(name) = node.child_by_field_name("name") {
+                    out.reference(
+                        owner,
+                        &name,
+                        node.child_by_field_name("package"),
+                        relation,
+                        source,
+                    );
+                }
+            }
+            _ => {
+                let mut cursor = node.walk();
+                for child in node.named_children(&mut cursor) {
+                    Self::type_references(child, source, owner, relation, out);
+                }
+            }
+        }
+    }
 }
 
 impl ComplexityFrontend for GoComplexity {
@@ -46,6 +142,31 @@ impl ComplexityFrontend for GoComplexity {
         tree_sitter_go::LANGUAGE.into()
     }
 
+    fn extract_calls(&self, source: &[u8]) -> Vec<CallSite> {
+        collect_calls(&self.language(), source, |node| {
+            if node.kind() != "call_expression" {
+                return None;
+            }
+            let function = node.child_by_field_name("function")?;
+            match function.kind() {
+                "identifier" => Some((None, function)),
+                "selector_expression" => Some((
+                    function.child_by_field_name("operand"),
+                    function.child_by_field_name("field")?,
+                )),
+                _ => None,
+            }
+        })
+    }
+
+    fn extract_types(&self, source: &[u8]) -> FileTypes {
+        let mut types = FileTypes::default();
+        if let Some(tree) = parse(&self.language(), source) {
+            Self::collect_types(tree.root_node(), source, None, &mut types);
+        }
+        types
+    }
+
     fn extract_functions(&self, source: &[u8], file_path: &Path) -> Vec<FunctionInfo> {
         let mut parser = tree_sitter::Parser::new();
         parser
@@ -136,4 +257,94 @@ func (s *Service) PointerMethod(flag bool) {
             .unwrap();
         assert!(pointer.cyclomatic_complexity >= 2);
     }
+
+    #[test]
+    fn extracts_calls_with_qualifiers() {
+        let source = br#"
+package main
+
+func run(s *Service) {
+    plain(true)
+    s.PointerMethod(false)
+    billing.Charge(10)
+}
+"#;
+
+        let calls = GoComplexity.extract_calls(source);
+        let calls: Vec<_> = calls
+            .iter()
+            .map(|c| (c.name.as_str(), c.qualifier.as_deref(), c.line))
+            .collect();
+
+        assert_eq!(
+            calls,
+            vec![
+                ("plain", None, 5),
+                ("PointerMethod", Some("s"), 6),
+                ("Charge", Some("billing"), 7),
+            ]
+        );
+    }
+
+    #[test]
+    fn extracts_types_with_embedding_and_method_receivers() {
+        let source = br#"
+package main
+
+type Service struct {
+    *store.Cache
+    repo Repo
+}
+
+func (s *Service) Get(id ID) *Item {
+    return nil
+}
+"#;
+
+        let types = GoComplexity.extract_types(source);
+        let names: Vec<_> = types.declarations.iter().map(|d| d.name.as_str()).collect();
+        assert_eq!(names, vec!["Service"]);
+
+        let references: Vec<_> = types
+            .references
+            .iter()
+            .map(|r| (r.name.as_str(), r.qualifier.as_deref(), r.relation))
+            .collect();
+        assert_eq!(
+            references,
+            vec![
+                ("Cache", Some("store"), TypeRelation::Mixin),
+                ("Repo", None, TypeRelation::References),
+                ("ID", None, TypeRelation::References),
+                ("Item", None, TypeRelation::References),
+            ]
+        );
+    }
+
+    #[test]
+    fn interfaces_are_abstract_types() {
+        let source = br#"
+package store
+
+type Reader interface {
+    Read(id ID) *Item
+}
+
+type Cache struct{}
+"#;
+
+        let types = GoComplexity.extract_types(source);
+        let kinds: Vec<_> = types
+            .declarations
+            .iter()
+            .map(|d| (d.name.as_str(), d.kind))
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                ("Reader", TypeKind::Abstract),
+                ("Cache", TypeKind::Concrete)
+            ]
+        );
+    }
 }
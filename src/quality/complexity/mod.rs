@@ -2,6 +2,7 @@ use crate::quality::FunctionInfo;
 use std::path::Path;
 
 pub mod go;
+pub mod php;
 pub mod python;
 pub mod ruby;
 pub mod rust;
@@ -10,6 +11,164 @@ pub mod rust;
 pub trait ComplexityFrontend {
     fn language(&self) -> tree_sitter::Language;
     fn extract_functions(&self, source: &[u8], file_path: &Path) -> Vec<FunctionInfo>;
+    /// Call expressions, for the function-level graph.
+    fn extract_calls(&self, source: &[u8]) -> Vec<CallSite>;
+    /// Type declarations and the types they refer to, for the class-level graph.
+    fn extract_types(&self, source: &[u8]) -> FileTypes;
+}
+
+/// Classes, modules, structs, traits and interfaces declared in a file.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FileTypes {
+    pub declarations: Vec<TypeDecl>,
+    pub references: Vec<TypeReference>,
+}
+
+/// A type declaration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypeDecl {
+    /// Name including enclosing types (`Outer.Inner`, `Billing::Invoice`)
+    pub name: String,
+    pub start_line: usize,
+    pub end_line: usize,
+    pub kind: TypeKind,
+}
+
+/// Whether a declared type is abstract, for component abstractness.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TypeKind {
+    /// Class, struct or enum
+    #[default]
+    Concrete,
+    /// Interface, trait, protocol or abstract base class
+    Abstract,
+    /// Ruby module: abstract when a class mixes it in, a namespace otherwise
+    Module,
+}
+
+/// How one type depends on another.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum TypeRelation {
+    /// Any other mention: field, parameter or return type, constructor call
+    References,
+    /// Ruby `include`/`extend`/`prepend`, PHP `use Trait`, Go struct embedding
+    Mixin,
+    /// Rust `impl Trait for Type`, PHP/Go interface implementation
+    Implements,
+    /// Base class, superclass, supertrait or embedded interface
+    Inherits,
+}
+
+/// A reference from a declared type to another type, by name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypeReference {
+    /// Declaration the reference belongs to; for Rust `impl` blocks and Go
+    /// methods, the type they are attached to
+    pub owner: String,
+    /// Referenced type name, without qualifier
+    pub name: String,
+    /// Module, package or namespace the name is qualified with, as written
+    pub qualifier: Option<String>,
+    pub relation: TypeRelation,
+    pub line: usize,
+}
+
+impl FileTypes {
+    fn declare(&mut self, name: &str, node: &tree_sitter::Node, kind: TypeKind) {
+        self.declarations.push(TypeDecl {
+            name: name.to_string(),
+            start_line: node.start_position().row + 1,
+            end_line: node.end_position().row + 1,
+            kind,
+        });
+    }
+
+    fn reference(
+        &mut self,
+        owner: &str,
+        name: &tree_sitter::Node,
+        qualifier: Option<tree_sitter::Node>,
+        relation: TypeRelation,
+        source: &[u8],
+    ) {
+        let text = last_segment(node_text(name, source));
+        if text.is_empty() || text == last_segment(owner) {
+            return;
+        }
+        self.references.push(TypeReference {
+            owner: owner.to_string(),
+            name: text.to_string(),
+            qualifier: qualifier.map(|q| node_text(&q, source).trim_matches('\\').to_string()),
+            relation,
+            line: name.start_position().row + 1,
+        });
+    }
+}
+
+fn parse(language: &tree_sitter::Language, source: &[u8]) -> Option<tree_sitter::Tree> {
+    let mut parser = tree_sitter::Parser::new();
+    parser.set_language(language).ok()?;
+    parser.parse(source, None)
+}
+
+fn starts_uppercase(text: &str) -> bool {
+    text.starts_with(|c: char| c.is_ascii_uppercase())
+}
+
+/// A call expression in a source file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CallSite {
+    /// Called function or method, without qualifier (`charge`)
+    pub name: String,
+    /// What the name is called on, as written (`self`, `billing`, `Invoice::`)
+    pub qualifier: Option<String>,
+    pub line: usize,
+}
+
+/// Last segment of a `.`, `::`, `->` or `\` separated name.
+pub fn last_segment(name: &str) -> &str {
+    name.rsplit(['.', ':', '>', '\\'])
+        .find(|segment| !segment.is_empty())
+        .unwrap_or(name)
+}
+
+/// Parse `source` and collect its call sites. `callee` maps a call
+/// expression node to its qualifier and name nodes, and other nodes to None.
+pub fn collect_calls<F>(language: &tree_sitter::Language, source: &[u8], callee: F) -> Vec<CallSite>
+where
+    F: for<'t> Fn(
+        &tree_sitter::Node<'t>,
+    ) -> Option<(Option<tree_sitter::Node<'t>>, tree_sitter::Node<'t>)>,
+{
+    let mut parser = tree_sitter::Parser::new();
+    if parser.set_language(language).is_err() {
+        return vec![];
+    }
+    let Some(tree) = parser.parse(source, None) else {
+        return vec![];
+    };
+
+    let mut calls = Vec::new();
+    let mut cursor = tree.root_node().walk();
+    let mut stack = vec![tree.root_node()];
+    while let Some(node) = stack.pop() {
+        if let Some((qualifier, name)) = callee(&node) {
+            let name = last_segment(node_text(&name, source));
+            if !name.is_empty() {
+                calls.push(CallSite {
+                    name: name.to_string(),
+                    qualifier: qualifier.map(|q| node_text(&q, source).to_string()),
+                    line: node.start_position().row + 1,
+                });
+            }
+        }
+        cursor.reset(node);
+        for child in node.children(&mut cursor) {
+            stack.push(child);
+        }
+    }
+    calls.sort_by_key(|call| call.line);
+    calls
 }
 
 fn node_text<'a>(node: &tree_sitter::Node, source: &'a [u8]) -> &'a str {
@@ -0,0 +1,337 @@
+use crate::quality::complexity::{
+    collect_calls, count_decisions, node_text, parse, CallSite, ComplexityFrontend, FileTypes,
+    TypeKind, TypeRelation,
+};
+use crate::quality::FunctionInfo;
+use crate::walk::Language;
+use std::path::Path;
+
+pub struct PhpComplexity;
+
+impl PhpComplexity {
+    fn cyclomatic_complexity(node: tree_sitter::Node, source: &[u8]) -> usize {
+        let decisions = count_decisions(node, source, |n, _| match n.kind() {
+            "if_statement"
+            | "else_if_clause"
+            | "while_statement"
+            | "do_statement"
+            | "for_statement"
+            | "foreach_statement"
+            | "case_statement"
+            | "catch_clause"
+            | "conditional_expression"
+            | "match_conditional_expression" => true,
+            "binary_expression" => n
+                .child_by_field_name("operator")
+                .is_some_and(|op| matches!(op.kind(), "&&" | "||" | "and" | "or" | "??")),
+            _ => false,
+        });
+        1 + decisions
+    }
+
+    fn extract_with_context(
+        node: tree_sitter::Node,
+        source: &[u8],
+        file_path: &Path,
+        context: &mut Vec<String>,
+        out: &mut Vec<FunctionInfo>,
+    ) {
+        let kind = node.kind();
+        if matches!(
+            kind,
+            "class_declaration"
+                | "interface_declaration"
+                | "trait_declaration"
+                | "enum_declaration"
+        ) {
+            if let Some(name) = node
+                .child_by_field_name("name")
+                .and_then(|n| n.utf8_text(source).ok())
+            {
+                context.push(name.to_string());
+                let mut cursor = node.walk();
+                for child in node.children(&mut cursor) {
+                    Self::extract_with_context(child, source, file_path, context, out);
+                }
+                context.pop();
+                return;
+            }
+        }
+
+        if kind == "function_definition" || kind == "method_declaration" {
+            let name = node
+                .child_by_field_name("name")
+                .and_then(|n| n.utf8_text(source).ok())
+                .unwrap_or("<anonymous>");
+            let prefix = if context.is_empty() {
+                "".to_string()
+            } else {
+                format!("{}.", context.join("."))
+            };
+            out.push(FunctionInfo {
+                name: format!("{prefix}{name}"),
+                file: file_path.to_path_buf(),
+                start_line: node.start_position().row + 1,
+                end_line: node.end_position().row + 1,
+                cyclomatic_complexity: Self::cyclomatic_complexity(node, source),
+                language: Language::Php,
+            });
+        }
+
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            Self::extract_with_context(child, source, file_path, context, out);
+        }
+    }
+
+    /// Interfaces, traits and abstract classes are abstract.
+    fn type_kind(node: tree_sitter::Node) -> TypeKind {
+        let mut cursor = node.walk();
+        let is_abstract = match node.kind() {
+            "interface_declaration" | "trait_declaration" => true,
+            "class_declaration" => node
+                .children(&mut cursor)
+                .any(|child| child.kind() == "abstract_modifier"),
+            _ => false,
+        };
+        if is_abstract {
+            TypeKind::Abstract
+        } else {
+            TypeKind::Concrete
+        }
+    }
+
+    fn collect_types(
+        node: tree_sitter::Node,
+        source: &[u8],
+        owner: Option<&str>,
+        out: &mut FileTypes,
+    ) {
+        match node.kind() {
+            "class_declaration"
+            | "interface_declaration"
+            | "trait_declaration"
+            | "enum_declaration" => {
+                let Some(name) = node.child_by_field_name("name") else {
+                    return;
+                };
+                let name = node_text(&name, source).to_string();
+                out.declare(&name, &node, Self::type_kind(node));
+                let mut cursor = node.walk();
+                for child in node.children(&mut cursor) {
+                    match child.kind() {
+                        "base_clause" => {
+                            Self::name_references(child, source, &name, TypeRelation::Inherits, out)
+                        }
+                        "class_interface_clause" => Self::name_references(
+                            child,
+                            source,
+                            &name,
+                            TypeRelation::Implements,
+                            out,
+                        ),
+                        "declaration_list" | "enum_declaration_list" => {
+                            Self::collect_types(child, source, Some(&name), out)
+                        }
+                        _ => {}
+                    }
+                }
+                return;
+            }
+            _ => {}
+        }
+        if let Some(owner) = owner {
+            match node.kind() {
+                // `use Loggable;` inside a class body
+                "use_declaration" => {
+                    Self::name_references(node, source, owner, TypeRelation::Mixin, out);
+                    return;
+                }
+                "qualified_name" => {
+                    Self::name_references(node, source, owner, TypeRelation::References, out);
+                    return;
+                }
+                "name" if Self::is_class_name(node) => {
+                    out.reference(owner, &node, None, TypeRelation::References, source);
+                    return;
+                }
+                _ => {}
+            }
+        }
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            Self::collect_types(child, source, owner, out);
+        }
+    }
+
+    /// Whether a `name` node names a class: a type hint, `new Foo`,
+    /// `Foo::bar()` or `Foo::BAR`.
+    fn is_class_name(node: tree_sitter::Node) -> bool {
+        let Some(parent) = node.parent() else {
+            return false;
+        };
+        match parent.kind() {
+            "named_type" | "object_creation_expression" => true,
+            "scoped_call_expression" | "scoped_property_access_expression" => {
+                parent.child_by_field_name("scope") == Some(node)
+            }
+            "class_constant_access_expression" => parent.named_child(0) == Some(node),
+            _ => false,
+        }
+    }
+
+    /// Record the class names listed under `node`; `\App\Invoice` refers to
+    /// `Invoice`, qualified by `App`.
+    fn name_references(
+        node: tree_sitter::Node,
+        source: &[u8],
+        owner: &str,
+        relation: TypeRelation,
+        out: &mut FileTypes,
+    ) {
+        match node.kind() {
+            "name" => out.reference(owner, &node, None, relation, source),
+            "qualified_name" => {
+                let mut cursor = node.walk();
+                let name = node
+                    .named_children(&mut cursor)
+                    .filter(|child| child.kind() == "name")
+                    .last();
+                if let Some(name) = name {
+                    out.reference(
+                        owner,
+                        &name,
+                        node.child_by_field_name("prefix"),
+                        relation,
+                        source,
+                    );
+                }
+            }
+            _ => {
+                let mut cursor = node.walk();
+                for child in node.named_children(&mut cursor) {
+                    Self::name_references(child, source, owner, relation, out);
+                }
+            }
+        }
+    }
+}
+
+impl ComplexityFrontend for PhpComplexity {
+    fn language(&self) -> tree_sitter::Language {
+        tree_sitter_php::LANGUAGE_PHP.into()
+    }
+
+    fn extract_calls(&self, source: &[u8]) -> Vec<CallSite> {
+        collect_calls(&self.language(), source, |node| match node.kind() {
+            "function_call_expression" => Some((None, node.child_by_field_name("function")?)),
+            "member_call_expression" | "nullsafe_member_call_expression" => Some((
+                node.child_by_field_name("object"),
+                node.child_by_field_name("name")?,
+            )),
+            "scoped_call_expression" => Some((
+                node.child_by_field_name("scope"),
+                node.child_by_field_name("name")?,
+            )),
+            _ => None,
+        })
+    }
+
+    fn extract_types(&self, source: &[u8]) -> FileTypes {
+        let mut types = FileTypes::default();
+        if let Some(tree) = parse(&self.language(), source) {
+            Self::collect_types(tree.root_node(), source, None, &mut types);
+        }
+        types
+    }
+
+    fn extract_functions(&self, source: &[u8], file_path: &Path) -> Vec<FunctionInfo> {
+        let mut parser = tree_sitter::Parser::new();
+        parser
+            .set_language(&self.language())
+            .expect("failed to set PHP language");
+
+        let tree = match parser.parse(source, None) {
+            Some(t) => t,
+            None => return vec![],
+        };
+
+        let mut out = Vec::new();
+        let mut context = Vec::new();
+        Self::extract_with_context(tree.root_node(), source, file_path, &mut context, &mut out);
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extracts_methods_and_functions_with_complexity() {
+        let source = br#"<?php
+namespace App;
+
+class Checkout
+{
+    public function total(array $items, ?Coupon $coupon): int
+    {
+        $sum = 0;
+        foreach ($items as $item) {
+            if ($item->taxable && $item->price > 0) {
+                $sum += $item->price;
+            } elseif ($item->free) {
+                continue;
+            }
+        }
+        return $coupon ? $coupon->apply($sum) : $sum;
+    }
+}
+
+function format_price(int $cents): string
+{
+    return match (true) {
+        $cents < 100 => "cheap",
+        default => "other",
+    };
+}
+"#;
+
+        let functions = PhpComplexity.extract_functions(source, Path::new("src/Checkout.php"));
+        let summary: Vec<_> = functions
+            .iter()
+            .map(|f| (f.name.as_str(), f.cyclomatic_complexity))
+            .collect();
+
+        // total: foreach, if, &&, elseif, ternary
+        assert_eq!(summary, vec![("Checkout.total", 6), ("format_price", 2)]);
+    }
+
+    #[test]
+    fn interfaces_traits_and_abstract_classes_are_abstract_types() {
+        let source = br#"<?php
+namespace App;
+
+interface Gateway {}
+trait Loggable {}
+abstract class Repository {}
+final class Order {}
+"#;
+
+        let types = PhpComplexity.extract_types(source);
+        let kinds: Vec<_> = types
+            .declarations
+            .iter()
+            .map(|d| (d.name.as_str(), d.kind))
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                ("Gateway", TypeKind::Abstract),
+                ("Loggable", TypeKind::Abstract),
+                ("Repository", TypeKind::Abstract),
+                ("Order", TypeKind::Concrete),
+            ]
+        );
+    }
+}
@@ -1,4 +1,7 @@
-use crate::quality::complexity::{binary_has_ops, count_decisions, ComplexityFrontend};
+use crate::quality::complexity::{
+    binary_has_ops, collect_calls, count_decisions, last_segment, node_text, parse,
+    starts_uppercase, CallSite, ComplexityFrontend, FileTypes, TypeKind, TypeRelation,
+};
 use crate::quality::FunctionInfo;
 use crate::walk::Language;
 use std::path::Path;
@@ -78,6 +81,101 @@ impl PythonComplexity {
             Self::extract_functions_with_context(child, source, file_path, class_stack, out);
         }
     }
+
+    /// Classes deriving from `ABC` or `Protocol`, or built by `ABCMeta`, are abstract.
+    fn class_kind(node: tree_sitter::Node, source: &[u8]) -> TypeKind {
+        let Some(bases) = node.child_by_field_name("superclasses") else {
+            return TypeKind::Concrete;
+        };
+        let mut cursor = bases.walk();
+        let is_abstract = bases.named_children(&mut cursor).any(|base| {
+            let base = match base.kind() {
+                "keyword_argument" => base
+                    .child_by_field_name("name")
+                    .filter(|name| node_text(name, source) == "metaclass")
+                    .and_then(|_| base.child_by_field_name("value")),
+                // `Protocol[T]`
+                "subscript" => base.child_by_field_name("value"),
+                _ => Some(base),
+            };
+            base.is_some_and(|base| {
+                matches!(
+                    last_segment(node_text(&base, source)),
+                    "ABC" | "ABCMeta" | "Protocol"
+                )
+            })
+        });
+        if is_abstract {
+            TypeKind::Abstract
+        } else {
+            TypeKind::Concrete
+        }
+    }
+
+    fn collect_types(
+        node: tree_sitter::Node,
+        source: &[u8],
+        owner: Option<&str>,
+        out: &mut FileTypes,
+    ) {
+        match node.kind() {
+            "class_definition" => {
+                let Some(name) = node.child_by_field_name("name") else {
+                    return;
+                };
+                let name = match owner {
+                    Some(owner) => format!("{owner}.{}", node_text(&name, source)),
+                    None => node_text(&name, source).to_string(),
+                };
+                out.declare(&name, &node, Self::class_kind(node, source));
+                if let Some(bases) = node.child_by_field_name("superclasses") {
+                    let mut cursor = bases.walk();
+                    for base in bases.named_children(&mut cursor) {
+                        match base.kind() {
+                            "identifier" => {
+                                out.reference(&name, &base, None, TypeRelation::Inherits, source)
+                            }
+                            "attribute" => {
+                                if let Some(attribute) = base.child_by_field_name("attribute") {
+                                    out.reference(
+                                        &name,
+                                        &attribute,
+                                        base.child_by_field_name("object"),
+                                        TypeRelation::Inherits,
+                                        source,
+                                    );
+                                }
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+                if let Some(body) = node.child_by_field_name("body") {
+                    Self::collect_types(body, source, Some(&name), out);
+                }
+                return;
+            }
+            // Class names are capitalised; other identifiers are values.
+            "identifier" if starts_uppercase(node_text(&node, source)) => {
+                if let Some(owner) = owner {
+                    let qualifier = node
+                        .parent()
+                        .filter(|parent| {
+                            parent.kind() == "attribute"
+                                && parent.child_by_field_name("attribute") == Some(node)
+                        })
+                        .and_then(|parent| parent.child_by_field_name("object"));
+                    out.reference(owner, &node, qualifier, TypeRelation::References, source);
+                }
+                return;
+            }
+            _ => {}
+        }
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            Self::collect_types(child, source, owner, out);
+        }
+    }
 }
 
 impl ComplexityFrontend for PythonComplexity {
@@ -85,6 +183,31 @@ impl ComplexityFrontend for PythonComplexity {
         tree_sitter_python::LANGUAGE.into()
     }
 
+    fn extract_calls(&self, source: &[u8]) -> Vec<CallSite> {
+        collect_calls(&self.language(), source, |node| {
+            if node.kind() != "call" {
+                return None;
+            }
+            let function = node.child_by_field_name("function")?;
+            match function.kind() {
+                "identifier" => Some((None, function)),
+                "attribute" => Some((
+                    function.child_by_field_name("object"),
+                    function.child_by_field_name("attribute")?,
+                )),
+                _ => None,
+            }
+        })
+    }
+
+    fn extract_types(&self, source: &[u8]) -> FileTypes {
+        let mut types = FileTypes::default();
+        if let Some(tree) = parse(&self.language(), source) {
+            Self::collect_types(tree.root_node(), source, None, &mut types);
+        }
+        types
+    }
+
     fn extract_functions(&self, source: &[u8], file_path: &Path) -> Vec<FunctionInfo> {
         let mut parser = tree_sitter::Parser::new();
         parser
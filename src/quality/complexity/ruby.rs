@@ -1,4 +1,7 @@
-use crate::quality::complexity::{binary_has_ops, count_decisions, ComplexityFrontend};
+use crate::quality::complexity::{
+    binary_has_ops, collect_calls, count_decisions, node_text, parse, CallSite, ComplexityFrontend,
+    FileTypes, TypeKind, TypeRelation,
+};
 use crate::quality::FunctionInfo;
 use crate::walk::Language;
 use std::path::Path;
@@ -67,6 +70,102 @@ impl RubyComplexity {
             Self::extract_with_context(child, source, file_path, context, out);
         }
     }
+
+    fn collect_types(
+        node: tree_sitter::Node,
+        source: &[u8],
+        owner: Option<&str>,
+        out: &mut FileTypes,
+    ) {
+        match node.kind() {
+            "class" | "module" => {
+                let Some(name) = node.child_by_field_name("name") else {
+                    return;
+                };
+                let name = match owner {
+                    Some(owner) => format!("{owner}::{}", node_text(&name, source)),
+                    None => node_text(&name, source).to_string(),
+                };
+                let kind = if node.kind() == "module" {
+                    TypeKind::Module
+                } else {
+                    TypeKind::Concrete
+                };
+                out.declare(&name, &node, kind);
+                if let Some(superclass) = node.child_by_field_name("superclass") {
+                    Self::constant_references(
+                        superclass,
+                        source,
+                        &name,
+                        TypeRelation::Inherits,
+                        out,
+                    );
+                }
+                if let Some(body) = node.child_by_field_name("body") {
+                    Self::collect_types(body, source, Some(&name), out);
+                }
+                return;
+            }
+            "call" if node.child_by_field_name("receiver").is_none() => {
+                let method = node
+                    .child_by_field_name("method")
+                    .map(|method| node_text(&method, source));
+                if let (Some(owner), Some("include" | "extend" | "prepend")) = (owner, method) {
+                    if let Some(arguments) = node.child_by_field_name("arguments") {
+                        Self::constant_references(
+                            arguments,
+                            source,
+                            owner,
+                            TypeRelation::Mixin,
+                            out,
+                        );
+                    }
+                    return;
+                }
+            }
+            "constant" | "scope_resolution" => {
+                if let Some(owner) = owner {
+                    Self::constant_references(node, source, owner, TypeRelation::References, out);
+                }
+                return;
+            }
+            _ => {}
+        }
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            Self::collect_types(child, source, owner, out);
+        }
+    }
+
+    /// Record the constants under `node`; `A::B` refers to `B`, qualified by `A`.
+    fn constant_references(
+        node: tree_sitter::Node,
+        source: &[u8],
+        owner: &str,
+        relation: TypeRelation,
+        out: &mut FileTypes,
+    ) {
+        match node.kind() {
+            "constant" => out.reference(owner, &node, None, relation, source),
+            "scope_resolution" => {
+                if let Some(name) = node.child_by_field_name("name") {
+                    out.reference(
+                        owner,
+                        &name,
+                        node.child_by_field_name("scope"),
+                        relation,
+                        source,
+                    );
+                }
+            }
+            _ => {
+                let mut cursor = node.walk();
+                for child in node.named_children(&mut cursor) {
+                    Self::constant_references(child, source, owner, relation, out);
+                }
+            }
+        }
+    }
 }
 
 impl ComplexityFrontend for RubyComplexity {
@@ -74,6 +173,26 @@ impl ComplexityFrontend for RubyComplexity {
         tree_sitter_ruby::LANGUAGE.into()
     }
 
+    fn extract_calls(&self, source: &[u8]) -> Vec<CallSite> {
+        collect_calls(&self.language(), source, |node| {
+            if node.kind() != "call" {
+                return None;
+            }
+            Some((
+                node.child_by_field_name("receiver"),
+                node.child_by_field_name("method")?,
+            ))
+        })
+    }
+
+    fn extract_types(&self, source: &[u8]) -> FileTypes {
+        let mut types = FileTypes::default();
+        if let Some(tree) = parse(&self.language(), source) {
+            Self::collect_types(tree.root_node(), source, None, &mut types);
+        }
+        types
+    }
+
     fn extract_functions(&self, source: &[u8], file_path: &Path) -> Vec<FunctionInfo> {
         let mut parser = tree_sitter::Parser::new();
         parser
@@ -130,4 +249,65 @@ end
             .unwrap();
         assert!(call.cyclomatic_complexity >= 2);
     }
+
+    #[test]
+    fn extracts_calls_with_receivers() {
+        let source = br#"
+class Checkout
+  def call
+    validate(order)
+    Billing::Invoice.create(order)
+  end
+end
+"#;
+
+        let calls = RubyComplexity.extract_calls(source);
+        let calls: Vec<_> = calls
+            .iter()
+            .map(|c| (c.name.as_str(), c.qualifier.as_deref()))
+            .collect();
+
+        assert_eq!(
+            calls,
+            vec![("validate", None), ("create", Some("Billing::Invoice"))]
+        );
+    }
+
+    #[test]
+    fn extracts_types_with_superclasses_and_mixins() {
+        let source = br#"
+module Billing
+  class Invoice < Base::Record
+    include Payable
+
+    def pay
+      Receipt.new(Billing::Card.find)
+    end
+  end
+end
+"#;
+
+        let types = RubyComplexity.extract_types(source);
+        let names: Vec<_> = types.declarations.iter().map(|d| d.name.as_str()).collect();
+        assert_eq!(names, vec!["Billing", "Billing::Invoice"]);
+
+        let references: Vec<_> = types
+            .references
+            .iter()
+            .map(|r| (r.name.as_str(), r.qualifier.as_deref(), r.relation))
+            .collect();
+        assert_eq!(
+            references,
+            vec![
+                ("Record", Some("Base"), TypeRelation::Inherits),
+                ("Payable", None, TypeRelation::Mixin),
+                ("Receipt", None, TypeRelation::References),
+                ("Card", Some("Billing"), TypeRelation::References),
+            ]
+        );
+        assert!(types
+            .references
+            .iter()
+            .all(|r| r.owner == "Billing::Invoice"));
+    }
 }
@@ -1,4 +1,7 @@
-use crate::quality::complexity::{binary_has_ops, count_decisions, ComplexityFrontend};
+use crate::quality::complexity::{
+    binary_has_ops, collect_calls, count_decisions, node_text, parse, starts_uppercase, CallSite,
+    ComplexityFrontend, FileTypes, TypeKind, TypeRelation,
+};
 use crate::quality::FunctionInfo;
 use crate::walk::Language;
 use std::path::Path;
@@ -53,6 +56,135 @@ impl RustComplexity {
         });
         1 + decisions
     }
+
+    fn collect_types(
+        node: tree_sitter::Node,
+        source: &[u8],
+        owner: Option<&str>,
+        out: &mut FileTypes,
+    ) {
+        match node.kind() {
+            "struct_item" | "enum_item" | "union_item" | "trait_item" => {
+                let Some(name_node) = node.child_by_field_name("name") else {
+                    return;
+                };
+                let name = node_text(&name_node, source).to_string();
+                let kind = if node.kind() == "trait_item" {
+                    TypeKind::Abstract
+                } else {
+                    TypeKind::Concrete
+                };
+                out.declare(&name, &node, kind);
+                let bounds = node.child_by_field_name("bounds");
+                if let Some(bounds) = bounds {
+                    Self::type_references(bounds, source, &name, TypeRelation::Inherits, out);
+                }
+                let mut cursor = node.walk();
+                for child in node.children(&mut cursor) {
+                    if child != name_node && Some(child) != bounds {
+                        Self::collect_types(child, source, Some(&name), out);
+                    }
+                }
+                return;
+            }
+            "impl_item" => {
+                // Methods count towards the type they are implemented on.
+                let Some(name) = node
+                    .child_by_field_name("type")
+                    .and_then(|ty| Self::impl_type_name(ty, source))
+                else {
+                    return;
+                };
+                if let Some(implemented) = node.child_by_field_name("trait") {
+                    Self::type_references(implemented, source, name, TypeRelation::Implements, out);
+                }
+                if let Some(body) = node.child_by_field_name("body") {
+                    Self::collect_types(body, source, Some(name), out);
+                }
+                return;
+            }
+            "type_identifier" => {
+                if let Some(owner) = owner {
+                    Self::type_references(node, source, owner, TypeRelation::References, out);
+                }
+                return;
+            }
+            // `Entry::new()`, `db::Pool::connect()`
+            "scoped_identifier" => {
+                if let (Some(owner), Some(path)) = (owner, node.child_by_field_name("path")) {
+                    let (name, qualifier) = match path.kind() {
+                        "scoped_identifier" => (
+                            path.child_by_field_name("name"),
+                            path.child_by_field_name("path"),
+                        ),
+                        _ => (Some(path), None),
+                    };
+                    if let Some(name) =
+                        name.filter(|name| starts_uppercase(node_text(name, source)))
+                    {
+                        out.reference(owner, &name, qualifier, TypeRelation::References, source);
+                    }
+                }
+                return;
+            }
+            _ => {}
+        }
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            Self::collect_types(child, source, owner, out);
+        }
+    }
+
+    /// Record the type named by `node` under `relation`; its generic
+    /// arguments are plain references.
+    fn type_references(
+        node: tree_sitter::Node,
+        source: &[u8],
+        owner: &str,
+        relation: TypeRelation,
+        out: &mut FileTypes,
+    ) {
+        match node.kind() {
+            "type_identifier" => out.reference(owner, &node, None, relation, source),
+            "scoped_type_identifier" => {
+                if let Some(name) = node.child_by_field_name("name") {
+                    out.reference(
+                        owner,
+                        &name,
+                        node.child_by_field_name("path"),
+                        relation,
+                        source,
+                    );
+                }
+            }
+            "generic_type" => {
+                if let Some(ty) = node.child_by_field_name("type") {
+                    Self::type_references(ty, source, owner, relation, out);
+                }
+                if let Some(arguments) = node.child_by_field_name("type_arguments") {
+                    Self::collect_types(arguments, source, Some(owner), out);
+                }
+            }
+            _ => {
+                let mut cursor = node.walk();
+                for child in node.named_children(&mut cursor) {
+                    Self::type_references(child, source, owner, relation, out);
+                }
+            }
+        }
+    }
+
+    /// Name of the type an `impl` block is for (`Ledger` in `impl<T> Ledger<T>`).
+    fn impl_type_name<'s>(node: tree_sitter::Node, source: &'s [u8]) -> Option<&'s str> {
+        match node.kind() {
+            "type_identifier" => Some(node_text(&node, source)),
+            "scoped_type_identifier" => Some(node_text(&node.child_by_field_name("name")?, source)),
+            "generic_type" | "reference_type" => {
+                Self::impl_type_name(node.child_by_field_name("type")?, source)
+            }
+            _ => None,
+        }
+    }
 }
 
 impl ComplexityFrontend for RustComplexity {
@@ -60,6 +192,39 @@ impl ComplexityFrontend for RustComplexity {
         tree_sitter_rust::LANGUAGE.into()
     }
 
+    fn extract_calls(&self, source: &[u8]) -> Vec<CallSite> {
+        collect_calls(&self.language(), source, |node| {
+            if node.kind() != "call_expression" {
+                return None;
+            }
+            let mut function = node.child_by_field_name("function")?;
+            // `parse::<T>()`
+            if function.kind() == "generic_function" {
+                function = function.child_by_field_name("function")?;
+            }
+            match function.kind() {
+                "identifier" => Some((None, function)),
+                "scoped_identifier" => Some((
+                    function.child_by_field_name("path"),
+                    function.child_by_field_name("name")?,
+                )),
+                "field_expression" => Some((
+                    function.child_by_field_name("value"),
+                    function.child_by_field_name("field")?,
+                )),
+                _ => None,
+            }
+        })
+    }
+
+    fn extract_types(&self, source: &[u8]) -> FileTypes {
+        let mut types = FileTypes::default();
+        if let Some(tree) = parse(&self.language(), source) {
+            Self::collect_types(tree.root_node(), source, None, &mut types);
+        }
+        types
+    }
+
     fn extract_functions(&self, source: &[u8], file_path: &Path) -> Vec<FunctionInfo> {
         let mut parser = tree_sitter::Parser::new();
         parser
@@ -1,5 +1,6 @@
 use crate::errors::{Result, UntangleError};
 use crate::quality::complexity::go::GoComplexity;
+use crate::quality::complexity::php::PhpComplexity;
 use crate::quality::complexity::python::PythonComplexity;
 use crate::quality::complexity::ruby::RubyComplexity;
 use crate::quality::complexity::rust::RustComplexity;
@@ -9,12 +10,24 @@ use crate::walk::{self, Language};
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 
-fn frontend_for(lang: Language) -> Box<dyn ComplexityFrontend> {
+type FilesByLang = HashMap<Language, Vec<PathBuf>>;
+
+/// Complexity frontend for a language, or `None` if function extraction
+/// is not supported for it yet.
+pub fn frontend_for(lang: Language) -> Option<Box<dyn ComplexityFrontend>> {
     match lang {
-        Language::Go => Box::new(GoComplexity),
-        Language::Python => Box::new(PythonComplexity),
-        Language::Ruby => Box::new(RubyComplexity),
-        Language::Rust => Box::new(RustComplexity),
+        Language::Go => Some(Box::new(GoComplexity)),
+        Language::Python => Some(Box::new(PythonComplexity)),
+        Language::Ruby => Some(Box::new(RubyComplexity)),
+        Language::Rust => Some(Box::new(RustComplexity)),
+        Language::Php => Some(Box::new(PhpComplexity)),
+        Language::TypeScript
+        | Language::Java
+        | Language::Kotlin
+        | Language::CSharp
+        | Language::Cpp
+        | Language::Elixir
+        | Language::Custom(_) => None,
     }
 }
 
@@ -24,17 +37,17 @@ pub fn discover_files_by_lang(
     include: &[String],
     exclude: &[String],
     include_tests: bool,
-) -> Result<(HashMap<Language, Vec<PathBuf>>, Vec<Language>)> {
+) -> Result<(FilesByLang, Vec<Language>)> {
     let files_by_lang: HashMap<Language, Vec<PathBuf>> = match lang {
         Some(lang) => {
-            let files = walk::discover_files(root, lang, include, exclude, include_tests)?;
+            let files = walk::discover_files(root, lang, include, exclude, include_tests, &[])?;
             let mut map = HashMap::new();
             if !files.is_empty() {
                 map.insert(lang, files);
             }
             map
         }
-        None => walk::discover_files_multi(root, include, exclude, include_tests)?,
+        None => walk::discover_files_multi(root, include, exclude, include_tests, &[])?,
     };
 
     if files_by_lang.is_empty() {
@@ -44,7 +57,7 @@ pub fn discover_files_by_lang(
     }
 
     let mut langs: Vec<Language> = files_by_lang.keys().copied().collect();
-    langs.sort_by(|a, b| a.to_string().cmp(&b.to_string()));
+    langs.sort_by_key(|lang| lang.to_string());
 
     Ok((files_by_lang, langs))
 }
@@ -57,7 +70,9 @@ pub fn collect_functions(
     let mut files_parsed = 0usize;
 
     for (lang, files) in files_by_lang {
-        let frontend = frontend_for(*lang);
+        let Some(frontend) = frontend_for(*lang) else {
+            continue;
+        };
         for file in files {
             let Ok(source) = std::fs::read(file) else {
                 continue;
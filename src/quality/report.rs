@@ -11,6 +11,8 @@ use crate::architecture::{
 };
 use crate::config::ResolvedConfig;
 use crate::errors::Result;
+use crate::formats::HotspotSort;
+use crate::graph::granularity::Granularity;
 use crate::insights::Insight;
 use crate::metrics::scc::SccInfo;
 use crate::metrics::summary::Summary;
@@ -232,8 +234,20 @@ pub fn run(config: UnifiedRunConfig) -> Result<UnifiedQualityReport> {
         quiet: config.quiet,
     })?;
 
-    let snapshot = build_analysis_snapshot(&config.root, &project_root, &config.resolved, false)?;
-    let structural_hotspots = build_hotspots(&snapshot.graph, &snapshot.sccs, limit);
+    let snapshot = build_analysis_snapshot(
+        &config.root,
+        &project_root,
+        &config.resolved,
+        false,
+        Granularity::Module,
+    )?;
+    let structural_hotspots = build_hotspots(
+        &snapshot.graph,
+        &snapshot.sccs,
+        &snapshot.module_types,
+        limit,
+        HotspotSort::Fanout,
+    );
     let architecture_level = config
         .architecture_level
         .unwrap_or(config.resolved.analyze_architecture.level)
@@ -750,6 +764,8 @@ impl std::fmt::Display for crate::insights::InsightCategory {
             crate::insights::InsightCategory::CircularDependency => "circular_dependency",
             crate::insights::InsightCategory::DeepChain => "deep_chain",
             crate::insights::InsightCategory::HighEntropy => "high_entropy",
+            crate::insights::InsightCategory::ZoneOfPain => "zone_of_pain",
+            crate::insights::InsightCategory::ZoneOfUselessness => "zone_of_uselessness",
         };
         write!(f, "{label}")
     }
@@ -2,6 +2,8 @@ use crate::analysis_context::resolve_project_root;
 use crate::analysis_report::build_analysis_snapshot;
 use crate::config::ResolvedConfig;
 use crate::errors::Result;
+use crate::formats::HotspotSort;
+use crate::graph::granularity::Granularity;
 use crate::output::json::build_hotspots;
 use crate::quality::{UntangleHotspot, UntangleMetricSummary};
 use std::path::Path;
@@ -12,22 +14,28 @@ pub fn compute_untangle_summary(
     hotspot_limit: usize,
 ) -> Result<UntangleMetricSummary> {
     let project_root = resolve_project_root(root, config.lang);
-    let snapshot = build_analysis_snapshot(root, &project_root, config, true)?;
-    let hotspots = build_hotspots(&snapshot.graph, &snapshot.sccs, Some(hotspot_limit))
-        .into_iter()
-        .map(|hotspot| UntangleHotspot {
-            path: snapshot
-                .graph
-                .node_indices()
-                .find(|&idx| snapshot.graph[idx].name == hotspot.node)
-                .map(|idx| snapshot.graph[idx].path.clone())
-                .unwrap_or_default(),
-            module: hotspot.node,
-            fanout: hotspot.fanout,
-            fanin: hotspot.fanin,
-            scc: hotspot.scc_id,
-        })
-        .collect();
+    let snapshot = build_analysis_snapshot(root, &project_root, config, true, Granularity::Module)?;
+    let hotspots = build_hotspots(
+        &snapshot.graph,
+        &snapshot.sccs,
+        &snapshot.module_types,
+        Some(hotspot_limit),
+        HotspotSort::Fanout,
+    )
+    .into_iter()
+    .map(|hotspot| UntangleHotspot {
+        path: snapshot
+            .graph
+            .node_indices()
+            .find(|&idx| snapshot.graph[idx].name == hotspot.node)
+            .map(|idx| snapshot.graph[idx].path.clone())
+            .unwrap_or_default(),
+        module: hotspot.node,
+        fanout: hotspot.fanout,
+        fanin: hotspot.fanin,
+        scc: hotspot.scc_id,
+    })
+    .collect();
 
     Ok(UntangleMetricSummary {
         nodes: snapshot.metadata.node_count,
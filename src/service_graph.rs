@@ -164,10 +164,10 @@ fn count_service_files(service_root: &Path, service: &ResolvedService) -> usize
     }
 
     match service.lang {
-        Some(lang) => walk::discover_files(service_root, lang, &[], &[], false)
+        Some(lang) => walk::discover_files(service_root, lang, &[], &[], false, &[])
             .map(|files| files.len())
             .unwrap_or(0),
-        None => walk::discover_files_multi(service_root, &[], &[], false)
+        None => walk::discover_files_multi(service_root, &[], &[], false, &[])
             .map(|files| files.values().map(|entries| entries.len()).sum())
             .unwrap_or(0),
     }
@@ -175,8 +175,10 @@ fn count_service_files(service_root: &Path, service: &ResolvedService) -> usize
 
 fn collect_source_files(service_root: &Path, service: &ResolvedService) -> Vec<PathBuf> {
     match service.lang {
-        Some(lang) => walk::discover_files(service_root, lang, &[], &[], false).unwrap_or_default(),
-        None => walk::discover_files_multi(service_root, &[], &[], false)
+        Some(lang) => {
+            walk::discover_files(service_root, lang, &[], &[], false, &[]).unwrap_or_default()
+        }
+        None => walk::discover_files_multi(service_root, &[], &[], false, &[])
             .map(|files| files.into_values().flatten().collect())
             .unwrap_or_default(),
     }
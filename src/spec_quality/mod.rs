@@ -393,7 +393,7 @@ fn discover_and_extract(
     let exclude_set = build_globset(exclude)?;
     let langs = match lang {
         Some(lang) => vec![lang],
-        None => walk::detect_languages(root),
+        None => walk::detect_languages(root, &[]),
     };
     if langs.is_empty() {
         return Err(UntangleError::NoFiles {
@@ -411,7 +411,7 @@ fn discover_and_extract(
         if !path.is_file() {
             continue;
         }
-        let Some(language) = walk::language_for_file(path) else {
+        let Some(language) = walk::language_for_file(path, &[]) else {
             continue;
         };
         if !langs.contains(&language) {
@@ -559,6 +559,39 @@ fn is_test_like_path(relative: &Path, language: Language) -> bool {
         }
         Language::Go => file_name.ends_with("_test.go"),
         Language::Rust => text.contains("/tests/") || text.starts_with("tests/"),
+        Language::TypeScript => {
+            text.contains("/__tests__/")
+                || text.starts_with("__tests__/")
+                || file_name.contains(".test.")
+                || file_name.contains(".spec.")
+        }
+        Language::Java | Language::Kotlin => {
+            text.contains("/src/test/")
+                || text.starts_with("src/test/")
+                || file_name.contains("Test.")
+                || file_name.contains("Tests.")
+        }
+        Language::CSharp => {
+            text.contains(".Tests/")
+                || text.contains(".Test/")
+                || file_name.ends_with("Tests.cs")
+                || file_name.ends_with("Test.cs")
+        }
+        Language::Cpp => {
+            text.contains("/tests/")
+                || text.contains("/test/")
+                || text.starts_with("tests/")
+                || text.starts_with("test/")
+                || file_name.contains("_test.")
+                || file_name.contains("_unittest.")
+        }
+        Language::Php => {
+            text.contains("/tests/")
+                || text.starts_with("tests/")
+                || file_name.ends_with("Test.php")
+        }
+        Language::Elixir => file_name.ends_with("_test.exs"),
+        Language::Custom(_) => false,
     }
 }
 
@@ -568,6 +601,16 @@ fn discover_cases(language: Language, source: &str) -> Vec<DiscoveredCase> {
         Language::Ruby => discover_ruby_cases(source),
         Language::Go => discover_go_cases(source),
         Language::Rust => discover_rust_cases(source),
+        Language::TypeScript => discover_js_cases(source),
+        Language::Java | Language::Kotlin => discover_annotated_test_cases(source, &["@Test"]),
+        Language::CSharp => discover_annotated_test_cases(
+            source,
+            &["[Fact", "[Theory", "[Test", "[TestCase", "[TestMethod"],
+        ),
+        Language::Cpp => discover_macro_test_cases(source),
+        Language::Php => discover_php_cases(source),
+        Language::Elixir => discover_exunit_cases(source),
+        Language::Custom(_) => Vec::new(),
     }
 }
 
@@ -591,12 +634,19 @@ fn discover_helper_names(
             Language::Rust if trimmed.starts_with("fn ") || trimmed.contains(" fn ") => {
                 extract_identifier_after(trimmed.split("fn ").nth(1).unwrap_or(""), "")
             }
+            Language::TypeScript if trimmed.starts_with("function ") => {
+                extract_identifier_after(trimmed, "function ")
+            }
+            Language::Kotlin if trimmed.contains("fun ") => {
+                extract_identifier_after(trimmed.split("fun ").nth(1).unwrap_or(""), "")
+            }
+            Language::Java if trimmed.starts_with("private ") && trimmed.contains('(') => {
+                extract_method_name(trimmed)
+            }
             _ => None,
         };
         if let Some(name) = name {
-            let cleaned = name
-                .trim_end_matches(|c: char| c == '(' || c == '{')
-                .to_string();
+            let cleaned = name.trim_end_matches(['(', '{']).to_string();
             if !case_names.contains(cleaned.as_str()) {
                 names.push(cleaned);
             }
@@ -720,6 +770,207 @@ fn discover_rust_cases(source: &str) -> Vec<DiscoveredCase> {
     cases
 }
 
+fn discover_js_cases(source: &str) -> Vec<DiscoveredCase> {
+    let lines: Vec<&str> = source.lines().collect();
+    let mut cases = Vec::new();
+    let mut contexts: Vec<(usize, String)> = Vec::new();
+    let mut idx = 0usize;
+    while idx < lines.len() {
+        contexts.retain(|(end, _)| *end > idx);
+        let trimmed = lines[idx].trim();
+        if trimmed.starts_with("describe(") {
+            let name = extract_quoted_name(trimmed).unwrap_or_else(|| trimmed.to_string());
+            contexts.push((find_brace_block_end(&lines, idx), name));
+        } else if trimmed.starts_with("it(") || trimmed.starts_with("test(") {
+            let name = extract_quoted_name(trimmed).unwrap_or_else(|| "example".to_string());
+            let end = find_brace_block_end(&lines, idx);
+            cases.push(DiscoveredCase {
+                name,
+                context_path: contexts.iter().map(|(_, ctx)| ctx.clone()).collect(),
+                start_line: idx + 1,
+                end_line: end,
+            });
+            idx = end;
+            continue;
+        }
+        idx += 1;
+    }
+    cases
+}
+
+/// xUnit-style cases: methods marked with a test annotation or attribute
+/// (`@Test` in Java/Kotlin, `[Fact]`/`[Test]`/`[TestMethod]` in C#).
+fn discover_annotated_test_cases(source: &str, markers: &[&str]) -> Vec<DiscoveredCase> {
+    let is_marker = |line: &str| {
+        markers.iter().any(|marker| {
+            line.strip_prefix(marker)
+                .is_some_and(|rest| !rest.starts_with(|c: char| c.is_alphanumeric()))
+        })
+    };
+    let lines: Vec<&str> = source.lines().collect();
+    let mut cases = Vec::new();
+    let mut idx = 0usize;
+    while idx < lines.len() {
+        if is_marker(lines[idx].trim()) {
+            let mut fn_idx = idx + 1;
+            while fn_idx < lines.len() && {
+                let line = lines[fn_idx].trim_start();
+                !line.contains('(') || line.starts_with('@') || line.starts_with('[')
+            } {
+                fn_idx += 1;
+            }
+            if fn_idx < lines.len() {
+                let name = extract_method_name(lines[fn_idx].trim()).unwrap_or_default();
+                let end = find_brace_block_end(&lines, fn_idx);
+                cases.push(DiscoveredCase {
+                    name,
+                    context_path: Vec::new(),
+                    start_line: fn_idx + 1,
+                    end_line: end,
+                });
+                idx = end;
+                continue;
+            }
+        }
+        idx += 1;
+    }
+    cases
+}
+
+/// PHPUnit methods (`test*` names, `@test`, `#[Test]`) and Pest `test()`/`it()` cases.
+fn discover_php_cases(source: &str) -> Vec<DiscoveredCase> {
+    let lines: Vec<&str> = source.lines().collect();
+    let mut cases = Vec::new();
+    let mut marked = false;
+    let mut idx = 0usize;
+    while idx < lines.len() {
+        let trimmed = lines[idx].trim();
+        if trimmed.starts_with("#[Test]") || trimmed.trim_start_matches(['/', '*', ' ']) == "@test"
+        {
+            marked = true;
+        } else if trimmed.contains("function ") {
+            let name = trimmed
+                .split("function ")
+                .nth(1)
+                .and_then(extract_method_name)
+                .unwrap_or_default();
+            if marked || name.starts_with("test") {
+                let end = find_brace_block_end(&lines, idx);
+                cases.push(DiscoveredCase {
+                    name,
+                    context_path: Vec::new(),
+                    start_line: idx + 1,
+                    end_line: end,
+                });
+                marked = false;
+                idx = end;
+                continue;
+            }
+            marked = false;
+        } else if trimmed.starts_with("test(") || trimmed.starts_with("it(") {
+            let name = extract_quoted_name(trimmed).unwrap_or_else(|| "example".to_string());
+            let end = find_brace_block_end(&lines, idx);
+            cases.push(DiscoveredCase {
+                name,
+                context_path: Vec::new(),
+                start_line: idx + 1,
+                end_line: end,
+            });
+            idx = end;
+            continue;
+        }
+        idx += 1;
+    }
+    cases
+}
+
+/// ExUnit `test "..." do` cases, nested under `describe` blocks.
+fn discover_exunit_cases(source: &str) -> Vec<DiscoveredCase> {
+    let lines: Vec<&str> = source.lines().collect();
+    let mut cases = Vec::new();
+    let mut contexts: Vec<(usize, String)> = Vec::new();
+    let mut idx = 0usize;
+    while idx < lines.len() {
+        contexts.retain(|(end, _)| *end > idx);
+        let trimmed = lines[idx].trim();
+        if trimmed.starts_with("describe ") {
+            let name = extract_quoted_name(trimmed).unwrap_or_else(|| trimmed.to_string());
+            contexts.push((find_do_block_end(&lines, idx), name));
+        } else if trimmed.starts_with("test ") {
+            let name = extract_quoted_name(trimmed).unwrap_or_else(|| "example".to_string());
+            let end = find_do_block_end(&lines, idx);
+            cases.push(DiscoveredCase {
+                name,
+                context_path: contexts.iter().map(|(_, ctx)| ctx.clone()).collect(),
+                start_line: idx + 1,
+                end_line: end,
+            });
+            idx = end;
+            continue;
+        }
+        idx += 1;
+    }
+    cases
+}
+
+/// End line (1-indexed) of an Elixir `do ... end` block opened on `start_idx`.
+fn find_do_block_end(lines: &[&str], start_idx: usize) -> usize {
+    let mut balance = 0isize;
+    for (idx, line) in lines.iter().enumerate().skip(start_idx) {
+        let trimmed = line.trim();
+        if trimmed.ends_with(" do") || trimmed.ends_with(" do ->") {
+            balance += 1;
+        }
+        if trimmed == "end" || trimmed.starts_with("end)") || trimmed.starts_with("end ") {
+            balance -= 1;
+        }
+        if balance <= 0 {
+            return idx + 1;
+        }
+    }
+    lines.len()
+}
+
+/// GoogleTest (`TEST`, `TEST_F`, `TEST_P`) and Catch2 (`TEST_CASE`) cases.
+fn discover_macro_test_cases(source: &str) -> Vec<DiscoveredCase> {
+    let lines: Vec<&str> = source.lines().collect();
+    let mut cases = Vec::new();
+    let mut idx = 0usize;
+    while idx < lines.len() {
+        let trimmed = lines[idx].trim();
+        let args = ["TEST(", "TEST_F(", "TEST_P(", "TEST_CASE("]
+            .iter()
+            .find_map(|macro_name| trimmed.strip_prefix(macro_name));
+        if let Some(args) = args {
+            let args = args.split(')').next().unwrap_or_default();
+            let name = extract_quoted_name(args)
+                .unwrap_or_else(|| args.split(',').map(str::trim).collect::<Vec<_>>().join("."));
+            let end = find_brace_block_end(&lines, idx);
+            cases.push(DiscoveredCase {
+                name,
+                context_path: Vec::new(),
+                start_line: idx + 1,
+                end_line: end,
+            });
+            idx = end;
+            continue;
+        }
+        idx += 1;
+    }
+    cases
+}
+
+/// Name of the method declared on `line`: the last token before `(`.
+fn extract_method_name(line: &str) -> Option<String> {
+    let before_paren = line.split('(').next()?;
+    let name = before_paren
+        .split_whitespace()
+        .last()?
+        .trim_matches('`')
+        .to_string();
+    (!name.is_empty()).then_some(name)
+}
+
 fn discover_ruby_cases(source: &str) -> Vec<DiscoveredCase> {
     let lines: Vec<&str> = source.lines().collect();
     let mut cases = Vec::new();
@@ -1210,6 +1461,18 @@ fn count_assertions(language: Language, snippet: &str) -> usize {
             "t.Fatal", "t.Fatalf", "t.Error", "t.Errorf", "require.", "assert.",
         ],
         Language::Rust => vec!["assert!", "assert_eq!", "assert_ne!", "matches!"],
+        Language::TypeScript => vec!["expect(", "assert.", "assert("],
+        Language::Java | Language::Kotlin => vec!["assert", "verify(", "shouldBe"],
+        Language::CSharp => vec!["Assert.", ".Should()", ".Verify("],
+        Language::Cpp => vec!["EXPECT_", "ASSERT_", "REQUIRE(", "CHECK("],
+        Language::Php => vec![
+            "$this->assert",
+            "self::assert",
+            "expect(",
+            "->expectException(",
+        ],
+        Language::Elixir => vec!["assert ", "refute ", "assert_raise", "assert_receive"],
+        Language::Custom(_) => vec![],
     };
     needles
         .into_iter()
@@ -1225,6 +1488,20 @@ fn count_branches(language: Language, snippet: &str) -> usize {
         ],
         Language::Go => vec!["if ", "for ", "case ", "&&", "||"],
         Language::Rust => vec!["if ", "while ", "for ", "match ", "&&", "||"],
+        Language::TypeScript => vec!["if ", "while ", "for ", "case ", "&&", "||", "??"],
+        Language::Java => vec!["if ", "while ", "for ", "case ", "&&", "||"],
+        Language::CSharp => vec![
+            "if ", "while ", "for ", "foreach ", "case ", "&&", "||", "??",
+        ],
+        Language::Kotlin => vec!["if ", "while ", "for ", "when ", "&&", "||"],
+        Language::Cpp => vec!["if ", "if(", "while ", "for ", "for(", "case ", "&&", "||"],
+        Language::Php => vec![
+            "if ", "if(", "foreach ", "while ", "for ", "case ", "&&", "||", "??",
+        ],
+        Language::Elixir => vec![
+            "if ", "unless ", "case ", "cond ", "with ", " and ", " or ", "&&", "||",
+        ],
+        Language::Custom(_) => vec![],
     };
     needles
         .into_iter()
@@ -1238,6 +1515,13 @@ fn count_setup_depth(language: Language, snippet: &str) -> usize {
         Language::Ruby => vec!["before ", "let(", "let!(", "subject", "setup"],
         Language::Go => vec!["t.Run(", "setup", "defer "],
         Language::Rust => vec!["let ", "setup", "arrange", "mod fixtures"],
+        Language::TypeScript => vec!["beforeEach(", "beforeAll(", "setup", "arrange"],
+        Language::Java | Language::Kotlin => vec!["@BeforeEach", "@Before", "setUp", "given"],
+        Language::CSharp => vec!["[SetUp]", "[TestInitialize]", "Arrange", "Setup("],
+        Language::Cpp => vec!["SetUp(", "SECTION(", "fixture"],
+        Language::Php => vec!["setUp(", "beforeEach(", "fixture", "factory("],
+        Language::Elixir => vec!["setup ", "setup_all", "fixture", "insert("],
+        Language::Custom(_) => vec![],
     };
     needles
         .into_iter()
@@ -1258,6 +1542,26 @@ fn count_mocks(language: Language, snippet: &str) -> usize {
         Language::Ruby => vec!["double(", "allow(", "receive(", "stub(", "mock("],
         Language::Go => vec!["gomock", "mock.", "fake", "stub"],
         Language::Rust => vec!["mockall", "mock_", "double", "stub"],
+        Language::TypeScript => vec![
+            "jest.fn(",
+            "jest.mock(",
+            "vi.fn(",
+            "vi.mock(",
+            "sinon.",
+            "stub",
+        ],
+        Language::Java | Language::Kotlin => vec!["mock(", "@Mock", "mockk", "every {", "when("],
+        Language::CSharp => vec!["new Mock<", "Mock.Of<", "Substitute.For<", "A.Fake<"],
+        Language::Cpp => vec!["MOCK_METHOD", "EXPECT_CALL(", "ON_CALL("],
+        Language::Php => vec![
+            "createMock(",
+            "getMockBuilder(",
+            "createStub(",
+            "Mockery::mock(",
+            "prophesize(",
+        ],
+        Language::Elixir => vec!["Mox.", "expect(", "stub(", "with_mock"],
+        Language::Custom(_) => vec![],
     };
     needles
         .into_iter()
@@ -1279,6 +1583,19 @@ fn is_table_driven(language: Language, snippet: &str) -> bool {
         Language::Ruby => snippet.contains("shared_examples") || snippet.contains("each do |"),
         Language::Go => snippet.contains("[]struct") || snippet.contains("t.Run("),
         Language::Rust => snippet.contains("for case in") || snippet.contains("cases.iter()"),
+        Language::TypeScript => snippet.contains(".each(") || snippet.contains(".each`"),
+        Language::Java | Language::Kotlin => snippet.contains("@ParameterizedTest"),
+        Language::CSharp => ["[Theory", "[InlineData(", "[TestCase(", "[DataRow("]
+            .iter()
+            .any(|marker| snippet.contains(marker)),
+        Language::Cpp => ["TEST_P(", "GENERATE(", "TEMPLATE_TEST_CASE("]
+            .iter()
+            .any(|marker| snippet.contains(marker)),
+        Language::Php => ["@dataProvider", "#[DataProvider(", "->with("]
+            .iter()
+            .any(|marker| snippet.contains(marker)),
+        Language::Elixir => snippet.contains("for {") || snippet.contains("Enum.each("),
+        Language::Custom(_) => false,
     }
 }
 
@@ -1,4 +1,6 @@
+use crate::config::ResolvedFrontend;
 use crate::errors::Result;
+use crate::parse::custom::{self, CustomLanguageId};
 use globset::{Glob, GlobSetBuilder};
 use ignore::WalkBuilder;
 use serde::{Deserialize, Serialize};
@@ -6,23 +8,55 @@ use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 
 /// Supported language for file discovery.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
-#[serde(rename_all = "lowercase")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Language {
     Python,
     Ruby,
     Go,
     Rust,
+    TypeScript,
+    Java,
+    Kotlin,
+    CSharp,
+    Cpp,
+    Php,
+    Elixir,
+    /// Language declared in `[frontends.<name>]`
+    Custom(CustomLanguageId),
 }
 
 impl Language {
-    /// File extensions for this language.
-    pub fn extensions(&self) -> &[&str] {
+    /// Every built-in language.
+    pub const ALL: [Language; 11] = [
+        Language::Python,
+        Language::Ruby,
+        Language::Go,
+        Language::Rust,
+        Language::TypeScript,
+        Language::Java,
+        Language::Kotlin,
+        Language::CSharp,
+        Language::Cpp,
+        Language::Php,
+        Language::Elixir,
+    ];
+
+    /// File extensions for this language. Custom languages list theirs in
+    /// their `[frontends]` definition; see [`language_extensions`].
+    pub fn extensions(&self) -> &'static [&'static str] {
         match self {
             Language::Python => &["py"],
             Language::Ruby => &["rb"],
             Language::Go => &["go"],
             Language::Rust => &["rs"],
+            Language::TypeScript => &["ts", "tsx", "mts", "cts", "js", "jsx", "mjs", "cjs"],
+            Language::Java => &["java"],
+            Language::Kotlin => &["kt"],
+            Language::CSharp => &["cs"],
+            Language::Cpp => &["c", "h", "cc", "cpp", "cxx", "hh", "hpp", "hxx"],
+            Language::Php => &["php"],
+            Language::Elixir => &["ex", "exs"],
+            Language::Custom(_) => &[],
         }
     }
 
@@ -30,9 +64,70 @@ impl Language {
     pub fn default_excludes(&self) -> Vec<String> {
         match self {
             Language::Go => vec!["*_test.go".to_string()],
-            Language::Rust | Language::Python | Language::Ruby => vec![],
+            Language::TypeScript => ["test", "spec"]
+                .iter()
+                .flat_map(|kind| {
+                    Language::TypeScript
+                        .extensions()
+                        .iter()
+                        .map(move |ext| format!("*.{kind}.{ext}"))
+                })
+                .chain(std::iter::once("**/__tests__/**".to_string()))
+                .collect(),
+            Language::Java | Language::Kotlin => {
+                let ext = self.extensions()[0];
+                vec![
+                    "**/src/test/**".to_string(),
+                    "**/src/androidTest/**".to_string(),
+                    format!("*Test.{ext}"),
+                    format!("*Tests.{ext}"),
+                ]
+            }
+            Language::CSharp => vec![
+                "**/*.Tests/**".to_string(),
+                "**/*.Test/**".to_string(),
+                "*Tests.cs".to_string(),
+                "*Test.cs".to_string(),
+            ],
+            Language::Cpp => ["c", "cc", "cpp", "cxx"]
+                .iter()
+                .flat_map(|ext| [format!("*_test.{ext}"), format!("*_unittest.{ext}")])
+                .collect(),
+            Language::Php => vec!["*Test.php".to_string()],
+            Language::Elixir => vec!["*_test.exs".to_string()],
+            Language::Rust | Language::Python | Language::Ruby | Language::Custom(_) => vec![],
+        }
+    }
+
+    /// Directories holding vendored third-party sources that are never analyzed.
+    pub fn vendor_dirs(&self) -> &[&str] {
+        match self {
+            Language::TypeScript => &["node_modules"],
+            Language::CSharp => &["bin", "obj"],
+            Language::Php => &["vendor"],
+            Language::Elixir => &["deps", "_build"],
+            Language::Python
+            | Language::Ruby
+            | Language::Go
+            | Language::Rust
+            | Language::Java
+            | Language::Kotlin
+            | Language::Cpp
+            | Language::Custom(_) => &[],
         }
     }
+
+    /// Whether a path lies inside one of this language's vendored directories.
+    pub fn is_vendored(&self, relative: &Path) -> bool {
+        let vendor_dirs = self.vendor_dirs();
+        !vendor_dirs.is_empty()
+            && relative.components().any(|component| {
+                component
+                    .as_os_str()
+                    .to_str()
+                    .is_some_and(|name| vendor_dirs.contains(&name))
+            })
+    }
 }
 
 impl std::str::FromStr for Language {
@@ -44,7 +139,16 @@ impl std::str::FromStr for Language {
             "ruby" | "rb" => Ok(Language::Ruby),
             "go" => Ok(Language::Go),
             "rust" | "rs" => Ok(Language::Rust),
-            _ => Err(format!("unsupported language: {s}")),
+            "typescript" | "ts" | "javascript" | "js" => Ok(Language::TypeScript),
+            "java" => Ok(Language::Java),
+            "kotlin" | "kt" => Ok(Language::Kotlin),
+            "csharp" | "cs" | "c#" => Ok(Language::CSharp),
+            "cpp" | "c++" | "cxx" | "c" => Ok(Language::Cpp),
+            "php" => Ok(Language::Php),
+            "elixir" | "ex" => Ok(Language::Elixir),
+            _ => CustomLanguageId::new(s)
+                .map(Language::Custom)
+                .ok_or_else(|| format!("unsupported language: {s}")),
         }
     }
 }
@@ -56,10 +160,47 @@ impl std::fmt::Display for Language {
             Language::Ruby => write!(f, "ruby"),
             Language::Go => write!(f, "go"),
             Language::Rust => write!(f, "rust"),
+            Language::TypeScript => write!(f, "typescript"),
+            Language::Java => write!(f, "java"),
+            Language::Kotlin => write!(f, "kotlin"),
+            Language::CSharp => write!(f, "csharp"),
+            Language::Cpp => write!(f, "cpp"),
+            Language::Php => write!(f, "php"),
+            Language::Elixir => write!(f, "elixir"),
+            Language::Custom(id) => write!(f, "{}", id.as_str()),
         }
     }
 }
 
+impl Serialize for Language {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Language {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        name.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// File extensions of `lang`, taking those of a custom language from its
+/// definition in `frontends`.
+pub fn language_extensions(lang: Language, frontends: &[ResolvedFrontend]) -> Vec<&str> {
+    match lang {
+        Language::Custom(id) => custom::definition(id, frontends)
+            .map(|frontend| frontend.extensions.iter().map(String::as_str).collect())
+            .unwrap_or_default(),
+        _ => lang.extensions().to_vec(),
+    }
+}
+
 /// Discover source files under `root` for the given language.
 ///
 /// - Respects `.gitignore`
@@ -72,8 +213,9 @@ pub fn discover_files(
     include_patterns: &[String],
     exclude_patterns: &[String],
     include_tests: bool,
+    frontends: &[ResolvedFrontend],
 ) -> Result<Vec<PathBuf>> {
-    let extensions = lang.extensions();
+    let extensions = language_extensions(lang, frontends);
 
     // Build exclude globset
     let mut exclude_builder = GlobSetBuilder::new();
@@ -130,6 +272,9 @@ pub fn discover_files(
 
         // Get relative path for glob matching
         let relative = path.strip_prefix(root).unwrap_or(path);
+        if lang.is_vendored(relative) {
+            continue;
+        }
 
         // Apply exclude patterns
         if exclude_set.is_match(relative) || exclude_set.is_match(path) {
@@ -160,10 +305,10 @@ pub fn discover_files(
 
 /// Auto-detect language by counting file extensions.
 pub fn detect_language(root: &Path) -> Option<Language> {
-    dominant_language(&count_languages(root))
+    dominant_language(&count_languages(root, &[]))
 }
 
-fn count_languages(root: &Path) -> HashMap<Language, usize> {
+fn count_languages(root: &Path, frontends: &[ResolvedFrontend]) -> HashMap<Language, usize> {
     let walker = WalkBuilder::new(root)
         .hidden(false)
         .git_ignore(true)
@@ -176,7 +321,7 @@ fn count_languages(root: &Path) -> HashMap<Language, usize> {
         if !path.is_file() {
             continue;
         }
-        if let Some(lang) = language_for_file(path) {
+        if let Some(lang) = language_for_file(path, frontends) {
             *counts.entry(lang).or_insert(0) += 1;
         }
     }
@@ -188,7 +333,24 @@ fn dominant_language(counts: &HashMap<Language, usize>) -> Option<Language> {
     let rb_count = counts.get(&Language::Ruby).copied().unwrap_or(0);
     let go_count = counts.get(&Language::Go).copied().unwrap_or(0);
     let rs_count = counts.get(&Language::Rust).copied().unwrap_or(0);
-    let max = py_count.max(rb_count).max(go_count).max(rs_count);
+    let ts_count = counts.get(&Language::TypeScript).copied().unwrap_or(0);
+    let java_count = counts.get(&Language::Java).copied().unwrap_or(0);
+    let kt_count = counts.get(&Language::Kotlin).copied().unwrap_or(0);
+    let cs_count = counts.get(&Language::CSharp).copied().unwrap_or(0);
+    let cpp_count = counts.get(&Language::Cpp).copied().unwrap_or(0);
+    let php_count = counts.get(&Language::Php).copied().unwrap_or(0);
+    let ex_count = counts.get(&Language::Elixir).copied().unwrap_or(0);
+    let max = py_count
+        .max(rb_count)
+        .max(go_count)
+        .max(rs_count)
+        .max(ts_count)
+        .max(java_count)
+        .max(kt_count)
+        .max(cs_count)
+        .max(cpp_count)
+        .max(php_count)
+        .max(ex_count);
     (max > 0).then_some({
         if max == py_count {
             Language::Python
@@ -196,28 +358,50 @@ fn dominant_language(counts: &HashMap<Language, usize>) -> Option<Language> {
             Language::Go
         } else if max == rs_count {
             Language::Rust
-        } else {
+        } else if max == rb_count {
             Language::Ruby
+        } else if max == ts_count {
+            Language::TypeScript
+        } else if max == java_count {
+            Language::Java
+        } else if max == kt_count {
+            Language::Kotlin
+        } else if max == cs_count {
+            Language::CSharp
+        } else if max == cpp_count {
+            Language::Cpp
+        } else if max == php_count {
+            Language::Php
+        } else {
+            Language::Elixir
         }
     })
 }
 
 /// Auto-detect all languages present in the directory, sorted by file count descending.
-pub fn detect_languages(root: &Path) -> Vec<Language> {
-    let mut langs: Vec<(Language, usize)> = count_languages(root).into_iter().collect();
-    langs.sort_by(|a, b| b.1.cmp(&a.1));
+pub fn detect_languages(root: &Path, frontends: &[ResolvedFrontend]) -> Vec<Language> {
+    let mut langs: Vec<(Language, usize)> = count_languages(root, frontends).into_iter().collect();
+    langs.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
     langs.into_iter().map(|(lang, _)| lang).collect()
 }
 
-/// Determine the language for a file based on its extension.
-pub fn language_for_file(path: &Path) -> Option<Language> {
+/// Determine the language for a file based on its extension. Extensions of
+/// built-in languages stay with them; others may belong to `frontends`.
+pub fn language_for_file(path: &Path, frontends: &[ResolvedFrontend]) -> Option<Language> {
     let ext = path.extension().and_then(|e| e.to_str())?;
     match ext {
         "py" => Some(Language::Python),
         "rb" => Some(Language::Ruby),
         "go" => Some(Language::Go),
         "rs" => Some(Language::Rust),
-        _ => None,
+        "ts" | "tsx" | "mts" | "cts" | "js" | "jsx" | "mjs" | "cjs" => Some(Language::TypeScript),
+        "java" => Some(Language::Java),
+        "kt" => Some(Language::Kotlin),
+        "cs" => Some(Language::CSharp),
+        "c" | "h" | "cc" | "cpp" | "cxx" | "hh" | "hpp" | "hxx" => Some(Language::Cpp),
+        "php" => Some(Language::Php),
+        "ex" | "exs" => Some(Language::Elixir),
+        _ => custom::for_extension(ext, frontends),
     }
 }
 
@@ -230,6 +414,7 @@ pub fn discover_files_multi(
     include_patterns: &[String],
     exclude_patterns: &[String],
     include_tests: bool,
+    frontends: &[ResolvedFrontend],
 ) -> Result<HashMap<Language, Vec<PathBuf>>> {
     // Build exclude globset
     let mut exclude_builder = GlobSetBuilder::new();
@@ -239,15 +424,20 @@ pub fn discover_files_multi(
     let exclude_set = exclude_builder.build()?;
 
     // Language-specific exclude sets (e.g., Go test files)
-    let go_test_exclude = if !include_tests {
-        let mut builder = GlobSetBuilder::new();
-        for pattern in Language::Go.default_excludes() {
-            builder.add(Glob::new(&pattern)?);
+    let mut test_excludes: HashMap<Language, globset::GlobSet> = HashMap::new();
+    if !include_tests {
+        for lang in Language::ALL {
+            let patterns = lang.default_excludes();
+            if patterns.is_empty() {
+                continue;
+            }
+            let mut builder = GlobSetBuilder::new();
+            for pattern in patterns {
+                builder.add(Glob::new(&pattern)?);
+            }
+            test_excludes.insert(lang, builder.build()?);
         }
-        builder.build().ok()
-    } else {
-        None
-    };
+    }
 
     // Build include globset (if any patterns specified)
     let include_set = if include_patterns.is_empty() {
@@ -280,13 +470,16 @@ pub fn discover_files_multi(
         }
 
         // Determine language from extension
-        let lang = match language_for_file(path) {
+        let lang = match language_for_file(path, frontends) {
             Some(l) => l,
             None => continue,
         };
 
         // Get relative path for glob matching
         let relative = path.strip_prefix(root).unwrap_or(path);
+        if lang.is_vendored(relative) {
+            continue;
+        }
 
         // Apply exclude patterns
         if exclude_set.is_match(relative) || exclude_set.is_match(path) {
@@ -299,17 +492,15 @@ pub fn discover_files_multi(
         }
 
         // Apply language-specific test excludes (e.g., Go *_test.go)
-        if lang == Language::Go {
-            if let Some(ref go_exclude) = go_test_exclude {
-                if go_exclude.is_match(relative)
-                    || go_exclude.is_match(path)
-                    || path
-                        .file_name()
-                        .map(|f| go_exclude.is_match(Path::new(f)))
-                        .unwrap_or(false)
-                {
-                    continue;
-                }
+        if let Some(test_exclude) = test_excludes.get(&lang) {
+            if test_exclude.is_match(relative)
+                || test_exclude.is_match(path)
+                || path
+                    .file_name()
+                    .map(|f| test_exclude.is_match(Path::new(f)))
+                    .unwrap_or(false)
+            {
+                continue;
             }
         }
 
@@ -389,7 +580,24 @@ mod tests {
         assert_eq!("go".parse::<Language>().unwrap(), Language::Go);
         assert_eq!("rust".parse::<Language>().unwrap(), Language::Rust);
         assert_eq!("rs".parse::<Language>().unwrap(), Language::Rust);
-        assert!("java".parse::<Language>().is_err());
+        assert_eq!(
+            "typescript".parse::<Language>().unwrap(),
+            Language::TypeScript
+        );
+        assert_eq!("js".parse::<Language>().unwrap(), Language::TypeScript);
+        assert_eq!("java".parse::<Language>().unwrap(), Language::Java);
+        assert_eq!("kt".parse::<Language>().unwrap(), Language::Kotlin);
+        assert_eq!("c#".parse::<Language>().unwrap(), Language::CSharp);
+        assert_eq!("c++".parse::<Language>().unwrap(), Language::Cpp);
+        assert_eq!("php".parse::<Language>().unwrap(), Language::Php);
+        assert_eq!("ex".parse::<Language>().unwrap(), Language::Elixir);
+        // Other names may be custom languages; config resolution checks that
+        // a `[frontends]` table declares them.
+        assert!(matches!(
+            "cobol".parse::<Language>().unwrap(),
+            Language::Custom(_)
+        ));
+        assert!("co bol".parse::<Language>().is_err());
     }
 
     #[test]
@@ -401,19 +609,117 @@ mod tests {
     #[test]
     fn language_for_file_extensions() {
         assert_eq!(
-            language_for_file(Path::new("foo.py")),
+            language_for_file(Path::new("foo.py"), &[]),
             Some(Language::Python)
         );
-        assert_eq!(language_for_file(Path::new("bar.rb")), Some(Language::Ruby));
-        assert_eq!(language_for_file(Path::new("baz.go")), Some(Language::Go));
-        assert_eq!(language_for_file(Path::new("qux.rs")), Some(Language::Rust));
-        assert_eq!(language_for_file(Path::new("readme.md")), None);
-        assert_eq!(language_for_file(Path::new("noext")), None);
+        assert_eq!(
+            language_for_file(Path::new("bar.rb"), &[]),
+            Some(Language::Ruby)
+        );
+        assert_eq!(
+            language_for_file(Path::new("baz.go"), &[]),
+            Some(Language::Go)
+        );
+        assert_eq!(
+            language_for_file(Path::new("qux.rs"), &[]),
+            Some(Language::Rust)
+        );
+        assert_eq!(
+            language_for_file(Path::new("app.tsx"), &[]),
+            Some(Language::TypeScript)
+        );
+        assert_eq!(
+            language_for_file(Path::new("index.mjs"), &[]),
+            Some(Language::TypeScript)
+        );
+        assert_eq!(
+            language_for_file(Path::new("net.hpp"), &[]),
+            Some(Language::Cpp)
+        );
+        assert_eq!(language_for_file(Path::new("readme.md"), &[]), None);
+        assert_eq!(language_for_file(Path::new("noext"), &[]), None);
+    }
+
+    #[test]
+    fn discover_files_multi_skips_typescript_tests_and_node_modules() {
+        let tmp = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(tmp.path().join("src/__tests__")).unwrap();
+        std::fs::create_dir_all(tmp.path().join("node_modules/react")).unwrap();
+        std::fs::write(tmp.path().join("src/app.ts"), "").unwrap();
+        std::fs::write(tmp.path().join("src/app.test.ts"), "").unwrap();
+        std::fs::write(tmp.path().join("src/__tests__/app.ts"), "").unwrap();
+        std::fs::write(tmp.path().join("node_modules/react/index.js"), "").unwrap();
+
+        let result = discover_files_multi(tmp.path(), &[], &[], false, &[]).unwrap();
+        let files = result.get(&Language::TypeScript).unwrap();
+        assert_eq!(files, &vec![tmp.path().join("src/app.ts")]);
+
+        let with_tests = discover_files_multi(tmp.path(), &[], &[], true, &[]).unwrap();
+        assert_eq!(with_tests.get(&Language::TypeScript).unwrap().len(), 3);
+    }
+
+    #[test]
+    fn jvm_default_excludes_test_sources() {
+        let tmp = tempfile::tempdir().unwrap();
+        let main = tmp.path().join("src/main/java/com/acme");
+        let test = tmp.path().join("src/test/java/com/acme");
+        std::fs::create_dir_all(&main).unwrap();
+        std::fs::create_dir_all(&test).unwrap();
+        std::fs::write(main.join("Service.java"), "").unwrap();
+        std::fs::write(main.join("ServiceTest.java"), "").unwrap();
+        std::fs::write(test.join("Helper.java"), "").unwrap();
+
+        let files = discover_files(tmp.path(), Language::Java, &[], &[], false, &[]).unwrap();
+        assert_eq!(files, vec![main.join("Service.java")]);
+    }
+
+    #[test]
+    fn csharp_skips_build_output_and_test_projects() {
+        let tmp = tempfile::tempdir().unwrap();
+        for dir in ["Acme.Core/obj/Debug", "Acme.Core.Tests"] {
+            std::fs::create_dir_all(tmp.path().join(dir)).unwrap();
+        }
+        std::fs::write(tmp.path().join("Acme.Core/Service.cs"), "").unwrap();
+        std::fs::write(tmp.path().join("Acme.Core/obj/Debug/AssemblyInfo.cs"), "").unwrap();
+        std::fs::write(tmp.path().join("Acme.Core.Tests/ServiceSpec.cs"), "").unwrap();
+
+        let result = discover_files_multi(tmp.path(), &[], &[], false, &[]).unwrap();
+        let files = result.get(&Language::CSharp).unwrap();
+        assert_eq!(files, &vec![tmp.path().join("Acme.Core/Service.cs")]);
+    }
+
+    #[test]
+    fn php_skips_composer_vendor_and_phpunit_tests() {
+        let tmp = tempfile::tempdir().unwrap();
+        for dir in ["src", "vendor/symfony/console", "tests"] {
+            std::fs::create_dir_all(tmp.path().join(dir)).unwrap();
+        }
+        std::fs::write(tmp.path().join("src/Kernel.php"), "").unwrap();
+        std::fs::write(tmp.path().join("vendor/symfony/console/App.php"), "").unwrap();
+        std::fs::write(tmp.path().join("tests/KernelTest.php"), "").unwrap();
+
+        let files = discover_files(tmp.path(), Language::Php, &[], &[], false, &[]).unwrap();
+        assert_eq!(files, vec![tmp.path().join("src/Kernel.php")]);
+    }
+
+    #[test]
+    fn elixir_skips_deps_build_and_exunit_tests() {
+        let tmp = tempfile::tempdir().unwrap();
+        for dir in ["lib/shop", "deps/plug/lib", "_build/dev/lib", "test"] {
+            std::fs::create_dir_all(tmp.path().join(dir)).unwrap();
+        }
+        std::fs::write(tmp.path().join("lib/shop/cart.ex"), "").unwrap();
+        std::fs::write(tmp.path().join("deps/plug/lib/plug.ex"), "").unwrap();
+        std::fs::write(tmp.path().join("_build/dev/lib/generated.ex"), "").unwrap();
+        std::fs::write(tmp.path().join("test/cart_test.exs"), "").unwrap();
+
+        let files = discover_files(tmp.path(), Language::Elixir, &[], &[], false, &[]).unwrap();
+        assert_eq!(files, vec![tmp.path().join("lib/shop/cart.ex")]);
     }
 
     #[test]
     fn detect_languages_mixed_fixture() {
-        let langs = detect_languages(Path::new("tests/fixtures/polyglot"));
+        let langs = detect_languages(Path::new("tests/fixtures/polyglot"), &[]);
         assert!(langs.contains(&Language::Go), "Should detect Go files");
         assert!(
             langs.contains(&Language::Python),
@@ -495,7 +801,8 @@ mod tests {
     #[test]
     fn discover_files_multi_partitions() {
         let result =
-            discover_files_multi(Path::new("tests/fixtures/polyglot"), &[], &[], false).unwrap();
+            discover_files_multi(Path::new("tests/fixtures/polyglot"), &[], &[], false, &[])
+                .unwrap();
 
         assert!(result.contains_key(&Language::Go), "Should have Go files");
         assert!(
@@ -0,0 +1,11 @@
+#pragma once
+
+#include <vector>
+
+namespace net {
+
+struct Buffer {
+    std::vector<char> bytes;
+};
+
+}  // namespace net
@@ -0,0 +1,13 @@
+#pragma once
+
+#include <cstddef>
+#include "net/buffer.h"
+
+namespace net {
+
+class Socket {
+public:
+    std::size_t send(const Buffer& buffer);
+};
+
+}  // namespace net
@@ -0,0 +1,12 @@
+#include <stdio.h>
+#include <fmt/core.h>
+
+#include "net/socket.h"
+#include "config.h"
+
+int main() {
+    net::Socket socket;
+    net::Buffer buffer;
+    printf("%zu\n", socket.send(buffer));
+    return load_config();
+}
@@ -0,0 +1,9 @@
+#include <gtest/gtest.h>
+
+#include "net/socket.h"
+
+TEST(SocketTest, SendsWholeBuffer) {
+    net::Socket socket;
+    net::Buffer buffer{{'a', 'b'}};
+    EXPECT_EQ(socket.send(buffer), 2u);
+}
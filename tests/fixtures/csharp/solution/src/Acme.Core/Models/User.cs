@@ -0,0 +1,4 @@
+namespace Acme.Core.Models
+{
+    public record User(string Name);
+}
@@ -0,0 +1,9 @@
+using System.Collections.Generic;
+using Acme.Core.Models;
+
+namespace Acme.Core;
+
+public class Service
+{
+    public IReadOnlyList<User> Find(string name) => new[] { new User(Strings.Normalize(name)) };
+}
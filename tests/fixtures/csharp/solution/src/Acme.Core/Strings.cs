@@ -0,0 +1,6 @@
+namespace Acme.Core;
+
+public static class Strings
+{
+    public static string Normalize(string value) => value.Trim().ToLowerInvariant();
+}
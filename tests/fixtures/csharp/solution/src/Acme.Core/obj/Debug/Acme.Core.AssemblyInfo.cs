@@ -0,0 +1,4 @@
+using System.Reflection;
+using Acme.Web;
+
+[assembly: AssemblyTitle("Acme.Core")]
@@ -0,0 +1,17 @@
+using Acme.Core;
+using Microsoft.AspNetCore.Mvc;
+using static Acme.Core.Strings;
+using Users = Acme.Core.Models.User;
+
+namespace Acme.Web.Controllers;
+
+public class HomeController : Controller
+{
+    private readonly Service _service = new();
+
+    public IActionResult Index(string name)
+    {
+        Users? first = _service.Find(Normalize(name)).FirstOrDefault();
+        return Ok(first);
+    }
+}
@@ -0,0 +1,8 @@
+using Acme.Web.Controllers;
+
+namespace Acme.Web;
+
+public static class Program
+{
+    public static void Main() => new HomeController().Index("ada");
+}
@@ -0,0 +1,15 @@
+using Acme.Core;
+using Acme.Web.Controllers;
+using Xunit;
+
+namespace Acme.Core.Tests;
+
+public class ServiceTests
+{
+    [Fact]
+    public void FindsUsers()
+    {
+        Assert.Single(new Service().Find("Ada"));
+        Assert.NotNull(new HomeController());
+    }
+}
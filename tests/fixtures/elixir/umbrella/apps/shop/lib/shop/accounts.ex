@@ -0,0 +1,10 @@
+defmodule Shop.Accounts do
+  alias Shop.Repo
+  alias Shop.Accounts.User
+  require Logger
+
+  def get_user(id) do
+    Logger.debug("fetching user")
+    Repo.get(User, id)
+  end
+end
@@ -0,0 +1,14 @@
+defmodule Shop.Accounts.User do
+  use Ecto.Schema
+  import Ecto.Changeset
+
+  schema "users" do
+    field :email, :string
+  end
+
+  defmodule Query do
+    import Ecto.Query
+
+    def by_email(email), do: from(u in Shop.Accounts.User, where: u.email == ^email)
+  end
+end
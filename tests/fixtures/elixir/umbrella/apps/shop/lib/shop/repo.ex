@@ -0,0 +1,3 @@
+defmodule Shop.Repo do
+  use Ecto.Repo, otp_app: :shop
+end
@@ -0,0 +1,10 @@
+defmodule Shop.AccountsTest do
+  use ExUnit.Case
+  alias Shop.Accounts
+
+  describe "get_user/1" do
+    test "returns nil for unknown ids" do
+      assert Accounts.get_user(-1) == nil
+    end
+  end
+end
@@ -0,0 +1,7 @@
+defmodule ShopWeb do
+  def controller do
+    quote do
+      use Phoenix.Controller
+    end
+  end
+end
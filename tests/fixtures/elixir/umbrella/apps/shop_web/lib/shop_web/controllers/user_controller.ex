@@ -0,0 +1,10 @@
+defmodule ShopWeb.UserController do
+  use ShopWeb, :controller
+  alias Shop.{Accounts, Repo}
+  alias Shop.Accounts.User.Query, as: UserQuery
+
+  def show(conn, %{"id" => id}) do
+    user = Accounts.get_user(id)
+    json(conn, %{email: user.email})
+  end
+end
@@ -0,0 +1,7 @@
+defmodule ShopWeb.MixProject do
+  use Mix.Project
+
+  def project do
+    [app: :shop_web, version: "0.1.0", deps: [{:shop, in_umbrella: true}]]
+  end
+end
@@ -0,0 +1,3 @@
+defmodule Plug do
+  alias Shop.Repo
+end
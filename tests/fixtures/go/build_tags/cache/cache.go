@@ -0,0 +1,3 @@
+package cache
+
+func Lookup(key string) string { return key }
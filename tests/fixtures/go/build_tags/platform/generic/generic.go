@@ -0,0 +1,3 @@
+package generic
+
+func Open() {}
@@ -0,0 +1,3 @@
+package linux
+
+func Open() {}
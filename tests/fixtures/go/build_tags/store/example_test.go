@@ -0,0 +1,13 @@
+package store_test
+
+import (
+	"testing"
+
+	"example.com/shop/store"
+	"example.com/shop/testutil"
+)
+
+func TestPublicAPI(t *testing.T) {
+	testutil.Reset()
+	_ = store.Get("sku")
+}
@@ -0,0 +1,7 @@
+//go:build linux
+
+package store
+
+import "example.com/shop/platform/linux"
+
+func open() { linux.Open() }
@@ -0,0 +1,9 @@
+// Fallback for platforms without a native backend.
+
+//go:build !linux && !windows
+
+package store
+
+import "example.com/shop/platform/generic"
+
+func open() { generic.Open() }
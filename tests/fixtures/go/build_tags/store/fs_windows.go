@@ -0,0 +1,5 @@
+package store
+
+import "example.com/shop/platform/windows"
+
+func open() { windows.Open() }
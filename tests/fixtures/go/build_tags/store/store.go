@@ -0,0 +1,7 @@
+package store
+
+import "example.com/shop/cache"
+
+func Get(key string) string {
+	return cache.Lookup(key)
+}
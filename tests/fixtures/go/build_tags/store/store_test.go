@@ -0,0 +1,11 @@
+package store
+
+import (
+	"testing"
+
+	"example.com/shop/testutil"
+)
+
+func TestGet(t *testing.T) {
+	testutil.Reset()
+}
@@ -0,0 +1,5 @@
+package token
+
+func Issue() string {
+	return "token"
+}
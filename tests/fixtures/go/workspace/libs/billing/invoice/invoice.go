@@ -0,0 +1,5 @@
+package invoice
+
+func Total() int {
+	return 42
+}
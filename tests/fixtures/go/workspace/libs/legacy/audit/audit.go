@@ -0,0 +1,5 @@
+package audit
+
+func Log() string {
+	return "audit"
+}
@@ -0,0 +1,11 @@
+module example.com/api
+
+go 1.22
+
+require (
+	example.com/auth v0.0.0
+	example.com/billing v0.0.0
+	example.com/legacy v1.4.0
+)
+
+replace example.com/billing => ../../libs/billing
@@ -0,0 +1,7 @@
+package handlers
+
+import "example.com/auth/token"
+
+func Name() string {
+	return "api:" + token.Issue()
+}
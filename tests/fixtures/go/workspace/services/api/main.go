@@ -0,0 +1,14 @@
+package main
+
+import (
+	"fmt"
+
+	"example.com/api/internal/handlers"
+	"example.com/auth/token"
+	"example.com/billing/invoice"
+	"example.com/legacy/audit"
+)
+
+func main() {
+	fmt.Println(handlers.Name(), token.Issue(), invoice.Total(), audit.Log())
+}
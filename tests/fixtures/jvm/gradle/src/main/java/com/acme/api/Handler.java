@@ -0,0 +1,14 @@
+package com.acme.api;
+
+import java.util.List;
+import com.acme.core.Service;
+import static com.acme.core.Strings.normalize;
+import com.acme.model.*;
+
+public class Handler {
+    private final Service service = new Service();
+
+    public List<User> handle(String name) {
+        return service.find(normalize(name));
+    }
+}
@@ -0,0 +1,13 @@
+package com.acme.core;
+
+import java.util.ArrayList;
+import java.util.List;
+import com.acme.model.User;
+
+public class Service {
+    public List<User> find(String name) {
+        List<User> users = new ArrayList<>();
+        users.add(new User(name));
+        return users;
+    }
+}
@@ -0,0 +1,7 @@
+package com.acme.core;
+
+public final class Strings {
+    public static String normalize(String value) {
+        return value.trim().toLowerCase();
+    }
+}
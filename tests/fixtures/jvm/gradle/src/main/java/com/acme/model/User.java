@@ -0,0 +1,3 @@
+package com.acme.model;
+
+public record User(String name) {}
@@ -0,0 +1,11 @@
+package com.acme.report
+
+import com.acme.api.Handler
+import com.acme.model.User as Account
+import org.slf4j.LoggerFactory
+
+class Report(private val handler: Handler) {
+    private val log = LoggerFactory.getLogger(Report::class.java)
+
+    fun render(name: String): List<Account> = handler.handle(name)
+}
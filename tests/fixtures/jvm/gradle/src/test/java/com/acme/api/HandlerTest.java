@@ -0,0 +1,11 @@
+package com.acme.api;
+
+import com.acme.report.Report;
+import org.junit.jupiter.api.Test;
+
+class HandlerTest {
+    @Test
+    void handlesNames() {
+        new Report(new Handler()).render("Ada");
+    }
+}
@@ -0,0 +1,7 @@
+<?php
+
+namespace Acme\Shop\Http;
+
+abstract class BaseController
+{
+}
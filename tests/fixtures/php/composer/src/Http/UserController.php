@@ -0,0 +1,21 @@
+<?php
+
+namespace Acme\Shop\Http;
+
+use Acme\Shop\Models\User;
+use Acme\Shop\Services;
+use Psr\Log\LoggerInterface;
+
+class UserController extends BaseController
+{
+    public function __construct(private LoggerInterface $logger)
+    {
+    }
+
+    public function register(string $email): User
+    {
+        $user = new User($email);
+        (new Services\Mailer())->welcome($user);
+        return $user;
+    }
+}
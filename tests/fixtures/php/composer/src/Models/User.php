@@ -0,0 +1,10 @@
+<?php
+
+namespace Acme\Shop\Models;
+
+class User
+{
+    public function __construct(public string $email)
+    {
+    }
+}
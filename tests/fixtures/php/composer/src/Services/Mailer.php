@@ -0,0 +1,15 @@
+<?php
+
+namespace Acme\Shop\Services;
+
+use Acme\Shop\Models\User;
+
+class Mailer
+{
+    public function welcome(User $user): void
+    {
+        if ($user->email === '') {
+            throw new \InvalidArgumentException('missing email');
+        }
+    }
+}
@@ -0,0 +1,6 @@
+<?php
+
+function money(int $cents): string
+{
+    return number_format($cents / 100, 2);
+}
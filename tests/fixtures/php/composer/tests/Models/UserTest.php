@@ -0,0 +1,15 @@
+<?php
+
+namespace Acme\Shop\Tests\Models;
+
+use Acme\Shop\Models\User;
+use PHPUnit\Framework\TestCase;
+
+class UserTest extends TestCase
+{
+    public function testKeepsEmail(): void
+    {
+        $user = new User('a@example.com');
+        $this->assertSame('a@example.com', $user->email);
+    }
+}
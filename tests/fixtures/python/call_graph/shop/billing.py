@@ -0,0 +1,15 @@
+from shop import orders
+
+
+def charge(order):
+    return _record(order)
+
+
+def reverse(order):
+    if order.get("retry"):
+        return orders.refund(order)
+    return _record(order)
+
+
+def _record(order):
+    return order
@@ -0,0 +1,2 @@
+def reserve(order):
+    return len(order)
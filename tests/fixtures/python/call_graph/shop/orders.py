@@ -0,0 +1,17 @@
+from shop import billing
+from shop.inventory import reserve
+
+
+class OrderService:
+    def place(self, order):
+        self.validate(order)
+        reserve(order)
+        return billing.charge(order)
+
+    def validate(self, order):
+        if not order:
+            raise ValueError("empty order")
+
+
+def refund(order):
+    return billing.reverse(order)
@@ -0,0 +1,5 @@
+from app import service
+
+
+def get_order(order_id):
+    return service.load(order_id)
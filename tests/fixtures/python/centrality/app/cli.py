@@ -0,0 +1,5 @@
+from app import service
+
+
+def main():
+    print(service.load(1))
@@ -0,0 +1,2 @@
+def fetch(table, key):
+    return {"table": table, "key": key}
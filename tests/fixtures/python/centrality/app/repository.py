@@ -0,0 +1,5 @@
+from app import db
+
+
+def find(order_id):
+    return db.fetch("orders", order_id)
@@ -0,0 +1,5 @@
+from app import repository
+
+
+def load(order_id):
+    return repository.find(order_id)
@@ -0,0 +1,20 @@
+from shop.base import Model, Timestamped
+
+
+class Order(Model, Timestamped):
+    def __init__(self, customer_id):
+        self.customer = Customer.find(customer_id)
+
+
+class Customer(Model):
+    def orders(self) -> list[Order]:
+        return []
+
+    @classmethod
+    def find(cls, customer_id):
+        return cls()
+
+
+class Invoice:
+    def __init__(self, order: Order):
+        self.order = order
@@ -0,0 +1,5 @@
+import sqlalchemy
+
+
+def connect(url):
+    return sqlalchemy.create_engine(url)
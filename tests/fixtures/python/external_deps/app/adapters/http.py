@@ -0,0 +1,8 @@
+import requests
+
+from app.domain.orders import place_order
+
+
+def submit(url, session, order):
+    place_order(session, order)
+    return requests.post(url, json={"order": order})
@@ -0,0 +1,11 @@
+import os
+
+from sqlalchemy.orm import Session
+
+from app.domain.rules import validate
+
+
+def place_order(session: Session, order):
+    validate(order)
+    session.add(order)
+    return os.getpid()
@@ -0,0 +1,2 @@
+def validate(order):
+    return order is not None
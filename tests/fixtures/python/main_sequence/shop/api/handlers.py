@@ -0,0 +1,6 @@
+from shop.core.models import Customer, Order
+
+
+class OrderHandler:
+    def create(self, name, total):
+        return Order(Customer(name), total)
@@ -0,0 +1,6 @@
+from shop.core.models import Order
+
+
+class Invoice:
+    def __init__(self, order: Order):
+        self.order = order
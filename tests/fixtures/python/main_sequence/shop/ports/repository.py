@@ -0,0 +1,8 @@
+from abc import ABC, abstractmethod
+
+from shop.core.models import Order
+
+
+class OrderRepository(ABC):
+    @abstractmethod
+    def save(self, order: Order) -> None: ...
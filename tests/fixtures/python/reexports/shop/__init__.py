@@ -0,0 +1,4 @@
+from .pricing import total
+from . import inventory
+
+__all__ = ["total", "inventory"]
@@ -0,0 +1,5 @@
+import secrets
+
+
+def new():
+    return secrets.token_hex(8)
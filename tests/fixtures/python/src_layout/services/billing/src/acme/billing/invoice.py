@@ -0,0 +1,5 @@
+from acme.auth import tokens
+
+
+def issue(amount):
+    return {"amount": amount, "token": tokens.new()}
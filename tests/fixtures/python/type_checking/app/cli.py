@@ -0,0 +1,4 @@
+def main():
+    import app.services
+
+    app.services.Billing()
@@ -0,0 +1,9 @@
+from typing import TYPE_CHECKING
+
+if TYPE_CHECKING:
+    from app.services import Billing
+
+
+class Invoice:
+    def charge(self, billing: "Billing") -> None:
+        billing.charge(self)
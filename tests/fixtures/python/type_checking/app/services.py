@@ -0,0 +1,11 @@
+from app.models import Invoice
+
+try:
+    import app.speedups
+except ImportError:
+    pass
+
+
+class Billing:
+    def charge(self, invoice: Invoice) -> None:
+        pass
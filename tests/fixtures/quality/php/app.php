@@ -0,0 +1,9 @@
+<?php
+
+function absolute(int $x): int
+{
+    if ($x > 0) {
+        return $x;
+    }
+    return -$x;
+}
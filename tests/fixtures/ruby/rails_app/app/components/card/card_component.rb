@@ -0,0 +1,5 @@
+class CardComponent
+  def initialize(record)
+    @record = record
+  end
+end
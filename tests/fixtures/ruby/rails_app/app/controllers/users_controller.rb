@@ -0,0 +1,7 @@
+class UsersController
+  def show
+    user = User.find(1)
+    invoice = Billing::Invoice.new(user)
+    CardComponent.new(invoice)
+  end
+end
@@ -0,0 +1,5 @@
+module Trackable
+  def track!
+    touch
+  end
+end
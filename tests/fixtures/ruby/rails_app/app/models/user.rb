@@ -0,0 +1,7 @@
+class User < ApplicationRecord
+  include Trackable
+
+  def audit
+    AuditLog.record(self)
+  end
+end
@@ -0,0 +1,8 @@
+require_relative "boot"
+
+module Shop
+  class Application < Rails::Application
+    config.autoload_paths << "#{root}/extras"
+    Rails.autoloaders.main.collapse("#{root}/app/components/*")
+  end
+end
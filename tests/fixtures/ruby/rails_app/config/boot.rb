@@ -0,0 +1 @@
+require "bundler/setup"
@@ -0,0 +1,7 @@
+module Billing
+  class Customer
+    def initialize(user)
+      @user = user
+    end
+  end
+end
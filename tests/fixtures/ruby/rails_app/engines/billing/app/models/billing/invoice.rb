@@ -0,0 +1,8 @@
+module Billing
+  class Invoice
+    def initialize(user)
+      @user = user
+      @customer = Customer.new(user)
+    end
+  end
+end
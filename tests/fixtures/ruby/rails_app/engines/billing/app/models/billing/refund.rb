@@ -0,0 +1,5 @@
+class Billing::Refund
+  def ledger
+    Ledger.new
+  end
+end
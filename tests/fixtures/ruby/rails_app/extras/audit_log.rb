@@ -0,0 +1,5 @@
+class AuditLog
+  def self.record(subject)
+    subject
+  end
+end
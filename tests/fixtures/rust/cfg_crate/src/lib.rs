@@ -0,0 +1,8 @@
+mod json;
+mod store;
+#[cfg(feature = "yaml")]
+mod yaml;
+#[cfg(test)]
+mod test_support;
+
+pub use store::Store;
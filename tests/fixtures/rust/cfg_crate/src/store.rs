@@ -0,0 +1,12 @@
+use crate::json::Encoder;
+#[cfg(feature = "yaml")]
+use crate::yaml::Loader;
+
+pub struct Store {
+    encoder: Encoder,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_support::fixture;
+}
@@ -0,0 +1,9 @@
+mod tcp;
+
+pub trait Transport {
+    fn send(&self, bytes: &[u8]);
+}
+
+pub fn connect() -> impl Transport {
+    tcp::TcpTransport
+}
@@ -0,0 +1,3 @@
+import React from "react";
+
+export const Header = ({ items }: { items: string[] }) => <h1>{items.join(" ")}</h1>;
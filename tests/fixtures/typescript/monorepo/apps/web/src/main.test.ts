@@ -0,0 +1,5 @@
+import { App } from "./main";
+
+test("renders", () => {
+  expect(App).toBeDefined();
+});
@@ -0,0 +1,8 @@
+import { parse } from "@acme/core";
+import { Header } from "@app/components";
+
+export const App = () => <Header items={parse("a,b")} />;
+
+export function loadAbout() {
+  return import("./pages/about");
+}
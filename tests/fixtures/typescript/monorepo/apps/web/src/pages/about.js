@@ -0,0 +1,3 @@
+const { format } = require("@acme/core/format");
+
+module.exports = () => format(" about ");
@@ -0,0 +1,5 @@
+import { format } from "./format.js";
+
+export function parse(input: string): string[] {
+  return input.split(",").map(format);
+}
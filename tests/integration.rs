@@ -3,13 +3,32 @@ mod integration {
     mod analyze_test;
     mod architecture_policy_test;
     mod architecture_test;
+    mod centrality_test;
     mod ci_exit_codes_test;
+    mod class_graph_test;
     mod config_test;
+    mod cpp_test;
+    mod csharp_test;
+    mod custom_frontend_test;
     mod diff_test;
+    mod directory_granularity_test;
+    mod elixir_test;
+    mod external_deps_test;
+    mod function_graph_test;
+    mod go_build_test;
+    mod go_workspace_test;
+    mod jvm_test;
+    mod main_sequence_test;
+    mod php_test;
     mod polyglot_test;
+    mod python_imports_test;
     mod quality_report_test;
     mod quality_test;
+    mod ruby_test;
+    mod rust_cfg_test;
+    mod rust_mod_edges_test;
     mod rust_workspace_test;
     mod service_graph_test;
     mod spec_quality_test;
+    mod typescript_test;
 }
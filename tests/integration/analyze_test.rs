@@ -51,6 +51,69 @@ fn analyze_report_text_output() {
         .stdout(predicate::str::contains("Completed in"));
 }
 
+#[test]
+fn analyze_report_includes_propagation_cost_and_blast_radius() {
+    let output = Command::cargo_bin("untangle")
+        .unwrap()
+        .args([
+            "analyze",
+            "report",
+            "tests/fixtures/python/centrality",
+            "--lang",
+            "python",
+            "--format",
+            "json",
+            "--quiet",
+        ])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+
+    let json: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    // api and cli see 4 modules each, service 3, repository 2, db 1: 14 of 25
+    assert_eq!(json["summary"]["propagation_cost"], 0.56);
+    assert_eq!(json["summary"]["max_blast_radius"], 4);
+
+    let db = json["hotspots"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|hotspot| hotspot["node"] == "app.db")
+        .unwrap();
+    assert_eq!(db["transitive_fanin"], 4);
+    assert_eq!(db["transitive_fanout"], 0);
+}
+
+#[test]
+fn analyze_report_includes_lakos_ccd() {
+    let output = Command::cargo_bin("untangle")
+        .unwrap()
+        .args([
+            "analyze",
+            "report",
+            "tests/fixtures/python/centrality",
+            "--lang",
+            "python",
+            "--format",
+            "json",
+            "--quiet",
+        ])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+
+    let json: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    // api and cli need 4 modules each, service 3, repository 2, db 1
+    assert_eq!(json["summary"]["ccd"], 14);
+    assert_eq!(json["summary"]["acd"], 2.8);
+    // a balanced binary tree of 5 modules has CCD 6 log2(6) - 5 ≈ 10.51
+    assert_eq!(json["summary"]["nccd"], 1.33);
+}
+
 #[test]
 fn analyze_report_sarif_output() {
     Command::cargo_bin("untangle")
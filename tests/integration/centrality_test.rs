@@ -0,0 +1,129 @@
+use assert_cmd::Command;
+use std::path::{Path, PathBuf};
+
+fn fixture_path(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("fixtures")
+        .join(name)
+}
+
+fn copy_dir_recursive(src: &Path, dst: &Path) {
+    for entry in std::fs::read_dir(src).unwrap() {
+        let entry = entry.unwrap();
+        let path = entry.path();
+        let dest_path = dst.join(entry.file_name());
+        if path.is_dir() {
+            std::fs::create_dir_all(&dest_path).unwrap();
+            copy_dir_recursive(&path, &dest_path);
+        } else {
+            std::fs::copy(&path, &dest_path).unwrap();
+        }
+    }
+}
+
+fn report(extra: &[&str]) -> Command {
+    let mut cmd = Command::cargo_bin("untangle").unwrap();
+    cmd.args([
+        "analyze",
+        "report",
+        "tests/fixtures/python/centrality",
+        "--lang",
+        "python",
+        "--quiet",
+    ])
+    .args(extra);
+    cmd
+}
+
+fn hotspots(sort: &str) -> Vec<serde_json::Value> {
+    let output = report(&["--format", "json", "--sort", sort])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let json: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    json["hotspots"].as_array().unwrap().clone()
+}
+
+fn order(hotspots: &[serde_json::Value]) -> Vec<&str> {
+    hotspots
+        .iter()
+        .map(|hotspot| hotspot["node"].as_str().unwrap())
+        .collect()
+}
+
+#[test]
+fn hotspots_report_centrality_and_cut_structure() {
+    let hotspots = hotspots("betweenness");
+    assert_eq!(
+        order(&hotspots)[..2],
+        ["app.service", "app.repository"],
+        "the chain's middle modules carry the most shortest paths"
+    );
+
+    let service = &hotspots[0];
+    assert_eq!(service["betweenness"], 0.3333);
+    assert_eq!(service["articulation_point"], true);
+    assert_eq!(service["fanout_edges"][0]["to"], "app.repository");
+    assert_eq!(service["fanout_edges"][0]["bridge"], true);
+
+    let db = hotspots.iter().find(|h| h["node"] == "app.db").unwrap();
+    assert_eq!(db["betweenness"], 0.0);
+    assert_eq!(db["articulation_point"], false);
+}
+
+#[test]
+fn sort_flag_reorders_hotspots() {
+    let by_pagerank = hotspots("pagerank");
+    assert_eq!(
+        order(&by_pagerank)[..3],
+        ["app.db", "app.repository", "app.service"]
+    );
+
+    let by_fanin = hotspots("fanin");
+    assert_eq!(order(&by_fanin)[0], "app.service");
+}
+
+#[test]
+fn sort_can_be_configured_and_shows_in_text_output() {
+    let tmp = tempfile::tempdir().unwrap();
+    let dest = tmp.path().join("project");
+    std::fs::create_dir_all(&dest).unwrap();
+    copy_dir_recursive(&fixture_path("python/centrality"), &dest);
+    std::fs::write(
+        dest.join(".untangle.toml"),
+        r#"
+[analyze.report]
+sort = "pagerank"
+"#,
+    )
+    .unwrap();
+
+    let output = Command::cargo_bin("untangle")
+        .unwrap()
+        .args([
+            "analyze",
+            "report",
+            dest.to_str().unwrap(),
+            "--lang",
+            "python",
+            "--format",
+            "text",
+            "--quiet",
+        ])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let stdout = String::from_utf8(output).unwrap();
+    assert!(stdout.contains("Top 5 Hotspots (by pagerank)"));
+    let first_row = stdout
+        .lines()
+        .skip_while(|line| !line.starts_with("Module"))
+        .nth(1)
+        .unwrap();
+    assert!(first_row.starts_with("app.db"), "{first_row}");
+}
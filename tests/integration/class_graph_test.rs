@@ -0,0 +1,103 @@
+use assert_cmd::Command;
+
+fn class_graph(fixture: &str, lang: &str) -> serde_json::Value {
+    let output = Command::cargo_bin("untangle")
+        .unwrap()
+        .args([
+            "analyze",
+            "graph",
+            fixture,
+            "--lang",
+            lang,
+            "--granularity",
+            "class",
+            "--format",
+            "json",
+            "--quiet",
+        ])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    serde_json::from_slice(&output).unwrap()
+}
+
+fn edge_kind<'a>(json: &'a serde_json::Value, from: &str, to: &str) -> Option<&'a str> {
+    json["edges"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|edge| edge["from"] == from && edge["to"] == to)
+        .and_then(|edge| edge["kind"].as_str())
+}
+
+#[test]
+fn analyze_graph_class_granularity_links_python_classes() {
+    let json = class_graph("tests/fixtures/python/class_graph", "python");
+
+    assert_eq!(
+        edge_kind(&json, "shop.models::Order", "shop.base::Timestamped"),
+        Some("inherits")
+    );
+    assert_eq!(
+        edge_kind(&json, "shop.models::Invoice", "shop.models::Order"),
+        Some("references")
+    );
+    // A cycle between two classes of the same file.
+    assert_eq!(
+        edge_kind(&json, "shop.models::Order", "shop.models::Customer"),
+        Some("references")
+    );
+    assert_eq!(
+        edge_kind(&json, "shop.models::Customer", "shop.models::Order"),
+        Some("references")
+    );
+
+    let nodes = json["nodes"].as_array().unwrap();
+    assert!(nodes.iter().all(|node| node["kind"] == "class"));
+    let customer = nodes
+        .iter()
+        .find(|node| node["name"] == "shop.models::Customer")
+        .unwrap();
+    assert_eq!(customer["span"], serde_json::json!([9, 15]));
+}
+
+#[test]
+fn analyze_graph_class_granularity_links_ruby_superclasses_and_mixins() {
+    let json = class_graph("tests/fixtures/ruby/rails_app", "ruby");
+
+    assert_eq!(
+        edge_kind(
+            &json,
+            "app.models.user::User",
+            "app.models.application_record::ApplicationRecord"
+        ),
+        Some("inherits")
+    );
+    assert_eq!(
+        edge_kind(
+            &json,
+            "app.models.user::User",
+            "app.models.concerns.trackable::Trackable"
+        ),
+        Some("mixin")
+    );
+    // `Customer` inside `module Billing` is `Billing::Customer`.
+    assert_eq!(
+        edge_kind(
+            &json,
+            "engines.billing.app.models.billing.invoice::Billing::Invoice",
+            "engines.billing.app.models.billing.customer::Billing::Customer"
+        ),
+        Some("references")
+    );
+    assert_eq!(
+        edge_kind(
+            &json,
+            "engines.billing.app.models.billing.invoice::Billing::Invoice",
+            "app.models.customer::Customer"
+        ),
+        None
+    );
+}
@@ -0,0 +1,128 @@
+use assert_cmd::Command;
+use std::path::Path;
+
+const FIXTURE: &str = "tests/fixtures/cpp/native";
+
+fn run_json(args: &[&str]) -> serde_json::Value {
+    let output = Command::cargo_bin("untangle")
+        .unwrap()
+        .args(args)
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+
+    serde_json::from_slice(&output).unwrap()
+}
+
+fn edges(json: &serde_json::Value) -> Vec<(String, String)> {
+    json["edges"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|edge| {
+            (
+                edge["from"].as_str().unwrap().to_string(),
+                edge["to"].as_str().unwrap().to_string(),
+            )
+        })
+        .collect()
+}
+
+fn has_edge(edges: &[(String, String)], from: &str, to: &str) -> bool {
+    edges.iter().any(|(f, t)| f == from && t == to)
+}
+
+fn write(root: &Path, relative: &str, content: &str) {
+    let path = root.join(relative);
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    std::fs::write(path, content).unwrap();
+}
+
+#[test]
+fn analyze_graph_cpp_resolves_includes_from_compile_commands() {
+    let json = run_json(&["analyze", "graph", FIXTURE, "--format", "json", "--quiet"]);
+
+    let edges = edges(&json);
+    // `-I../include` from the compilation database
+    assert!(has_edge(&edges, "src.app.main.cpp", "include.net.socket.h"));
+    assert!(has_edge(
+        &edges,
+        "src.net.socket.cc",
+        "include.net.socket.h"
+    ));
+    // headers fall back to the union of the database's include directories
+    assert!(has_edge(
+        &edges,
+        "include.net.socket.h",
+        "include.net.buffer.h"
+    ));
+    // quoted includes search the including file's directory first
+    assert!(has_edge(&edges, "src.app.main.cpp", "src.app.config.h"));
+    assert!(has_edge(&edges, "src.net.socket.cc", "src.net.log.h"));
+    // system headers and `<...>` includes stay external; *_test.cc is skipped
+    assert_eq!(edges.len(), 5);
+}
+
+#[test]
+fn analyze_graph_cpp_include_tests_adds_test_sources() {
+    let json = run_json(&[
+        "analyze",
+        "graph",
+        FIXTURE,
+        "--format",
+        "json",
+        "--include-tests",
+        "--quiet",
+    ]);
+
+    assert!(has_edge(
+        &edges(&json),
+        "tests.socket_test.cc",
+        "include.net.socket.h"
+    ));
+}
+
+#[test]
+fn analyze_graph_cpp_uses_configured_include_paths() {
+    let tmp = tempfile::tempdir().unwrap();
+    write(
+        tmp.path(),
+        ".untangle.toml",
+        r#"
+[cpp]
+include_paths = ["include"]
+angle_includes = true
+"#,
+    );
+    write(tmp.path(), "include/core/types.h", "#pragma once\n");
+    write(
+        tmp.path(),
+        "include/core/io.h",
+        "#pragma once\n#include <string>\n#include <core/types.h>\n",
+    );
+    write(
+        tmp.path(),
+        "src/main.c",
+        "#include <stdio.h>\n#include \"core/io.h\"\nint main(void) { return 0; }\n",
+    );
+
+    let json = run_json(&[
+        "analyze",
+        "graph",
+        tmp.path().to_str().unwrap(),
+        "--format",
+        "json",
+        "--quiet",
+    ]);
+
+    let edges = edges(&json);
+    assert!(has_edge(&edges, "src.main.c", "include.core.io.h"));
+    assert!(has_edge(
+        &edges,
+        "include.core.io.h",
+        "include.core.types.h"
+    ));
+    assert_eq!(edges.len(), 2);
+}
@@ -0,0 +1,100 @@
+use assert_cmd::Command;
+
+const FIXTURE: &str = "tests/fixtures/csharp/solution";
+
+fn run_json(args: &[&str]) -> serde_json::Value {
+    let output = Command::cargo_bin("untangle")
+        .unwrap()
+        .args(args)
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+
+    serde_json::from_slice(&output).unwrap()
+}
+
+fn edges(json: &serde_json::Value) -> Vec<(String, String)> {
+    json["edges"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|edge| {
+            (
+                edge["from"].as_str().unwrap().to_string(),
+                edge["to"].as_str().unwrap().to_string(),
+            )
+        })
+        .collect()
+}
+
+fn has_edge(edges: &[(String, String)], from: &str, to: &str) -> bool {
+    edges.iter().any(|(f, t)| f == from && t == to)
+}
+
+#[test]
+fn analyze_graph_csharp_resolves_usings_to_namespaces() {
+    let json = run_json(&["analyze", "graph", FIXTURE, "--format", "json", "--quiet"]);
+
+    let mut nodes: Vec<&str> = json["nodes"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|node| node["name"].as_str().unwrap())
+        .collect();
+    nodes.sort();
+    // obj/ output and the test project are skipped by default
+    assert_eq!(
+        nodes,
+        vec![
+            "Acme.Core",
+            "Acme.Core.Models",
+            "Acme.Web",
+            "Acme.Web.Controllers"
+        ]
+    );
+
+    let edges = edges(&json);
+    assert_eq!(edges.len(), 4);
+    assert!(has_edge(&edges, "Acme.Web.Controllers", "Acme.Core"));
+    // alias using resolves to the namespace declaring the aliased type
+    assert!(has_edge(&edges, "Acme.Web.Controllers", "Acme.Core.Models"));
+    assert!(has_edge(&edges, "Acme.Web", "Acme.Web.Controllers"));
+}
+
+#[test]
+fn analyze_architecture_csharp_level_one_maps_to_assemblies() {
+    let json = run_json(&[
+        "analyze",
+        "architecture",
+        FIXTURE,
+        "--format",
+        "json",
+        "--quiet",
+    ]);
+
+    let nodes: Vec<&str> = json["nodes"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|node| node["id"].as_str().unwrap())
+        .collect();
+    assert_eq!(nodes, vec!["Acme.Core", "Acme.Web"]);
+    assert!(has_edge(&edges(&json), "Acme.Web", "Acme.Core"));
+}
+
+#[test]
+fn analyze_graph_csharp_include_tests_adds_test_assembly() {
+    let json = run_json(&[
+        "analyze",
+        "graph",
+        FIXTURE,
+        "--format",
+        "json",
+        "--include-tests",
+        "--quiet",
+    ]);
+
+    assert!(has_edge(&edges(&json), "Acme.Core.Tests", "Acme.Core"));
+}
@@ -0,0 +1,179 @@
+use assert_cmd::Command;
+use predicates::prelude::*;
+use std::path::{Path, PathBuf};
+
+#[test]
+fn analyze_rejects_undeclared_custom_language() {
+    let tmp = tempfile::tempdir().unwrap();
+    std::fs::write(tmp.path().join("main.dsl"), "import lib\n").unwrap();
+
+    Command::cargo_bin("untangle")
+        .unwrap()
+        .args(["analyze", "graph", "--lang", "dsl", "--quiet"])
+        .arg(tmp.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("unsupported language: dsl"));
+}
+
+#[test]
+fn analyze_reports_custom_frontend_that_cannot_load() {
+    let tmp = tempfile::tempdir().unwrap();
+    std::fs::write(
+        tmp.path().join(".untangle.toml"),
+        r#"
+[frontends.dsl]
+extensions = ["dsl"]
+grammar = "grammars/libtree-sitter-dsl.so"
+query = "grammars/imports.scm"
+"#,
+    )
+    .unwrap();
+    std::fs::create_dir_all(tmp.path().join("grammars")).unwrap();
+    std::fs::write(
+        tmp.path().join("grammars/imports.scm"),
+        "(import_statement) @import\n",
+    )
+    .unwrap();
+    std::fs::write(tmp.path().join("main.dsl"), "import lib\n").unwrap();
+
+    // Auto-detection picks up `.dsl` files once the frontend is declared.
+    Command::cargo_bin("untangle")
+        .unwrap()
+        .args(["analyze", "graph", "--allow-custom-grammars", "--quiet"])
+        .arg(tmp.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "frontend `dsl`: cannot load grammar",
+        ))
+        .stderr(predicate::str::contains("libtree-sitter-dsl.so"));
+}
+
+/// Source directory of the tree-sitter-go crate untangle itself builds on.
+#[cfg(unix)]
+fn tree_sitter_go_source() -> PathBuf {
+    let output = std::process::Command::new(env!("CARGO"))
+        .args(["metadata", "--offline", "--format-version", "1"])
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "cargo metadata failed");
+    let metadata: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let manifest = metadata["packages"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|package| package["name"] == "tree-sitter-go")
+        .and_then(|package| package["manifest_path"].as_str())
+        .unwrap();
+    Path::new(manifest).parent().unwrap().join("src")
+}
+
+/// Project whose `.golite` files are parsed by the Go grammar, compiled into
+/// a standalone library the way a user would build one for their language.
+#[cfg(unix)]
+fn golite_project() -> tempfile::TempDir {
+    let tmp = tempfile::tempdir().unwrap();
+    let grammars = tmp.path().join("grammars");
+    std::fs::create_dir_all(&grammars).unwrap();
+
+    let source = tree_sitter_go_source();
+    let status = std::process::Command::new("cc")
+        .args(["-shared", "-fPIC", "-O0", "-I"])
+        .arg(&source)
+        .arg(source.join("parser.c"))
+        .arg("-o")
+        .arg(grammars.join("libtree-sitter-golite.so"))
+        .status()
+        .unwrap();
+    assert!(status.success(), "cannot compile the Go grammar");
+
+    std::fs::write(
+        grammars.join("imports.scm"),
+        "(import_spec path: (interpreted_string_literal) @import)\n",
+    )
+    .unwrap();
+    std::fs::write(
+        tmp.path().join(".untangle.toml"),
+        r#"
+[frontends.golite]
+extensions = ["golite"]
+grammar = "grammars/libtree-sitter-golite.so"
+symbol = "tree_sitter_go"
+query = "grammars/imports.scm"
+resolve = ["{import}.golite"]
+"#,
+    )
+    .unwrap();
+    std::fs::write(
+        tmp.path().join("main.golite"),
+        "package main\n\nimport \"lib/util\"\n",
+    )
+    .unwrap();
+    std::fs::create_dir_all(tmp.path().join("lib")).unwrap();
+    std::fs::write(tmp.path().join("lib/util.golite"), "package lib\n").unwrap();
+    tmp
+}
+
+#[cfg(unix)]
+#[test]
+fn analyze_graph_loads_custom_grammar_and_resolves_imports() {
+    let tmp = golite_project();
+
+    let output = Command::cargo_bin("untangle")
+        .unwrap()
+        .args([
+            "analyze",
+            "graph",
+            "--lang",
+            "golite",
+            "--allow-custom-grammars",
+            "--format",
+            "json",
+            "--quiet",
+        ])
+        .arg(tmp.path())
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let edges: Vec<(&str, &str)> = json["edges"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|edge| (edge["from"].as_str().unwrap(), edge["to"].as_str().unwrap()))
+        .collect();
+    assert_eq!(edges, vec![("main", "lib.util")]);
+}
+
+#[cfg(unix)]
+#[test]
+fn analyze_refuses_project_grammar_without_opt_in() {
+    let tmp = golite_project();
+
+    // The project config alone cannot allow its own grammar library.
+    std::fs::write(
+        tmp.path().join(".untangle.toml"),
+        format!(
+            "[defaults]\nallow_custom_grammars = true\n{}",
+            std::fs::read_to_string(tmp.path().join(".untangle.toml")).unwrap()
+        ),
+    )
+    .unwrap();
+
+    Command::cargo_bin("untangle")
+        .unwrap()
+        .args(["analyze", "graph", "--lang", "golite", "--quiet"])
+        .arg(tmp.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "frontend `golite`: not loading grammar",
+        ));
+}
@@ -221,3 +221,68 @@ def handle():
         "db"
     );
 }
+
+#[test]
+fn diff_can_fail_on_blast_radius_increase() {
+    let src = fixture_path("python/simple_project");
+    let tmp = tempfile::tempdir().unwrap();
+    let dest = tmp.path().join("repo");
+    std::fs::create_dir_all(&dest).unwrap();
+    copy_dir_recursive(&src, &dest);
+
+    git(&dest, &["init"]);
+    git(&dest, &["config", "user.email", "test@example.com"]);
+    git(&dest, &["config", "user.name", "Test User"]);
+    git(&dest, &["add", "."]);
+    git(&dest, &["commit", "-m", "base"]);
+
+    std::fs::write(
+        dest.join("src/utils/logging.py"),
+        r#"from src.db import connection
+
+def info(msg):
+    print(msg, connection.query())
+"#,
+    )
+    .unwrap();
+    git(&dest, &["add", "."]);
+    git(&dest, &["commit", "-m", "head"]);
+
+    let output = Command::cargo_bin("untangle")
+        .unwrap()
+        .current_dir(&dest)
+        .args([
+            "diff",
+            "--base",
+            "HEAD~1",
+            "--head",
+            "HEAD",
+            "--lang",
+            "python",
+            "--format",
+            "json",
+            "--fail-on",
+            "blast-radius-increase",
+            "--quiet",
+        ])
+        .assert()
+        .failure()
+        .get_output()
+        .stdout
+        .clone();
+
+    let json: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    let report = &json["report"];
+    assert_eq!(report["verdict"], "fail");
+    assert_eq!(report["reasons"][0], "blast-radius-increase");
+    // connection is now reached from both handler and logging
+    assert_eq!(report["summary_delta"]["max_blast_radius_delta"], 1);
+    let increases = report["blast_radius_increases"].as_array().unwrap();
+    assert_eq!(increases.len(), 1);
+    assert_eq!(increases[0]["node"], "src.db.connection");
+    assert_eq!(increases[0]["blast_radius_before"], 1);
+    assert_eq!(increases[0]["blast_radius_after"], 2);
+    assert_eq!(increases[0]["delta"], 1);
+    // handler still sees 2 modules, logging 0 -> 1: 5/9 -> 6/9
+    assert_eq!(report["summary_delta"]["propagation_cost_delta"], 0.1111);
+}
@@ -0,0 +1,100 @@
+use assert_cmd::Command;
+
+fn untangle_json(args: &[&str]) -> serde_json::Value {
+    let output = Command::cargo_bin("untangle")
+        .unwrap()
+        .args(args)
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    serde_json::from_slice(&output).unwrap()
+}
+
+#[test]
+fn analyze_report_directory_granularity_reports_package_cycles() {
+    let json = untangle_json(&[
+        "analyze",
+        "report",
+        "tests/fixtures/python/circular",
+        "--lang",
+        "python",
+        "--granularity",
+        "directory",
+        "--format",
+        "json",
+        "--quiet",
+    ]);
+
+    assert_eq!(json["metadata"]["granularity"], "directory");
+    assert_eq!(json["metadata"]["node_count"], 3);
+    assert_eq!(json["summary"]["scc_count"], 1);
+    assert_eq!(json["summary"]["largest_scc_size"], 3);
+
+    let mut nodes: Vec<&str> = json["hotspots"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|hotspot| hotspot["node"].as_str().unwrap())
+        .collect();
+    nodes.sort();
+    assert_eq!(nodes, vec!["src.a", "src.b", "src.c"]);
+}
+
+#[test]
+fn analyze_report_directory_granularity_emits_sarif() {
+    let json = untangle_json(&[
+        "analyze",
+        "report",
+        "tests/fixtures/python/circular",
+        "--lang",
+        "python",
+        "--granularity",
+        "directory",
+        "--format",
+        "sarif",
+        "--quiet",
+    ]);
+
+    assert!(!json["runs"][0]["results"].as_array().unwrap().is_empty());
+}
+
+#[test]
+fn analyze_graph_directory_depth_collapses_to_top_level() {
+    let json = untangle_json(&[
+        "analyze",
+        "graph",
+        "tests/fixtures/python/simple_project",
+        "--lang",
+        "python",
+        "--granularity",
+        "directory:1",
+        "--format",
+        "json",
+        "--quiet",
+    ]);
+
+    let nodes = json["nodes"].as_array().unwrap();
+    assert_eq!(nodes.len(), 1);
+    assert_eq!(nodes[0]["name"], "src");
+    assert_eq!(nodes[0]["kind"], "directory");
+    assert!(json["edges"].as_array().unwrap().is_empty());
+}
+
+#[test]
+fn analyze_graph_rejects_zero_directory_depth() {
+    Command::cargo_bin("untangle")
+        .unwrap()
+        .args([
+            "analyze",
+            "graph",
+            "tests/fixtures/python/simple_project",
+            "--lang",
+            "python",
+            "--granularity",
+            "directory:0",
+        ])
+        .assert()
+        .failure();
+}
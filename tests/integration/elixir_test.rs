@@ -0,0 +1,91 @@
+use assert_cmd::Command;
+
+const FIXTURE: &str = "tests/fixtures/elixir/umbrella";
+
+fn run_json(args: &[&str]) -> serde_json::Value {
+    let output = Command::cargo_bin("untangle")
+        .unwrap()
+        .args(args)
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+
+    serde_json::from_slice(&output).unwrap()
+}
+
+fn edges(json: &serde_json::Value) -> Vec<(String, String)> {
+    json["edges"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|edge| {
+            (
+                edge["from"].as_str().unwrap().to_string(),
+                edge["to"].as_str().unwrap().to_string(),
+            )
+        })
+        .collect()
+}
+
+fn has_edge(edges: &[(String, String)], from: &str, to: &str) -> bool {
+    edges.iter().any(|(f, t)| f == from && t == to)
+}
+
+#[test]
+fn analyze_graph_elixir_maps_directives_to_umbrella_lib_files() {
+    let json = run_json(&["analyze", "graph", FIXTURE, "--format", "json", "--quiet"]);
+
+    let edges = edges(&json);
+    assert_eq!(edges.len(), 6);
+    assert!(has_edge(
+        &edges,
+        "apps.shop.lib.shop.accounts",
+        "apps.shop.lib.shop.repo"
+    ));
+    // `use ShopWeb, :controller`
+    assert!(has_edge(
+        &edges,
+        "apps.shop_web.lib.shop_web.controllers.user_controller",
+        "apps.shop_web.lib.shop_web"
+    ));
+    // multi-alias `alias Shop.{Accounts, Repo}` across umbrella apps
+    assert!(has_edge(
+        &edges,
+        "apps.shop_web.lib.shop_web.controllers.user_controller",
+        "apps.shop.lib.shop.accounts"
+    ));
+    assert!(has_edge(
+        &edges,
+        "apps.shop_web.lib.shop_web.controllers.user_controller",
+        "apps.shop.lib.shop.repo"
+    ));
+    // nested module `Shop.Accounts.User.Query` lives in user.ex
+    assert!(has_edge(
+        &edges,
+        "apps.shop_web.lib.shop_web.controllers.user_controller",
+        "apps.shop.lib.shop.accounts.user"
+    ));
+    // deps/ is never analyzed
+    assert!(!edges.iter().any(|(from, _)| from.starts_with("deps.")));
+}
+
+#[test]
+fn analyze_graph_elixir_include_tests_adds_exunit_files() {
+    let json = run_json(&[
+        "analyze",
+        "graph",
+        FIXTURE,
+        "--format",
+        "json",
+        "--include-tests",
+        "--quiet",
+    ]);
+
+    assert!(has_edge(
+        &edges(&json),
+        "apps.shop.test.accounts_test",
+        "apps.shop.lib.shop.accounts"
+    ));
+}
@@ -0,0 +1,170 @@
+use assert_cmd::Command;
+use std::path::{Path, PathBuf};
+
+fn fixture_path(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("fixtures")
+        .join(name)
+}
+
+fn copy_dir_recursive(src: &Path, dst: &Path) {
+    for entry in std::fs::read_dir(src).unwrap() {
+        let entry = entry.unwrap();
+        let path = entry.path();
+        let dest_path = dst.join(entry.file_name());
+        if path.is_dir() {
+            std::fs::create_dir_all(&dest_path).unwrap();
+            copy_dir_recursive(&path, &dest_path);
+        } else {
+            std::fs::copy(&path, &dest_path).unwrap();
+        }
+    }
+}
+
+fn untangle_json(args: &[&str]) -> serde_json::Value {
+    let output = Command::cargo_bin("untangle")
+        .unwrap()
+        .args(args)
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    serde_json::from_slice(&output).unwrap()
+}
+
+#[test]
+fn analyze_report_include_external_lists_packages_and_fanout() {
+    let json = untangle_json(&[
+        "analyze",
+        "report",
+        "tests/fixtures/python/external_deps",
+        "--lang",
+        "python",
+        "--include-external",
+        "--format",
+        "json",
+        "--quiet",
+    ]);
+
+    // Third-party packages stay out of the module graph itself.
+    assert_eq!(json["metadata"]["node_count"], 4);
+
+    let packages = json["external"]["packages"].as_array().unwrap();
+    assert_eq!(packages.len(), 2);
+    assert_eq!(packages[0]["package"], "sqlalchemy");
+    assert_eq!(packages[0]["fanin"], 2);
+    assert_eq!(
+        packages[0]["importers"],
+        serde_json::json!(["app.adapters.db", "app.domain.orders"])
+    );
+    assert_eq!(packages[1]["package"], "requests");
+
+    let modules = json["external"]["modules"].as_array().unwrap();
+    assert_eq!(modules.len(), 3);
+    assert!(modules
+        .iter()
+        .all(|module| module["packages"] != serde_json::json!(["os"])));
+}
+
+#[test]
+fn analyze_report_omits_external_section_by_default() {
+    let json = untangle_json(&[
+        "analyze",
+        "report",
+        "tests/fixtures/python/external_deps",
+        "--lang",
+        "python",
+        "--format",
+        "json",
+        "--quiet",
+    ]);
+
+    assert!(json.get("external").is_none());
+}
+
+#[test]
+fn analyze_graph_include_external_adds_package_nodes() {
+    let json = untangle_json(&[
+        "analyze",
+        "graph",
+        "tests/fixtures/python/external_deps",
+        "--lang",
+        "python",
+        "--include-external",
+        "--granularity",
+        "directory",
+        "--format",
+        "json",
+        "--quiet",
+    ]);
+
+    let mut externals: Vec<&str> = json["nodes"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter(|node| node["kind"] == "external")
+        .map(|node| node["name"].as_str().unwrap())
+        .collect();
+    externals.sort();
+    assert_eq!(externals, vec!["requests", "sqlalchemy"]);
+    assert!(json["edges"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .any(|edge| edge["from"] == "app.domain" && edge["to"] == "sqlalchemy"));
+}
+
+#[test]
+fn architecture_check_fails_on_forbidden_package() {
+    let src = fixture_path("python/external_deps");
+    let tmp = tempfile::tempdir().unwrap();
+    let dest = tmp.path().join("project");
+    std::fs::create_dir_all(&dest).unwrap();
+    copy_dir_recursive(&src, &dest);
+    std::fs::write(
+        dest.join(".untangle.toml"),
+        r#"
+[analyze.architecture]
+level = 1
+fail_on_violations = true
+
+[[analyze.architecture.forbidden_packages]]
+from = "domain"
+package = "sqlalchemy"
+"#,
+    )
+    .unwrap();
+
+    let output = Command::cargo_bin("untangle")
+        .unwrap()
+        .args([
+            "analyze",
+            "architecture-check",
+            dest.to_str().unwrap(),
+            "--lang",
+            "python",
+            "--format",
+            "json",
+            "--quiet",
+        ])
+        .assert()
+        .failure()
+        .get_output()
+        .stdout
+        .clone();
+
+    let json: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    assert_eq!(json["summary"]["verdict"], "fail");
+    assert_eq!(json["summary"]["violation_count"], 1);
+    assert_eq!(json["violations"][0]["from"], "domain");
+    assert_eq!(json["violations"][0]["to"], "sqlalchemy");
+    assert_eq!(json["violations"][0]["kind"], "forbidden_package");
+    assert_eq!(
+        json["violations"][0]["evidence"][0]["from_module"],
+        "app.domain.orders"
+    );
+    // External packages never show up as components.
+    assert_eq!(json["components"].as_array().unwrap().len(), 2);
+}
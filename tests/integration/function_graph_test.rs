@@ -0,0 +1,90 @@
+use assert_cmd::Command;
+
+const FIXTURE: &str = "tests/fixtures/python/call_graph";
+
+fn function_graph() -> serde_json::Value {
+    let output = Command::cargo_bin("untangle")
+        .unwrap()
+        .args([
+            "analyze",
+            "graph",
+            FIXTURE,
+            "--lang",
+            "python",
+            "--granularity",
+            "function",
+            "--format",
+            "json",
+            "--quiet",
+        ])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    serde_json::from_slice(&output).unwrap()
+}
+
+fn has_edge(json: &serde_json::Value, from: &str, to: &str) -> bool {
+    json["edges"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .any(|edge| edge["from"] == from && edge["to"] == to && edge["kind"] == "call")
+}
+
+#[test]
+fn analyze_graph_function_granularity_links_calls() {
+    let json = function_graph();
+
+    // Within a class, across a `from ... import` and through a module alias.
+    assert!(has_edge(
+        &json,
+        "shop.orders::OrderService.place",
+        "shop.orders::OrderService.validate"
+    ));
+    assert!(has_edge(
+        &json,
+        "shop.orders::OrderService.place",
+        "shop.inventory::reserve"
+    ));
+    assert!(has_edge(
+        &json,
+        "shop.orders::OrderService.place",
+        "shop.billing::charge"
+    ));
+    // Mutual recursion across modules.
+    assert!(has_edge(
+        &json,
+        "shop.orders::refund",
+        "shop.billing::reverse"
+    ));
+    assert!(has_edge(
+        &json,
+        "shop.billing::reverse",
+        "shop.orders::refund"
+    ));
+}
+
+#[test]
+fn analyze_graph_function_nodes_carry_spans() {
+    let json = function_graph();
+    let nodes = json["nodes"].as_array().unwrap();
+    assert!(nodes.iter().all(|node| node["kind"] == "function"));
+
+    let validate = nodes
+        .iter()
+        .find(|node| node["name"] == "shop.orders::OrderService.validate")
+        .unwrap();
+    assert_eq!(validate["path"], "shop/orders.py");
+    assert_eq!(validate["span"], serde_json::json!([11, 13]));
+}
+
+#[test]
+fn analyze_graph_rejects_unknown_granularity() {
+    Command::cargo_bin("untangle")
+        .unwrap()
+        .args(["analyze", "graph", FIXTURE, "--granularity", "statement"])
+        .assert()
+        .failure();
+}
@@ -0,0 +1,68 @@
+use assert_cmd::Command;
+
+const FIXTURE: &str = "tests/fixtures/go/build_tags";
+
+fn graph_edges(extra: &[&str]) -> Vec<(String, String)> {
+    let mut args = vec![
+        "analyze", "graph", FIXTURE, "--lang", "go", "--format", "json", "--quiet",
+    ];
+    args.extend_from_slice(extra);
+    let output = Command::cargo_bin("untangle")
+        .unwrap()
+        .args(args)
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let json: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    json["edges"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|edge| {
+            (
+                edge["from"].as_str().unwrap().to_string(),
+                edge["to"].as_str().unwrap().to_string(),
+            )
+        })
+        .collect()
+}
+
+fn has_edge(edges: &[(String, String)], from: &str, to: &str) -> bool {
+    edges.iter().any(|edge| edge.0 == from && edge.1 == to)
+}
+
+#[test]
+fn analyze_graph_models_go_external_test_packages() {
+    let edges = graph_edges(&["--include-tests"]);
+
+    // `package store_test` is a separate consumer of `store`.
+    assert!(has_edge(&edges, "store_test", "store"));
+    assert!(has_edge(&edges, "store_test", "testutil"));
+    // `package store` tests stay part of `store`.
+    assert!(has_edge(&edges, "store", "testutil"));
+    assert!(!has_edge(&edges, "store", "store"));
+
+    let edges = graph_edges(&[]);
+    assert!(!edges.iter().any(|edge| edge.0 == "store_test"));
+}
+
+#[test]
+fn analyze_graph_honours_go_build_tags() {
+    // No tags selected: every file counts.
+    let edges = graph_edges(&[]);
+    assert!(has_edge(&edges, "store", "platform.linux"));
+    assert!(has_edge(&edges, "store", "platform.windows"));
+    assert!(has_edge(&edges, "store", "platform.generic"));
+
+    let edges = graph_edges(&["--tags", "linux,amd64"]);
+    assert!(has_edge(&edges, "store", "platform.linux"));
+    assert!(!has_edge(&edges, "store", "platform.windows"));
+    assert!(!has_edge(&edges, "store", "platform.generic"));
+    assert!(has_edge(&edges, "store", "cache"));
+
+    let edges = graph_edges(&["--tags", "darwin"]);
+    assert!(has_edge(&edges, "store", "platform.generic"));
+    assert!(!has_edge(&edges, "store", "platform.linux"));
+}
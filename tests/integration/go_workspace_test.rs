@@ -0,0 +1,104 @@
+use assert_cmd::Command;
+use std::path::Path;
+use std::process::Command as ProcessCommand;
+
+fn git(dir: &Path, args: &[&str]) {
+    let status = ProcessCommand::new("git")
+        .current_dir(dir)
+        .args(args)
+        .status()
+        .unwrap();
+    assert!(status.success(), "git {:?} failed", args);
+}
+
+#[test]
+fn analyze_graph_resolves_go_work_and_replace_modules() {
+    let output = Command::cargo_bin("untangle")
+        .unwrap()
+        .args([
+            "analyze",
+            "graph",
+            "tests/fixtures/go/workspace",
+            "--lang",
+            "go",
+            "--format",
+            "json",
+            "--quiet",
+        ])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let json: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    let edge = |from: &str, to: &str| {
+        json["edges"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|edge| edge["from"] == from && edge["to"] == to)
+            .cloned()
+    };
+
+    // Own module, rooted at its directory in the monorepo.
+    assert!(edge("services.api", "services.api.internal.handlers").is_some());
+    // `go.work` member.
+    let auth = edge("services.api", "libs.auth.token").expect("go.work edge");
+    assert_eq!(auth["source_locations"][0]["file"], "services/api");
+    assert_eq!(auth["source_locations"][0]["line"], 7);
+    // Local `replace` directive.
+    assert!(edge("services.api", "libs.billing.invoice").is_some());
+    // Neither in the workspace nor replaced: stays external.
+    assert!(edge("services.api", "libs.legacy.audit").is_none());
+}
+
+#[test]
+fn diff_reports_edges_added_by_replace_directive() {
+    let tmp = tempfile::tempdir().unwrap();
+    let repo = tmp.path();
+    std::fs::create_dir_all(repo.join("api")).unwrap();
+    std::fs::create_dir_all(repo.join("billing/invoice")).unwrap();
+    std::fs::write(repo.join("api/go.mod"), "module example.com/api\n").unwrap();
+    std::fs::write(
+        repo.join("api/main.go"),
+        "package main\n\nimport \"example.com/billing/invoice\"\n\nfunc main() { invoice.Total() }\n",
+    )
+    .unwrap();
+    std::fs::write(repo.join("billing/go.mod"), "module example.com/billing\n").unwrap();
+    std::fs::write(
+        repo.join("billing/invoice/invoice.go"),
+        "package invoice\n\nfunc Total() int { return 1 }\n",
+    )
+    .unwrap();
+    git(repo, &["init"]);
+    git(repo, &["config", "user.email", "test@example.com"]);
+    git(repo, &["config", "user.name", "Test User"]);
+    git(repo, &["add", "."]);
+    git(repo, &["commit", "-m", "base"]);
+
+    std::fs::write(
+        repo.join("api/go.mod"),
+        "module example.com/api\n\nreplace example.com/billing => ../billing\n",
+    )
+    .unwrap();
+    git(repo, &["add", "."]);
+    git(repo, &["commit", "-m", "head"]);
+
+    let output = Command::cargo_bin("untangle")
+        .unwrap()
+        .current_dir(repo)
+        .args([
+            "diff", "--base", "HEAD~1", "--head", "HEAD", "--lang", "go", "--format", "json",
+            "--quiet",
+        ])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let json: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    let new_edges = json["report"]["new_edges"].as_array().unwrap();
+    assert!(new_edges
+        .iter()
+        .any(|edge| edge["from"] == "api" && edge["to"] == "billing.invoice"));
+}
@@ -0,0 +1,98 @@
+use assert_cmd::Command;
+
+const FIXTURE: &str = "tests/fixtures/jvm/gradle";
+
+fn graph_json(args: &[&str]) -> serde_json::Value {
+    let output = Command::cargo_bin("untangle")
+        .unwrap()
+        .args(args)
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+
+    serde_json::from_slice(&output).unwrap()
+}
+
+fn edges(json: &serde_json::Value) -> Vec<(String, String)> {
+    json["edges"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|edge| {
+            (
+                edge["from"].as_str().unwrap().to_string(),
+                edge["to"].as_str().unwrap().to_string(),
+            )
+        })
+        .collect()
+}
+
+fn has_edge(edges: &[(String, String)], from: &str, to: &str) -> bool {
+    edges.iter().any(|(f, t)| f == from && t == to)
+}
+
+#[test]
+fn analyze_graph_jvm_groups_sources_by_package() {
+    let json = graph_json(&["analyze", "graph", FIXTURE, "--format", "json", "--quiet"]);
+
+    let nodes: Vec<&str> = json["nodes"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|node| node["name"].as_str().unwrap())
+        .collect();
+    assert_eq!(nodes.len(), 4);
+    assert!(nodes.contains(&"src.main.java.com.acme.core"));
+    assert!(nodes.contains(&"src.main.kotlin.com.acme.report"));
+
+    let edges = edges(&json);
+    assert_eq!(edges.len(), 5);
+    // class import and static member import both land on the package
+    assert!(has_edge(
+        &edges,
+        "src.main.java.com.acme.api",
+        "src.main.java.com.acme.core"
+    ));
+    // wildcard import
+    assert!(has_edge(
+        &edges,
+        "src.main.java.com.acme.api",
+        "src.main.java.com.acme.model"
+    ));
+    // Kotlin importing Java across source roots
+    assert!(has_edge(
+        &edges,
+        "src.main.kotlin.com.acme.report",
+        "src.main.java.com.acme.api"
+    ));
+}
+
+#[test]
+fn analyze_graph_jvm_include_tests_adds_test_packages() {
+    let json = graph_json(&[
+        "analyze",
+        "graph",
+        FIXTURE,
+        "--format",
+        "json",
+        "--include-tests",
+        "--quiet",
+    ]);
+
+    assert!(has_edge(
+        &edges(&json),
+        "src.test.java.com.acme.api",
+        "src.main.kotlin.com.acme.report"
+    ));
+}
+
+#[test]
+fn analyze_graph_lang_java_skips_kotlin_sources() {
+    let json = graph_json(&[
+        "analyze", "graph", FIXTURE, "--lang", "java", "--format", "json", "--quiet",
+    ]);
+
+    assert_eq!(json["nodes"].as_array().unwrap().len(), 3);
+}
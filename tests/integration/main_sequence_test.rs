@@ -0,0 +1,236 @@
+use assert_cmd::Command;
+use std::path::{Path, PathBuf};
+
+fn fixture_path(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("fixtures")
+        .join(name)
+}
+
+fn copy_dir_recursive(src: &Path, dst: &Path) {
+    for entry in std::fs::read_dir(src).unwrap() {
+        let entry = entry.unwrap();
+        let path = entry.path();
+        let dest_path = dst.join(entry.file_name());
+        if path.is_dir() {
+            std::fs::create_dir_all(&dest_path).unwrap();
+            copy_dir_recursive(&path, &dest_path);
+        } else {
+            std::fs::copy(&path, &dest_path).unwrap();
+        }
+    }
+}
+
+fn untangle_json(args: &[&str]) -> serde_json::Value {
+    let output = Command::cargo_bin("untangle")
+        .unwrap()
+        .args(args)
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    serde_json::from_slice(&output).unwrap()
+}
+
+/// The main_sequence fixture, copied with `config` as its `.untangle.toml`.
+fn fixture_with_config(config: &str) -> tempfile::TempDir {
+    let tmp = tempfile::tempdir().unwrap();
+    let dest = tmp.path().join("project");
+    std::fs::create_dir_all(&dest).unwrap();
+    copy_dir_recursive(&fixture_path("python/main_sequence"), &dest);
+    std::fs::write(dest.join(".untangle.toml"), config).unwrap();
+    tmp
+}
+
+fn hotspot<'a>(json: &'a serde_json::Value, node: &str) -> &'a serde_json::Value {
+    json["hotspots"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|hotspot| hotspot["node"] == node)
+        .unwrap()
+}
+
+fn component<'a>(json: &'a serde_json::Value, id: &str) -> &'a serde_json::Value {
+    json["components"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|component| component["id"] == id)
+        .unwrap()
+}
+
+#[test]
+fn architecture_check_reports_abstractness_and_distance() {
+    let json = untangle_json(&[
+        "analyze",
+        "architecture-check",
+        "tests/fixtures/python/main_sequence",
+        "--lang",
+        "python",
+        "--format",
+        "json",
+        "--quiet",
+    ]);
+
+    let core = component(&json, "core");
+    assert_eq!(core["instability"], 0.0);
+    assert_eq!(core["abstractness"], 0.0);
+    assert_eq!(core["distance"], 1.0);
+
+    let ports = component(&json, "ports");
+    assert_eq!(ports["instability"], 1.0);
+    assert_eq!(ports["abstractness"], 1.0);
+    assert_eq!(ports["distance"], 1.0);
+
+    let api = component(&json, "api");
+    assert_eq!(api["distance"], 0.0);
+}
+
+#[test]
+fn analyze_report_flags_zones_of_pain_and_uselessness() {
+    let tmp = fixture_with_config("[rules.main_sequence]\nenabled = true\n");
+    let json = untangle_json(&[
+        "analyze",
+        "report",
+        tmp.path().join("project").to_str().unwrap(),
+        "--lang",
+        "python",
+        "--format",
+        "json",
+        "--quiet",
+    ]);
+
+    let zones: Vec<(&str, &str)> = json["insights"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter(|insight| {
+            insight["category"]
+                .as_str()
+                .unwrap()
+                .starts_with("zone_of_")
+        })
+        .map(|insight| {
+            (
+                insight["category"].as_str().unwrap(),
+                insight["module"].as_str().unwrap(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        zones,
+        vec![("zone_of_pain", "core"), ("zone_of_uselessness", "ports")]
+    );
+    assert_eq!(json["insights"][0]["metrics"]["distance"], 1.0);
+}
+
+#[test]
+fn main_sequence_rule_can_be_disabled() {
+    let tmp = tempfile::tempdir().unwrap();
+    let dest = tmp.path().join("project");
+    std::fs::create_dir_all(&dest).unwrap();
+    copy_dir_recursive(&fixture_path("python/main_sequence"), &dest);
+    std::fs::write(
+        dest.join(".untangle.toml"),
+        "[rules.main_sequence]\nenabled = false\n",
+    )
+    .unwrap();
+
+    let json = untangle_json(&[
+        "analyze",
+        "report",
+        dest.to_str().unwrap(),
+        "--lang",
+        "python",
+        "--format",
+        "json",
+        "--quiet",
+    ]);
+
+    assert!(json["insights"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .all(|insight| !insight["category"]
+            .as_str()
+            .unwrap()
+            .starts_with("zone_of_")));
+}
+
+#[test]
+fn analyze_report_hotspots_carry_module_abstractness_and_distance() {
+    let tmp = fixture_with_config("[rules.main_sequence]\nenabled = true\n");
+    let json = untangle_json(&[
+        "analyze",
+        "report",
+        tmp.path().join("project").to_str().unwrap(),
+        "--lang",
+        "python",
+        "--format",
+        "json",
+        "--quiet",
+    ]);
+
+    let models = hotspot(&json, "shop.core.models");
+    assert_eq!(models["abstract_types"], 0);
+    assert_eq!(models["concrete_types"], 2);
+    assert_eq!(models["abstractness"], 0.0);
+    assert_eq!(models["distance"], 1.0);
+
+    let repository = hotspot(&json, "shop.ports.repository");
+    assert_eq!(repository["abstract_types"], 1);
+    assert_eq!(repository["concrete_types"], 0);
+    assert_eq!(repository["abstractness"], 1.0);
+    assert_eq!(repository["distance"], 1.0);
+
+    let output = Command::cargo_bin("untangle")
+        .unwrap()
+        .args([
+            "analyze",
+            "report",
+            tmp.path().join("project").to_str().unwrap(),
+            "--lang",
+            "python",
+            "--format",
+            "text",
+            "--quiet",
+        ])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let text = String::from_utf8(output).unwrap();
+    assert!(text.contains("Cut     A     D"));
+}
+
+#[test]
+fn main_sequence_rule_is_off_by_default() {
+    let json = untangle_json(&[
+        "analyze",
+        "report",
+        "tests/fixtures/python/main_sequence",
+        "--lang",
+        "python",
+        "--format",
+        "json",
+        "--quiet",
+    ]);
+
+    assert!(json["insights"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .all(|insight| !insight["category"]
+            .as_str()
+            .unwrap()
+            .starts_with("zone_of_")));
+    assert!(json["hotspots"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .all(|hotspot| hotspot.get("abstractness").is_none()));
+}
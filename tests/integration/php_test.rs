@@ -0,0 +1,102 @@
+use assert_cmd::Command;
+
+const FIXTURE: &str = "tests/fixtures/php/composer";
+
+fn run_json(args: &[&str]) -> serde_json::Value {
+    let output = Command::cargo_bin("untangle")
+        .unwrap()
+        .args(args)
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+
+    serde_json::from_slice(&output).unwrap()
+}
+
+fn edges(json: &serde_json::Value) -> Vec<(String, String)> {
+    json["edges"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|edge| {
+            (
+                edge["from"].as_str().unwrap().to_string(),
+                edge["to"].as_str().unwrap().to_string(),
+            )
+        })
+        .collect()
+}
+
+fn has_edge(edges: &[(String, String)], from: &str, to: &str) -> bool {
+    edges.iter().any(|(f, t)| f == from && t == to)
+}
+
+#[test]
+fn analyze_graph_php_resolves_psr4_classes_and_includes() {
+    let json = run_json(&["analyze", "graph", FIXTURE, "--format", "json", "--quiet"]);
+
+    let mut nodes: Vec<&str> = json["nodes"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|node| node["name"].as_str().unwrap())
+        .collect();
+    nodes.sort();
+    // vendor/ and PHPUnit tests are skipped by default
+    assert_eq!(
+        nodes,
+        vec![
+            "bootstrap",
+            "src.Http.BaseController",
+            "src.Http.UserController",
+            "src.Models.User",
+            "src.Services.Mailer",
+            "src.helpers",
+        ]
+    );
+
+    let edges = edges(&json);
+    assert_eq!(edges.len(), 5);
+    // `use` statements through the PSR-4 map
+    assert!(has_edge(
+        &edges,
+        "src.Http.UserController",
+        "src.Models.User"
+    ));
+    assert!(has_edge(&edges, "src.Services.Mailer", "src.Models.User"));
+    // same-namespace class without a `use`
+    assert!(has_edge(
+        &edges,
+        "src.Http.UserController",
+        "src.Http.BaseController"
+    ));
+    // name qualified through a namespace alias
+    assert!(has_edge(
+        &edges,
+        "src.Http.UserController",
+        "src.Services.Mailer"
+    ));
+    // require_once __DIR__ . '/...'
+    assert!(has_edge(&edges, "bootstrap", "src.helpers"));
+}
+
+#[test]
+fn analyze_graph_php_include_tests_uses_autoload_dev() {
+    let json = run_json(&[
+        "analyze",
+        "graph",
+        FIXTURE,
+        "--format",
+        "json",
+        "--include-tests",
+        "--quiet",
+    ]);
+
+    assert!(has_edge(
+        &edges(&json),
+        "tests.Models.UserTest",
+        "src.Models.User"
+    ));
+}
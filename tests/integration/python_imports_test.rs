@@ -0,0 +1,229 @@
+use assert_cmd::Command;
+use std::path::Path;
+use std::process::Command as ProcessCommand;
+
+const FIXTURE: &str = "tests/fixtures/python/type_checking";
+
+fn run_json(args: &[&str]) -> serde_json::Value {
+    let output = Command::cargo_bin("untangle")
+        .unwrap()
+        .args(args)
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    serde_json::from_slice(&output).unwrap()
+}
+
+fn git(dir: &Path, args: &[&str]) {
+    let status = ProcessCommand::new("git")
+        .current_dir(dir)
+        .args(args)
+        .status()
+        .unwrap();
+    assert!(status.success(), "git {:?} failed", args);
+}
+
+#[test]
+fn analyze_graph_classifies_python_import_contexts() {
+    let json = run_json(&[
+        "analyze", "graph", FIXTURE, "--lang", "python", "--format", "json", "--quiet",
+    ]);
+    let edges = json["edges"].as_array().unwrap();
+    let context = |from: &str, to: &str| {
+        edges
+            .iter()
+            .find(|edge| edge["from"] == from && edge["to"] == to)
+            .unwrap_or_else(|| panic!("missing edge {from} -> {to}"))
+            .get("context")
+            .cloned()
+    };
+
+    assert_eq!(
+        context("app.models", "app.services"),
+        Some("type_checking".into())
+    );
+    assert_eq!(
+        context("app.cli", "app.services"),
+        Some("function_local".into())
+    );
+    assert_eq!(
+        context("app.services", "app.speedups"),
+        Some("guarded".into())
+    );
+    assert_eq!(context("app.services", "app.models"), None);
+}
+
+#[test]
+fn analyze_report_can_ignore_type_only_cycles() {
+    let args = [
+        "analyze", "report", FIXTURE, "--lang", "python", "--format", "json", "--quiet",
+    ];
+    let json = run_json(&args);
+    assert_eq!(json["summary"]["scc_count"], 1);
+    assert_eq!(json["sccs"].as_array().unwrap().len(), 1);
+
+    let mut ignoring = args.to_vec();
+    ignoring.push("--ignore-type-only");
+    let json = run_json(&ignoring);
+    assert_eq!(json["summary"]["scc_count"], 0);
+    assert!(json["sccs"].as_array().unwrap().is_empty());
+}
+
+#[test]
+fn diff_new_scc_can_ignore_type_only_cycles() {
+    let tmp = tempfile::tempdir().unwrap();
+    let repo = tmp.path();
+    std::fs::create_dir_all(repo.join("app")).unwrap();
+    std::fs::write(repo.join("app/__init__.py"), "").unwrap();
+    std::fs::write(repo.join("app/models.py"), "class Invoice:\n    pass\n").unwrap();
+    std::fs::write(
+        repo.join("app/services.py"),
+        "from app.models import Invoice\n",
+    )
+    .unwrap();
+    git(repo, &["init"]);
+    git(repo, &["config", "user.email", "test@example.com"]);
+    git(repo, &["config", "user.name", "Test User"]);
+    git(repo, &["add", "."]);
+    git(repo, &["commit", "-m", "base"]);
+
+    std::fs::write(
+        repo.join("app/models.py"),
+        "from typing import TYPE_CHECKING\n\nif TYPE_CHECKING:\n    from app.services import Billing\n",
+    )
+    .unwrap();
+    git(repo, &["add", "."]);
+    git(repo, &["commit", "-m", "head"]);
+
+    let diff = |extra: &[&str]| {
+        let mut args = vec![
+            "diff",
+            "--base",
+            "HEAD~1",
+            "--head",
+            "HEAD",
+            "--lang",
+            "python",
+            "--format",
+            "json",
+            "--fail-on",
+            "new-scc",
+            "--quiet",
+        ];
+        args.extend_from_slice(extra);
+        Command::cargo_bin("untangle")
+            .unwrap()
+            .current_dir(repo)
+            .args(args)
+            .assert()
+    };
+
+    diff(&[]).failure();
+    diff(&["--ignore-type-only"]).success();
+}
+
+#[test]
+fn analyze_graph_resolves_python_source_roots_and_namespace_packages() {
+    let json = run_json(&[
+        "analyze",
+        "graph",
+        "tests/fixtures/python/src_layout",
+        "--lang",
+        "python",
+        "--format",
+        "json",
+        "--quiet",
+    ]);
+    let edges: Vec<(String, String)> = json["edges"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|edge| {
+            (
+                edge["from"].as_str().unwrap().to_string(),
+                edge["to"].as_str().unwrap().to_string(),
+            )
+        })
+        .collect();
+    let has_edge = |from: &str, to: &str| edges.iter().any(|edge| edge.0 == from && edge.1 == to);
+
+    // `src/` layout declared through `[tool.setuptools] package-dir`.
+    assert!(has_edge("src.shop.cart", "src.shop.pricing"));
+    // `[python] source_roots = ["services/*/src"]`, across PEP 420 namespace packages.
+    assert!(has_edge(
+        "src.shop.cart",
+        "services.billing.src.acme.billing.invoice"
+    ));
+    assert!(has_edge(
+        "services.billing.src.acme.billing.invoice",
+        "services.auth.src.acme.auth.tokens"
+    ));
+}
+
+#[test]
+fn analyze_graph_follows_python_init_reexports() {
+    let json = run_json(&[
+        "analyze",
+        "graph",
+        "tests/fixtures/python/reexports",
+        "--lang",
+        "python",
+        "--format",
+        "json",
+        "--quiet",
+    ]);
+    let targets: Vec<&str> = json["edges"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter(|edge| edge["from"] == "app")
+        .map(|edge| edge["to"].as_str().unwrap())
+        .collect();
+
+    // `from shop import total, inventory` skips `shop/__init__.py`.
+    assert!(targets.contains(&"shop.pricing"));
+    assert!(targets.contains(&"shop.inventory"));
+    assert!(!targets.contains(&"shop.__init__"));
+}
+
+#[test]
+fn from_import_statements_count_once() {
+    let tmp = tempfile::tempdir().unwrap();
+    let root = tmp.path();
+    std::fs::create_dir_all(root.join("pkg")).unwrap();
+    std::fs::write(
+        root.join("pkg/__init__.py"),
+        "def a(): pass\ndef b(): pass\n",
+    )
+    .unwrap();
+    std::fs::write(
+        root.join("app.py"),
+        "from pkg import a, b\nfrom pkg.missing import x, y, z\n",
+    )
+    .unwrap();
+    let root = root.to_str().unwrap();
+
+    let graph = run_json(&[
+        "analyze", "graph", root, "--lang", "python", "--format", "json", "--quiet",
+    ]);
+    let edges = graph["edges"].as_array().unwrap();
+    assert_eq!(edges.len(), 1);
+    assert_eq!(edges[0]["source_locations"].as_array().unwrap().len(), 1);
+
+    let report = run_json(&[
+        "analyze", "report", root, "--lang", "python", "--format", "json", "--quiet",
+    ]);
+    assert_eq!(report["metadata"]["unresolved_imports"], 1);
+    let hotspot = report["hotspots"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|hotspot| hotspot["node"] == "app")
+        .unwrap();
+    let locations = hotspot["fanout_edges"][0]["source_locations"]
+        .as_array()
+        .unwrap();
+    assert_eq!(locations.len(), 1);
+}
@@ -32,6 +32,38 @@ fn quality_functions_rust_json() {
     assert!(json["report"]["results"].to_string().contains("\"simple\""));
 }
 
+#[test]
+fn quality_functions_php_json() {
+    let output = Command::cargo_bin("untangle")
+        .unwrap()
+        .args([
+            "quality",
+            "functions",
+            "tests/fixtures/quality",
+            "--lang",
+            "php",
+            "--metric",
+            "crap",
+            "--coverage",
+            "tests/fixtures/quality/lcov.info",
+            "--format",
+            "json",
+            "--quiet",
+        ])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+
+    let json: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    let results = json["report"]["results"].as_array().unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0]["function"], "absolute");
+    assert_eq!(results[0]["cyclomatic_complexity"], 2);
+    assert_eq!(results[0]["coverage_pct"], 75.0);
+}
+
 #[test]
 fn quality_functions_min_cc_filters() {
     let output = Command::cargo_bin("untangle")
@@ -0,0 +1,106 @@
+use assert_cmd::Command;
+
+const RAILS_FIXTURE: &str = "tests/fixtures/ruby/rails_app";
+
+#[test]
+fn analyze_graph_resolves_rails_autoload_paths() {
+    let output = Command::cargo_bin("untangle")
+        .unwrap()
+        .args([
+            "analyze",
+            "graph",
+            RAILS_FIXTURE,
+            "--format",
+            "json",
+            "--quiet",
+        ])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let json: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    let edges = json["edges"].as_array().unwrap();
+    let has_edge = |from: &str, to: &str| {
+        edges
+            .iter()
+            .any(|edge| edge["from"] == from && edge["to"] == to)
+    };
+
+    // `app/*` directories and the concerns directory beneath `app/models`.
+    assert!(has_edge(
+        "app.controllers.users_controller",
+        "app.models.user"
+    ));
+    assert!(has_edge("app.models.user", "app.models.concerns.trackable"));
+    // `config.autoload_paths` and `collapse` from `config/application.rb`.
+    assert!(has_edge("app.models.user", "extras.audit_log"));
+    assert!(has_edge(
+        "app.controllers.users_controller",
+        "app.components.card.card_component"
+    ));
+    // Engine under `engines/`.
+    assert!(has_edge(
+        "app.controllers.users_controller",
+        "engines.billing.app.models.billing.invoice"
+    ));
+}
+
+#[test]
+fn analyze_graph_resolves_ruby_constants_lexically() {
+    let output = Command::cargo_bin("untangle")
+        .unwrap()
+        .args([
+            "analyze",
+            "graph",
+            RAILS_FIXTURE,
+            "--format",
+            "json",
+            "--quiet",
+        ])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let json: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    let targets = |from: &str| -> Vec<String> {
+        json["edges"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter(|edge| edge["from"] == from)
+            .map(|edge| edge["to"].as_str().unwrap().to_string())
+            .collect()
+    };
+
+    // `Customer` inside `module Billing` is `Billing::Customer`, not the
+    // top-level `Customer` model.
+    assert_eq!(
+        targets("engines.billing.app.models.billing.invoice"),
+        vec!["engines.billing.app.models.billing.customer"]
+    );
+    // `class Billing::Refund` does not open `Billing`, so `Ledger` is left
+    // unresolved instead of guessing `Billing::Ledger`.
+    assert!(targets("engines.billing.app.models.billing.refund").is_empty());
+}
+
+#[test]
+fn config_show_reports_inferred_rails_load_paths() {
+    let output = Command::cargo_bin("untangle")
+        .unwrap()
+        .args(["config", "show", RAILS_FIXTURE])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let stdout = String::from_utf8(output).unwrap();
+
+    assert!(stdout.contains("ruby.detect_rails: true <- default"));
+    assert!(stdout.contains("Inferred settings:"));
+    assert!(stdout.contains("ruby.load_path: app/models <- inferred from config/application.rb"));
+    assert!(stdout.contains(
+        "ruby.load_path: engines/billing/app/models <- inferred from engines/billing/lib/billing/engine.rb"
+    ));
+}
@@ -0,0 +1,88 @@
+use assert_cmd::Command;
+
+fn graph_edges(extra_args: &[&str]) -> Vec<serde_json::Value> {
+    let mut args = vec![
+        "analyze",
+        "graph",
+        "tests/fixtures/rust/cfg_crate",
+        "--lang",
+        "rust",
+        "--format",
+        "json",
+        "--quiet",
+    ];
+    args.extend_from_slice(extra_args);
+    let output = Command::cargo_bin("untangle")
+        .unwrap()
+        .args(args)
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+
+    let json: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    json["edges"].as_array().unwrap().clone()
+}
+
+fn edge<'a>(edges: &'a [serde_json::Value], from: &str, to: &str) -> Option<&'a serde_json::Value> {
+    edges.iter().find(|edge| {
+        edge["from"] == format!("cfg_crate.src.{from}")
+            && edge["to"] == format!("cfg_crate.src.{to}")
+    })
+}
+
+#[test]
+fn analyze_graph_tags_cfg_gated_rust_edges() {
+    let edges = graph_edges(&[]);
+
+    let gated = edge(&edges, "store", "yaml").expect("feature-gated use edge");
+    assert_eq!(gated["cfg"], "feature = \"yaml\"");
+    assert_eq!(gated["source_locations"][0]["cfg"], "feature = \"yaml\"");
+
+    let test_only = edge(&edges, "store", "test_support").expect("test module edge");
+    assert_eq!(test_only["cfg"], "test");
+
+    let unconditional = edge(&edges, "store", "json").expect("plain use edge");
+    assert!(unconditional.get("cfg").is_none());
+}
+
+#[test]
+fn analyze_graph_exclude_cfg_drops_test_only_edges() {
+    let edges = graph_edges(&["--exclude-cfg", "test"]);
+
+    assert!(edge(&edges, "lib", "test_support").is_none());
+    assert!(edge(&edges, "store", "test_support").is_none());
+    assert!(edge(&edges, "store", "yaml").is_some());
+    assert!(edge(&edges, "store", "json").is_some());
+}
+
+#[test]
+fn analyze_report_cfg_feature_set_excludes_other_features() {
+    let output = Command::cargo_bin("untangle")
+        .unwrap()
+        .args([
+            "analyze",
+            "report",
+            "tests/fixtures/rust/cfg_crate",
+            "--lang",
+            "rust",
+            "--format",
+            "json",
+            "--quiet",
+            "--cfg",
+            "feature=fast",
+            "--exclude-cfg",
+            "test",
+        ])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+
+    let json: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    // store -> json; store's `yaml` and `test_support` imports are only
+    // compiled with the `yaml` feature or under test.
+    assert_eq!(json["metadata"]["edge_count"], 1);
+}
@@ -0,0 +1,117 @@
+use assert_cmd::Command;
+use std::path::{Path, PathBuf};
+
+fn fixture_path(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("fixtures")
+        .join(name)
+}
+
+fn copy_dir_recursive(src: &Path, dst: &Path) {
+    for entry in std::fs::read_dir(src).unwrap() {
+        let entry = entry.unwrap();
+        let path = entry.path();
+        let dest_path = dst.join(entry.file_name());
+        if path.is_dir() {
+            std::fs::create_dir_all(&dest_path).unwrap();
+            copy_dir_recursive(&path, &dest_path);
+        } else {
+            std::fs::copy(&path, &dest_path).unwrap();
+        }
+    }
+}
+
+fn with_mod_edges(fixture: &str) -> tempfile::TempDir {
+    let tmp = tempfile::tempdir().unwrap();
+    copy_dir_recursive(&fixture_path(fixture), tmp.path());
+    std::fs::write(
+        tmp.path().join(".untangle.toml"),
+        "[rust]\nmod_edges = true\n",
+    )
+    .unwrap();
+    tmp
+}
+
+fn run_json(args: &[&str]) -> serde_json::Value {
+    let output = Command::cargo_bin("untangle")
+        .unwrap()
+        .args(args)
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    serde_json::from_slice(&output).unwrap()
+}
+
+#[test]
+fn analyze_report_child_using_parent_module_is_not_a_cycle() {
+    let json = run_json(&[
+        "analyze",
+        "report",
+        "tests/fixtures/rust/nested_mods",
+        "--lang",
+        "rust",
+        "--format",
+        "json",
+        "--quiet",
+    ]);
+
+    // net/tcp.rs `use super::Transport;` is the only edge; `mod tcp;` is not.
+    assert_eq!(json["metadata"]["edge_count"], 1);
+    assert_eq!(json["summary"]["scc_count"], 0);
+}
+
+#[test]
+fn analyze_report_mod_edges_opt_in_closes_parent_child_cycle() {
+    let tmp = with_mod_edges("rust/nested_mods");
+    let json = run_json(&[
+        "analyze",
+        "report",
+        tmp.path().to_str().unwrap(),
+        "--lang",
+        "rust",
+        "--format",
+        "json",
+        "--quiet",
+    ]);
+
+    assert_eq!(json["summary"]["scc_count"], 1);
+    assert_eq!(json["summary"]["largest_scc_size"], 2);
+}
+
+#[test]
+fn analyze_graph_mod_edges_opt_in_adds_containment_edges() {
+    let tmp = with_mod_edges("rust/simple_crate");
+    let json = run_json(&[
+        "analyze",
+        "graph",
+        tmp.path().join("src").to_str().unwrap(),
+        "--lang",
+        "rust",
+        "--format",
+        "json",
+        "--quiet",
+    ]);
+
+    // `mod models;` in main.rs without a `use` is a containment edge.
+    let edges = json["edges"].as_array().unwrap();
+    assert_eq!(edges.len(), 3);
+    let containment = edges
+        .iter()
+        .find(|edge| {
+            edge["from"].as_str().unwrap().ends_with("src.main")
+                && edge["to"].as_str().unwrap().ends_with("src.models")
+        })
+        .expect("mod declaration edge");
+    assert_eq!(containment["kind"], "contains");
+    let import = edges
+        .iter()
+        .find(|edge| {
+            edge["from"].as_str().unwrap().ends_with("src.main")
+                && edge["to"].as_str().unwrap().ends_with("src.utils")
+        })
+        .expect("use edge");
+    assert!(import.get("kind").is_none());
+}
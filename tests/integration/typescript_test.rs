@@ -0,0 +1,142 @@
+use assert_cmd::Command;
+
+fn graph_edges(args: &[&str]) -> Vec<(String, String)> {
+    let output = Command::cargo_bin("untangle")
+        .unwrap()
+        .args(args)
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+
+    let json: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    json["edges"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|edge| {
+            (
+                edge["from"].as_str().unwrap().to_string(),
+                edge["to"].as_str().unwrap().to_string(),
+            )
+        })
+        .collect()
+}
+
+fn has_edge(edges: &[(String, String)], from: &str, to: &str) -> bool {
+    edges.iter().any(|(f, t)| f == from && t == to)
+}
+
+#[test]
+fn analyze_graph_typescript_monorepo_resolves_workspaces_paths_and_barrels() {
+    let edges = graph_edges(&[
+        "analyze",
+        "graph",
+        "tests/fixtures/typescript/monorepo",
+        "--format",
+        "json",
+        "--quiet",
+    ]);
+
+    // package.json workspace import resolved to the package's src/index.ts
+    assert!(has_edge(
+        &edges,
+        "apps.web.src.main",
+        "packages.core.src.index"
+    ));
+    // tsconfig `paths` alias resolved to an index barrel
+    assert!(has_edge(
+        &edges,
+        "apps.web.src.main",
+        "apps.web.src.components.index"
+    ));
+    // dynamic import() with a literal specifier
+    assert!(has_edge(
+        &edges,
+        "apps.web.src.main",
+        "apps.web.src.pages.about"
+    ));
+    // require() of a workspace package subpath from a .js file
+    assert!(has_edge(
+        &edges,
+        "apps.web.src.pages.about",
+        "packages.core.src.format"
+    ));
+    // export-from re-exports and `.js` specifiers pointing at .ts sources
+    assert!(has_edge(
+        &edges,
+        "packages.core.src.index",
+        "packages.core.src.parse"
+    ));
+    assert!(has_edge(
+        &edges,
+        "packages.core.src.parse",
+        "packages.core.src.format"
+    ));
+}
+
+#[test]
+fn analyze_report_typescript_excludes_tests_and_counts_externals() {
+    let output = Command::cargo_bin("untangle")
+        .unwrap()
+        .args([
+            "analyze",
+            "report",
+            "tests/fixtures/typescript/monorepo",
+            "--lang",
+            "typescript",
+            "--format",
+            "json",
+            "--quiet",
+        ])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+
+    let json: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    assert_eq!(json["metadata"]["language"], "typescript");
+    assert_eq!(json["metadata"]["files_parsed"], 7);
+    assert_eq!(json["metadata"]["edge_count"], 8);
+    assert_eq!(json["metadata"]["unresolved_imports"], 1);
+}
+
+#[test]
+fn analyze_graph_typescript_include_tests_adds_spec_files() {
+    let edges = graph_edges(&[
+        "analyze",
+        "graph",
+        "tests/fixtures/typescript/monorepo",
+        "--lang",
+        "ts",
+        "--include-tests",
+        "--format",
+        "json",
+        "--quiet",
+    ]);
+
+    assert!(has_edge(
+        &edges,
+        "apps.web.src.main.test",
+        "apps.web.src.main"
+    ));
+}
+
+#[test]
+fn analyze_graph_typescript_base_url_keeps_npm_packages_external() {
+    let edges = graph_edges(&[
+        "analyze",
+        "graph",
+        "tests/fixtures/typescript/monorepo",
+        "--lang",
+        "ts",
+        "--include-external",
+        "--format",
+        "json",
+        "--quiet",
+    ]);
+
+    assert!(has_edge(&edges, "apps.web.src.components.Header", "react"));
+}